@@ -1,4 +1,5 @@
 use thiserror::Error;
+use crate::diagnostic::{FieldRef, LocatedError, SyntaxError};
 
 #[derive(Error, Debug, PartialEq)]
 pub enum AdexpError {
@@ -34,5 +35,47 @@ pub enum AdexpError {
     
     #[error("Message too long: max {max} characters, got {got}")]
     MessageTooLong { max: usize, got: usize },
+    
+    #[error("Parse error: {0}")]
+    Syntax(Box<SyntaxError>),
 }
 
+impl LocatedError for AdexpError {
+    fn code(&self) -> &'static str {
+        match self {
+            AdexpError::ParseError(_) => "ADEXP001",
+            AdexpError::InvalidFormat(_) => "ADEXP002",
+            AdexpError::InvalidSection(_) => "ADEXP003",
+            AdexpError::InvalidField(_) => "ADEXP004",
+            AdexpError::MissingField(_) => "ADEXP005",
+            AdexpError::InvalidFieldValue(_) => "ADEXP006",
+            AdexpError::InvalidMessageType(_) => "ADEXP007",
+            AdexpError::SectionNotFound(_) => "ADEXP008",
+            AdexpError::FieldNotFound { .. } => "ADEXP009",
+            AdexpError::InvalidDateTime(_) => "ADEXP010",
+            AdexpError::MessageTooLong { .. } => "ADEXP011",
+            AdexpError::Syntax(_) => "ADEXP012",
+        }
+    }
+
+    fn syntax(&self) -> Option<&SyntaxError> {
+        match self {
+            AdexpError::Syntax(error) => Some(error),
+            _ => None,
+        }
+    }
+
+    fn field(&self) -> Option<FieldRef> {
+        match self {
+            AdexpError::InvalidField(field) | AdexpError::MissingField(field) => Some(FieldRef::Name(field.clone())),
+            AdexpError::FieldNotFound { field, .. } => Some(FieldRef::Name(field.clone())),
+            _ => self.syntax().and_then(|error| error.field.clone()),
+        }
+    }
+}
+
+impl From<SyntaxError> for AdexpError {
+    fn from(error: SyntaxError) -> Self {
+        AdexpError::Syntax(Box::new(error))
+    }
+}
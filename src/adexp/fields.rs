@@ -2,6 +2,10 @@
 //! Référence: https://www.eurocontrol.int/sites/default/files/2023-06/eurocontrol-released-specification-adexp-3-4.pdf
 
 use serde::{Deserialize, Serialize};
+use crate::adexp::error::AdexpError;
+use crate::adexp::message::Section;
+use crate::adexp::validation;
+use crate::datetime::{days_in_month, UtcDateTime};
 
 /// Champs primaires ADEXP selon la spécification 3.4
 /// Ces champs sont les principaux identifiants et informations de base
@@ -141,9 +145,9 @@ pub struct AddrField {
 /// Structure pour un champ VEC (Vector compound field)
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct VecField {
-    pub track_angle: Option<String>,
-    pub ground_speed: Option<String>,
-    pub altitude: Option<String>,
+    pub track_angle: Option<u16>,          // Angle de route en degrés (001-360)
+    pub ground_speed: Option<AdexpSpeed>,
+    pub altitude: Option<AdexpAltitude>,
 }
 
 /// Structure pour un point de route (RTEPTS)
@@ -151,14 +155,14 @@ pub struct VecField {
 pub struct RoutePoint {
     pub pt: Option<String>,           // Point identifier
     pub ptid: Option<String>,         // Point identifier alternative
-    pub lat: Option<String>,          // Latitude
-    pub lon: Option<String>,          // Longitude
-    pub fl: Option<String>,           // Flight level
-    pub eto: Option<String>,          // Estimated time over
-    pub atot: Option<String>,         // Actual time over
-    pub speed: Option<String>,        // Speed
-    pub alt: Option<String>,          // Altitude
-    pub dist: Option<String>,         // Distance
+    pub lat: Option<f64>,             // Latitude en degrés décimaux
+    pub lon: Option<f64>,             // Longitude en degrés décimaux
+    pub fl: Option<u16>,              // Niveau de vol (centaines de pieds)
+    pub eto: Option<AdexpTime>,       // Estimated time over
+    pub atot: Option<AdexpTime>,      // Actual time over
+    pub speed: Option<AdexpSpeed>,    // Speed
+    pub alt: Option<AdexpAltitude>,   // Altitude
+    pub dist: Option<f64>,            // Distance
     pub reason: Option<String>,       // Reason
     pub ahead: Option<String>,        // Ahead
 }
@@ -180,6 +184,277 @@ pub struct CstatField {
     pub statreason: Option<String>,
 }
 
+/// Date ADEXP (format DDMMYY, années 2000-2099)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AdexpDate {
+    pub year: u16,
+    pub month: u8,
+    pub day: u8,
+}
+
+/// Heure ADEXP (format HHMM ou HHMMSS)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AdexpTime {
+    pub hour: u8,
+    pub minute: u8,
+    pub second: u8,
+}
+
+/// Vitesse ADEXP
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AdexpSpeed {
+    /// Vitesse en nœuds (`450`, `N0450`, `450KT`)
+    Knots(u16),
+    
+    /// Vitesse en km/h (`K0830`)
+    KilometresPerHour(u16),
+    
+    /// Nombre de Mach en centièmes (`M082` = Mach 0.82)
+    Mach(u16),
+}
+
+/// Altitude ADEXP (ALT, ALTNZ)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AdexpAltitude {
+    /// Niveau de vol (`FL350`)
+    FlightLevel(u16),
+    
+    /// Altitude en pieds
+    Feet(u32),
+}
+
+impl AdexpDate {
+    /// Parse une date au format DDMMYY
+    pub fn parse(value: &str) -> Result<Self, AdexpError> {
+        validation::validate_date(value)?;
+        let date = AdexpDate {
+            year: 2000 + value[4..6].parse::<u16>().unwrap_or(0),
+            month: value[2..4].parse().unwrap_or(0),
+            day: value[0..2].parse().unwrap_or(0),
+        };
+        if date.day > days_in_month(date.year, date.month) {
+            return Err(AdexpError::InvalidDateTime(format!(
+                "Jour {} invalide pour le mois {} de l'année {}",
+                date.day, date.month, date.year
+            )));
+        }
+        Ok(date)
+    }
+    
+    /// Combine la date et l'heure en instant UTC (les secondes sont ignorées)
+    pub fn at(&self, time: &AdexpTime) -> UtcDateTime {
+        UtcDateTime {
+            year: self.year,
+            month: self.month,
+            day: self.day,
+            hour: time.hour,
+            minute: time.minute,
+        }
+    }
+}
+
+impl AdexpTime {
+    /// Parse une heure au format HHMM ou HHMMSS
+    pub fn parse(value: &str) -> Result<Self, AdexpError> {
+        match value.len() {
+            4 => validation::validate_time_hhmm(value)?,
+            6 => validation::validate_time_hhmmss(value)?,
+            _ => {
+                return Err(AdexpError::InvalidDateTime(format!(
+                    "Temps doit avoir 4 (HHMM) ou 6 chiffres (HHMMSS), reçu: {}",
+                    value
+                )))
+            }
+        }
+        Ok(AdexpTime {
+            hour: value[0..2].parse().unwrap_or(0),
+            minute: value[2..4].parse().unwrap_or(0),
+            second: value.get(4..6).and_then(|s| s.parse().ok()).unwrap_or(0),
+        })
+    }
+}
+
+impl AdexpSpeed {
+    pub fn parse(value: &str) -> Result<Self, AdexpError> {
+        let invalid = || AdexpError::InvalidFieldValue(format!("Format de vitesse invalide: {}", value));
+        let number = |digits: &str| -> Result<u16, AdexpError> {
+            if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+                return Err(invalid());
+            }
+            digits.parse().map_err(|_| invalid())
+        };
+        
+        validation::validate_speed(value)?;
+        if let Some(mach) = value.strip_prefix('M') {
+            return Ok(AdexpSpeed::Mach(number(mach)?));
+        }
+        if let Some(knots) = value.strip_prefix('N') {
+            return Ok(AdexpSpeed::Knots(number(knots)?));
+        }
+        if let Some(kmh) = value.strip_prefix('K') {
+            return Ok(AdexpSpeed::KilometresPerHour(number(kmh)?));
+        }
+        let knots = value.strip_suffix("KTS").or_else(|| value.strip_suffix("KT")).unwrap_or(value);
+        Ok(AdexpSpeed::Knots(number(knots)?))
+    }
+}
+
+impl AdexpAltitude {
+    pub fn parse(value: &str) -> Result<Self, AdexpError> {
+        validation::validate_altitude(value)?;
+        if value.starts_with("FL") {
+            return Ok(AdexpAltitude::FlightLevel(parse_flight_level(value)?));
+        }
+        value.parse().map(AdexpAltitude::Feet).map_err(|_| {
+            AdexpError::InvalidFieldValue(format!("Altitude invalide: {}", value))
+        })
+    }
+}
+
+/// Parse un niveau de vol (`FL350`, `F350` ou `350`) en centaines de pieds
+pub fn parse_flight_level(value: &str) -> Result<u16, AdexpError> {
+    let level = value.strip_prefix("FL").or_else(|| value.strip_prefix('F')).unwrap_or(value);
+    validation::validate_flight_level(level)?;
+    level.parse().map_err(|_| {
+        AdexpError::InvalidFieldValue(format!("Niveau de vol invalide: {}", value))
+    })
+}
+
+/// Parse une latitude en degrés décimaux signés
+pub fn parse_latitude(value: &str) -> Result<f64, AdexpError> {
+    validation::validate_latitude(value)?;
+    value.parse().map_err(|_| AdexpError::InvalidFieldValue(format!("Latitude invalide: {}", value)))
+}
+
+/// Parse une longitude en degrés décimaux signés
+pub fn parse_longitude(value: &str) -> Result<f64, AdexpError> {
+    validation::validate_longitude(value)?;
+    value.parse().map_err(|_| AdexpError::InvalidFieldValue(format!("Longitude invalide: {}", value)))
+}
+
+/// Valeur d'indice `index` d'un sous-champ, convertie si présente
+fn indexed<T>(
+    section: &Section,
+    field: &str,
+    index: usize,
+    parse: impl Fn(&str) -> Result<T, AdexpError>,
+) -> Result<Option<T>, AdexpError> {
+    match section.fields.get(field).and_then(|values| values.get(index)) {
+        Some(value) if !value.is_empty() => parse(value).map(Some),
+        _ => Ok(None),
+    }
+}
+
+fn text(value: &str) -> Result<String, AdexpError> {
+    Ok(value.to_string())
+}
+
+impl AddrField {
+    /// Construit la liste d'adresses d'une section ADDR
+    /// 
+    /// Chaque `-ADDR` est associé au `-FAC` de même rang. Une section ne
+    /// contenant que des `-FAC` (exemple de l'Annexe F) liste directement
+    /// les adresses des organismes destinataires.
+    pub fn from_section(section: &Section) -> Vec<Self> {
+        let facilities = section.fields.get("FAC");
+        match section.fields.get("ADDR") {
+            Some(addresses) => addresses
+                .iter()
+                .enumerate()
+                .map(|(i, address)| AddrField {
+                    address: address.clone(),
+                    facility: facilities.and_then(|f| f.get(i)).cloned(),
+                })
+                .collect(),
+            None => facilities
+                .into_iter()
+                .flatten()
+                .map(|address| AddrField { address: address.clone(), facility: None })
+                .collect(),
+        }
+    }
+}
+
+impl VecField {
+    /// Construit le vecteur depuis une section VEC
+    pub fn from_section(section: &Section) -> Result<Self, AdexpError> {
+        Ok(VecField {
+            track_angle: indexed(section, "TRACKANGLE", 0, |v| {
+                validation::validate_track_angle(v)?;
+                v.parse().map_err(|_| AdexpError::InvalidFieldValue(format!("Angle de route invalide: {}", v)))
+            })?,
+            ground_speed: indexed(section, "GROUNDSPEED", 0, AdexpSpeed::parse)?,
+            altitude: indexed(section, "ALT", 0, AdexpAltitude::parse)?,
+        })
+    }
+}
+
+impl RoutePoint {
+    /// Construit les points de route depuis une section RTEPTS
+    /// 
+    /// Les sous-champs sont associés par rang: la n-ième occurrence de
+    /// `-FL` appartient au n-ième point (`-PT`/`-PTID`, ou `-LAT`/`-LON`).
+    /// Un sous-champ présent sur certains points seulement est donc
+    /// rattaché aux premiers points de la section ; un message parsé passe
+    /// plutôt par les éléments de sa liste (`AdexpMessage::route_points`).
+    pub fn from_section(section: &Section) -> Result<Vec<Self>, AdexpError> {
+        let count = |field: &str| section.fields.get(field).map_or(0, Vec::len);
+        let points = count("PT").max(count("PTID")).max(count("LAT"));
+        
+        (0..points)
+            .map(|i| {
+                Ok(RoutePoint {
+                    pt: indexed(section, "PT", i, text)?,
+                    ptid: indexed(section, "PTID", i, text)?,
+                    lat: indexed(section, "LAT", i, parse_latitude)?,
+                    lon: indexed(section, "LON", i, parse_longitude)?,
+                    fl: indexed(section, "FL", i, parse_flight_level)?,
+                    eto: indexed(section, "ETO", i, AdexpTime::parse)?,
+                    atot: indexed(section, "ATOT", i, AdexpTime::parse)?,
+                    speed: indexed(section, "SPEED", i, AdexpSpeed::parse)?,
+                    alt: indexed(section, "ALT", i, AdexpAltitude::parse)?,
+                    dist: indexed(section, "DIST", i, |v| {
+                        validation::validate_distance(v)?;
+                        v.parse().map_err(|_| AdexpError::InvalidFieldValue(format!("Distance invalide: {}", v)))
+                    })?,
+                    reason: indexed(section, "REASON", i, text)?,
+                    ahead: indexed(section, "AHEAD", i, text)?,
+                })
+            })
+            .collect()
+    }
+    
+    /// Identifiant du point (PTID, sinon PT)
+    pub fn name(&self) -> Option<&str> {
+        self.ptid.as_deref().or(self.pt.as_deref())
+    }
+}
+
+impl RefDataField {
+    /// Construit les données de référence depuis une section REFDATA
+    pub fn from_section(section: &Section) -> Self {
+        let first = |field: &str| section.fields.get(field).and_then(|v| v.first()).cloned();
+        RefDataField {
+            ifplid: first("IFPLID"),
+            origin: first("ORIGIN"),
+            fac: first("FAC"),
+            networktype: first("NETWORKTYPE"),
+        }
+    }
+}
+
+impl CstatField {
+    /// Construit le statut depuis une section CSTAT (`-CODE` porte le statut)
+    pub fn from_section(section: &Section) -> Self {
+        let first = |field: &str| section.fields.get(field).and_then(|v| v.first()).cloned();
+        CstatField {
+            status: first("CODE").or_else(|| first("CSTAT")),
+            reason: first("REASON"),
+            statreason: first("STATREASON"),
+        }
+    }
+}
+
 /// Liste complète des champs ADEXP selon la spécification 3.4
 pub struct AdexpFields;
 
@@ -225,7 +500,11 @@ impl AdexpFields {
             "ALTN" | "ALTT" | "ALTZ" | "ALTA" | "ALTS" | "ALTR" |
             "RMK" | "RMKS" | "COMMENT" | "TEXT" | "CODE" | "CODEICAO" |
             "GEO" | "GEONAME" | "LAT" | "LON" | "ALT" | "ALTNZ" | "DIST" |
-            "NUM" | "TIMEHHMM" | "TIMEHHMMSS" | "DATE" | "REASON" | "AHEAD" | "STATREASON"
+            "NUM" | "TIMEHHMM" | "TIMEHHMMSS" | "DATE" | "REASON" | "AHEAD" | "STATREASON" |
+            // Correspondance avec les champs OACI (types 7, 9, 14, 17 et 18)
+            "SSRCODE" | "NBARC" | "ESTDATA" | "SFL" | "ADARR" | "ATA" |
+            "STS" | "DEPZ" | "DESTZ" | "TYPZ" | "ARCADDR" | "DLE" | "ORGN" |
+            "RALT" | "TALT" | "RIF" | "RVR" | "RFP"
         )
     }
     
@@ -251,7 +530,7 @@ impl AdexpFields {
     /// Vérifie si un nom de champ est un champ composé valide
     pub fn is_compound_field(field_name: &str) -> bool {
         matches!(field_name,
-            "ADDR" | "REFDATA" | "CSTAT" | "VEC" | "RTEPTS"
+            "ADDR" | "REFDATA" | "CSTAT" | "VEC" | "RTEPTS" | "ESTDATA"
         )
     }
     
@@ -0,0 +1,648 @@
+//! Conversion entre messages ADEXP et messages ATS OACI (FPL, CPL, CHG, DLA,
+//! CNL, DEP, ARR, EST, ABI)
+//!
+//! La correspondance suit les annexes de la spécification ADEXP 3.4 :
+//!
+//! | OACI          | ADEXP                                   |
+//! |---------------|-----------------------------------------|
+//! | 3             | TITLE (CPL ↔ ACT)                       |
+//! | 7             | ARCID, SSRCODE                          |
+//! | 8             | FLTRUL, FLTTYP                          |
+//! | 9             | NBARC, ARCTYP, WKTRC                    |
+//! | 10            | CEQPT, SEQPT                            |
+//! | 13            | ADEP, EOBT (ATD pour un DEP)            |
+//! | 14            | ESTDATA (-PTID -ETO -FL -SFL)           |
+//! | 15            | SPEED, RFL, ROUTE                       |
+//! | 16            | ADES, TTLEET, ALTRNT1, ALTRNT2          |
+//! | 17            | ADARR, ATA                              |
+//! | 18 DOF/       | EOBD                                    |
+//! | 18 (autres)   | STS, PBN, ..., DEPZ, DESTZ, TYPZ, RMK   |
+//! | 22            | champs ADEXP du Field Type modifié      |
+//!
+//! Les données sans équivalent dans le format cible ne bloquent pas la
+//! conversion : elles sont listées dans `Conversion::losses`.
+
+use serde::{Deserialize, Serialize};
+use crate::adexp::error::AdexpError;
+use crate::adexp::message::{AdexpItem, AdexpMessage};
+use crate::adexp::parser::AdexpParser;
+use crate::aftn::categories::flight_plan::ats::{message_field_numbers, AtsMessage};
+use crate::aftn::categories::flight_plan::ats_fields::{CruisingLevel, Route};
+
+/// Éléments du Field Type 18 et champs ADEXP correspondants (hors DOF/)
+const FIELD18_MAPPING: &[(&str, &str)] = &[
+    ("STS", "STS"), ("PBN", "PBN"), ("NAV", "NAV"), ("COM", "COM"), ("DAT", "DAT"),
+    ("SUR", "SUR"), ("DEP", "DEPZ"), ("DEST", "DESTZ"), ("REG", "REG"), ("EET", "EET"),
+    ("SEL", "SEL"), ("TYP", "TYPZ"), ("CODE", "ARCADDR"), ("DLE", "DLE"), ("OPR", "OPR"),
+    ("ORGN", "ORGN"), ("PER", "PER"), ("ALTN", "ALTNZ"), ("RALT", "RALT"), ("TALT", "TALT"),
+    ("RIF", "RIF"), ("RMK", "RMK"), ("RVR", "RVR"), ("RFP", "RFP"), ("SRC", "SRC"),
+    ("IFP", "IFPSDISCREPANCY"),
+];
+
+/// Donnée du message source sans équivalent dans le format cible
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConversionLoss {
+    /// Champ source (nom ADEXP, ou numéro de Field Type OACI avec l'indicateur)
+    pub field: String,
+
+    /// Valeur non reprise
+    pub value: String,
+
+    /// Raison de la perte
+    pub reason: String,
+}
+
+/// Résultat d'une conversion avec les données non représentables
+#[derive(Debug, Clone, PartialEq)]
+pub struct Conversion<T> {
+    /// Message converti
+    pub message: T,
+
+    /// Données du message source absentes du message converti
+    pub losses: Vec<ConversionLoss>,
+}
+
+impl<T> Conversion<T> {
+    /// Indique si toutes les données du message source ont été reprises
+    pub fn is_lossless(&self) -> bool {
+        self.losses.is_empty()
+    }
+}
+
+fn loss(field: impl Into<String>, value: impl Into<String>, reason: impl Into<String>) -> ConversionLoss {
+    ConversionLoss {
+        field: field.into(),
+        value: value.into(),
+        reason: reason.into(),
+    }
+}
+
+/// Texte d'un Field Type OACI et champs ADEXP dont il provient
+struct IcaoField {
+    text: String,
+    sources: Vec<&'static str>,
+}
+
+impl AdexpMessage {
+    /// Construit le message ADEXP équivalent à un message ATS OACI
+    pub fn from_ats(ats: &AtsMessage) -> Result<Conversion<AdexpMessage>, AdexpError> {
+        let mut writer = AdexpWriter::default();
+        let designator = ats.message_type.designator.as_str();
+
+        writer.push("TITLE", if designator == "CPL" { "ACT" } else { designator });
+        if let Some(number) = &ats.message_type.number {
+            writer.lost("3", number, "Numéro de message sans équivalent ADEXP");
+        }
+        if let Some(reference) = &ats.message_type.reference {
+            writer.lost("3", reference, "Référence de message sans équivalent ADEXP");
+        }
+
+        for number in message_field_numbers(designator).unwrap_or_default() {
+            match number {
+                19 => {
+                    for item in ats.supplementary_information.iter().flat_map(|f| &f.items) {
+                        writer.lost(
+                            format!("19 {}/", item.indicator),
+                            &item.value,
+                            "Renseignements complémentaires non transmis en ADEXP",
+                        );
+                    }
+                }
+                22 => {
+                    for amendment in &ats.amendments {
+                        if matches!(amendment.field_number, 3 | 7 | 13 | 16) {
+                            writer.lost(
+                                format!("22 {}/", amendment.field_number),
+                                &amendment.content,
+                                "Modification d'un champ d'identification du vol sans équivalent ADEXP",
+                            );
+                            continue;
+                        }
+                        let mut amended = ats.clone();
+                        amended.set_field(amendment.field_number, &amendment.content)
+                            .map_err(|e| AdexpError::InvalidFieldValue(format!(
+                                "Amendement {} invalide: {}", amendment.serialize(), e
+                            )))?;
+                        writer.write_field(&amended, amendment.field_number);
+                    }
+                }
+                _ => writer.write_field(ats, number),
+            }
+        }
+
+        let mut text = String::from("-ADEXP\n");
+        for (name, value) in &writer.lines {
+            text.push('-');
+            text.push_str(name);
+            if !value.is_empty() {
+                text.push(' ');
+                text.push_str(value);
+            }
+            text.push('\n');
+        }
+        Ok(Conversion {
+            message: AdexpParser::parse_message(&text)?,
+            losses: writer.losses,
+        })
+    }
+
+    /// Construit le message ATS OACI équivalent (FPL, CPL, CHG, DLA, CNL,
+    /// DEP, ARR, EST ou ABI selon le TITLE)
+    pub fn to_ats(&self) -> Result<Conversion<AtsMessage>, AdexpError> {
+        let title = self.get_title()?.to_uppercase();
+        let designator = match title.as_str() {
+            "ACT" => "CPL",
+            "IFPL" | "ICHG" | "IDLA" | "ICNL" | "IDEP" | "IARR" => &title[1..],
+            "FPL" | "CHG" | "DLA" | "CNL" | "DEP" | "ARR" | "EST" | "ABI" => title.as_str(),
+            _ => {
+                return Err(AdexpError::InvalidMessageType(format!(
+                    "Pas de message OACI équivalent au type {}",
+                    title
+                )))
+            }
+        };
+        let numbers = message_field_numbers(designator).unwrap_or_default();
+
+        let mut used = vec!["TITLE"];
+        let mut fields = Vec::new();
+        let mut amendments = Vec::new();
+        for number in [7, 8, 9, 10, 13, 14, 15, 16, 17, 18] {
+            let field = match number {
+                // Dans un CHG, le Field Type 18 ne porte que la date du vol,
+                // les autres éléments sont des amendements
+                18 if designator == "CHG" => {
+                    if let Some(field) = self.icao_other_information(false, true) {
+                        amendments.push((18, field));
+                    }
+                    self.icao_other_information(true, false)
+                }
+                18 => self.icao_other_information(true, true),
+                _ => self.icao_field(number, designator),
+            };
+            let Some(field) = field else { continue };
+
+            if numbers.contains(&number) {
+                fields.push((number, field));
+            } else if numbers.contains(&22) {
+                amendments.push((number, field));
+            }
+        }
+        if matches!(designator, "FPL" | "CPL") && !fields.iter().any(|(n, _)| *n == 18) {
+            fields.push((18, IcaoField { text: "0".to_string(), sources: Vec::new() }));
+        }
+        amendments.sort_by_key(|(number, _)| *number);
+
+        let mut text = format!("({}", designator);
+        for number in &numbers {
+            if *number == 22 {
+                for (amended, field) in &amendments {
+                    text.push_str(&format!("-{}/{}", amended, field.text));
+                    used.extend(&field.sources);
+                }
+            }
+            for (_, field) in fields.iter().filter(|(n, _)| n == number) {
+                text.push('-');
+                text.push_str(&field.text);
+                used.extend(&field.sources);
+            }
+        }
+        text.push(')');
+
+        let message = AtsMessage::parse(&text).map_err(|e| {
+            AdexpError::InvalidFormat(format!("Message OACI invalide ({}): {}", text, e))
+        })?;
+
+        let mut losses = Vec::new();
+        for (name, value) in self.top_level_fields() {
+            if !used.contains(&name.as_str()) {
+                losses.push(loss(
+                    name,
+                    value,
+                    format!("Aucun champ OACI équivalent dans un message {}", designator),
+                ));
+            }
+        }
+        Ok(Conversion { message, losses })
+    }
+
+    /// Champs et listes de premier niveau, dans l'ordre du message
+    fn top_level_fields(&self) -> Vec<(String, String)> {
+        if !self.items.is_empty() {
+            return self.items.iter()
+                .map(|item| match item {
+                    AdexpItem::Field(field) if field.subfields.is_empty() => {
+                        (field.name.clone(), field.value.clone())
+                    }
+                    AdexpItem::Field(field) => {
+                        let value = field.subfields.iter()
+                            .map(|sub| format!("-{} {}", sub.name, sub.value).trim_end().to_string())
+                            .collect::<Vec<_>>()
+                            .join(" ");
+                        (field.name.clone(), value)
+                    }
+                    AdexpItem::List(list) => (list.name.clone(), String::new()),
+                })
+                .collect();
+        }
+
+        let mut fields: Vec<(String, String)> = self.sections.values()
+            .flat_map(|section| {
+                section.fields.iter()
+                    .flat_map(|(name, values)| values.iter().map(move |value| (name.clone(), value.clone())))
+            })
+            .collect();
+        fields.sort();
+        fields
+    }
+
+    /// Texte d'un Field Type OACI (hors 18) à partir des champs ADEXP
+    fn icao_field(&self, number: u8, designator: &str) -> Option<IcaoField> {
+        let value = |name: &str| self.main_value(name);
+        let field = |text: String, sources: Vec<&'static str>| Some(IcaoField { text, sources });
+
+        match number {
+            7 => {
+                let arcid = value("ARCID")?;
+                match value("SSRCODE") {
+                    Some(code) => field(format!("{}/{}", arcid, code), vec!["ARCID", "SSRCODE"]),
+                    None => field(arcid.to_string(), vec!["ARCID"]),
+                }
+            }
+            8 => {
+                let rules = value("FLTRUL")?;
+                let kind = value("FLTTYP").unwrap_or("");
+                field(format!("{}{}", rules, kind), vec!["FLTRUL", "FLTTYP"])
+            }
+            9 => {
+                let arctyp = value("ARCTYP")?;
+                let count = value("NBARC").unwrap_or("");
+                let wake = value("WKTRC")?;
+                field(format!("{}{}/{}", count, arctyp, wake), vec!["NBARC", "ARCTYP", "WKTRC"])
+            }
+            10 => {
+                let ceqpt = value("CEQPT")?;
+                let seqpt = value("SEQPT").unwrap_or("N");
+                field(format!("{}/{}", ceqpt, seqpt), vec!["CEQPT", "SEQPT"])
+            }
+            13 => {
+                let adep = value("ADEP")?;
+                let (time, source) = match value("ATD") {
+                    Some(atd) if designator == "DEP" => (atd, "ATD"),
+                    _ => (value("EOBT").unwrap_or(""), "EOBT"),
+                };
+                field(format!("{}{}", adep, time), vec!["ADEP", source])
+            }
+            14 => {
+                let estdata = AdexpParser::split_inline_fields(value("ESTDATA")?);
+                let sub = |name: &str| {
+                    estdata.iter().find(|(n, _)| n == name).map(|(_, v)| v.as_str())
+                };
+                let level = sub("FL").map(normalise_level)?;
+                let supplementary = sub("SFL").map(normalise_level).unwrap_or_default();
+                let eto = sub("ETO")?;
+                let eto = eto.get(eto.len().saturating_sub(4)..).unwrap_or(eto);
+                field(format!("{}/{}{}{}", sub("PTID")?, eto, level, supplementary), vec!["ESTDATA"])
+            }
+            15 => {
+                let route = value("ROUTE");
+                if let Some(route) = route.and_then(|r| Route::parse(r).ok()) {
+                    let mut sources = vec!["ROUTE"];
+                    // SPEED et RFL répètent la vitesse et le niveau initiaux
+                    if value("SPEED") == Some(route.initial.speed.serialize().as_str()) {
+                        sources.push("SPEED");
+                    }
+                    if value("RFL").map(normalise_level) == Some(route.initial.level.serialize()) {
+                        sources.push("RFL");
+                    }
+                    return field(route.serialize(), sources);
+                }
+                let speed = value("SPEED")?;
+                let level = normalise_level(value("RFL")?);
+                field(
+                    format!("{}{} {}", speed, level, route.unwrap_or("DCT")),
+                    vec!["SPEED", "RFL", "ROUTE"],
+                )
+            }
+            16 => {
+                let mut text = value("ADES")?.to_string();
+                if let Some(eet) = value("TTLEET") {
+                    text.push_str(eet);
+                }
+                for alternate in [value("ALTRNT1"), value("ALTRNT2")].into_iter().flatten() {
+                    text.push(' ');
+                    text.push_str(alternate);
+                }
+                // Dans un ARR, le Field Type 16 n'apparaît qu'en cas de déroutement
+                if designator == "ARR" && value("ADARR").is_none_or(|adarr| Some(adarr) == value("ADES")) {
+                    return None;
+                }
+                field(text, vec!["ADES", "TTLEET", "ALTRNT1", "ALTRNT2"])
+            }
+            17 => {
+                let (aerodrome, aerodrome_source) = match value("ADARR") {
+                    Some(adarr) => (adarr, "ADARR"),
+                    None => (value("ADES")?, "ADES"),
+                };
+                let (time, time_source) = match value("ATA") {
+                    Some(ata) => (ata, "ATA"),
+                    None => (value("ACTARR")?, "ACTARR"),
+                };
+                field(format!("{}{}", aerodrome, time), vec![aerodrome_source, time_source])
+            }
+            _ => None,
+        }
+    }
+
+    /// Field Type 18, dans l'ordre des champs ADEXP, avec ou sans la date
+    /// du vol (DOF/) et les autres éléments
+    fn icao_other_information(&self, with_dof: bool, with_others: bool) -> Option<IcaoField> {
+        let mut items = Vec::new();
+        let mut sources = Vec::new();
+        for (name, value) in self.top_level_fields() {
+            if name == "EOBD" {
+                if let Some(dof) = eobd_to_dof(&value).filter(|_| with_dof && !sources.contains(&"EOBD")) {
+                    items.push(format!("DOF/{}", dof));
+                    sources.push("EOBD");
+                }
+            } else if let Some((indicator, adexp)) = FIELD18_MAPPING.iter()
+                .find(|(_, adexp)| *adexp == name)
+                .filter(|_| with_others)
+            {
+                items.push(format!("{}/{}", indicator, value));
+                sources.push(*adexp);
+            }
+        }
+        if items.is_empty() {
+            return None;
+        }
+        Some(IcaoField { text: items.join(" "), sources })
+    }
+}
+
+/// Lignes ADEXP produites depuis un message OACI
+#[derive(Default)]
+struct AdexpWriter {
+    lines: Vec<(&'static str, String)>,
+    losses: Vec<ConversionLoss>,
+}
+
+impl AdexpWriter {
+    fn push(&mut self, name: &'static str, value: impl Into<String>) {
+        self.lines.push((name, value.into()));
+    }
+
+    fn lost(&mut self, field: impl Into<String>, value: impl Into<String>, reason: &str) {
+        self.losses.push(loss(field, value, reason));
+    }
+
+    /// Champs ADEXP d'un Field Type OACI
+    fn write_field(&mut self, ats: &AtsMessage, number: u8) {
+        match number {
+            7 => {
+                let id = &ats.aircraft_identification;
+                self.push("ARCID", &id.aircraft_id);
+                if let Some(code) = &id.ssr_code {
+                    self.push("SSRCODE", format!("{}{}", id.ssr_mode.unwrap_or('A'), code));
+                }
+            }
+            8 => {
+                if let Some(rules) = &ats.flight_rules {
+                    self.push("FLTRUL", rules.flight_rules.to_string());
+                    if let Some(kind) = rules.flight_type {
+                        self.push("FLTTYP", kind.to_string());
+                    }
+                }
+            }
+            9 => {
+                if let Some(aircraft) = &ats.aircraft {
+                    if let Some(count) = aircraft.number {
+                        self.push("NBARC", count.to_string());
+                    }
+                    self.push("ARCTYP", &aircraft.aircraft_type);
+                    self.push("WKTRC", aircraft.wake_turbulence.code().to_string());
+                }
+            }
+            10 => {
+                if let Some(equipment) = &ats.equipment {
+                    self.push("CEQPT", equipment.radio_nav.concat());
+                    self.push("SEQPT", equipment.surveillance.concat());
+                }
+            }
+            13 => {
+                if let Some(departure) = &ats.departure {
+                    self.push("ADEP", &departure.aerodrome);
+                    if let Some(time) = departure.time {
+                        let name = if ats.message_type.designator == "DEP" { "ATD" } else { "EOBT" };
+                        self.push(name, time.serialize());
+                    }
+                }
+            }
+            14 => {
+                if let Some(estimate) = &ats.estimate {
+                    let mut value = format!(
+                        "-PTID {} -ETO {} -FL {}",
+                        estimate.boundary_point,
+                        estimate.time.serialize(),
+                        estimate.cleared_level.serialize()
+                    );
+                    if let Some(level) = estimate.supplementary_level {
+                        value.push_str(&format!(" -SFL {}", level.serialize()));
+                    }
+                    if let Some(condition) = estimate.crossing_condition {
+                        self.lost("14", condition.to_string(), "Condition de franchissement sans équivalent ADEXP");
+                    }
+                    self.push("ESTDATA", value);
+                }
+            }
+            15 => {
+                if let Some(route) = &ats.route {
+                    self.push("SPEED", route.initial.speed.serialize());
+                    self.push("RFL", route.initial.level.serialize());
+                    self.push("ROUTE", route.serialize());
+                }
+            }
+            16 => {
+                if let Some(destination) = &ats.destination {
+                    self.push("ADES", &destination.aerodrome);
+                    if let Some(eet) = destination.total_eet {
+                        self.push("TTLEET", eet.serialize());
+                    }
+                    for (name, alternate) in ["ALTRNT1", "ALTRNT2"].into_iter().zip(&destination.alternates) {
+                        self.push(name, alternate);
+                    }
+                }
+            }
+            17 => {
+                if let Some(arrival) = &ats.arrival {
+                    self.push("ADARR", &arrival.aerodrome);
+                    if let Some(time) = arrival.time {
+                        self.push("ATA", time.serialize());
+                    }
+                }
+            }
+            18 => {
+                for item in ats.other_information.iter().flat_map(|f| &f.items) {
+                    if item.indicator == "DOF" {
+                        match dof_to_eobd(&item.value) {
+                            Some(eobd) => self.push("EOBD", eobd),
+                            None => self.lost("18 DOF/", &item.value, "Date du vol invalide"),
+                        }
+                        continue;
+                    }
+                    match FIELD18_MAPPING.iter().find(|(indicator, _)| *indicator == item.indicator) {
+                        Some((_, adexp)) => self.push(adexp, &item.value),
+                        None => self.lost(
+                            format!("18 {}/", item.indicator),
+                            &item.value,
+                            "Indicateur sans champ ADEXP équivalent",
+                        ),
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// DOF/ (AAMMJJ) vers EOBD (JJMMAA)
+fn dof_to_eobd(dof: &str) -> Option<String> {
+    if dof.len() != 6 || !dof.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    Some(format!("{}{}{}", &dof[4..6], &dof[2..4], &dof[0..2]))
+}
+
+/// EOBD (JJMMAA) vers DOF/ (AAMMJJ)
+fn eobd_to_dof(eobd: &str) -> Option<String> {
+    dof_to_eobd(eobd)
+}
+
+/// Niveau ADEXP (`F350`, `350`) au format OACI (`F350`)
+fn normalise_level(level: &str) -> String {
+    if level.chars().all(|c| c.is_ascii_digit()) && !level.is_empty() {
+        return format!("F{:0>3}", level);
+    }
+    CruisingLevel::parse(level).map(|l| l.serialize()).unwrap_or_else(|_| level.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FPL: &str = "(FPL-AFR123/A1234-IS-A320/M-SDFGIRWY/S-LFPG0900-N0450F350 UM184 RESMI UN860-EGLL0105 EGKK-PBN/A1B1 DOF/261017 RMK/TCAS)";
+
+    #[test]
+    fn test_fpl_round_trip() {
+        let ats = AtsMessage::parse(FPL).unwrap();
+        let adexp = AdexpMessage::from_ats(&ats).unwrap();
+        assert!(adexp.is_lossless());
+
+        let message = &adexp.message;
+        assert_eq!(message.main_value("ARCID"), Some("AFR123"));
+        assert_eq!(message.main_value("SSRCODE"), Some("A1234"));
+        assert_eq!(message.main_value("EOBD"), Some("171026"));
+        assert_eq!(message.main_value("ALTRNT1"), Some("EGKK"));
+
+        let back = message.to_ats().unwrap();
+        assert!(back.is_lossless(), "{:?}", back.losses);
+        assert_eq!(back.message.serialize(), FPL);
+    }
+
+    #[test]
+    fn test_chg_amendments_round_trip() {
+        let chg = "(CHG-AFR123-LFPG0900-EGLL-DOF/261017-8/IN-15/N0460F370 DCT)";
+        let ats = AtsMessage::parse(chg).unwrap();
+        let adexp = AdexpMessage::from_ats(&ats).unwrap();
+        assert_eq!(adexp.message.main_value("FLTTYP"), Some("N"));
+        assert_eq!(adexp.message.main_value("RFL"), Some("F370"));
+
+        assert_eq!(adexp.message.to_ats().unwrap().message.serialize(), chg);
+    }
+
+    #[test]
+    fn test_reports_unrepresentable_data() {
+        let ats = AtsMessage::parse("(FPL-AFR123-IS-A320/M-SDFGIRWY/S-LFPG0900-N0450F350 DCT-EGLL0105-EUR/PROTECTED-E/0745 P/180)").unwrap();
+        let adexp = AdexpMessage::from_ats(&ats).unwrap();
+        let fields: Vec<&str> = adexp.losses.iter().map(|l| l.field.as_str()).collect();
+        assert_eq!(fields, vec!["18 EUR/", "19 E/", "19 P/"]);
+
+        let input = "-ADEXP
+-TITLE IDLA
+-ARCID AFR123
+-IFPLID AA12345678
+-ADEP LFPG
+-EOBT 0930
+-ADES EGLL
+-ROUTE N0450F350 DCT
+";
+        let message = AdexpParser::parse_message(input).unwrap();
+        let dla = message.to_ats().unwrap();
+        assert_eq!(dla.message.serialize(), "(DLA-AFR123-LFPG0930-EGLL)");
+        let fields: Vec<&str> = dla.losses.iter().map(|l| l.field.as_str()).collect();
+        assert_eq!(fields, vec!["IFPLID", "ROUTE"]);
+    }
+
+    #[test]
+    fn test_arr_est_and_cpl() {
+        for icao in [
+            "(ARR-AFR123/A1234-LFPG0900-EGLL1005)",
+            "(ARR-AFR123-LFPG0900-EGLL-EGKK1010)",
+            "(EST-AFR123/A1234-LFPG-LIMRI/1015F350F200-EGLL)",
+            "(CPL-AFR123-IS-A320/M-SDFGIRWY/S-LFPG0900-LIMRI/1015F350-N0450F350 DCT-EGLL-0)",
+        ] {
+            let ats = AtsMessage::parse(icao).unwrap();
+            let adexp = AdexpMessage::from_ats(&ats).unwrap();
+            assert!(adexp.is_lossless(), "{}: {:?}", icao, adexp.losses);
+            assert_eq!(adexp.message.to_ats().unwrap().message.serialize(), icao);
+        }
+    }
+
+    #[test]
+    fn test_cnl_dep_abi_round_trip() {
+        for icao in [
+            "(CNL-AFR123-LFPG0900-EGLL-DOF/261017)",
+            "(DEP-AFR123/A1234-LFPG0912-EGLL-DOF/261017)",
+            "(ABI-AFR123/A1234-LFPG-LIMRI/1015F350-EGLL-9/A320/M-15/N0450F350 DCT)",
+        ] {
+            let ats = AtsMessage::parse(icao).unwrap();
+            let adexp = AdexpMessage::from_ats(&ats).unwrap();
+            assert!(adexp.is_lossless(), "{}: {:?}", icao, adexp.losses);
+            assert_eq!(adexp.message.to_ats().unwrap().message.serialize(), icao);
+        }
+
+        // L'heure d'un DEP est l'heure réelle de départ (ATD), pas l'EOBT
+        let dep = AtsMessage::parse("(DEP-AFR123-LFPG0912-EGLL)").unwrap();
+        let adexp = AdexpMessage::from_ats(&dep).unwrap();
+        assert_eq!(adexp.message.main_value("ATD"), Some("0912"));
+        assert_eq!(adexp.message.main_value("EOBT"), None);
+    }
+
+    #[test]
+    fn test_cnl_dep_abi_report_losses() {
+        let abi = AtsMessage::parse("(ABI-AFR123-LFPG-LIMRI/1015F350F200A-EGLL)").unwrap();
+        let adexp = AdexpMessage::from_ats(&abi).unwrap();
+        let fields: Vec<&str> = adexp.losses.iter().map(|l| l.field.as_str()).collect();
+        assert_eq!(fields, vec!["14"]);
+        assert_eq!(adexp.losses[0].value, "A");
+
+        let dep = AtsMessage::parse("(DEP-AFR123-LFPG0912-EGLL-DOF/261017 EUR/PROTECTED)").unwrap();
+        let adexp = AdexpMessage::from_ats(&dep).unwrap();
+        let fields: Vec<&str> = adexp.losses.iter().map(|l| l.field.as_str()).collect();
+        assert_eq!(fields, vec!["18 EUR/"]);
+
+        let input = "-ADEXP
+-TITLE CNL
+-ARCID AFR123
+-ADEP LFPG
+-EOBT 0900
+-ADES EGLL
+-ARCTYP A320
+-RFL F350
+";
+        let cnl = AdexpParser::parse_message(input).unwrap().to_ats().unwrap();
+        assert_eq!(cnl.message.serialize(), "(CNL-AFR123-LFPG0900-EGLL)");
+        let fields: Vec<&str> = cnl.losses.iter().map(|l| l.field.as_str()).collect();
+        assert_eq!(fields, vec!["ARCTYP", "RFL"]);
+        assert!(cnl.losses[0].reason.contains("CNL"));
+    }
+}
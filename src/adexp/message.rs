@@ -3,6 +3,7 @@ use std::collections::HashMap;
 use crate::adexp::error::AdexpError;
 use crate::adexp::types::MessageType;
 use crate::adexp::validation;
+use crate::diagnostic::Checks;
 
 /// Représente un message ADEXP complet
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -13,10 +14,51 @@ pub struct AdexpMessage {
     /// Sections du message (clé = nom de section, valeur = champs)
     pub sections: HashMap<String, Section>,
     
+    /// Champs et listes du message, dans l'ordre d'origine
+    /// 
+    /// Les champs composés portent leurs sous-champs et chaque bloc
+    /// `-BEGIN`/`-END` est une liste ordonnée d'éléments. `sections` reste
+    /// une vue aplatie de cet arbre.
+    #[serde(default)]
+    pub items: Vec<AdexpItem>,
+    
     /// Données brutes du message (pour référence)
     pub raw: String,
 }
 
+/// Élément ordonné d'un message ADEXP
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum AdexpItem {
+    /// Champ simple ou composé (`-ARCID ABC123`, `-REFDATA -IFPLID ...`)
+    Field(AdexpField),
+    
+    /// Liste `-BEGIN NAME` ... `-END NAME`
+    List(AdexpList),
+}
+
+/// Champ ADEXP avec ses sous-champs éventuels
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AdexpField {
+    /// Nom du champ (sans le tiret)
+    pub name: String,
+    
+    /// Valeur du champ (vide pour un champ composé ou sans valeur)
+    pub value: String,
+    
+    /// Sous-champs, dans l'ordre (champ composé ou élément de liste)
+    pub subfields: Vec<AdexpField>,
+}
+
+/// Liste ADEXP (`-BEGIN RTEPTS` ... `-END RTEPTS`)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AdexpList {
+    /// Nom de la liste
+    pub name: String,
+    
+    /// Éléments de la liste, dans l'ordre (champs ou listes imbriquées)
+    pub items: Vec<AdexpItem>,
+}
+
 /// Représente une section ADEXP avec ses champs
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Section {
@@ -42,6 +84,7 @@ impl AdexpMessage {
         AdexpMessage {
             message_type: MessageType::Generic,
             sections: HashMap::new(),
+            items: Vec::new(),
             raw,
         }
     }
@@ -99,6 +142,22 @@ impl AdexpMessage {
         }
     }
     
+    /// Premier champ de premier niveau portant ce nom
+    pub fn find_field(&self, name: &str) -> Option<&AdexpField> {
+        self.items.iter().find_map(|item| match item {
+            AdexpItem::Field(field) if field.name == name => Some(field),
+            _ => None,
+        })
+    }
+    
+    /// Première liste de premier niveau portant ce nom
+    pub fn find_list(&self, name: &str) -> Option<&AdexpList> {
+        self.items.iter().find_map(|item| match item {
+            AdexpItem::List(list) if list.name == name => Some(list),
+            _ => None,
+        })
+    }
+    
     /// Obtient le champ TITLE (requis pour tous les messages ADEXP).
     /// 
     /// Le TITLE détermine le type de message (FPL, CHG, CNL, DLA, etc.).
@@ -132,88 +191,56 @@ impl AdexpMessage {
     /// message.validate()?; // Valide la structure et la sémantique
     /// ```
     pub fn validate(&self) -> Result<(), AdexpError> {
+        match self.check().into_iter().next() {
+            Some(error) => Err(error),
+            None => Ok(()),
+        }
+    }
+    
+    /// Contrôle le message comme [`validate`](Self::validate) mais retourne
+    /// tous les problèmes relevés, dans l'ordre où `validate` les rencontre.
+    pub fn check(&self) -> Vec<AdexpError> {
+        let mut checks = Checks::new();
+        
         // Vérifier que TITLE existe
-        self.get_title()?;
+        checks.run(|| self.get_title().map(|_| ()));
         
         // Validation sémantique de tous les champs
-        self.validate_all_fields()?;
+        self.check_all_fields(&mut checks);
         
         // Validation spécifique selon le type de message
-        match self.message_type {
-            MessageType::FlightPlan => self.validate_fpl()?,
-            MessageType::Change => self.validate_chg()?,
-            MessageType::Delay => self.validate_dla()?,
-            MessageType::Cancel => self.validate_cnl()?,
-            _ => {}
+        let required: &[&str] = match self.message_type {
+            MessageType::FlightPlan => &["ARCID", "ADEP", "ADES"],
+            MessageType::Change | MessageType::Delay | MessageType::Cancel => &["ARCID"],
+            _ => &[],
+        };
+        for field in required {
+            checks.run(|| match self.get_field_value("", field)? {
+                Some(_) => Ok(()),
+                None => Err(AdexpError::MissingField(field.to_string())),
+            });
         }
         
-        Ok(())
+        checks.0
     }
     
     /// Valide sémantiquement tous les champs du message
-    fn validate_all_fields(&self) -> Result<(), AdexpError> {
-        // Valider les champs de la section principale (vide)
-        if let Some(section) = self.sections.get("") {
+    fn check_all_fields(&self, checks: &mut Checks<AdexpError>) {
+        // La section principale (vide) d'abord, puis toutes les autres
+        let main = self.sections.get("").into_iter();
+        let others = self.sections.iter()
+            .filter(|(name, _)| !name.is_empty())
+            .map(|(_, section)| section);
+        for section in main.chain(others) {
             // Valider les champs simples
             for (field_name, values) in &section.fields {
                 for value in values {
-                    validation::validate_field(field_name, value)?;
-                }
-            }
-            // Valider les structures composées dans la section principale
-            validation::validate_compound_fields_in_section(section)?;
-        }
-        
-        // Valider les champs de toutes les autres sections
-        for (section_name, section) in &self.sections {
-            if section_name != "" {
-                // Valider les champs simples
-                for (field_name, values) in &section.fields {
-                    for value in values {
-                        validation::validate_field(field_name, value)?;
-                    }
+                    checks.run(|| validation::validate_field(field_name, value));
                 }
-                // Valider les structures composées dans cette section
-                validation::validate_compound_fields_in_section(section)?;
             }
+            // Valider les structures composées dans cette section
+            checks.run(|| validation::validate_compound_fields_in_section(section));
         }
-        
-        Ok(())
-    }
-    
-    fn validate_fpl(&self) -> Result<(), AdexpError> {
-        // Pour un FPL, vérifier les champs requis
-        let required_fields = vec!["ARCID", "ADEP", "ADES"];
-        for field in required_fields {
-            if self.get_field_value("", field)?.is_none() {
-                return Err(AdexpError::MissingField(field.to_string()));
-            }
-        }
-        Ok(())
-    }
-    
-    fn validate_chg(&self) -> Result<(), AdexpError> {
-        // CHG doit avoir un ARCID
-        if self.get_field_value("", "ARCID")?.is_none() {
-            return Err(AdexpError::MissingField("ARCID".to_string()));
-        }
-        Ok(())
-    }
-    
-    fn validate_dla(&self) -> Result<(), AdexpError> {
-        // DLA doit avoir un ARCID
-        if self.get_field_value("", "ARCID")?.is_none() {
-            return Err(AdexpError::MissingField("ARCID".to_string()));
-        }
-        Ok(())
-    }
-    
-    fn validate_cnl(&self) -> Result<(), AdexpError> {
-        // CNL doit avoir un ARCID
-        if self.get_field_value("", "ARCID")?.is_none() {
-            return Err(AdexpError::MissingField("ARCID".to_string()));
-        }
-        Ok(())
     }
     
     /// Sérialise le message ADEXP en chaîne de caractères.
@@ -237,34 +264,16 @@ impl AdexpMessage {
         // Marqueur ADEXP
         result.push_str("-ADEXP\n");
         
+        // Un message parsé conserve l'ordre d'origine de ses champs et listes ;
+        // les valeurs écrites restent celles de `sections`
+        if !self.items.is_empty() {
+            self.write_items(&mut result);
+            return result.trim_end().to_string();
+        }
+        
         // Section principale (section vide "")
         if let Some(main_section) = self.sections.get("") {
-            // Trier les champs pour un ordre cohérent (TITLE en premier si présent)
-            let mut fields: Vec<(&String, &Vec<String>)> = main_section.fields.iter().collect();
-            
-            // Trier: TITLE en premier, puis les autres par ordre alphabétique
-            fields.sort_by(|a, b| {
-                if a.0 == "TITLE" {
-                    std::cmp::Ordering::Less
-                } else if b.0 == "TITLE" {
-                    std::cmp::Ordering::Greater
-                } else {
-                    a.0.cmp(b.0)
-                }
-            });
-            
-            // Écrire les champs de la section principale
-            for (field_name, values) in fields {
-                for value in values {
-                    result.push_str("-");
-                    result.push_str(field_name);
-                    if !value.is_empty() {
-                        result.push(' ');
-                        result.push_str(value.trim());
-                    }
-                    result.push('\n');
-                }
-            }
+            write_remaining_fields(&mut result, main_section, &HashMap::new());
         }
         
         // Sections avec BEGIN/END (toutes les sections sauf la principale)
@@ -276,38 +285,129 @@ impl AdexpMessage {
         array_sections.sort_by(|a, b| a.0.cmp(b.0));
         
         for (section_name, section) in array_sections {
-            // Marqueur BEGIN
-            result.push_str("-BEGIN ");
-            result.push_str(section_name);
-            result.push('\n');
-            
-            // Trier les champs par nom
-            let mut fields: Vec<(&String, &Vec<String>)> = section.fields.iter().collect();
-            fields.sort_by(|a, b| a.0.cmp(b.0));
-            
-            // Écrire les champs de la section
-            for (field_name, values) in fields {
-                for value in values {
-                    result.push_str("-");
-                    result.push_str(field_name);
-                    if !value.is_empty() {
-                        result.push(' ');
-                        result.push_str(value.trim());
+            write_block(&mut result, section_name, section);
+        }
+        
+        result.trim_end().to_string()
+    }
+    
+    /// Écrit les éléments parsés dans leur ordre d'origine
+    /// 
+    /// Un champ ou une liste inchangés dans `sections` sont réécrits tels
+    /// quels ; une valeur modifiée est réécrite depuis `sections`, une valeur
+    /// retirée est omise et une valeur ajoutée est écrite à la fin de sa
+    /// section. Les sections ajoutées suivent, en blocs BEGIN/END.
+    fn write_items(&self, out: &mut String) {
+        let mut current = "";
+        let mut written: HashMap<&str, HashMap<&str, usize>> = HashMap::new();
+        let mut known: Vec<&str> = vec![""];
+        
+        for item in &self.items {
+            match item {
+                // En-tête de section (`-NAME` seul sur sa ligne)
+                AdexpItem::Field(field) if field.value.is_empty() && field.subfields.is_empty() => {
+                    self.write_remaining(out, current, &written);
+                    current = &field.name;
+                    known.push(current);
+                    if self.sections.contains_key(current) {
+                        out.push('-');
+                        out.push_str(current);
+                        out.push('\n');
+                    }
+                }
+                AdexpItem::Field(field) => {
+                    let count = written.entry(current).or_default().entry(&field.name).or_insert(0);
+                    let value = self.sections.get(current)
+                        .and_then(|section| section.fields.get(&field.name))
+                        .and_then(|values| values.get(*count));
+                    *count += 1;
+                    match value {
+                        Some(value) if value.trim() == field.value_text() => item.write_to(out),
+                        Some(value) => write_value(out, &field.name, value),
+                        None => {}
+                    }
+                }
+                AdexpItem::List(list) => {
+                    known.push(&list.name);
+                    match self.sections.get(&list.name) {
+                        Some(section) if *section == list.to_section() => item.write_to(out),
+                        Some(section) => write_block(out, &list.name, section),
+                        None => {}
                     }
-                    result.push('\n');
                 }
             }
-            
-            // Marqueur END avec nom de section
-            result.push_str("-END ");
-            result.push_str(section_name);
-            result.push('\n');
         }
+        self.write_remaining(out, current, &written);
         
-        result.trim_end().to_string()
+        let mut added: Vec<(&String, &Section)> = self.sections.iter()
+            .filter(|(name, _)| !known.contains(&name.as_str()))
+            .collect();
+        added.sort_by(|a, b| a.0.cmp(b.0));
+        for (section_name, section) in added {
+            write_block(out, section_name, section);
+        }
+    }
+    
+    /// Écrit les valeurs d'une section qui n'ont pas d'élément d'origine
+    fn write_remaining(&self, out: &mut String, name: &str, written: &HashMap<&str, HashMap<&str, usize>>) {
+        if let Some(section) = self.sections.get(name) {
+            write_remaining_fields(out, section, written.get(name).unwrap_or(&HashMap::new()));
+        }
+    }
+}
+
+/// Écrit une ligne `-NAME value`
+fn write_value(out: &mut String, name: &str, value: &str) {
+    out.push('-');
+    out.push_str(name);
+    if !value.is_empty() {
+        out.push(' ');
+        out.push_str(value.trim());
+    }
+    out.push('\n');
+}
+
+/// Écrit les champs d'une section au-delà des `written` premières valeurs
+/// de chacun (TITLE en premier, puis les autres par ordre alphabétique)
+fn write_remaining_fields(out: &mut String, section: &Section, written: &HashMap<&str, usize>) {
+    let mut fields: Vec<(&String, &Vec<String>)> = section.fields.iter().collect();
+    fields.sort_by(|a, b| {
+        if a.0 == "TITLE" {
+            std::cmp::Ordering::Less
+        } else if b.0 == "TITLE" {
+            std::cmp::Ordering::Greater
+        } else {
+            a.0.cmp(b.0)
+        }
+    });
+    
+    for (field_name, values) in fields {
+        let skip = written.get(field_name.as_str()).copied().unwrap_or(0);
+        for value in values.iter().skip(skip) {
+            write_value(out, field_name, value);
+        }
     }
 }
 
+/// Écrit une section entre `-BEGIN name` et `-END name`, champs triés par nom
+fn write_block(out: &mut String, name: &str, section: &Section) {
+    out.push_str("-BEGIN ");
+    out.push_str(name);
+    out.push('\n');
+    
+    let mut fields: Vec<(&String, &Vec<String>)> = section.fields.iter().collect();
+    fields.sort_by(|a, b| a.0.cmp(b.0));
+    for (field_name, values) in fields {
+        for value in values {
+            write_value(out, field_name, value);
+        }
+    }
+    
+    out.push_str("-END ");
+    out.push_str(name);
+    out.push('\n');
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -339,6 +439,132 @@ mod tests {
 
         assert_eq!(message.message_type, reparsed.message_type);
     }
+
+    #[test]
+    fn test_serialize_keeps_section_edits() {
+        let original = "-ADEXP\n-TITLE FPL\n-ARCID ABC123\n-ADEP LFPG\n-ADES LFPB\n-BEGIN RTEPTS\n-PT -PTID BOLIN\n-END RTEPTS";
+        let mut message = AdexpParser::parse_message(original).unwrap();
+        let main = message.sections.get_mut("").unwrap();
+        main.fields.insert("ARCID".to_string(), vec!["XYZ789".to_string()]);
+        main.fields.remove("ADEP");
+        main.add_field("RFL".to_string(), "F350".to_string());
+
+        let serialized = message.serialize();
+        assert_eq!(
+            serialized,
+            "-ADEXP\n-TITLE FPL\n-ARCID XYZ789\n-ADES LFPB\n-BEGIN RTEPTS\n-PT -PTID BOLIN\n-END RTEPTS\n-RFL F350"
+        );
+        let reparsed = AdexpParser::parse_message(&serialized).unwrap();
+        assert_eq!(reparsed.arcid(), Some("XYZ789"));
+        assert_eq!(reparsed.adep(), None);
+    }
+}
+
+impl AdexpItem {
+    /// Écrit l'élément au format ADEXP (une ligne par champ ou élément de liste)
+    fn write_to(&self, out: &mut String) {
+        match self {
+            AdexpItem::Field(field) => {
+                field.write_to(out);
+                out.push('\n');
+            }
+            AdexpItem::List(list) => {
+                out.push_str("-BEGIN ");
+                out.push_str(&list.name);
+                out.push('\n');
+                for item in &list.items {
+                    item.write_to(out);
+                }
+                out.push_str("-END ");
+                out.push_str(&list.name);
+                out.push('\n');
+            }
+        }
+    }
+}
+
+impl AdexpField {
+    /// Crée un champ simple
+    pub fn new(name: String, value: String) -> Self {
+        AdexpField {
+            name,
+            value,
+            subfields: Vec::new(),
+        }
+    }
+    
+    /// Premier sous-champ portant ce nom
+    pub fn subfield(&self, name: &str) -> Option<&AdexpField> {
+        self.subfields.iter().find(|field| field.name == name)
+    }
+    
+    /// Vue aplatie du champ et de ses sous-champs
+    pub fn to_section(&self) -> Section {
+        let mut section = Section::new(self.name.clone());
+        self.flatten_into(&mut section);
+        section
+    }
+    
+    fn flatten_into(&self, section: &mut Section) {
+        section.add_field(self.name.clone(), self.value.clone());
+        for subfield in &self.subfields {
+            subfield.flatten_into(section);
+        }
+    }
+    
+    /// Valeur du champ telle qu'écrite après son nom (sous-champs compris)
+    fn value_text(&self) -> String {
+        let mut line = String::new();
+        self.write_to(&mut line);
+        line[self.name.len() + 1..].trim_start().to_string()
+    }
+    
+    fn write_to(&self, out: &mut String) {
+        out.push('-');
+        out.push_str(&self.name);
+        if !self.value.is_empty() {
+            out.push(' ');
+            out.push_str(&self.value);
+        }
+        for subfield in &self.subfields {
+            out.push(' ');
+            subfield.write_to(out);
+        }
+    }
+}
+
+impl AdexpList {
+    /// Éléments de la liste qui sont des champs (hors listes imbriquées)
+    pub fn fields(&self) -> impl Iterator<Item = &AdexpField> {
+        self.items.iter().filter_map(|item| match item {
+            AdexpItem::Field(field) => Some(field),
+            AdexpItem::List(_) => None,
+        })
+    }
+    
+    /// Première liste imbriquée portant ce nom
+    pub fn find_list(&self, name: &str) -> Option<&AdexpList> {
+        self.items.iter().find_map(|item| match item {
+            AdexpItem::List(list) if list.name == name => Some(list),
+            _ => None,
+        })
+    }
+    
+    /// Vue aplatie de la liste: tous les champs, listes imbriquées comprises
+    pub fn to_section(&self) -> Section {
+        let mut section = Section::new(self.name.clone());
+        self.flatten_into(&mut section);
+        section
+    }
+    
+    fn flatten_into(&self, section: &mut Section) {
+        for item in &self.items {
+            match item {
+                AdexpItem::Field(field) => field.flatten_into(section),
+                AdexpItem::List(list) => list.flatten_into(section),
+            }
+        }
+    }
 }
 
 impl Section {
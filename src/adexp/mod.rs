@@ -4,10 +4,14 @@ pub mod error;
 pub mod types;
 pub mod fields;
 pub mod validation;
+pub mod typed;
+pub mod icao;
 
 pub use error::AdexpError;
-pub use message::AdexpMessage;
+pub use message::{AdexpMessage, AdexpItem, AdexpField, AdexpList};
 pub use parser::AdexpParser;
 pub use types::MessageType;
-pub use fields::{AdexpFields, PrimaryField, BasicField, CompoundField, AddrField, VecField, RoutePoint, RefDataField, CstatField};
+pub use fields::{AdexpFields, PrimaryField, BasicField, CompoundField, AddrField, VecField, RoutePoint, RefDataField, CstatField, AdexpDate, AdexpTime, AdexpSpeed, AdexpAltitude};
+pub use typed::{AdexpTypedMessage, AdexpFpl, AdexpChg, AdexpDla, AdexpCnl, AdexpDep, AdexpArr};
 
+pub use icao::{Conversion, ConversionLoss};
@@ -1,10 +1,15 @@
 use pest::Parser;
 use pest_derive::Parser;
 use std::collections::HashMap;
-use crate::adexp::message::{AdexpMessage, Section};
+use crate::adexp::message::{AdexpField, AdexpItem, AdexpList, AdexpMessage, Section};
 use crate::adexp::types::MessageType;
 use crate::adexp::error::AdexpError;
 use crate::adexp::fields::AdexpFields;
+use crate::diagnostic::{FieldRef, Lenient, LocatedError, SyntaxError};
+
+/// Texte sans blocs BEGIN/END, listes extraites (avec leur position) et
+/// numéros des lignes d'origine conservées
+type ArrayBlocks = (String, Vec<(usize, AdexpList)>, Vec<usize>);
 
 #[derive(Parser)]
 #[grammar = "adexp/adexp.pest"]
@@ -14,32 +19,61 @@ impl AdexpParser {
     /// Parse un message ADEXP complet
     pub fn parse_message(input: &str) -> Result<AdexpMessage, AdexpError> {
         // D'abord, parser manuellement les blocs BEGIN/END
-        let (input_without_arrays, array_sections) = Self::extract_array_blocks(input)?;
+        let (input_without_arrays, lists, kept_lines) = Self::extract_array_blocks(input)?;
         
         // Parser le reste avec PEST
         let mut pairs = AdexpParser::parse(Rule::message, &input_without_arrays)
-            .map_err(|e| AdexpError::ParseError(format!("{}", e)))?;
+            .map_err(|e| {
+                let error = SyntaxError::from_pest(&e, &input_without_arrays, "");
+                AdexpError::from(Self::locate_in_input(error, input, &input_without_arrays, &kept_lines))
+            })?;
         
         let message_pair = pairs.next().ok_or_else(|| {
             AdexpError::ParseError("Empty parse result".to_string())
         })?;
         
-        let mut message = Self::parse_message_pair(message_pair, &input_without_arrays)?;
+        let mut message = Self::parse_message_pair(message_pair, &input_without_arrays, lists)?;
         
-        // Ajouter les sections de tableaux parsées manuellement
-        for (name, section) in array_sections {
-            message.sections.insert(name, section);
+        // Ajouter la vue aplatie des tableaux parsés manuellement
+        let array_sections: Vec<Section> = message.items.iter()
+            .filter_map(|item| match item {
+                AdexpItem::List(list) => Some(list.to_section()),
+                AdexpItem::Field(_) => None,
+            })
+            .collect();
+        for section in array_sections {
+            message.sections.insert(section.name.clone(), section);
         }
         
         Ok(message)
     }
     
+    /// Parse un message ADEXP en mode tolérant
+    /// 
+    /// Une erreur de syntaxe empêche de reconstruire le message ; sinon le
+    /// message est retourné avec tous les problèmes relevés par
+    /// [`AdexpMessage::check`] (champs invalides, champs requis manquants).
+    pub fn parse_lenient(input: &str) -> Lenient<AdexpMessage> {
+        match Self::parse_message(input) {
+            Ok(message) => {
+                let diagnostics = message.check().iter()
+                    .map(AdexpError::to_diagnostic)
+                    .collect();
+                Lenient { message: Some(message), diagnostics }
+            }
+            Err(error) => Lenient { message: None, diagnostics: vec![error.to_diagnostic()] },
+        }
+    }
+    
     /// Extrait et parse manuellement les blocs BEGIN/END
-    /// Retourne le texte sans les blocs et les sections parsées
-    fn extract_array_blocks(input: &str) -> Result<(String, HashMap<String, Section>), AdexpError> {
+    /// Retourne le texte sans les blocs et les listes parsées, chacune avec
+    /// le numéro de ligne (dans le texte retourné) devant lequel elle se trouvait
+    fn extract_array_blocks(input: &str) -> Result<ArrayBlocks, AdexpError> {
         let mut result = String::new();
-        let mut array_sections = HashMap::new();
+        let mut lists = Vec::new();
+        let mut kept = Vec::new();
         let lines: Vec<&str> = input.lines().collect();
+        let mut kept_lines = 0;
         let mut i = 0;
         
         while i < lines.len() {
@@ -47,138 +81,201 @@ impl AdexpParser {
             
             // Détecter un bloc BEGIN
             if line.starts_with("-BEGIN") {
-                // Extraire le nom de la section
-                let section_name = line.strip_prefix("-BEGIN")
-                    .ok_or_else(|| AdexpError::ParseError("Invalid BEGIN marker".to_string()))?
-                    .trim()
-                    .to_string();
-                
-                if section_name.is_empty() {
-                    return Err(AdexpError::ParseError("Empty section name in BEGIN".to_string()));
-                }
-                
-                // Créer une section pour ce tableau
-                let mut array_section = Section::new(section_name.clone());
-                i += 1; // Passer à la ligne suivante
-                
-                // Parser les éléments du tableau jusqu'à trouver -END
-                while i < lines.len() {
-                    let current_line = lines[i].trim();
-                    
-                    // Vérifier si c'est la fin du bloc
-                    if current_line.starts_with("-END") {
-                        let end_section_name = current_line.strip_prefix("-END")
-                            .ok_or_else(|| AdexpError::ParseError("Invalid END marker".to_string()))?
-                            .trim();
-                        
-                        if end_section_name != section_name {
-                            return Err(AdexpError::ParseError(
-                                format!("Mismatched END marker: expected {}, got {}", section_name, end_section_name)
-                            ));
-                        }
-                        i += 1; // Passer à la ligne suivante
-                        break;
-                    }
-                    
-                    // Parser les champs inline de cette ligne
-                    // Format: -FIELD1 VALUE1 -FIELD2 VALUE2 ...
-                    let chars: Vec<char> = current_line.chars().collect();
-                    let mut char_idx = 0;
-                    
-                    while char_idx < chars.len() {
-                        // Ignorer les espaces en début
-                        while char_idx < chars.len() && (chars[char_idx] == ' ' || chars[char_idx] == '\t') {
-                            char_idx += 1;
-                        }
-                        
-                        if char_idx >= chars.len() {
-                            break;
-                        }
-                        
-                        // Chercher le prochain "-" qui indique un champ
-                        if chars[char_idx] != '-' {
-                            // Si on n'est pas sur un "-", chercher le prochain
-                            while char_idx < chars.len() && chars[char_idx] != '-' {
-                                char_idx += 1;
-                            }
-                            if char_idx >= chars.len() {
-                                break;
-                            }
-                        }
-                        
-                        // On est sur un "-", extraire le nom du champ
-                        char_idx += 1; // Passer le "-"
-                        let field_name_start = char_idx;
-                        let mut field_name_end = char_idx;
-                        
-                        while field_name_end < chars.len() && 
-                              (chars[field_name_end].is_alphanumeric() || chars[field_name_end] == '_') {
-                            field_name_end += 1;
-                        }
-                        
-                        if field_name_end > field_name_start {
-                            let field_name: String = chars[field_name_start..field_name_end].iter().collect();
-                            
-                            // Valider le nom du champ selon ADEXP 3.4
-                            // Note: TITLE est toujours accepté même s'il n'est pas dans la liste standard
-                            if field_name != "TITLE" && !AdexpFields::is_valid_field(&field_name) {
-                                return Err(AdexpError::InvalidField(format!(
-                                    "Invalid field name '{}' in BEGIN {} block. Field is not defined in ADEXP 3.4 specification.",
-                                    field_name, section_name
-                                )));
-                            }
-                            
-                            // Ignorer les espaces après le nom du champ
-                            let mut value_start = field_name_end;
-                            while value_start < chars.len() && 
-                                  (chars[value_start] == ' ' || chars[value_start] == '\t') {
-                                value_start += 1;
-                            }
-                            
-                            // Extraire la valeur jusqu'au prochain "-" ou fin de ligne
-                            let mut value_end = value_start;
-                            while value_end < chars.len() {
-                                // Si on trouve un "-" précédé d'un espace, c'est le début du prochain champ
-                                if chars[value_end] == '-' && value_end > value_start && 
-                                   (chars[value_end - 1] == ' ' || chars[value_end - 1] == '\t') {
-                                    break;
-                                }
-                                value_end += 1;
-                            }
-                            
-                            let field_value: String = if value_end > value_start {
-                                chars[value_start..value_end].iter().collect()
-                            } else {
-                                String::new()
-                            };
-                            
-                            array_section.add_field(field_name, field_value.trim().to_string());
-                            char_idx = value_end;
-                        } else {
-                            break;
-                        }
-                    }
-                    
-                    i += 1;
-                }
-                
-                // Ajouter la section au résultat
-                array_sections.insert(section_name, array_section);
+                let list = Self::parse_list(&lines, &mut i)?;
+                lists.push((kept_lines + 1, list));
             } else {
                 // Ligne normale, l'ajouter au résultat
                 result.push_str(lines[i]);
                 result.push('\n');
+                kept.push(i);
+                kept_lines += 1;
                 i += 1;
             }
         }
         
-        Ok((result, array_sections))
+        Ok((result, lists, kept))
     }
     
-    fn parse_message_pair(pair: pest::iterators::Pair<Rule>, raw: &str) -> Result<AdexpMessage, AdexpError> {
+    /// Reporte une erreur de syntaxe du texte sans blocs BEGIN/END sur le
+    /// message d'origine et renseigne le champ (`-NOM`) de la ligne en erreur
+    fn locate_in_input(error: SyntaxError, input: &str, stripped: &str, kept_lines: &[usize]) -> SyntaxError {
+        let stripped_line_start = stripped[..error.span.start].rfind('\n').map(|i| i + 1).unwrap_or(0);
+        let column = error.span.start - stripped_line_start;
+        // Au-delà de la dernière ligne conservée: fin du message
+        let line = kept_lines.get(error.span.line - 1).copied()
+            .unwrap_or_else(|| kept_lines.last().map_or(0, |l| l + 1));
+        let line_start: usize = input.split_inclusive('\n').take(line).map(str::len).sum();
+        let error = error.offset_in(input, line_start.saturating_sub(stripped_line_start));
+        
+        // Champ en cause: dernier mot-clé `-NOM` avant l'erreur sur sa ligne
+        let line_text = input[line_start..].lines().next().unwrap_or("");
+        let before = &line_text[..column.min(line_text.len())];
+        let keyword = before.rmatch_indices('-')
+            .map(|(i, _)| &line_text[i + 1..])
+            .chain(line_text.trim_start().strip_prefix('-'))
+            .map(|rest| rest.split(|c: char| !c.is_ascii_alphanumeric()).next().unwrap_or(""))
+            .find(|name| !name.is_empty());
+        match keyword {
+            Some(name) => error.with_field(FieldRef::Name(name.to_string())),
+            None => error,
+        }
+    }
+    
+    /// Parse un bloc `-BEGIN NAME` ... `-END NAME` à partir de la ligne `*i`
+    /// 
+    /// Le premier champ rencontré dans le bloc ouvre chaque élément (par exemple
+    /// `-PT` dans `RTEPTS`) ; les champs suivants deviennent ses sous-champs
+    /// jusqu'à la prochaine occurrence. Les blocs imbriqués sont des listes.
+    fn parse_list(lines: &[&str], i: &mut usize) -> Result<AdexpList, AdexpError> {
+        // Extraire le nom de la section
+        let section_name = lines[*i].trim().strip_prefix("-BEGIN")
+            .ok_or_else(|| AdexpError::ParseError("Invalid BEGIN marker".to_string()))?
+            .trim()
+            .to_string();
+        
+        if section_name.is_empty() {
+            return Err(AdexpError::ParseError("Empty section name in BEGIN".to_string()));
+        }
+        
+        let mut list = AdexpList { name: section_name.clone(), items: Vec::new() };
+        let mut leader: Option<String> = None;
+        *i += 1; // Passer à la ligne suivante
+        
+        // Parser les éléments du tableau jusqu'à trouver -END
+        while *i < lines.len() {
+            let current_line = lines[*i].trim();
+            
+            // Vérifier si c'est la fin du bloc
+            if current_line.starts_with("-END") {
+                let end_section_name = current_line.strip_prefix("-END")
+                    .ok_or_else(|| AdexpError::ParseError("Invalid END marker".to_string()))?
+                    .trim();
+                
+                if end_section_name != section_name {
+                    return Err(AdexpError::ParseError(
+                        format!("Mismatched END marker: expected {}, got {}", section_name, end_section_name)
+                    ));
+                }
+                *i += 1; // Passer à la ligne suivante
+                return Ok(list);
+            }
+            
+            // Bloc imbriqué
+            if current_line.starts_with("-BEGIN") {
+                list.items.push(AdexpItem::List(Self::parse_list(lines, i)?));
+                continue;
+            }
+            
+            for (field_name, field_value) in Self::split_inline_fields(current_line) {
+                // Valider le nom du champ selon ADEXP 3.4
+                // Note: TITLE est toujours accepté même s'il n'est pas dans la liste standard
+                if field_name != "TITLE" && !AdexpFields::is_valid_field(&field_name) {
+                    return Err(AdexpError::InvalidField(format!(
+                        "Invalid field name '{}' in BEGIN {} block. Field is not defined in ADEXP 3.4 specification.",
+                        field_name, section_name
+                    )));
+                }
+                
+                let field = AdexpField::new(field_name, field_value);
+                let leader_name = leader.get_or_insert_with(|| field.name.clone());
+                match list.items.last_mut() {
+                    Some(AdexpItem::Field(element)) if field.name != *leader_name => {
+                        element.subfields.push(field);
+                    }
+                    _ => list.items.push(AdexpItem::Field(field)),
+                }
+            }
+            
+            *i += 1;
+        }
+        
+        Ok(list)
+    }
+    
+    /// Découpe une ligne de champs inline
+    /// Format: -FIELD1 VALUE1 -FIELD2 VALUE2 ...
+    pub(crate) fn split_inline_fields(line: &str) -> Vec<(String, String)> {
+        let mut fields = Vec::new();
+        let chars: Vec<char> = line.chars().collect();
+        let mut char_idx = 0;
+        
+        while char_idx < chars.len() {
+            // Ignorer les espaces en début
+            while char_idx < chars.len() && (chars[char_idx] == ' ' || chars[char_idx] == '\t') {
+                char_idx += 1;
+            }
+            
+            if char_idx >= chars.len() {
+                break;
+            }
+            
+            // Chercher le prochain "-" qui indique un champ
+            if chars[char_idx] != '-' {
+                // Si on n'est pas sur un "-", chercher le prochain
+                while char_idx < chars.len() && chars[char_idx] != '-' {
+                    char_idx += 1;
+                }
+                if char_idx >= chars.len() {
+                    break;
+                }
+            }
+            
+            // On est sur un "-", extraire le nom du champ
+            char_idx += 1; // Passer le "-"
+            let field_name_start = char_idx;
+            let mut field_name_end = char_idx;
+            
+            while field_name_end < chars.len() && 
+                  (chars[field_name_end].is_alphanumeric() || chars[field_name_end] == '_') {
+                field_name_end += 1;
+            }
+            
+            if field_name_end == field_name_start {
+                break;
+            }
+            let field_name: String = chars[field_name_start..field_name_end].iter().collect();
+            
+            // Ignorer les espaces après le nom du champ
+            let mut value_start = field_name_end;
+            while value_start < chars.len() && 
+                  (chars[value_start] == ' ' || chars[value_start] == '\t') {
+                value_start += 1;
+            }
+            
+            // Extraire la valeur jusqu'au prochain "-" ou fin de ligne
+            // (un "-" juste après le nom indique un champ sans valeur: "-PT -PTID ABB")
+            let mut value_end = value_start;
+            while value_end < chars.len() {
+                if chars[value_end] == '-' && value_end == value_start && value_start > field_name_end
+                    && chars.get(value_end + 1).is_some_and(|c| c.is_ascii_alphabetic()) {
+                    break;
+                }
+                // Si on trouve un "-" précédé d'un espace, c'est le début du prochain champ
+                if chars[value_end] == '-' && value_end > value_start && 
+                   (chars[value_end - 1] == ' ' || chars[value_end - 1] == '\t') {
+                    break;
+                }
+                value_end += 1;
+            }
+            
+            let field_value: String = chars[value_start..value_end].iter().collect();
+            fields.push((field_name, field_value.trim().to_string()));
+            char_idx = value_end;
+        }
+        
+        fields
+    }
+    
+    fn parse_message_pair(
+        pair: pest::iterators::Pair<Rule>,
+        raw: &str,
+        lists: Vec<(usize, AdexpList)>,
+    ) -> Result<AdexpMessage, AdexpError> {
         let mut message = AdexpMessage::new(raw.to_string());
         let mut current_section = Section::new("".to_string());
         let mut sections = HashMap::new();
+        let mut items = Vec::new();
+        let mut lists = lists.into_iter().peekable();
         
         for inner_pair in pair.into_inner() {
             match inner_pair.as_rule() {
@@ -186,6 +283,12 @@ impl AdexpParser {
                     for item_pair in inner_pair.into_inner() {
                         match item_pair.as_rule() {
                             Rule::section_item => {
+                                // Replacer les listes extraites qui précédaient cette ligne
+                                let (line, _) = item_pair.as_span().start_pos().line_col();
+                                while let Some((_, list)) = lists.next_if(|(position, _)| *position <= line) {
+                                    items.push(AdexpItem::List(list));
+                                }
+                                
                                 // section_item peut être section_header ou field
                                 // Les blocs BEGIN/END sont parsés manuellement avant d'appeler PEST
                                 for section_item_pair in item_pair.into_inner() {
@@ -200,6 +303,10 @@ impl AdexpParser {
                                             for header_pair in section_item_pair.into_inner() {
                                                 if header_pair.as_rule() == Rule::section_name {
                                                     current_section = Section::new(header_pair.as_str().to_string());
+                                                    items.push(AdexpItem::Field(AdexpField::new(
+                                                        header_pair.as_str().to_string(),
+                                                        String::new(),
+                                                    )));
                                                 }
                                             }
                                         }
@@ -215,6 +322,7 @@ impl AdexpParser {
                                                 )));
                                             }
                                             
+                                            items.push(AdexpItem::Field(Self::tree_field(&field_name, &field_value)));
                                             current_section.add_field(field_name, field_value);
                                         }
                                         _ => {}
@@ -240,7 +348,9 @@ impl AdexpParser {
             sections.insert("".to_string(), default_section);
         }
         
+        items.extend(lists.map(|(_, list)| AdexpItem::List(list)));
         message.sections = sections;
+        message.items = items;
         
         // Déterminer le type de message depuis TITLE
         if let Ok(Some(title)) = message.get_field_value("", "TITLE") {
@@ -251,6 +361,24 @@ impl AdexpParser {
     }
     
     
+    /// Champ de l'arbre: une valeur commençant par `-` suivi d'une lettre
+    /// est un champ composé (`-REFDATA -IFPLID XX -ORIGIN YY`)
+    fn tree_field(name: &str, value: &str) -> AdexpField {
+        let mut chars = value.chars();
+        if chars.next() == Some('-') && chars.next().is_some_and(|c| c.is_ascii_alphabetic()) {
+            AdexpField {
+                name: name.to_string(),
+                value: String::new(),
+                subfields: Self::split_inline_fields(value)
+                    .into_iter()
+                    .map(|(name, value)| AdexpField::new(name, value))
+                    .collect(),
+            }
+        } else {
+            AdexpField::new(name.to_string(), value.to_string())
+        }
+    }
+    
     fn parse_field(pair: pest::iterators::Pair<Rule>) -> Result<(String, String), AdexpError> {
         let mut field_name = String::new();
         let mut field_value = String::new();
@@ -422,5 +550,19 @@ mod tests {
         let result = AdexpParser::parse_message(input);
         assert!(result.is_ok(), "Should accept valid fields in BEGIN/END block");
     }
-}
 
+    #[test]
+    fn test_locate_in_input_skips_begin_blocks() {
+        let input = "-ADEXP\n-BEGIN RTEPTS\n-PT -PTID A\n-END RTEPTS\n-ARCID AFR 123\n";
+        let (stripped, _, kept_lines) = AdexpParser::extract_array_blocks(input).unwrap();
+        assert_eq!(stripped, "-ADEXP\n-ARCID AFR 123\n");
+
+        // Erreur sur "123" dans le texte sans bloc
+        let start = stripped.find("123").unwrap();
+        let error = SyntaxError::new("test", crate::diagnostic::Span::new(&stripped, start, start + 3));
+        let error = AdexpParser::locate_in_input(error, input, &stripped, &kept_lines);
+        assert_eq!(error.span.line, 5);
+        assert_eq!(&input[error.span.start..error.span.end], "123");
+        assert_eq!(error.field, Some(FieldRef::Name("ARCID".to_string())));
+    }
+}
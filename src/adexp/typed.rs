@@ -0,0 +1,516 @@
+//! Accès typé aux champs ADEXP et messages de plan de vol typés
+//!
+//! Les champs du message sont convertis à la lecture (dates, heures, niveaux,
+//! vitesses, coordonnées) à partir des structures de `fields.rs`. Les champs
+//! composés (ADDR, RTEPTS, VEC, REFDATA, CSTAT) sont lus depuis leur bloc
+//! `-BEGIN`/`-END` ou, à défaut, depuis leur forme en ligne
+//! (`-REFDATA -IFPLID AA12345678 -ORIGIN LFPG`). Pour un message parsé, les
+//! listes sont lues élément par élément dans l'arbre ordonné (`items`).
+
+use std::borrow::Cow;
+use serde::{Deserialize, Serialize};
+use crate::adexp::error::AdexpError;
+use crate::adexp::fields::{
+    self, AddrField, AdexpDate, AdexpSpeed, AdexpTime, CstatField, RefDataField, RoutePoint, VecField,
+};
+use crate::adexp::message::{AdexpMessage, Section};
+use crate::adexp::types::MessageType;
+use crate::datetime::UtcDateTime;
+
+impl AdexpMessage {
+    /// Première valeur non vide d'un champ de la section principale
+    pub fn main_value(&self, field: &str) -> Option<&str> {
+        self.sections
+            .get("")
+            .and_then(|section| section.fields.get(field))
+            .and_then(|values| values.first())
+            .map(String::as_str)
+            .filter(|value| !value.is_empty())
+    }
+
+    /// Convertit la première valeur d'un champ de la section principale
+    fn main_parsed<T>(&self, field: &str, parse: impl Fn(&str) -> Result<T, AdexpError>) -> Result<Option<T>, AdexpError> {
+        self.main_value(field).map(parse).transpose()
+    }
+
+    /// Indicatif d'appel (ARCID)
+    pub fn arcid(&self) -> Option<&str> {
+        self.main_value("ARCID")
+    }
+
+    /// Aérodrome de départ (ADEP)
+    pub fn adep(&self) -> Option<&str> {
+        self.main_value("ADEP")
+    }
+
+    /// Aérodrome de destination (ADES)
+    pub fn ades(&self) -> Option<&str> {
+        self.main_value("ADES")
+    }
+
+    /// Date estimée de départ du poste (EOBD)
+    pub fn eobd(&self) -> Result<Option<AdexpDate>, AdexpError> {
+        self.main_parsed("EOBD", AdexpDate::parse)
+    }
+
+    /// Heure estimée de départ du poste (EOBT)
+    pub fn eobt(&self) -> Result<Option<AdexpTime>, AdexpError> {
+        self.main_parsed("EOBT", AdexpTime::parse)
+    }
+
+    /// Instant estimé de départ du poste (EOBD + EOBT)
+    pub fn off_block_time(&self) -> Result<Option<UtcDateTime>, AdexpError> {
+        Ok(match (self.eobd()?, self.eobt()?) {
+            (Some(date), Some(time)) => Some(date.at(&time)),
+            _ => None,
+        })
+    }
+
+    /// Niveau de vol demandé (RFL), en centaines de pieds
+    pub fn rfl(&self) -> Result<Option<u16>, AdexpError> {
+        self.main_parsed("RFL", fields::parse_flight_level)
+    }
+
+    /// Vitesse de croisière (SPEED)
+    pub fn speed(&self) -> Result<Option<AdexpSpeed>, AdexpError> {
+        self.main_parsed("SPEED", AdexpSpeed::parse)
+    }
+
+    /// Liste d'adresses (ADDR)
+    pub fn addresses(&self) -> Vec<AddrField> {
+        if let Some(list) = self.find_list("ADDR") {
+            return list.fields()
+                .flat_map(|element| AddrField::from_section(&element.to_section()))
+                .collect();
+        }
+        self.compound_section("ADDR")
+            .map(|section| AddrField::from_section(&section))
+            .unwrap_or_default()
+    }
+
+    /// Points de route (RTEPTS)
+    pub fn route_points(&self) -> Result<Vec<RoutePoint>, AdexpError> {
+        if let Some(list) = self.find_list("RTEPTS") {
+            let mut points = Vec::new();
+            for element in list.fields() {
+                points.extend(RoutePoint::from_section(&element.to_section())?);
+            }
+            return Ok(points);
+        }
+        match self.compound_section("RTEPTS") {
+            Some(section) => RoutePoint::from_section(&section),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    /// Vecteur (VEC)
+    pub fn vector(&self) -> Result<Option<VecField>, AdexpError> {
+        self.compound_section("VEC")
+            .map(|section| VecField::from_section(&section))
+            .transpose()
+    }
+
+    /// Données de référence (REFDATA)
+    pub fn refdata(&self) -> Option<RefDataField> {
+        self.compound_section("REFDATA").map(|section| RefDataField::from_section(&section))
+    }
+
+    /// Statut courant (CSTAT)
+    pub fn cstat(&self) -> Option<CstatField> {
+        self.compound_section("CSTAT").map(|section| CstatField::from_section(&section))
+    }
+
+    /// Section d'un champ composé: bloc `-BEGIN NAME` ou forme en ligne
+    fn compound_section(&self, name: &str) -> Option<Cow<'_, Section>> {
+        if let Some(section) = self.sections.get(name) {
+            return Some(Cow::Borrowed(section));
+        }
+        let inline = self.main_value(name)?;
+        Some(Cow::Owned(parse_subfields(name, inline)))
+    }
+
+    /// Message typé selon le TITLE, `None` pour les types sans structure dédiée
+    pub fn typed(&self) -> Result<Option<AdexpTypedMessage>, AdexpError> {
+        Ok(Some(match self.message_type {
+            MessageType::FlightPlan | MessageType::Ifpl => AdexpTypedMessage::Fpl(AdexpFpl::from_message(self)?),
+            MessageType::Change | MessageType::Ichg => AdexpTypedMessage::Chg(AdexpChg::from_message(self)?),
+            MessageType::Delay | MessageType::Idla => AdexpTypedMessage::Dla(AdexpDla::from_message(self)?),
+            MessageType::Cancel | MessageType::Icnl => AdexpTypedMessage::Cnl(AdexpCnl::from_message(self)?),
+            MessageType::Departure | MessageType::Idep => AdexpTypedMessage::Dep(AdexpDep::from_message(self)?),
+            MessageType::Arrival | MessageType::Iarr => AdexpTypedMessage::Arr(AdexpArr::from_message(self)?),
+            _ => return Ok(None),
+        }))
+    }
+
+    fn required(&self, field: &str) -> Result<String, AdexpError> {
+        self.main_value(field)
+            .map(str::to_string)
+            .ok_or_else(|| AdexpError::MissingField(field.to_string()))
+    }
+
+    fn optional(&self, field: &str) -> Option<String> {
+        self.main_value(field).map(str::to_string)
+    }
+
+    fn expect_type(&self, expected: &[MessageType]) -> Result<(), AdexpError> {
+        if expected.contains(&self.message_type) {
+            Ok(())
+        } else {
+            Err(AdexpError::InvalidMessageType(format!(
+                "Type {:?} attendu, reçu: {:?}",
+                expected[0], self.message_type
+            )))
+        }
+    }
+}
+
+/// Découpe la forme en ligne d'un champ composé (`-FIELD valeur -FIELD valeur`)
+fn parse_subfields(name: &str, value: &str) -> Section {
+    let mut section = Section::new(name.to_string());
+    let mut current: Option<(&str, Vec<&str>)> = None;
+
+    for word in value.split_whitespace() {
+        match word.strip_prefix('-').filter(|w| !w.is_empty()) {
+            Some(field) => {
+                if let Some((field, words)) = current.take() {
+                    section.add_field(field.to_string(), words.join(" "));
+                }
+                current = Some((field, Vec::new()));
+            }
+            None => {
+                if let Some((_, words)) = current.as_mut() {
+                    words.push(word);
+                }
+            }
+        }
+    }
+    if let Some((field, words)) = current {
+        section.add_field(field.to_string(), words.join(" "));
+    }
+    section
+}
+
+/// Message ADEXP typé
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum AdexpTypedMessage {
+    Fpl(AdexpFpl),
+    Chg(AdexpChg),
+    Dla(AdexpDla),
+    Cnl(AdexpCnl),
+    Dep(AdexpDep),
+    Arr(AdexpArr),
+}
+
+/// Plan de vol (FPL / IFPL)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AdexpFpl {
+    pub arcid: String,
+    pub adep: String,
+    pub ades: String,
+    pub eobd: Option<AdexpDate>,
+    pub eobt: Option<AdexpTime>,
+    pub arctyp: Option<String>,
+    pub wktrc: Option<String>,
+    pub fltrul: Option<String>,
+    pub flttyp: Option<String>,
+
+    /// Niveau de vol demandé (centaines de pieds)
+    pub rfl: Option<u16>,
+    pub speed: Option<AdexpSpeed>,
+    pub route: Option<String>,
+    pub altrnt1: Option<String>,
+    pub altrnt2: Option<String>,
+
+    /// Durée totale estimée (TTLEET)
+    pub ttleet: Option<AdexpTime>,
+    pub reg: Option<String>,
+    pub sel: Option<String>,
+    pub pbn: Option<String>,
+    pub addresses: Vec<AddrField>,
+    pub route_points: Vec<RoutePoint>,
+    pub refdata: Option<RefDataField>,
+}
+
+/// Modification (CHG / ICHG): identification du vol et nouvelles valeurs
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AdexpChg {
+    pub arcid: String,
+    pub adep: Option<String>,
+    pub ades: Option<String>,
+    pub eobd: Option<AdexpDate>,
+    pub eobt: Option<AdexpTime>,
+    pub arctyp: Option<String>,
+    pub rfl: Option<u16>,
+    pub speed: Option<AdexpSpeed>,
+    pub route: Option<String>,
+    pub altrnt1: Option<String>,
+    pub route_points: Vec<RoutePoint>,
+    pub refdata: Option<RefDataField>,
+}
+
+/// Retard (DLA / IDLA): EOBT porte la nouvelle heure de départ
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AdexpDla {
+    pub arcid: String,
+    pub adep: Option<String>,
+    pub ades: Option<String>,
+    pub eobd: Option<AdexpDate>,
+    pub eobt: Option<AdexpTime>,
+    pub refdata: Option<RefDataField>,
+}
+
+/// Annulation (CNL / ICNL)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AdexpCnl {
+    pub arcid: String,
+    pub adep: Option<String>,
+    pub ades: Option<String>,
+    pub eobd: Option<AdexpDate>,
+    pub eobt: Option<AdexpTime>,
+    pub refdata: Option<RefDataField>,
+}
+
+/// Départ (DEP / IDEP)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AdexpDep {
+    pub arcid: String,
+    pub adep: Option<String>,
+    pub ades: Option<String>,
+    pub eobd: Option<AdexpDate>,
+    pub eobt: Option<AdexpTime>,
+
+    /// Heure réelle de départ (ATD, sinon ACTDEP)
+    pub departure_time: Option<AdexpTime>,
+    pub refdata: Option<RefDataField>,
+}
+
+/// Arrivée (ARR / IARR)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AdexpArr {
+    pub arcid: String,
+    pub adep: Option<String>,
+    pub ades: Option<String>,
+    pub eobd: Option<AdexpDate>,
+    pub eobt: Option<AdexpTime>,
+
+    /// Heure réelle d'arrivée (ATA, sinon ACTARR)
+    pub arrival_time: Option<AdexpTime>,
+    pub refdata: Option<RefDataField>,
+}
+
+impl AdexpFpl {
+    pub fn from_message(message: &AdexpMessage) -> Result<Self, AdexpError> {
+        message.expect_type(&[MessageType::FlightPlan, MessageType::Ifpl])?;
+        Ok(AdexpFpl {
+            arcid: message.required("ARCID")?,
+            adep: message.required("ADEP")?,
+            ades: message.required("ADES")?,
+            eobd: message.eobd()?,
+            eobt: message.eobt()?,
+            arctyp: message.optional("ARCTYP"),
+            wktrc: message.optional("WKTRC"),
+            fltrul: message.optional("FLTRUL"),
+            flttyp: message.optional("FLTTYP"),
+            rfl: message.rfl()?,
+            speed: message.speed()?,
+            route: message.optional("ROUTE"),
+            altrnt1: message.optional("ALTRNT1"),
+            altrnt2: message.optional("ALTRNT2"),
+            ttleet: message.main_parsed("TTLEET", AdexpTime::parse)?,
+            reg: message.optional("REG"),
+            sel: message.optional("SEL"),
+            pbn: message.optional("PBN"),
+            addresses: message.addresses(),
+            route_points: message.route_points()?,
+            refdata: message.refdata(),
+        })
+    }
+
+    /// Instant estimé de départ du poste (EOBD + EOBT)
+    pub fn off_block_time(&self) -> Option<UtcDateTime> {
+        Some(self.eobd?.at(&self.eobt?))
+    }
+}
+
+impl AdexpChg {
+    pub fn from_message(message: &AdexpMessage) -> Result<Self, AdexpError> {
+        message.expect_type(&[MessageType::Change, MessageType::Ichg])?;
+        Ok(AdexpChg {
+            arcid: message.required("ARCID")?,
+            adep: message.optional("ADEP"),
+            ades: message.optional("ADES"),
+            eobd: message.eobd()?,
+            eobt: message.eobt()?,
+            arctyp: message.optional("ARCTYP"),
+            rfl: message.rfl()?,
+            speed: message.speed()?,
+            route: message.optional("ROUTE"),
+            altrnt1: message.optional("ALTRNT1"),
+            route_points: message.route_points()?,
+            refdata: message.refdata(),
+        })
+    }
+}
+
+impl AdexpDla {
+    pub fn from_message(message: &AdexpMessage) -> Result<Self, AdexpError> {
+        message.expect_type(&[MessageType::Delay, MessageType::Idla])?;
+        Ok(AdexpDla {
+            arcid: message.required("ARCID")?,
+            adep: message.optional("ADEP"),
+            ades: message.optional("ADES"),
+            eobd: message.eobd()?,
+            eobt: message.eobt()?,
+            refdata: message.refdata(),
+        })
+    }
+}
+
+impl AdexpCnl {
+    pub fn from_message(message: &AdexpMessage) -> Result<Self, AdexpError> {
+        message.expect_type(&[MessageType::Cancel, MessageType::Icnl])?;
+        Ok(AdexpCnl {
+            arcid: message.required("ARCID")?,
+            adep: message.optional("ADEP"),
+            ades: message.optional("ADES"),
+            eobd: message.eobd()?,
+            eobt: message.eobt()?,
+            refdata: message.refdata(),
+        })
+    }
+}
+
+impl AdexpDep {
+    pub fn from_message(message: &AdexpMessage) -> Result<Self, AdexpError> {
+        message.expect_type(&[MessageType::Departure, MessageType::Idep])?;
+        let departure_time = match message.main_parsed("ATD", AdexpTime::parse)? {
+            Some(time) => Some(time),
+            None => message.main_parsed("ACTDEP", AdexpTime::parse)?,
+        };
+        Ok(AdexpDep {
+            arcid: message.required("ARCID")?,
+            adep: message.optional("ADEP"),
+            ades: message.optional("ADES"),
+            eobd: message.eobd()?,
+            eobt: message.eobt()?,
+            departure_time,
+            refdata: message.refdata(),
+        })
+    }
+}
+
+impl AdexpArr {
+    pub fn from_message(message: &AdexpMessage) -> Result<Self, AdexpError> {
+        message.expect_type(&[MessageType::Arrival, MessageType::Iarr])?;
+        let arrival_time = match message.main_parsed("ATA", AdexpTime::parse)? {
+            Some(time) => Some(time),
+            None => message.main_parsed("ACTARR", AdexpTime::parse)?,
+        };
+        Ok(AdexpArr {
+            arcid: message.required("ARCID")?,
+            adep: message.optional("ADEP"),
+            ades: message.optional("ADES"),
+            eobd: message.eobd()?,
+            eobt: message.eobt()?,
+            arrival_time,
+            refdata: message.refdata(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adexp::fields::AdexpAltitude;
+    use crate::adexp::parser::AdexpParser;
+
+    #[test]
+    fn test_typed_fpl() {
+        let input = "-ADEXP
+-TITLE IFPL
+-ARCID AFR123
+-ADEP LFPG
+-ADES EGLL
+-EOBD 150324
+-EOBT 1230
+-RFL FL350
+-SPEED N0450
+-REFDATA -IFPLID AA12345678 -ORIGIN LFPG
+-BEGIN RTEPTS
+-PT -PTID LFPG -FL 000 -ETO 1230
+-PT -PTID ABB -FL 350 -ETO 1310
+-END RTEPTS
+";
+        let message = AdexpParser::parse_message(input).unwrap();
+        let fpl = match message.typed().unwrap() {
+            Some(AdexpTypedMessage::Fpl(fpl)) => fpl,
+            other => panic!("FPL attendu, obtenu {:?}", other),
+        };
+
+        assert_eq!(fpl.arcid, "AFR123");
+        assert_eq!(fpl.rfl, Some(350));
+        assert_eq!(fpl.speed, Some(AdexpSpeed::Knots(450)));
+        assert_eq!(fpl.eobd, Some(AdexpDate { year: 2024, month: 3, day: 15 }));
+        assert_eq!(fpl.off_block_time(), Some(UtcDateTime::new(2024, 3, 15, 12, 30).unwrap()));
+
+        assert_eq!(fpl.route_points.len(), 2);
+        assert_eq!(fpl.route_points[1].name(), Some("ABB"));
+        assert_eq!(fpl.route_points[1].fl, Some(350));
+        assert_eq!(fpl.route_points[1].eto, Some(AdexpTime { hour: 13, minute: 10, second: 0 }));
+
+        let refdata = fpl.refdata.unwrap();
+        assert_eq!(refdata.ifplid.as_deref(), Some("AA12345678"));
+        assert_eq!(refdata.origin.as_deref(), Some("LFPG"));
+    }
+
+    #[test]
+    fn test_typed_fpl_f_prefixed_level() {
+        let input = "-ADEXP\n-TITLE FPL\n-ARCID AFR123\n-ADEP LFPG\n-ADES EGLL\n-RFL F350\n";
+        let message = AdexpParser::parse_message(input).unwrap();
+        let fpl = AdexpFpl::from_message(&message).unwrap();
+        assert_eq!(fpl.rfl, Some(350));
+    }
+
+    #[test]
+    fn test_typed_dep_and_type_mismatch() {
+        let input = "-ADEXP\n-TITLE DEP\n-ARCID AFR123\n-ADEP LFPG\n-ATD 1242\n";
+        let message = AdexpParser::parse_message(input).unwrap();
+        let dep = AdexpDep::from_message(&message).unwrap();
+        assert_eq!(dep.departure_time, Some(AdexpTime { hour: 12, minute: 42, second: 0 }));
+
+        assert!(matches!(AdexpFpl::from_message(&message), Err(AdexpError::InvalidMessageType(_))));
+    }
+
+    #[test]
+    fn test_typed_fpl_missing_and_invalid_fields() {
+        let message = AdexpParser::parse_message("-ADEXP\n-TITLE FPL\n-ARCID AFR123\n-ADEP LFPG\n").unwrap();
+        assert_eq!(AdexpFpl::from_message(&message), Err(AdexpError::MissingField("ADES".to_string())));
+
+        let message = AdexpParser::parse_message("-ADEXP\n-TITLE CHG\n-ARCID AFR123\n-EOBT 2561\n").unwrap();
+        assert!(matches!(AdexpChg::from_message(&message), Err(AdexpError::InvalidDateTime(_))));
+    }
+
+    #[test]
+    fn test_typed_addresses_and_vector() {
+        let input = "-ADEXP
+-TITLE FPL
+-ARCID AFR123
+-BEGIN ADDR
+-FAC LFPGZQZX
+-FAC EGLLZQZX
+-END ADDR
+-BEGIN VEC
+-TRACKANGLE 090 -GROUNDSPEED 450 -ALT 12000
+-END VEC
+";
+        let message = AdexpParser::parse_message(input).unwrap();
+        let addresses = message.addresses();
+        assert_eq!(addresses.len(), 2);
+        assert_eq!(addresses[1].address, "EGLLZQZX");
+
+        let vector = message.vector().unwrap().unwrap();
+        assert_eq!(vector.track_angle, Some(90));
+        assert_eq!(vector.ground_speed, Some(AdexpSpeed::Knots(450)));
+        assert_eq!(vector.altitude, Some(AdexpAltitude::Feet(12000)));
+    }
+}
@@ -0,0 +1,654 @@
+//! Construction programmatique de messages AFTN
+//!
+//! Les builders produisent un texte OACI conforme puis le relisent avec les
+//! parsers de la crate : un message construit avec succès passe donc les mêmes
+//! contrôles que `AftnMessage::validate` et les fonctions de `aftn::validation`.
+//!
+//! ```
+//! use aftn::aftn::AftnMessageBuilder;
+//! use aftn::aftn::categories::flight_plan::FplMessage;
+//! use aftn::aftn::categories::flight_plan::ats_fields::{CruisingLevel, CruisingSpeed, Hhmm};
+//!
+//! let fpl = FplMessage::builder()
+//!     .aircraft_id("AFR123")
+//!     .flight_rules('I', Some('S'))
+//!     .aircraft(None, "A320", 'M')
+//!     .equipment("SDFGIRWY", "S")
+//!     .departure("LFPG", Some(Hhmm { hour: 9, minute: 0 }))
+//!     .route(CruisingSpeed::Knots(450), CruisingLevel::FlightLevel(350), "UM184 RESMI UN860")
+//!     .destination("EGLL", Some(Hhmm { hour: 1, minute: 5 }))
+//!     .other_information("DOF", "261017")
+//!     .build()?;
+//!
+//! let message = AftnMessageBuilder::new()
+//!     .priority("FF")
+//!     .origin("LFPGZPZX")
+//!     .destination("EGLLZPZX")
+//!     .filing_time(17, 8, 30)
+//!     .text(&fpl.raw)
+//!     .build()?;
+//! assert!(message.submessage.parsed().is_some());
+//! # Ok::<(), aftn::AftnError>(())
+//! ```
+
+use std::marker::PhantomData;
+use crate::aftn::categories::coordination::{AbiMessage, CofMessage, ReqMessage};
+use crate::aftn::categories::flight_plan::ats::{self, is_ats_format, AtsMessage};
+use crate::aftn::categories::flight_plan::ats_fields::{CruisingLevel, CruisingSpeed, EstimateData, Hhmm};
+use crate::aftn::categories::flight_plan::{
+    ArrMessage, ChgMessage, CnlMessage, DepMessage, DlaMessage, EstMessage, FplMessage, SplMessage,
+};
+use crate::datetime::UtcDateTime;
+use crate::aftn::error::AftnError;
+use crate::aftn::message::{Addresses, AftnEnvelope, AftnMessage, TransmissionTime};
+use crate::aftn::parser::AftnParser;
+use crate::aftn::submessages::SubMessage;
+use crate::aftn::validation;
+
+/// Longueur maximale d'une ligne de texte AFTN (Annexe 10, Vol. II)
+pub const MAX_LINE_LENGTH: usize = 69;
+
+/// Nombre maximal d'indicateurs de destinataires d'un message
+pub const MAX_ADDRESSEES: usize = 21;
+
+/// Découpe un texte en lignes d'au plus `width` caractères.
+///
+/// Les coupures se font sur les espaces ou, de préférence dans un message ATS
+/// en forme parenthésée, devant les séparateurs de champs `-`. Un mot plus long que la
+/// ligne est coupé à la largeur maximale.
+pub fn wrap_text(text: &str, width: usize) -> Vec<String> {
+    let width = width.max(1);
+    let split_fields = is_ats_format(text);
+    let mut lines = Vec::new();
+
+    for line in text.lines() {
+        let mut rest: Vec<char> = line.trim_end().chars().collect();
+        while rest.len() > width {
+            // Dernière coupure possible qui laisse une ligne non vide, de
+            // préférence devant un champ ATS
+            let field_cut = (1..=width).rev()
+                .find(|&i| split_fields && rest[i] == '-' && rest[i - 1] != ' ');
+            let cut = field_cut.or_else(|| (1..=width).rev().find(|&i| rest[i] == ' '));
+            let (head, tail) = match cut {
+                Some(i) => (rest[..i].to_vec(), rest[i..].to_vec()),
+                None => (rest[..width].to_vec(), rest[width..].to_vec()),
+            };
+            lines.push(head.iter().collect::<String>().trim_end().to_string());
+            rest = match tail.iter().position(|&c| c != ' ') {
+                Some(start) => tail[start..].to_vec(),
+                None => Vec::new(),
+            };
+        }
+        if !rest.is_empty() {
+            lines.push(rest.into_iter().collect());
+        }
+    }
+
+    lines
+}
+
+/// Builder d'un message AFTN complet (en-tête, adresses, origine et texte)
+///
+/// Sans ligne de début (`heading`), le message est produit sous forme compacte
+/// `PRIORITÉ ORIGINE DESTINATAIRES DDHHMM TEXTE` ; avec une ligne de début, il
+/// est produit sous forme d'enveloppe télégraphique (ZCZC ... NNNN).
+#[derive(Debug, Clone, Default)]
+pub struct AftnMessageBuilder {
+    priority: Option<String>,
+    origin: Option<String>,
+    destinations: Vec<String>,
+    filing_time: Option<TransmissionTime>,
+    heading: Option<(String, String)>,
+    heading_info: Option<String>,
+    origin_info: Option<String>,
+    text: Option<String>,
+}
+
+impl AftnMessageBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Indicateur de priorité (GG, DD, FF, SS, KK, LL)
+    pub fn priority(mut self, priority: &str) -> Self {
+        self.priority = Some(priority.to_string());
+        self
+    }
+
+    /// Indicateur d'origine (8 lettres)
+    pub fn origin(mut self, origin: &str) -> Self {
+        self.origin = Some(origin.to_string());
+        self
+    }
+
+    /// Ajoute un indicateur de destinataire
+    pub fn destination(mut self, address: &str) -> Self {
+        self.destinations.push(address.to_string());
+        self
+    }
+
+    /// Ajoute plusieurs indicateurs de destinataires
+    pub fn destinations<'a>(mut self, addresses: impl IntoIterator<Item = &'a str>) -> Self {
+        self.destinations.extend(addresses.into_iter().map(|a| a.to_string()));
+        self
+    }
+
+    /// Heure de dépôt (groupe DDHHMM)
+    pub fn filing_time(mut self, day: u8, hour: u8, minute: u8) -> Self {
+        self.filing_time = Some(TransmissionTime { day, hour, minute });
+        self
+    }
+
+    /// Heure de dépôt à partir d'un instant UTC
+    pub fn filed_at(self, time: &UtcDateTime) -> Self {
+        self.filing_time(time.day, time.hour, time.minute)
+    }
+
+    /// Ligne de début : identification de transmission (voie et numéro)
+    pub fn heading(mut self, channel_id: &str, channel_sequence: &str) -> Self {
+        self.heading = Some((channel_id.to_string(), channel_sequence.to_string()));
+        self
+    }
+
+    /// Renseignements complémentaires de la ligne de début
+    pub fn heading_info(mut self, info: &str) -> Self {
+        self.heading_info = Some(info.to_string());
+        self
+    }
+
+    /// Renseignements complémentaires de la ligne d'origine (ex: priorité d'alarme)
+    pub fn origin_info(mut self, info: &str) -> Self {
+        self.origin_info = Some(info.to_string());
+        self
+    }
+
+    /// Texte du message (sous-message)
+    pub fn text(mut self, text: &str) -> Self {
+        self.text = Some(text.to_string());
+        self
+    }
+
+    /// Texte du message à partir d'un message ATS
+    pub fn ats_message(self, message: &AtsMessage) -> Self {
+        let text = message.serialize();
+        self.text(&text)
+    }
+
+    /// Construit le message : le texte est découpé en lignes de 69 caractères
+    /// au plus, puis le message est validé comme un message reçu.
+    pub fn build(self) -> Result<AftnMessage, AftnError> {
+        let priority = self.priority.ok_or_else(|| {
+            AftnError::InvalidPriority("Priority indicator is required".to_string())
+        })?;
+        let origin = self.origin.ok_or_else(|| {
+            AftnError::InvalidAddress("Origin address is required".to_string())
+        })?;
+        if self.destinations.is_empty() {
+            return Err(AftnError::InvalidAddress("At least one destination address is required".to_string()));
+        }
+        if self.destinations.len() > MAX_ADDRESSEES {
+            return Err(AftnError::InvalidAddress(format!(
+                "At most {} destination addresses are allowed, got {}",
+                MAX_ADDRESSEES,
+                self.destinations.len()
+            )));
+        }
+        for address in std::iter::once(&origin).chain(&self.destinations) {
+            if !address.chars().all(|c| c.is_ascii_uppercase()) {
+                return Err(AftnError::InvalidAddress(format!(
+                    "Address must contain uppercase letters only, got: {}",
+                    address
+                )));
+            }
+        }
+        let filing_time = self.filing_time.ok_or_else(|| {
+            AftnError::InvalidDateTime("Filing time is required".to_string())
+        })?;
+        let text = self.text.unwrap_or_default();
+        if text.trim().is_empty() {
+            return Err(AftnError::InvalidFormat("Message text cannot be empty".to_string()));
+        }
+
+        let envelope = match self.heading {
+            Some((channel_id, channel_sequence)) => Some(AftnEnvelope {
+                channel_id,
+                channel_sequence,
+                heading_info: self.heading_info,
+                origin_info: self.origin_info,
+                end_of_message: true,
+            }),
+            None if self.heading_info.is_some() || self.origin_info.is_some() => {
+                return Err(AftnError::InvalidFormat(
+                    "Heading and origin information require a heading line".to_string(),
+                ));
+            }
+            None => None,
+        };
+
+        let separator = if envelope.is_some() { "\r\n" } else { "\n" };
+        let body = wrap_text(text.trim(), MAX_LINE_LENGTH).join(separator);
+        let addresses = Addresses { origin, destinations: self.destinations };
+        let message = AftnParser::build_message(priority, addresses, filing_time, body, None, envelope)?;
+
+        // Un texte reconnu mais invalide pour sa catégorie n'est pas émis
+        if let Some(error) = message.submessage.error() {
+            return Err(error.clone());
+        }
+        Ok(message)
+    }
+}
+
+/// Message pouvant être produit par un `AtsMessageBuilder`
+pub trait FromAtsText: Sized {
+    /// Décode et valide le texte OACI produit par le builder
+    fn from_ats_text(text: &str) -> Result<Self, AftnError>;
+}
+
+impl FromAtsText for AtsMessage {
+    fn from_ats_text(text: &str) -> Result<Self, AftnError> {
+        AtsMessage::parse(text)
+    }
+}
+
+macro_rules! ats_builder {
+    ($($message:ty => $designator:literal),* $(,)?) => {
+        $(
+            impl FromAtsText for $message {
+                fn from_ats_text(text: &str) -> Result<Self, AftnError> {
+                    let message = <$message as SubMessage>::parse(text)?;
+                    message.validate()?;
+                    Ok(message)
+                }
+            }
+
+            impl $message {
+                /// Builder du message en forme parenthésée OACI
+                pub fn builder() -> AtsMessageBuilder<$message> {
+                    AtsMessageBuilder::with_designator($designator)
+                }
+            }
+        )*
+    };
+}
+
+ats_builder! {
+    FplMessage => "FPL",
+    ChgMessage => "CHG",
+    CnlMessage => "CNL",
+    DlaMessage => "DLA",
+    DepMessage => "DEP",
+    ArrMessage => "ARR",
+    EstMessage => "EST",
+    SplMessage => "SPL",
+    AbiMessage => "ABI",
+}
+
+impl AtsMessage {
+    /// Builder d'un message ATS du type donné (FPL, CPL, CHG, ...)
+    pub fn builder(designator: &str) -> AtsMessageBuilder<AtsMessage> {
+        AtsMessageBuilder::with_designator(designator)
+    }
+}
+
+/// Builder d'un message ATS en forme parenthésée (Doc 4444, Appendice 3)
+///
+/// Les champs sont renseignés dans n'importe quel ordre ; `build` les émet dans
+/// l'ordre de la composition du type de message et refuse un champ obligatoire
+/// manquant ou un champ étranger à ce type.
+#[derive(Debug, Clone)]
+pub struct AtsMessageBuilder<T> {
+    designator: String,
+    aircraft_id: Option<String>,
+    ssr_code: Option<String>,
+    fields: Vec<(u8, String)>,
+    other_information: Vec<(String, String)>,
+    supplementary_information: Vec<(String, String)>,
+    alternates: Vec<String>,
+    amendments: Vec<String>,
+    aerodromes: Vec<String>,
+    _message: PhantomData<T>,
+}
+
+impl<T: FromAtsText> AtsMessageBuilder<T> {
+    fn with_designator(designator: &str) -> Self {
+        AtsMessageBuilder {
+            designator: designator.to_string(),
+            aircraft_id: None,
+            ssr_code: None,
+            fields: Vec::new(),
+            other_information: Vec::new(),
+            supplementary_information: Vec::new(),
+            alternates: Vec::new(),
+            amendments: Vec::new(),
+            aerodromes: Vec::new(),
+            _message: PhantomData,
+        }
+    }
+
+    fn field(mut self, number: u8, text: String) -> Self {
+        self.fields.retain(|(n, _)| *n != number);
+        self.fields.push((number, text));
+        self
+    }
+
+    /// Field Type 7a : identification de l'aéronef
+    pub fn aircraft_id(mut self, aircraft_id: &str) -> Self {
+        self.aircraft_id = Some(aircraft_id.to_string());
+        self
+    }
+
+    /// Field Types 7b/7c : mode et code SSR, ex: `A1234`
+    pub fn ssr_code(mut self, code: &str) -> Self {
+        self.ssr_code = Some(code.to_string());
+        self
+    }
+
+    /// Field Type 8 : règles de vol (I, V, Y, Z) et type de vol
+    pub fn flight_rules(self, rules: char, flight_type: Option<char>) -> Self {
+        let text: String = std::iter::once(rules).chain(flight_type).collect();
+        self.field(8, text)
+    }
+
+    /// Field Type 9 : nombre d'aéronefs, type et catégorie de turbulence de sillage
+    pub fn aircraft(self, number: Option<u8>, aircraft_type: &str, wake_turbulence: char) -> Self {
+        let number = number.map(|n| n.to_string()).unwrap_or_default();
+        self.field(9, format!("{}{}/{}", number, aircraft_type, wake_turbulence))
+    }
+
+    /// Field Type 10 : équipement radio/navigation (10a) et de surveillance (10b)
+    pub fn equipment(self, radio_nav: &str, surveillance: &str) -> Self {
+        self.field(10, format!("{}/{}", radio_nav, surveillance))
+    }
+
+    /// Field Type 13 : aérodrome et heure de départ
+    pub fn departure(mut self, aerodrome: &str, time: Option<Hhmm>) -> Self {
+        self.aerodromes.push(aerodrome.to_string());
+        self.field(13, aerodrome_time(aerodrome, time))
+    }
+
+    /// Field Type 14 : données d'estimation
+    pub fn estimate(self, estimate: &EstimateData) -> Self {
+        self.field(14, estimate.serialize())
+    }
+
+    /// Field Type 15 : vitesse et niveau de croisière, puis éléments de route
+    pub fn route(self, speed: CruisingSpeed, level: CruisingLevel, elements: &str) -> Self {
+        let text = format!("{}{} {}", speed.serialize(), level.serialize(), elements.trim());
+        self.field(15, text.trim_end().to_string())
+    }
+
+    /// Field Type 16a/16b : aérodrome de destination et durée totale estimée
+    pub fn destination(mut self, aerodrome: &str, total_eet: Option<Hhmm>) -> Self {
+        self.aerodromes.push(aerodrome.to_string());
+        self.field(16, aerodrome_time(aerodrome, total_eet))
+    }
+
+    /// Field Type 16c : aérodrome de dégagement (deux au plus)
+    pub fn alternate(mut self, aerodrome: &str) -> Self {
+        self.aerodromes.push(aerodrome.to_string());
+        self.alternates.push(aerodrome.to_string());
+        self
+    }
+
+    /// Field Type 17 : aérodrome et heure d'arrivée
+    pub fn arrival(mut self, aerodrome: &str, time: Hhmm) -> Self {
+        self.aerodromes.push(aerodrome.to_string());
+        self.field(17, aerodrome_time(aerodrome, Some(time)))
+    }
+
+    /// Field Type 18 : ajoute un renseignement `INDICATEUR/valeur`
+    pub fn other_information(mut self, indicator: &str, value: &str) -> Self {
+        self.other_information.push((indicator.to_string(), value.to_string()));
+        self
+    }
+
+    /// Field Type 19 : ajoute un renseignement complémentaire `X/valeur`
+    pub fn supplementary_information(mut self, indicator: &str, value: &str) -> Self {
+        self.supplementary_information.push((indicator.to_string(), value.to_string()));
+        self
+    }
+
+    /// Field Type 22 : ajoute un amendement du champ `field_number`
+    pub fn amendment(mut self, field_number: u8, content: &str) -> Self {
+        self.amendments.push(format!("{}/{}", field_number, content));
+        self
+    }
+
+    /// Produit le texte OACI du message, après contrôle de la composition
+    fn compose(&self) -> Result<String, AftnError> {
+        let numbers = ats::message_field_numbers(&self.designator).ok_or_else(|| {
+            AftnError::InvalidFormat(format!("Unsupported ATS message type: {}", self.designator))
+        })?;
+
+        let aircraft_id = self.aircraft_id.as_deref().ok_or_else(|| {
+            AftnError::InvalidFormat("Field 7: aircraft identification is required".to_string())
+        })?;
+        validation::validate_callsign(aircraft_id)?;
+        for aerodrome in &self.aerodromes {
+            validation::validate_aerodrome_code(aerodrome)?;
+        }
+        if self.alternates.len() > 2 {
+            return Err(AftnError::InvalidFormat(format!(
+                "Field 16: at most 2 alternate aerodromes are allowed, got {}",
+                self.alternates.len()
+            )));
+        }
+
+        let mut texts: Vec<(u8, Vec<String>)> = self.fields.iter()
+            .map(|(n, text)| (*n, vec![text.clone()]))
+            .collect();
+        texts.push((7, vec![match self.ssr_code {
+            Some(ref code) => format!("{}/{}", aircraft_id, code),
+            None => aircraft_id.to_string(),
+        }]));
+        if !self.alternates.is_empty() {
+            let destination = texts.iter_mut().find(|(n, _)| *n == 16).ok_or_else(|| {
+                AftnError::InvalidFormat("Field 16: alternate aerodromes require a destination".to_string())
+            })?;
+            for alternate in &self.alternates {
+                destination.1[0].push(' ');
+                destination.1[0].push_str(alternate);
+            }
+        }
+        if !self.other_information.is_empty() {
+            texts.push((18, vec![info_items(&self.other_information)]));
+        } else if matches!(self.designator.as_str(), "FPL" | "CPL") {
+            texts.push((18, vec!["0".to_string()]));
+        }
+        if !self.supplementary_information.is_empty() {
+            texts.push((19, vec![info_items(&self.supplementary_information)]));
+        }
+        if !self.amendments.is_empty() {
+            texts.push((22, self.amendments.clone()));
+        }
+
+        if let Some((number, _)) = texts.iter().find(|(n, _)| !numbers.contains(n)) {
+            return Err(AftnError::InvalidFormat(format!(
+                "Field {} is not part of a {} message",
+                number, self.designator
+            )));
+        }
+
+        let mut result = format!("({}", self.designator);
+        for number in numbers {
+            for (_, values) in texts.iter().filter(|(n, _)| *n == number) {
+                for value in values {
+                    result.push('-');
+                    result.push_str(value);
+                }
+            }
+        }
+        result.push(')');
+        Ok(result)
+    }
+
+    /// Construit le message ; le texte produit est relu par le parser du type
+    /// de message, ce qui garantit qu'il est conforme.
+    pub fn build(self) -> Result<T, AftnError> {
+        let text = self.compose()?;
+        // Champ obligatoire manquant: signalé par la relecture de la composition
+        AtsMessage::parse_as(&text, &[self.designator.as_str()])?;
+        T::from_ats_text(&text)
+    }
+}
+
+/// Texte `AÉRODROME[HHMM]` des Field Types 13, 16a et 17
+fn aerodrome_time(aerodrome: &str, time: Option<Hhmm>) -> String {
+    match time {
+        Some(time) => format!("{}{}", aerodrome, time.serialize()),
+        None => aerodrome.to_string(),
+    }
+}
+
+/// Texte `INDICATEUR/valeur ...` des Field Types 18 et 19
+fn info_items(items: &[(String, String)]) -> String {
+    items.iter()
+        .map(|(indicator, value)| format!("{}/{}", indicator, value))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+impl CofMessage {
+    /// Builder d'un message de coordination COF
+    pub fn builder() -> CofMessageBuilder {
+        CofMessageBuilder::default()
+    }
+}
+
+/// Builder d'un message de coordination `COF <indicatif> <données>`
+#[derive(Debug, Clone, Default)]
+pub struct CofMessageBuilder {
+    callsign: Option<String>,
+    coordination_data: Option<String>,
+}
+
+impl CofMessageBuilder {
+    /// Indicatif du vol coordonné
+    pub fn callsign(mut self, callsign: &str) -> Self {
+        self.callsign = Some(callsign.to_string());
+        self
+    }
+
+    /// Données de coordination
+    pub fn coordination_data(mut self, data: &str) -> Self {
+        self.coordination_data = Some(data.to_string());
+        self
+    }
+
+    pub fn build(self) -> Result<CofMessage, AftnError> {
+        let callsign = self.callsign.ok_or_else(|| {
+            AftnError::InvalidFormat("COF message requires a callsign".to_string())
+        })?;
+        validation::validate_callsign(&callsign)?;
+
+        let mut text = format!("COF {}", callsign);
+        if let Some(data) = self.coordination_data.as_deref().map(str::trim).filter(|d| !d.is_empty()) {
+            text.push(' ');
+            text.push_str(data);
+        }
+        let message = CofMessage::parse(&text)?;
+        message.validate()?;
+        Ok(message)
+    }
+}
+
+impl ReqMessage {
+    /// Builder d'un message de demande REQ
+    pub fn builder() -> ReqMessageBuilder {
+        ReqMessageBuilder::default()
+    }
+}
+
+/// Builder d'un message de demande `REQ <type> <contenu>`
+#[derive(Debug, Clone, Default)]
+pub struct ReqMessageBuilder {
+    request_type: Option<String>,
+    request_content: Option<String>,
+}
+
+impl ReqMessageBuilder {
+    /// Type de la demande (ex: `FPL`)
+    pub fn request_type(mut self, request_type: &str) -> Self {
+        self.request_type = Some(request_type.to_string());
+        self
+    }
+
+    /// Contenu de la demande (ex: indicatif du vol)
+    pub fn request_content(mut self, content: &str) -> Self {
+        self.request_content = Some(content.to_string());
+        self
+    }
+
+    pub fn build(self) -> Result<ReqMessage, AftnError> {
+        let request_type = self.request_type.ok_or_else(|| {
+            AftnError::InvalidFormat("REQ message requires a request type".to_string())
+        })?;
+        if request_type.is_empty() || request_type.contains(char::is_whitespace) {
+            return Err(AftnError::InvalidFormat(format!(
+                "REQ request type must be a single word, got: {}",
+                request_type
+            )));
+        }
+
+        let mut text = format!("REQ {}", request_type);
+        if let Some(content) = self.request_content.as_deref().map(str::trim).filter(|c| !c.is_empty()) {
+            text.push(' ');
+            text.push_str(content);
+        }
+        let message = ReqMessage::parse(&text)?;
+        message.validate()?;
+        Ok(message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wrap_text_breaks_before_ats_fields() {
+        let text = "(FPL-AFR123-IS-A320/M-SDFGIRWY/S-LFPG0900-N0450F350 UM184 RESMI UN860 SOVAT-EGLL0105 EGKK-PBN/A1B1 DOF/261017)";
+        let lines = wrap_text(text, MAX_LINE_LENGTH);
+        assert!(lines.len() > 1);
+        assert!(lines.iter().all(|l| l.len() <= MAX_LINE_LENGTH));
+        assert!(lines[1].starts_with('-'));
+        assert_eq!(lines.concat().replace(' ', ""), text.replace(' ', ""));
+    }
+
+    #[test]
+    fn test_wrap_text_splits_long_words() {
+        let lines = wrap_text("ABCDEFGHIJ KL", 4);
+        assert_eq!(lines, vec!["ABCD", "EFGH", "IJ", "KL"]);
+    }
+
+    #[test]
+    fn test_ats_builder_missing_required_field() {
+        let result = DlaMessage::builder()
+            .aircraft_id("AFR123")
+            .departure("LFPG", Some(Hhmm { hour: 9, minute: 30 }))
+            .build();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_ats_builder_rejects_foreign_field() {
+        let result = CnlMessage::builder()
+            .aircraft_id("AFR123")
+            .departure("LFPG", None)
+            .destination("EGLL", None)
+            .arrival("EGLL", Hhmm { hour: 10, minute: 0 })
+            .build();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_cof_and_req_builders() {
+        let cof = CofMessage::builder().callsign("ABC123").coordination_data("FL350 RESMI").build().unwrap();
+        assert_eq!(cof.raw, "COF ABC123 FL350 RESMI");
+
+        let req = ReqMessage::builder().request_type("FPL").request_content("ABC123").build().unwrap();
+        assert_eq!(req.raw, "REQ FPL ABC123");
+
+        assert!(CofMessage::builder().callsign("1ABC").build().is_err());
+    }
+}
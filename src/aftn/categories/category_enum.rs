@@ -11,6 +11,9 @@ pub enum MessageCategory {
     /// METAR (Meteorological Aerodrome Report)
     Metar,
     
+    /// SPECI (Special Meteorological Report)
+    Speci,
+    
     /// TAF (Terminal Aerodrome Forecast)
     Taf,
     
@@ -144,6 +147,7 @@ impl MessageCategory {
             // Messages météorologiques
             "NOT" | "NOF" => Ok(MessageCategory::Notam),
             "MET" => Ok(MessageCategory::Metar),
+            "SPE" => Ok(MessageCategory::Speci),
             "TAF" => Ok(MessageCategory::Taf),
             "SIG" => Ok(MessageCategory::Sigmet),
             "AIR" => Ok(MessageCategory::Airmet),
@@ -210,6 +214,7 @@ impl MessageCategory {
             // Messages météorologiques
             MessageCategory::Notam => "NOT",
             MessageCategory::Metar => "MET",
+            MessageCategory::Speci => "SPE",
             MessageCategory::Taf => "TAF",
             MessageCategory::Sigmet => "SIG",
             MessageCategory::Airmet => "AIR",
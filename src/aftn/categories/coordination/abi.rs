@@ -6,6 +6,7 @@ use crate::aftn::categories::MessageCategory;
 use crate::aftn::error::AftnError;
 use crate::aftn::submessages::SubMessage;
 use crate::aftn::validation;
+use crate::aftn::categories::flight_plan::ats::{is_ats_format, AtsMessage};
 
 #[derive(Parser)]
 #[grammar = "aftn/categories/coordination/coordination.pest"]
@@ -33,12 +34,21 @@ pub struct AbiMessage {
     
     /// Corps brut du message
     pub raw: String,
+    
+    /// Message ATS décodé champ par champ (forme parenthésée OACI)
+    pub ats: Option<AtsMessage>,
 }
 
 impl SubMessage for AbiMessage {
     fn parse(body: &str) -> Result<Self, AftnError> {
         use pest::Parser;
         
+        // Forme parenthésée OACI: décodage champ par champ
+        if is_ats_format(body) {
+            let ats = AtsMessage::parse_as(body, &["ABI"])?;
+            return Ok(Self::from_ats(ats, body));
+        }
+        
         let result = AbiParser::parse(Rule::abi, body);
         
         if let Ok(mut pairs) = result {
@@ -96,6 +106,7 @@ impl SubMessage for AbiMessage {
             estimated_data,
             aircraft_type,
             raw: body.to_string(),
+            ats: None,
         })
     }
     
@@ -131,6 +142,19 @@ impl SubMessage for AbiMessage {
 }
 
 impl AbiMessage {
+    fn from_ats(ats: AtsMessage, raw: &str) -> Self {
+        AbiMessage {
+            aircraft_id: Some(ats.aircraft_id().to_string()),
+            ssr_code: ats.aircraft_identification.ssr_code.clone(),
+            departure: ats.departure.as_ref().map(|f| f.aerodrome.clone()),
+            destination: ats.destination.as_ref().map(|f| f.aerodrome.clone()),
+            estimated_data: ats.estimate.as_ref().map(|f| f.serialize()),
+            aircraft_type: None,
+            raw: raw.to_string(),
+            ats: Some(ats),
+        }
+    }
+    
     fn parse_abi_pair(pair: pest::iterators::Pair<Rule>, raw: &str) -> Result<Self, AftnError> {
         let mut aircraft_id = None;
         let mut ssr_code = None;
@@ -171,6 +195,7 @@ impl AbiMessage {
             estimated_data,
             aircraft_type,
             raw: raw.to_string(),
+            ats: None,
         })
     }
 }
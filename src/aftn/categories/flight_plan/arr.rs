@@ -6,6 +6,7 @@ use crate::aftn::categories::MessageCategory;
 use crate::aftn::error::AftnError;
 use crate::aftn::submessages::SubMessage;
 use crate::aftn::validation;
+use super::ats::{is_ats_format, AtsMessage};
 
 #[derive(Parser)]
 #[grammar = "aftn/categories/flight_plan/flight_plan.pest"]
@@ -24,12 +25,21 @@ pub struct ArrMessage {
     
     /// Corps brut du message
     pub raw: String,
+    
+    /// Message ATS décodé champ par champ (forme parenthésée OACI)
+    pub ats: Option<AtsMessage>,
 }
 
 impl SubMessage for ArrMessage {
     fn parse(body: &str) -> Result<Self, AftnError> {
         use pest::Parser;
         
+        // Forme parenthésée OACI: décodage champ par champ
+        if is_ats_format(body) {
+            let ats = AtsMessage::parse_as(body, &["ARR"])?;
+            return Ok(Self::from_ats(ats, body));
+        }
+        
         let result = ArrParser::parse(Rule::arr, body);
         
         if let Ok(mut pairs) = result {
@@ -51,6 +61,7 @@ impl SubMessage for ArrMessage {
             arrival,
             arrival_time,
             raw: body.to_string(),
+            ats: None,
         })
     }
     
@@ -83,6 +94,16 @@ impl SubMessage for ArrMessage {
 }
 
 impl ArrMessage {
+    fn from_ats(ats: AtsMessage, raw: &str) -> Self {
+        ArrMessage {
+            callsign: Some(ats.aircraft_id().to_string()),
+            arrival: ats.arrival.as_ref().map(|f| f.aerodrome.clone()),
+            arrival_time: ats.arrival.as_ref().and_then(|f| f.time).map(|t| t.serialize()),
+            raw: raw.to_string(),
+            ats: Some(ats),
+        }
+    }
+    
     fn parse_arr_pair(pair: pest::iterators::Pair<Rule>, raw: &str) -> Result<Self, AftnError> {
         let mut callsign = None;
         let mut arrival = None;
@@ -108,6 +129,7 @@ impl ArrMessage {
             arrival,
             arrival_time,
             raw: raw.to_string(),
+            ats: None,
         })
     }
 }
@@ -130,6 +152,7 @@ mod tests {
             arrival: Some("LFPB".to_string()),
             arrival_time: Some("1400".to_string()),
             raw: "ARR ABC123 LFPB 1400".to_string(),
+            ats: None,
         };
         assert!(msg.validate().is_ok());
         
@@ -147,5 +170,15 @@ mod tests {
         msg.arrival_time = Some("1260".to_string());
         assert!(msg.validate().is_err());
     }
+    
+    #[test]
+    fn test_parse_arr_icao_format() {
+        let arr = ArrMessage::parse("(ARR-AFR123/A1234-LFPG0900-EGLL1005)").unwrap();
+        assert_eq!(arr.callsign, Some("AFR123".to_string()));
+        assert_eq!(arr.arrival, Some("EGLL".to_string()));
+        assert_eq!(arr.arrival_time, Some("1005".to_string()));
+        
+        // Un type de message inattendu est rejeté
+        assert!(ArrMessage::parse("(DEP-AFR123-LFPG0900-EGLL)").is_err());
+    }
 }
-
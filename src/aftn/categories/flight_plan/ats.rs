@@ -0,0 +1,428 @@
+//! Parser des messages ATS OACI en forme parenthésée (Doc 4444, Appendice 3)
+//!
+//! Exemple: `(FPL-AFR123-IS-A320/M-SDFGIRWY/S-LFPG0900-N0450F350 UM184-EGLL0105-PBN/A1B1 DOF/261017)`
+//!
+//! Le parser est partagé par les sous-messages FPL, CPL, CHG, CNL, DLA, DEP,
+//! ARR, EST, SPL et ABI : la composition en champs de chaque type de message
+//! est décrite par `message_fields`.
+
+use serde::{Deserialize, Serialize};
+use pest::Parser;
+use pest_derive::Parser;
+use crate::aftn::error::AftnError;
+use super::ats_fields::*;
+use crate::aftn::datetime;
+use crate::datetime::UtcDateTime;
+use crate::diagnostic::{FieldRef, Span, SyntaxError};
+
+#[derive(Parser)]
+#[grammar = "aftn/categories/flight_plan/ats.pest"]
+struct AtsParser;
+
+/// Position d'un champ dans la composition d'un message
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Slot {
+    /// Champ obligatoire
+    Required(u8),
+
+    /// Champ facultatif
+    Optional(u8),
+
+    /// Champ répété (Field Type 22)
+    Repeated(u8),
+}
+
+/// Composition en Field Types de chaque type de message (hors Field Type 3)
+fn message_fields(designator: &str) -> Option<&'static [Slot]> {
+    use Slot::*;
+    match designator {
+        "FPL" => Some(&[Required(7), Required(8), Required(9), Required(10), Required(13),
+            Required(15), Required(16), Optional(18), Optional(19)]),
+        "CPL" => Some(&[Required(7), Required(8), Required(9), Required(10), Required(13),
+            Required(14), Required(15), Required(16), Optional(18)]),
+        "CHG" => Some(&[Required(7), Required(13), Required(16), Optional(18), Repeated(22)]),
+        "CNL" | "DLA" | "DEP" => Some(&[Required(7), Required(13), Required(16), Optional(18)]),
+        "ARR" => Some(&[Required(7), Required(13), Optional(16), Required(17)]),
+        "EST" => Some(&[Required(7), Required(13), Required(14), Required(16)]),
+        "SPL" => Some(&[Required(7), Required(13), Required(16), Required(18), Required(19)]),
+        "ABI" => Some(&[Required(7), Required(13), Required(14), Required(16), Repeated(22)]),
+        _ => None,
+    }
+}
+
+/// Field Types composant un type de message, dans l'ordre (hors Field Type 3)
+pub(crate) fn message_field_numbers(designator: &str) -> Option<Vec<u8>> {
+    message_fields(designator).map(|slots| {
+        slots.iter()
+            .map(|slot| match *slot {
+                Slot::Required(n) | Slot::Optional(n) | Slot::Repeated(n) => n,
+            })
+            .collect()
+    })
+}
+
+/// Indique si le texte d'un champ a la forme d'un Field Type 22 (`NN/contenu`)
+fn looks_like_amendment(text: &str) -> bool {
+    text.split_once('/')
+        .map(|(n, _)| !n.is_empty() && n.len() <= 2 && n.chars().all(|c| c.is_ascii_digit()))
+        .unwrap_or(false)
+}
+
+/// Indique si le texte d'un champ a la forme d'un Field Type 19 (`X/...`)
+fn looks_like_supplementary(text: &str) -> bool {
+    text.split_once('/')
+        .map(|(indicator, _)| indicator.len() == 1 && indicator.chars().all(|c| c.is_ascii_uppercase()))
+        .unwrap_or(false)
+}
+
+/// Localise l'erreur de décodage d'un champ dans le corps du message
+fn located(error: AftnError, body: &str, start: usize, text: &str, number: u8) -> AftnError {
+    let span = Span::new(body, start, start + text.len());
+    let syntax = SyntaxError::new(error.to_string(), span)
+        .with_field(FieldRef::Name(format!("Field {}", number)));
+    AftnError::Located { error: Box::new(error), syntax: Box::new(syntax) }
+}
+
+/// Indique si un corps de message est un message ATS en forme parenthésée
+pub fn is_ats_format(body: &str) -> bool {
+    body.trim_start().starts_with('(')
+}
+
+/// Message ATS décodé champ par champ
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AtsMessage {
+    /// Field Type 3 : type de message
+    pub message_type: MessageTypeField,
+
+    /// Field Type 7 : identification de l'aéronef
+    pub aircraft_identification: AircraftIdentification,
+
+    /// Field Type 8 : règles de vol et type de vol
+    pub flight_rules: Option<FlightRulesAndType>,
+
+    /// Field Type 9 : type d'aéronef et turbulence de sillage
+    pub aircraft: Option<AircraftTypeAndWake>,
+
+    /// Field Type 10 : équipement
+    pub equipment: Option<Equipment>,
+
+    /// Field Type 13 : aérodrome et heure de départ
+    pub departure: Option<AerodromeTime>,
+
+    /// Field Type 14 : données d'estimation
+    pub estimate: Option<EstimateData>,
+
+    /// Field Type 15 : route
+    pub route: Option<Route>,
+
+    /// Field Type 16 : destination, EET et dégagements
+    pub destination: Option<DestinationField>,
+
+    /// Field Type 17 : aérodrome et heure d'arrivée
+    pub arrival: Option<AerodromeTime>,
+
+    /// Field Type 18 : autres renseignements
+    pub other_information: Option<OtherInformation>,
+
+    /// Field Type 19 : renseignements complémentaires
+    pub supplementary_information: Option<SupplementaryInformation>,
+
+    /// Field Type 22 : amendements (CHG, ABI)
+    pub amendments: Vec<Amendment>,
+}
+
+impl AtsMessage {
+    /// Parse un message ATS en forme parenthésée
+    pub fn parse(body: &str) -> Result<Self, AftnError> {
+        let mut pairs = AtsParser::parse(Rule::ats_message, body)
+            .map_err(|e| AftnError::from(SyntaxError::from_pest(&e, body, "ATS message").with_field_index(body, '-')))?;
+
+        let message_pair = pairs.next().ok_or_else(|| {
+            AftnError::ParseError("Empty ATS message parse result".to_string())
+        })?;
+
+        let mut message_type = None;
+        let mut fields = Vec::new();
+        for inner_pair in message_pair.into_inner() {
+            match inner_pair.as_rule() {
+                Rule::message_type => {
+                    message_type = Some(MessageTypeField::parse(inner_pair.as_str())?);
+                }
+                Rule::field => {
+                    let text = inner_pair.as_str();
+                    let start = inner_pair.as_span().start() + (text.len() - text.trim_start().len());
+                    fields.push((text.trim().to_string(), start));
+                }
+                _ => {}
+            }
+        }
+
+        let message_type = message_type.ok_or_else(|| {
+            AftnError::ParseError("ATS message: missing message type".to_string())
+        })?;
+        Self::from_fields(message_type, &fields, body)
+    }
+
+    /// Parse un message ATS et vérifie son type (ex: `&["FPL", "CPL"]`)
+    pub fn parse_as(body: &str, designators: &[&str]) -> Result<Self, AftnError> {
+        let message = Self::parse(body)?;
+        if !designators.contains(&message.message_type.designator.as_str()) {
+            return Err(AftnError::InvalidCategory(format!(
+                "Expected ATS message {:?}, got: {}",
+                designators, message.message_type.designator
+            )));
+        }
+        Ok(message)
+    }
+
+    /// Décode les champs `(texte, position dans body)` selon la composition du message
+    fn from_fields(message_type: MessageTypeField, fields: &[(String, usize)], body: &str) -> Result<Self, AftnError> {
+        let slots = message_fields(&message_type.designator).ok_or_else(|| {
+            AftnError::InvalidCategory(format!(
+                "Unsupported ATS message type: {}",
+                message_type.designator
+            ))
+        })?;
+
+        let mut message = AtsMessage {
+            message_type,
+            aircraft_identification: AircraftIdentification {
+                aircraft_id: String::new(),
+                ssr_mode: None,
+                ssr_code: None,
+            },
+            flight_rules: None,
+            aircraft: None,
+            equipment: None,
+            departure: None,
+            estimate: None,
+            route: None,
+            destination: None,
+            arrival: None,
+            other_information: None,
+            supplementary_information: None,
+            amendments: Vec::new(),
+        };
+
+        let mut index = 0;
+        for (slot_index, slot) in slots.iter().enumerate() {
+            match *slot {
+                Slot::Required(number) => {
+                    let (text, start) = fields.get(index).ok_or_else(|| {
+                        AftnError::InvalidFormat(format!(
+                            "{}: missing field {}",
+                            message.message_type.designator, number
+                        ))
+                    })?;
+                    message.set_field(number, text).map_err(|e| located(e, body, *start, text, number))?;
+                    index += 1;
+                }
+                Slot::Optional(number) => {
+                    let Some((text, start)) = fields.get(index) else { continue };
+                    let required_after = slots[slot_index + 1..]
+                        .iter()
+                        .filter(|s| matches!(s, Slot::Required(_)))
+                        .count();
+                    let present = match number {
+                        18 => !looks_like_amendment(text) && !looks_like_supplementary(text),
+                        19 => looks_like_supplementary(text),
+                        _ => fields.len() - index > required_after,
+                    };
+                    if present {
+                        message.set_field(number, text).map_err(|e| located(e, body, *start, text, number))?;
+                        index += 1;
+                    }
+                }
+                Slot::Repeated(number) => {
+                    while let Some((text, start)) = fields.get(index) {
+                        message.set_field(number, text).map_err(|e| located(e, body, *start, text, number))?;
+                        index += 1;
+                    }
+                }
+            }
+        }
+
+        if let Some((text, start)) = fields.get(index) {
+            let error = SyntaxError::new(
+                format!("{}: unexpected field: {}", message.message_type.designator, text),
+                Span::new(body, *start, start + text.len()),
+            );
+            return Err(error.with_field(FieldRef::Index(index + 1)).into());
+        }
+
+        Ok(message)
+    }
+
+    /// Décode le texte d'un champ selon son Field Type
+    pub(crate) fn set_field(&mut self, number: u8, text: &str) -> Result<(), AftnError> {
+        match number {
+            7 => self.aircraft_identification = AircraftIdentification::parse(text)?,
+            8 => self.flight_rules = Some(FlightRulesAndType::parse(text)?),
+            9 => self.aircraft = Some(AircraftTypeAndWake::parse(text)?),
+            10 => self.equipment = Some(Equipment::parse(text)?),
+            13 => self.departure = Some(AerodromeTime::parse_departure(text)?),
+            14 => self.estimate = Some(EstimateData::parse(text)?),
+            15 => self.route = Some(Route::parse(text)?),
+            16 => self.destination = Some(DestinationField::parse(text)?),
+            17 => self.arrival = Some(AerodromeTime::parse_arrival(text)?),
+            18 => self.other_information = Some(OtherInformation::parse(text)?),
+            19 => self.supplementary_information = Some(SupplementaryInformation::parse(text)?),
+            22 => self.amendments.push(Amendment::parse(text)?),
+            _ => {
+                return Err(AftnError::InvalidFormat(format!(
+                    "Unsupported field type: {}",
+                    number
+                )))
+            }
+        }
+        Ok(())
+    }
+
+    /// Texte OACI d'un champ, s'il est présent
+    pub(crate) fn field_text(&self, number: u8) -> Vec<String> {
+        let text = match number {
+            7 => Some(self.aircraft_identification.serialize()),
+            8 => self.flight_rules.as_ref().map(|f| f.serialize()),
+            9 => self.aircraft.as_ref().map(|f| f.serialize()),
+            10 => self.equipment.as_ref().map(|f| f.serialize()),
+            13 => self.departure.as_ref().map(|f| f.serialize()),
+            14 => self.estimate.as_ref().map(|f| f.serialize()),
+            15 => self.route.as_ref().map(|f| f.serialize()),
+            16 => self.destination.as_ref().map(|f| f.serialize()),
+            17 => self.arrival.as_ref().map(|f| f.serialize()),
+            18 => self.other_information.as_ref().map(|f| f.serialize()),
+            19 => self.supplementary_information.as_ref().map(|f| f.serialize()),
+            22 => return self.amendments.iter().map(|a| a.serialize()).collect(),
+            _ => None,
+        };
+        text.into_iter().collect()
+    }
+
+    /// Identification de l'aéronef (Field Type 7a)
+    pub fn aircraft_id(&self) -> &str {
+        &self.aircraft_identification.aircraft_id
+    }
+
+    /// Date du vol (élément DOF/ du Field Type 18)
+    pub fn date_of_flight(&self) -> Option<&str> {
+        self.other_information.as_ref().and_then(|f| f.get("DOF"))
+    }
+
+    /// Heure estimée de départ du poste de stationnement (EOBT, Field Type 13)
+    /// résolue en instant UTC, à partir du DOF/ s'il est présent
+    pub fn estimated_off_block_time(&self, reference: &UtcDateTime) -> Result<Option<UtcDateTime>, AftnError> {
+        let time = match self.departure.as_ref().and_then(|d| d.time) {
+            Some(time) => time,
+            None => return Ok(None),
+        };
+        datetime::resolve_eobt(time.hour, time.minute, self.date_of_flight(), reference).map(Some)
+    }
+
+    /// Sérialise le message en forme parenthésée OACI, sur une ligne
+    pub fn serialize(&self) -> String {
+        let mut result = String::from("(");
+        result.push_str(&self.message_type.serialize());
+
+        let slots = message_fields(&self.message_type.designator).unwrap_or(&[]);
+        for slot in slots {
+            let number = match *slot {
+                Slot::Required(n) | Slot::Optional(n) | Slot::Repeated(n) => n,
+            };
+            for text in self.field_text(number) {
+                result.push('-');
+                result.push_str(&text);
+            }
+        }
+
+        result.push(')');
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FPL: &str = "(FPL-AFR123-IS\n-A320/M-SDFGIRWY/S\n-LFPG0900\n-N0450F350 UM184 RESMI UN860\n-EGLL0105 EGKK\n-PBN/A1B1 DOF/261017)";
+
+    #[test]
+    fn test_parse_fpl() {
+        let msg = AtsMessage::parse(FPL).unwrap();
+        assert_eq!(msg.message_type.designator, "FPL");
+        assert_eq!(msg.aircraft_id(), "AFR123");
+        assert_eq!(msg.flight_rules.as_ref().unwrap().flight_rules, 'I');
+        assert_eq!(msg.flight_rules.as_ref().unwrap().flight_type, Some('S'));
+        assert_eq!(msg.aircraft.as_ref().unwrap().wake_turbulence, WakeTurbulenceCategory::Medium);
+        assert_eq!(msg.departure.as_ref().unwrap().aerodrome, "LFPG");
+        assert_eq!(msg.departure.as_ref().unwrap().time, Some(Hhmm { hour: 9, minute: 0 }));
+        assert_eq!(msg.route.as_ref().unwrap().elements.len(), 3);
+        assert_eq!(msg.destination.as_ref().unwrap().alternates, vec!["EGKK"]);
+        assert_eq!(msg.date_of_flight(), Some("261017"));
+    }
+
+    #[test]
+    fn test_estimated_off_block_time() {
+        let msg = AtsMessage::parse(FPL).unwrap();
+        let reference = UtcDateTime::new(2026, 10, 16, 20, 0).unwrap();
+        assert_eq!(
+            msg.estimated_off_block_time(&reference).unwrap(),
+            Some(UtcDateTime::new(2026, 10, 17, 9, 0).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_serialize_round_trip() {
+        let msg = AtsMessage::parse(FPL).unwrap();
+        let serialized = msg.serialize();
+        assert_eq!(
+            serialized,
+            "(FPL-AFR123-IS-A320/M-SDFGIRWY/S-LFPG0900-N0450F350 UM184 RESMI UN860-EGLL0105 EGKK-PBN/A1B1 DOF/261017)"
+        );
+        assert_eq!(AtsMessage::parse(&serialized).unwrap(), msg);
+    }
+
+    #[test]
+    fn test_parse_chg_with_amendments() {
+        let msg = AtsMessage::parse("(CHG-AFR123-LFPG0900-EGLL-DOF/261017-8/IN-15/N0460F370 DCT)").unwrap();
+        assert!(msg.other_information.is_some());
+        assert_eq!(msg.amendments.len(), 2);
+        assert_eq!(msg.amendments[0].field_number, 8);
+        assert_eq!(msg.amendments[1].content, "N0460F370 DCT");
+    }
+
+    #[test]
+    fn test_parse_chg_without_field_18() {
+        let msg = AtsMessage::parse("(CHG-AFR123-LFPG0900-EGLL-8/IN)").unwrap();
+        assert!(msg.other_information.is_none());
+        assert_eq!(msg.amendments.len(), 1);
+    }
+
+    #[test]
+    fn test_parse_arr_with_and_without_diversion() {
+        let msg = AtsMessage::parse("(ARR-AFR123/A1234-LFPG0900-EGLL1005)").unwrap();
+        assert!(msg.destination.is_none());
+        assert_eq!(msg.arrival.as_ref().unwrap().aerodrome, "EGLL");
+
+        let msg = AtsMessage::parse("(ARR-AFR123-LFPG0900-EGLL-EGKK1010)").unwrap();
+        assert_eq!(msg.destination.as_ref().unwrap().aerodrome, "EGLL");
+        assert_eq!(msg.arrival.as_ref().unwrap().aerodrome, "EGKK");
+    }
+
+    #[test]
+    fn test_parse_est_and_spl() {
+        let msg = AtsMessage::parse("(EST-AFR123/A1234-LFPG-LIMRI/1015F350-EGLL)").unwrap();
+        assert_eq!(msg.estimate.as_ref().unwrap().boundary_point, "LIMRI");
+
+        let msg = AtsMessage::parse("(SPL-AFR123-LFPG0900-EGLL0105 EGKK-DOF/261017-E/0745 P/180 R/VE)").unwrap();
+        assert_eq!(msg.supplementary_information.as_ref().unwrap().get("P"), Some("180"));
+    }
+
+    #[test]
+    fn test_parse_invalid_messages() {
+        assert!(AtsMessage::parse("FPL-AFR123-IS").is_err()); // Pas de parenthèses
+        assert!(AtsMessage::parse("(XYZ-AFR123)").is_err()); // Type inconnu
+        assert!(AtsMessage::parse("(DLA-AFR123-LFPG0900)").is_err()); // Champ 16 manquant
+        assert!(AtsMessage::parse("(DLA-AFR123-LFPG0900-EGLL-0-EXTRA)").is_err()); // Champ en trop
+        assert!(AtsMessage::parse_as(FPL, &["CHG"]).is_err());
+    }
+}
@@ -0,0 +1,1067 @@
+//! Types de champs des messages ATS selon l'OACI Doc 4444, Appendice 3
+//!
+//! Chaque structure correspond à un "Field Type" et fournit une fonction
+//! `parse` depuis le texte du champ (sans le tiret séparateur) ainsi qu'une
+//! fonction `serialize` qui reproduit la forme OACI.
+
+use serde::{Deserialize, Serialize};
+use crate::aftn::error::AftnError;
+use crate::aftn::validation;
+
+/// Heure ou durée au format HHMM
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Hhmm {
+    /// Heures (00-23 pour une heure, 00-99 pour une durée)
+    pub hour: u8,
+
+    /// Minutes (00-59)
+    pub minute: u8,
+}
+
+impl Hhmm {
+    /// Parse une heure HHMM (heure 00-23, minute 00-59)
+    pub fn parse_time(text: &str) -> Result<Self, AftnError> {
+        validation::validate_time_hhmm(text)?;
+        Self::parse_digits(text)
+    }
+
+    /// Parse une durée HHMM (heures 00-99, minute 00-59), ex: EET
+    pub fn parse_duration(text: &str) -> Result<Self, AftnError> {
+        let value = Self::parse_digits(text)?;
+        if value.minute > 59 {
+            return Err(AftnError::InvalidFormat(format!(
+                "Minute must be between 00 and 59, got: {}",
+                text
+            )));
+        }
+        Ok(value)
+    }
+
+    fn parse_digits(text: &str) -> Result<Self, AftnError> {
+        if text.len() != 4 || !text.chars().all(|c| c.is_ascii_digit()) {
+            return Err(AftnError::InvalidFormat(format!(
+                "Time must be 4 digits (HHMM), got: {}",
+                text
+            )));
+        }
+        Ok(Hhmm {
+            hour: text[0..2].parse().unwrap_or(0),
+            minute: text[2..4].parse().unwrap_or(0),
+        })
+    }
+
+    /// Nombre total de minutes
+    pub fn total_minutes(&self) -> u32 {
+        self.hour as u32 * 60 + self.minute as u32
+    }
+
+    pub fn serialize(&self) -> String {
+        format!("{:02}{:02}", self.hour, self.minute)
+    }
+}
+
+/// Field Type 3 : type de message, numéro et données de référence
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MessageTypeField {
+    /// Désignateur du type de message (FPL, CHG, DLA, ...)
+    pub designator: String,
+
+    /// Numéro du message (3b), ex: `A/B123`
+    pub number: Option<String>,
+
+    /// Données de référence (3c), même format que le numéro
+    pub reference: Option<String>,
+}
+
+impl MessageTypeField {
+    pub fn parse(text: &str) -> Result<Self, AftnError> {
+        let text = text.trim();
+        let designator = text
+            .get(..3)
+            .filter(|d| d.chars().all(|c| c.is_ascii_uppercase()))
+            .ok_or_else(|| {
+                AftnError::InvalidFormat(format!(
+                    "Field 3: message type must start with 3 uppercase letters, got: {}",
+                    text
+                ))
+            })?
+            .to_string();
+        let mut numbers = Vec::new();
+        let mut rest = text[3..].trim();
+        while !rest.is_empty() {
+            let len = Self::message_number_len(rest).ok_or_else(|| {
+                AftnError::InvalidFormat(format!("Field 3: invalid message number: {}", rest))
+            })?;
+            numbers.push(rest[..len].to_string());
+            rest = rest[len..].trim();
+        }
+
+        if numbers.len() > 2 {
+            return Err(AftnError::InvalidFormat(format!(
+                "Field 3: too many message numbers: {}",
+                text
+            )));
+        }
+
+        let mut numbers = numbers.into_iter();
+        Ok(MessageTypeField {
+            designator,
+            number: numbers.next(),
+            reference: numbers.next(),
+        })
+    }
+
+    /// Longueur d'un numéro de message en tête de texte
+    /// Format: 1-4 lettres (émetteur) / 1-4 lettres (destinataire) 3 chiffres
+    fn message_number_len(text: &str) -> Option<usize> {
+        let bytes = text.as_bytes();
+        let sender = bytes.iter().take_while(|b| b.is_ascii_uppercase()).count();
+        if sender == 0 || sender > 4 || bytes.get(sender) != Some(&b'/') {
+            return None;
+        }
+        let start = sender + 1;
+        let receiver = bytes[start..].iter().take_while(|b| b.is_ascii_uppercase()).count();
+        if receiver == 0 || receiver > 4 {
+            return None;
+        }
+        let serial = start + receiver;
+        if bytes.len() < serial + 3 || !bytes[serial..serial + 3].iter().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        Some(serial + 3)
+    }
+
+    pub fn serialize(&self) -> String {
+        let mut result = self.designator.clone();
+        if let Some(ref number) = self.number {
+            result.push_str(number);
+        }
+        if let Some(ref reference) = self.reference {
+            result.push_str(reference);
+        }
+        result
+    }
+}
+
+/// Field Type 7 : identification de l'aéronef et code SSR
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AircraftIdentification {
+    /// Identification de l'aéronef (1-7 caractères)
+    pub aircraft_id: String,
+
+    /// Mode SSR (A)
+    pub ssr_mode: Option<char>,
+
+    /// Code SSR (4 chiffres octaux)
+    pub ssr_code: Option<String>,
+}
+
+impl AircraftIdentification {
+    pub fn parse(text: &str) -> Result<Self, AftnError> {
+        let text = text.trim();
+        let (aircraft_id, ssr) = match text.split_once('/') {
+            Some((id, ssr)) => (id, Some(ssr)),
+            None => (text, None),
+        };
+
+        validation::validate_callsign(aircraft_id)?;
+
+        let (ssr_mode, ssr_code) = match ssr {
+            Some(ssr) => {
+                let mut chars = ssr.chars();
+                let mode = chars.next().filter(|c| c.is_ascii_uppercase());
+                let code: String = chars.collect();
+                if mode.is_none() || code.len() != 4 || !code.chars().all(|c| ('0'..='7').contains(&c)) {
+                    return Err(AftnError::InvalidFormat(format!(
+                        "Field 7: SSR mode and code must be a letter and 4 octal digits, got: {}",
+                        ssr
+                    )));
+                }
+                (mode, Some(code))
+            }
+            None => (None, None),
+        };
+
+        Ok(AircraftIdentification {
+            aircraft_id: aircraft_id.to_string(),
+            ssr_mode,
+            ssr_code,
+        })
+    }
+
+    pub fn serialize(&self) -> String {
+        match (self.ssr_mode, &self.ssr_code) {
+            (Some(mode), Some(code)) => format!("{}/{}{}", self.aircraft_id, mode, code),
+            _ => self.aircraft_id.clone(),
+        }
+    }
+}
+
+/// Field Type 8 : règles de vol et type de vol
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FlightRulesAndType {
+    /// Règles de vol (I, V, Y, Z)
+    pub flight_rules: char,
+
+    /// Type de vol (S, N, G, M, X)
+    pub flight_type: Option<char>,
+}
+
+impl FlightRulesAndType {
+    pub fn parse(text: &str) -> Result<Self, AftnError> {
+        let text = text.trim();
+        let mut chars = text.chars();
+        let flight_rules = chars.next().ok_or_else(|| {
+            AftnError::InvalidFormat("Field 8: flight rules cannot be empty".to_string())
+        })?;
+        validation::validate_flight_type(&flight_rules.to_string())?;
+
+        let flight_type = chars.next();
+        if let Some(t) = flight_type {
+            if !"SNGMX".contains(t) {
+                return Err(AftnError::InvalidFormat(format!(
+                    "Field 8: type of flight must be one of S, N, G, M, X, got: {}",
+                    t
+                )));
+            }
+        }
+        if chars.next().is_some() {
+            return Err(AftnError::InvalidFormat(format!(
+                "Field 8: expected at most 2 characters, got: {}",
+                text
+            )));
+        }
+
+        Ok(FlightRulesAndType { flight_rules, flight_type })
+    }
+
+    pub fn serialize(&self) -> String {
+        let mut result = self.flight_rules.to_string();
+        if let Some(t) = self.flight_type {
+            result.push(t);
+        }
+        result
+    }
+}
+
+/// Catégorie de turbulence de sillage
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WakeTurbulenceCategory {
+    /// L - Light
+    Light,
+
+    /// M - Medium
+    Medium,
+
+    /// H - Heavy
+    Heavy,
+
+    /// J - Super
+    Super,
+}
+
+impl WakeTurbulenceCategory {
+    pub fn from_code(code: char) -> Result<Self, AftnError> {
+        match code {
+            'L' => Ok(WakeTurbulenceCategory::Light),
+            'M' => Ok(WakeTurbulenceCategory::Medium),
+            'H' => Ok(WakeTurbulenceCategory::Heavy),
+            'J' => Ok(WakeTurbulenceCategory::Super),
+            _ => Err(AftnError::InvalidFormat(format!(
+                "Wake turbulence category must be L, M, H or J, got: {}",
+                code
+            ))),
+        }
+    }
+
+    pub fn code(&self) -> char {
+        match self {
+            WakeTurbulenceCategory::Light => 'L',
+            WakeTurbulenceCategory::Medium => 'M',
+            WakeTurbulenceCategory::Heavy => 'H',
+            WakeTurbulenceCategory::Super => 'J',
+        }
+    }
+}
+
+/// Field Type 9 : nombre, type d'aéronef et catégorie de turbulence de sillage
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AircraftTypeAndWake {
+    /// Nombre d'aéronefs (vols en formation)
+    pub number: Option<u8>,
+
+    /// Type d'aéronef (2-4 caractères, ZZZZ si non désigné)
+    pub aircraft_type: String,
+
+    /// Catégorie de turbulence de sillage
+    pub wake_turbulence: WakeTurbulenceCategory,
+}
+
+impl AircraftTypeAndWake {
+    pub fn parse(text: &str) -> Result<Self, AftnError> {
+        let text = text.trim();
+        let (type_part, wake) = text.split_once('/').ok_or_else(|| {
+            AftnError::InvalidFormat(format!("Field 9: missing wake turbulence category: {}", text))
+        })?;
+
+        let digits = type_part.chars().take_while(|c| c.is_ascii_digit()).count();
+        let number = if digits > 0 {
+            Some(type_part[..digits].parse::<u8>().map_err(|_| {
+                AftnError::InvalidFormat(format!("Field 9: invalid number of aircraft: {}", type_part))
+            })?)
+        } else {
+            None
+        };
+
+        let aircraft_type = &type_part[digits..];
+        if aircraft_type.len() < 2
+            || aircraft_type.len() > 4
+            || !aircraft_type.chars().all(|c| c.is_ascii_uppercase() || c.is_ascii_digit())
+            || !aircraft_type.starts_with(|c: char| c.is_ascii_uppercase())
+        {
+            return Err(AftnError::InvalidFormat(format!(
+                "Field 9: aircraft type must be 2-4 characters, got: {}",
+                aircraft_type
+            )));
+        }
+
+        let mut wake_chars = wake.chars();
+        let wake_turbulence = match (wake_chars.next(), wake_chars.next()) {
+            (Some(c), None) => WakeTurbulenceCategory::from_code(c)?,
+            _ => {
+                return Err(AftnError::InvalidFormat(format!(
+                    "Field 9: invalid wake turbulence category: {}",
+                    wake
+                )))
+            }
+        };
+
+        Ok(AircraftTypeAndWake {
+            number,
+            aircraft_type: aircraft_type.to_string(),
+            wake_turbulence,
+        })
+    }
+
+    pub fn serialize(&self) -> String {
+        let number = self.number.map(|n| n.to_string()).unwrap_or_default();
+        format!("{}{}/{}", number, self.aircraft_type, self.wake_turbulence.code())
+    }
+}
+
+/// Field Type 10 : équipement et capacités
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Equipment {
+    /// Équipement radio, navigation et d'approche (10a), ex: `S`, `D`, `E2`, `J1`
+    pub radio_nav: Vec<String>,
+
+    /// Équipement de surveillance (10b), ex: `S`, `B1`, `D1`
+    pub surveillance: Vec<String>,
+}
+
+impl Equipment {
+    pub fn parse(text: &str) -> Result<Self, AftnError> {
+        let text = text.trim();
+        let (radio_nav, surveillance) = text.split_once('/').ok_or_else(|| {
+            AftnError::InvalidFormat(format!("Field 10: missing surveillance equipment: {}", text))
+        })?;
+
+        Ok(Equipment {
+            radio_nav: Self::split_codes(radio_nav, "10a")?,
+            surveillance: Self::split_codes(surveillance, "10b")?,
+        })
+    }
+
+    /// Découpe une suite de codes "lettre[chiffre]"
+    fn split_codes(text: &str, item: &str) -> Result<Vec<String>, AftnError> {
+        let chars: Vec<char> = text.chars().collect();
+        let mut codes = Vec::new();
+        let mut i = 0;
+        while i < chars.len() {
+            if !chars[i].is_ascii_uppercase() {
+                return Err(AftnError::InvalidFormat(format!(
+                    "Field {}: invalid equipment code in: {}",
+                    item, text
+                )));
+            }
+            let mut code = chars[i].to_string();
+            if i + 1 < chars.len() && chars[i + 1].is_ascii_digit() {
+                code.push(chars[i + 1]);
+                i += 1;
+            }
+            codes.push(code);
+            i += 1;
+        }
+
+        if codes.is_empty() {
+            return Err(AftnError::InvalidFormat(format!(
+                "Field {}: equipment cannot be empty",
+                item
+            )));
+        }
+        Ok(codes)
+    }
+
+    /// Indique si un code d'équipement 10a est présent
+    pub fn has_radio_nav(&self, code: &str) -> bool {
+        self.radio_nav.iter().any(|c| c == code)
+    }
+
+    /// Indique si un code d'équipement 10b est présent
+    pub fn has_surveillance(&self, code: &str) -> bool {
+        self.surveillance.iter().any(|c| c == code)
+    }
+
+    pub fn serialize(&self) -> String {
+        format!("{}/{}", self.radio_nav.concat(), self.surveillance.concat())
+    }
+}
+
+/// Aérodrome suivi d'une heure optionnelle (Field Types 13, 16a, 17)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AerodromeTime {
+    /// Indicateur d'emplacement OACI (ZZZZ/AFIL si non désigné)
+    pub aerodrome: String,
+
+    /// Heure (HHMM)
+    pub time: Option<Hhmm>,
+}
+
+impl AerodromeTime {
+    fn parse_field(text: &str, field: &str) -> Result<Self, AftnError> {
+        let text = text.trim();
+        // L'indicateur peut être ZZZZ (non désigné) ou AFIL (plan déposé en vol)
+        let aerodrome = text.get(..4).ok_or_else(|| {
+            AftnError::InvalidFormat(format!(
+                "Field {}: aerodrome must be 4 letters, got: {}",
+                field, text
+            ))
+        })?;
+        validation::validate_aerodrome_code(aerodrome)?;
+        let time = match &text[4..] {
+            "" => None,
+            t => Some(Hhmm::parse_time(t)?),
+        };
+        Ok(AerodromeTime { aerodrome: aerodrome.to_string(), time })
+    }
+
+    /// Parse le Field Type 13 (aérodrome et heure de départ)
+    pub fn parse_departure(text: &str) -> Result<Self, AftnError> {
+        Self::parse_field(text, "13")
+    }
+
+    /// Parse le Field Type 17 (aérodrome et heure d'arrivée)
+    pub fn parse_arrival(text: &str) -> Result<Self, AftnError> {
+        // Le nom de l'aérodrome peut suivre si l'indicateur est ZZZZ
+        let first = text.split_whitespace().next().unwrap_or("");
+        Self::parse_field(first, "17")
+    }
+
+    pub fn serialize(&self) -> String {
+        match self.time {
+            Some(time) => format!("{}{}", self.aerodrome, time.serialize()),
+            None => self.aerodrome.clone(),
+        }
+    }
+}
+
+/// Vitesse de croisière (Field Type 15a)
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum CruisingSpeed {
+    /// N#### - nœuds
+    Knots(u16),
+
+    /// K#### - kilomètres par heure
+    KilometresPerHour(u16),
+
+    /// M### - nombre de Mach en centièmes
+    Mach(u16),
+}
+
+impl CruisingSpeed {
+    /// Parse une vitesse en tête de texte, retourne la vitesse et le nombre de caractères lus
+    fn parse_prefix(text: &str) -> Result<(Self, usize), AftnError> {
+        let digits = |n: usize| -> Result<u16, AftnError> {
+            text.get(1..1 + n)
+                .filter(|d| d.chars().all(|c| c.is_ascii_digit()))
+                .and_then(|d| d.parse().ok())
+                .ok_or_else(|| AftnError::InvalidFormat(format!("Invalid cruising speed: {}", text)))
+        };
+        match text.chars().next() {
+            Some('N') => Ok((CruisingSpeed::Knots(digits(4)?), 5)),
+            Some('K') => Ok((CruisingSpeed::KilometresPerHour(digits(4)?), 5)),
+            Some('M') => Ok((CruisingSpeed::Mach(digits(3)?), 4)),
+            _ => Err(AftnError::InvalidFormat(format!("Invalid cruising speed: {}", text))),
+        }
+    }
+
+    pub fn serialize(&self) -> String {
+        match self {
+            CruisingSpeed::Knots(v) => format!("N{:04}", v),
+            CruisingSpeed::KilometresPerHour(v) => format!("K{:04}", v),
+            CruisingSpeed::Mach(v) => format!("M{:03}", v),
+        }
+    }
+}
+
+/// Niveau de croisière (Field Types 14 et 15)
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum CruisingLevel {
+    /// F### - niveau de vol
+    FlightLevel(u16),
+
+    /// S#### - niveau métrique en dizaines de mètres
+    MetricLevel(u16),
+
+    /// A### - altitude en centaines de pieds
+    Altitude(u16),
+
+    /// M#### - altitude en dizaines de mètres
+    MetricAltitude(u16),
+
+    /// VFR - vol VFR sans niveau
+    Vfr,
+}
+
+impl CruisingLevel {
+    /// Parse un niveau en tête de texte, retourne le niveau et le nombre de caractères lus
+    fn parse_prefix(text: &str) -> Result<(Self, usize), AftnError> {
+        if text.starts_with("VFR") {
+            return Ok((CruisingLevel::Vfr, 3));
+        }
+        let digits = |n: usize| -> Result<u16, AftnError> {
+            text.get(1..1 + n)
+                .filter(|d| d.chars().all(|c| c.is_ascii_digit()))
+                .and_then(|d| d.parse().ok())
+                .ok_or_else(|| AftnError::InvalidFormat(format!("Invalid cruising level: {}", text)))
+        };
+        match text.chars().next() {
+            Some('F') => Ok((CruisingLevel::FlightLevel(digits(3)?), 4)),
+            Some('S') => Ok((CruisingLevel::MetricLevel(digits(4)?), 5)),
+            Some('A') => Ok((CruisingLevel::Altitude(digits(3)?), 4)),
+            Some('M') => Ok((CruisingLevel::MetricAltitude(digits(4)?), 5)),
+            _ => Err(AftnError::InvalidFormat(format!("Invalid cruising level: {}", text))),
+        }
+    }
+
+    /// Parse un niveau complet (ex: `F350`)
+    pub fn parse(text: &str) -> Result<Self, AftnError> {
+        let (level, len) = Self::parse_prefix(text)?;
+        if len != text.len() {
+            return Err(AftnError::InvalidFormat(format!("Invalid cruising level: {}", text)));
+        }
+        Ok(level)
+    }
+
+    /// Niveau exprimé en pieds (approximatif pour les niveaux métriques)
+    pub fn to_feet(&self) -> Option<u32> {
+        match self {
+            CruisingLevel::FlightLevel(v) | CruisingLevel::Altitude(v) => Some(*v as u32 * 100),
+            CruisingLevel::MetricLevel(v) | CruisingLevel::MetricAltitude(v) => {
+                Some((*v as f64 * 10.0 / 0.3048).round() as u32)
+            }
+            CruisingLevel::Vfr => None,
+        }
+    }
+
+    pub fn serialize(&self) -> String {
+        match self {
+            CruisingLevel::FlightLevel(v) => format!("F{:03}", v),
+            CruisingLevel::MetricLevel(v) => format!("S{:04}", v),
+            CruisingLevel::Altitude(v) => format!("A{:03}", v),
+            CruisingLevel::MetricAltitude(v) => format!("M{:04}", v),
+            CruisingLevel::Vfr => "VFR".to_string(),
+        }
+    }
+}
+
+/// Couple vitesse/niveau (ex: `N0450F350`)
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SpeedLevel {
+    pub speed: CruisingSpeed,
+    pub level: CruisingLevel,
+}
+
+impl SpeedLevel {
+    pub fn parse(text: &str) -> Result<Self, AftnError> {
+        let (speed, speed_len) = CruisingSpeed::parse_prefix(text)?;
+        let level = CruisingLevel::parse(&text[speed_len..])?;
+        Ok(SpeedLevel { speed, level })
+    }
+
+    pub fn serialize(&self) -> String {
+        format!("{}{}", self.speed.serialize(), self.level.serialize())
+    }
+}
+
+/// Field Type 14 : données d'estimation
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EstimateData {
+    /// Point de limite (désignateur, coordonnées ou relèvement/distance)
+    pub boundary_point: String,
+
+    /// Heure estimée au point
+    pub time: Hhmm,
+
+    /// Niveau autorisé
+    pub cleared_level: CruisingLevel,
+
+    /// Niveau de franchissement supplémentaire
+    pub supplementary_level: Option<CruisingLevel>,
+
+    /// Condition de franchissement (A: au-dessus, B: au-dessous)
+    pub crossing_condition: Option<char>,
+}
+
+impl EstimateData {
+    pub fn parse(text: &str) -> Result<Self, AftnError> {
+        let text = text.trim();
+        let (point, data) = text.split_once('/').ok_or_else(|| {
+            AftnError::InvalidFormat(format!("Field 14: missing '/' after boundary point: {}", text))
+        })?;
+        if point.is_empty() {
+            return Err(AftnError::InvalidFormat("Field 14: boundary point cannot be empty".to_string()));
+        }
+
+        let time = Hhmm::parse_time(data.get(..4).unwrap_or(data))?;
+        let rest = &data[4..];
+        let (cleared_level, len) = CruisingLevel::parse_prefix(rest)?;
+        let mut rest = &rest[len..];
+
+        let mut supplementary_level = None;
+        let mut crossing_condition = None;
+        if !rest.is_empty() {
+            let (level, len) = CruisingLevel::parse_prefix(rest)?;
+            supplementary_level = Some(level);
+            rest = &rest[len..];
+            match rest {
+                "" => {}
+                "A" | "B" => crossing_condition = rest.chars().next(),
+                _ => {
+                    return Err(AftnError::InvalidFormat(format!(
+                        "Field 14: crossing condition must be A or B, got: {}",
+                        rest
+                    )))
+                }
+            }
+        }
+
+        Ok(EstimateData {
+            boundary_point: point.to_string(),
+            time,
+            cleared_level,
+            supplementary_level,
+            crossing_condition,
+        })
+    }
+
+    pub fn serialize(&self) -> String {
+        let mut result = format!(
+            "{}/{}{}",
+            self.boundary_point,
+            self.time.serialize(),
+            self.cleared_level.serialize()
+        );
+        if let Some(level) = self.supplementary_level {
+            result.push_str(&level.serialize());
+        }
+        if let Some(condition) = self.crossing_condition {
+            result.push(condition);
+        }
+        result
+    }
+}
+
+/// Élément de route du Field Type 15c
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RouteElement {
+    /// Point significatif, route ATS, DCT, SID/STAR ou indicateur (VFR, IFR, T)
+    pub designator: String,
+
+    /// Changement de vitesse/niveau au point (ex: `/N0450F370`)
+    pub speed_level_change: Option<SpeedLevel>,
+}
+
+impl RouteElement {
+    pub fn serialize(&self) -> String {
+        match self.speed_level_change {
+            Some(change) => format!("{}/{}", self.designator, change.serialize()),
+            None => self.designator.clone(),
+        }
+    }
+}
+
+/// Field Type 15 : route
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Route {
+    /// Vitesse et niveau de croisière initiaux
+    pub initial: SpeedLevel,
+
+    /// Éléments de la route dans l'ordre
+    pub elements: Vec<RouteElement>,
+}
+
+impl Route {
+    pub fn parse(text: &str) -> Result<Self, AftnError> {
+        let mut tokens = text.split_whitespace();
+        let first = tokens.next().ok_or_else(|| {
+            AftnError::InvalidFormat("Field 15: route cannot be empty".to_string())
+        })?;
+        let initial = SpeedLevel::parse(first)?;
+
+        let mut elements = Vec::new();
+        for token in tokens {
+            let element = match token.split_once('/') {
+                // Une vitesse/niveau après le slash indique un changement au point
+                Some((point, change)) if SpeedLevel::parse(change).is_ok() => RouteElement {
+                    designator: point.to_string(),
+                    speed_level_change: SpeedLevel::parse(change).ok(),
+                },
+                _ => RouteElement {
+                    designator: token.to_string(),
+                    speed_level_change: None,
+                },
+            };
+            elements.push(element);
+        }
+
+        Ok(Route { initial, elements })
+    }
+
+    /// Texte de la route sans la vitesse/niveau initiaux
+    pub fn route_text(&self) -> String {
+        self.elements.iter().map(|e| e.serialize()).collect::<Vec<_>>().join(" ")
+    }
+
+    pub fn serialize(&self) -> String {
+        let mut result = self.initial.serialize();
+        for element in &self.elements {
+            result.push(' ');
+            result.push_str(&element.serialize());
+        }
+        result
+    }
+}
+
+/// Field Type 16 : destination, durée totale estimée et aérodromes de dégagement
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DestinationField {
+    /// Aérodrome de destination
+    pub aerodrome: String,
+
+    /// Durée totale estimée (EET)
+    pub total_eet: Option<Hhmm>,
+
+    /// Aérodromes de dégagement (2 au maximum)
+    pub alternates: Vec<String>,
+}
+
+impl DestinationField {
+    pub fn parse(text: &str) -> Result<Self, AftnError> {
+        let mut tokens = text.split_whitespace();
+        let first = tokens.next().ok_or_else(|| {
+            AftnError::InvalidFormat("Field 16: destination cannot be empty".to_string())
+        })?;
+        let aerodrome = first.get(..4).ok_or_else(|| {
+            AftnError::InvalidFormat(format!("Field 16: aerodrome must be 4 letters, got: {}", first))
+        })?;
+        validation::validate_aerodrome_code(aerodrome)?;
+        let total_eet = match &first[4..] {
+            "" => None,
+            eet => Some(Hhmm::parse_duration(eet)?),
+        };
+
+        let mut alternates = Vec::new();
+        for alternate in tokens {
+            validation::validate_aerodrome_code(alternate)?;
+            alternates.push(alternate.to_string());
+        }
+        if alternates.len() > 2 {
+            return Err(AftnError::InvalidFormat(format!(
+                "Field 16: at most 2 alternate aerodromes, got {}",
+                alternates.len()
+            )));
+        }
+
+        Ok(DestinationField {
+            aerodrome: aerodrome.to_string(),
+            total_eet,
+            alternates,
+        })
+    }
+
+    pub fn serialize(&self) -> String {
+        let mut result = self.aerodrome.clone();
+        if let Some(eet) = self.total_eet {
+            result.push_str(&eet.serialize());
+        }
+        for alternate in &self.alternates {
+            result.push(' ');
+            result.push_str(alternate);
+        }
+        result
+    }
+}
+
+/// Élément d'information `INDICATEUR/valeur` (Field Types 18 et 19)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InfoItem {
+    /// Indicateur (ex: `DOF`, `PBN`, `E`)
+    pub indicator: String,
+
+    /// Valeur associée
+    pub value: String,
+}
+
+/// Indicateurs reconnus dans le Field Type 18
+const FIELD18_INDICATORS: &[&str] = &[
+    "STS", "PBN", "NAV", "COM", "DAT", "SUR", "DEP", "DEST", "DOF", "REG", "EET", "SEL",
+    "TYP", "CODE", "DLE", "OPR", "ORGN", "PER", "ALTN", "RALT", "TALT", "RIF", "RMK",
+    "RVR", "RFP", "IFP", "SRC", "EUR", "AWR",
+];
+
+/// Indicateurs reconnus dans le Field Type 19
+const FIELD19_INDICATORS: &[&str] = &["E", "P", "R", "S", "J", "D", "A", "N", "C"];
+
+/// Découpe un texte en éléments `INDICATEUR/valeur` selon une liste d'indicateurs
+fn parse_info_items(text: &str, indicators: &[&str], field: &str) -> Result<Vec<InfoItem>, AftnError> {
+    let mut items: Vec<InfoItem> = Vec::new();
+    for token in text.split_whitespace() {
+        let starts_item = token
+            .split_once('/')
+            .filter(|(indicator, _)| indicators.contains(indicator));
+        match starts_item {
+            Some((indicator, value)) => items.push(InfoItem {
+                indicator: indicator.to_string(),
+                value: value.to_string(),
+            }),
+            None => match items.last_mut() {
+                Some(item) => {
+                    if !item.value.is_empty() {
+                        item.value.push(' ');
+                    }
+                    item.value.push_str(token);
+                }
+                None => {
+                    return Err(AftnError::InvalidFormat(format!(
+                        "Field {}: expected INDICATOR/value, got: {}",
+                        field, token
+                    )))
+                }
+            },
+        }
+    }
+    Ok(items)
+}
+
+fn serialize_info_items(items: &[InfoItem]) -> String {
+    items
+        .iter()
+        .map(|item| format!("{}/{}", item.indicator, item.value))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Field Type 18 : autres renseignements
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OtherInformation {
+    /// Éléments dans l'ordre du message (vide si le champ vaut `0`)
+    pub items: Vec<InfoItem>,
+}
+
+impl OtherInformation {
+    pub fn parse(text: &str) -> Result<Self, AftnError> {
+        let text = text.trim();
+        if text == "0" {
+            return Ok(OtherInformation { items: Vec::new() });
+        }
+        Ok(OtherInformation {
+            items: parse_info_items(text, FIELD18_INDICATORS, "18")?,
+        })
+    }
+
+    /// Valeur du premier élément portant cet indicateur (ex: `DOF`)
+    pub fn get(&self, indicator: &str) -> Option<&str> {
+        self.items
+            .iter()
+            .find(|item| item.indicator == indicator)
+            .map(|item| item.value.as_str())
+    }
+
+    pub fn serialize(&self) -> String {
+        if self.items.is_empty() {
+            "0".to_string()
+        } else {
+            serialize_info_items(&self.items)
+        }
+    }
+}
+
+/// Field Type 19 : renseignements complémentaires
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SupplementaryInformation {
+    /// Éléments dans l'ordre du message
+    pub items: Vec<InfoItem>,
+}
+
+impl SupplementaryInformation {
+    pub fn parse(text: &str) -> Result<Self, AftnError> {
+        Ok(SupplementaryInformation {
+            items: parse_info_items(text, FIELD19_INDICATORS, "19")?,
+        })
+    }
+
+    /// Valeur du premier élément portant cet indicateur (ex: `E` pour l'endurance)
+    pub fn get(&self, indicator: &str) -> Option<&str> {
+        self.items
+            .iter()
+            .find(|item| item.indicator == indicator)
+            .map(|item| item.value.as_str())
+    }
+
+    /// Autonomie (E/HHMM)
+    pub fn endurance(&self) -> Option<Hhmm> {
+        self.get("E").and_then(|e| Hhmm::parse_duration(e).ok())
+    }
+
+    pub fn serialize(&self) -> String {
+        serialize_info_items(&self.items)
+    }
+}
+
+/// Field Type 22 : amendement (numéro du champ modifié et nouveau contenu)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Amendment {
+    /// Numéro du Field Type modifié
+    pub field_number: u8,
+
+    /// Nouveau contenu du champ
+    pub content: String,
+}
+
+impl Amendment {
+    pub fn parse(text: &str) -> Result<Self, AftnError> {
+        let text = text.trim();
+        let (number, content) = text.split_once('/').ok_or_else(|| {
+            AftnError::InvalidFormat(format!("Field 22: missing '/' after field number: {}", text))
+        })?;
+        let field_number = number.parse::<u8>().map_err(|_| {
+            AftnError::InvalidFormat(format!("Field 22: invalid field number: {}", number))
+        })?;
+        Ok(Amendment {
+            field_number,
+            content: content.to_string(),
+        })
+    }
+
+    pub fn serialize(&self) -> String {
+        format!("{}/{}", self.field_number, self.content)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_message_type() {
+        let f3 = MessageTypeField::parse("FPL").unwrap();
+        assert_eq!(f3.designator, "FPL");
+        assert_eq!(f3.number, None);
+
+        let f3 = MessageTypeField::parse("CHGA/B234A/B231").unwrap();
+        assert_eq!(f3.designator, "CHG");
+        assert_eq!(f3.number, Some("A/B234".to_string()));
+        assert_eq!(f3.reference, Some("A/B231".to_string()));
+        assert_eq!(f3.serialize(), "CHGA/B234A/B231");
+    }
+
+    #[test]
+    fn test_parse_aircraft_identification() {
+        let f7 = AircraftIdentification::parse("AFR123/A1234").unwrap();
+        assert_eq!(f7.aircraft_id, "AFR123");
+        assert_eq!(f7.ssr_mode, Some('A'));
+        assert_eq!(f7.ssr_code, Some("1234".to_string()));
+        assert_eq!(f7.serialize(), "AFR123/A1234");
+
+        assert!(AircraftIdentification::parse("AFR123/A1289").is_err()); // Non octal
+    }
+
+    #[test]
+    fn test_parse_aircraft_type_and_wake() {
+        let f9 = AircraftTypeAndWake::parse("A320/M").unwrap();
+        assert_eq!(f9.number, None);
+        assert_eq!(f9.aircraft_type, "A320");
+        assert_eq!(f9.wake_turbulence, WakeTurbulenceCategory::Medium);
+
+        let f9 = AircraftTypeAndWake::parse("2F16/M").unwrap();
+        assert_eq!(f9.number, Some(2));
+        assert_eq!(f9.aircraft_type, "F16");
+        assert_eq!(f9.serialize(), "2F16/M");
+
+        assert!(AircraftTypeAndWake::parse("A320/X").is_err());
+        assert!(AircraftTypeAndWake::parse("A320").is_err());
+    }
+
+    #[test]
+    fn test_parse_equipment() {
+        let f10 = Equipment::parse("SDE2E3FGHIJ1RWY/LB1D1").unwrap();
+        assert_eq!(f10.radio_nav, vec!["S", "D", "E2", "E3", "F", "G", "H", "I", "J1", "R", "W", "Y"]);
+        assert_eq!(f10.surveillance, vec!["L", "B1", "D1"]);
+        assert!(f10.has_radio_nav("J1"));
+        assert_eq!(f10.serialize(), "SDE2E3FGHIJ1RWY/LB1D1");
+    }
+
+    #[test]
+    fn test_parse_route() {
+        let f15 = Route::parse("N0450F350 UM184 RESMI/N0440F370 UN860 DCT").unwrap();
+        assert_eq!(f15.initial.speed, CruisingSpeed::Knots(450));
+        assert_eq!(f15.initial.level, CruisingLevel::FlightLevel(350));
+        assert_eq!(f15.elements.len(), 4);
+        assert_eq!(f15.elements[1].designator, "RESMI");
+        assert_eq!(f15.elements[1].speed_level_change.unwrap().level, CruisingLevel::FlightLevel(370));
+        assert_eq!(f15.route_text(), "UM184 RESMI/N0440F370 UN860 DCT");
+
+        let f15 = Route::parse("M082S1130 DCT").unwrap();
+        assert_eq!(f15.initial.speed, CruisingSpeed::Mach(82));
+        assert_eq!(f15.initial.level, CruisingLevel::MetricLevel(1130));
+    }
+
+    #[test]
+    fn test_parse_estimate_data() {
+        let f14 = EstimateData::parse("LIMRI/1234F350F310A").unwrap();
+        assert_eq!(f14.boundary_point, "LIMRI");
+        assert_eq!(f14.time, Hhmm { hour: 12, minute: 34 });
+        assert_eq!(f14.cleared_level, CruisingLevel::FlightLevel(350));
+        assert_eq!(f14.supplementary_level, Some(CruisingLevel::FlightLevel(310)));
+        assert_eq!(f14.crossing_condition, Some('A'));
+        assert_eq!(f14.serialize(), "LIMRI/1234F350F310A");
+    }
+
+    #[test]
+    fn test_parse_destination() {
+        let f16 = DestinationField::parse("EGLL0105 EGKK EGSS").unwrap();
+        assert_eq!(f16.aerodrome, "EGLL");
+        assert_eq!(f16.total_eet, Some(Hhmm { hour: 1, minute: 5 }));
+        assert_eq!(f16.alternates, vec!["EGKK", "EGSS"]);
+
+        assert!(DestinationField::parse("EGLL0105 EGKK EGSS EGGW").is_err());
+    }
+
+    #[test]
+    fn test_parse_other_information() {
+        let f18 = OtherInformation::parse("PBN/A1B1 DOF/261017 RMK/TCAS EQUIPPED").unwrap();
+        assert_eq!(f18.items.len(), 3);
+        assert_eq!(f18.get("DOF"), Some("261017"));
+        assert_eq!(f18.get("RMK"), Some("TCAS EQUIPPED"));
+
+        assert!(OtherInformation::parse("0").unwrap().items.is_empty());
+    }
+
+    #[test]
+    fn test_parse_supplementary_information() {
+        let f19 = SupplementaryInformation::parse("E/0745 P/TBN R/VE S/M J/L D/2 8 C YELLOW").unwrap();
+        assert_eq!(f19.endurance(), Some(Hhmm { hour: 7, minute: 45 }));
+        assert_eq!(f19.get("D"), Some("2 8 C YELLOW"));
+    }
+}
@@ -6,6 +6,7 @@ use crate::aftn::categories::MessageCategory;
 use crate::aftn::error::AftnError;
 use crate::aftn::submessages::SubMessage;
 use crate::aftn::validation;
+use super::ats::{is_ats_format, AtsMessage};
 
 #[derive(Parser)]
 #[grammar = "aftn/categories/flight_plan/flight_plan.pest"]
@@ -24,12 +25,21 @@ pub struct ChgMessage {
     
     /// Corps brut du message
     pub raw: String,
+    
+    /// Message ATS décodé champ par champ (forme parenthésée OACI)
+    pub ats: Option<AtsMessage>,
 }
 
 impl SubMessage for ChgMessage {
     fn parse(body: &str) -> Result<Self, AftnError> {
         use pest::Parser;
         
+        // Forme parenthésée OACI: décodage champ par champ
+        if is_ats_format(body) {
+            let ats = AtsMessage::parse_as(body, &["CHG"])?;
+            return Ok(Self::from_ats(ats, body));
+        }
+        
         let result = ChgParser::parse(Rule::chg, body);
         
         if let Ok(mut pairs) = result {
@@ -51,6 +61,7 @@ impl SubMessage for ChgMessage {
             changed_field,
             new_value,
             raw: body.to_string(),
+            ats: None,
         })
     }
     
@@ -73,6 +84,16 @@ impl SubMessage for ChgMessage {
 }
 
 impl ChgMessage {
+    fn from_ats(ats: AtsMessage, raw: &str) -> Self {
+        ChgMessage {
+            callsign: Some(ats.aircraft_id().to_string()),
+            changed_field: ats.amendments.first().map(|a| a.field_number.to_string()),
+            new_value: ats.amendments.first().map(|a| a.content.clone()),
+            raw: raw.to_string(),
+            ats: Some(ats),
+        }
+    }
+    
     fn parse_chg_pair(pair: pest::iterators::Pair<Rule>, raw: &str) -> Result<Self, AftnError> {
         let mut callsign = None;
         let mut changed_field = None;
@@ -98,6 +119,7 @@ impl ChgMessage {
             changed_field,
             new_value,
             raw: raw.to_string(),
+            ats: None,
         })
     }
 }
@@ -120,6 +142,7 @@ mod tests {
             changed_field: Some("ADEP".to_string()),
             new_value: Some("LFPB".to_string()),
             raw: "CHG ABC123 ADEP LFPB".to_string(),
+            ats: None,
         };
         assert!(msg.validate().is_ok());
         
@@ -127,5 +150,14 @@ mod tests {
         msg.callsign = Some("123ABC".to_string());
         assert!(msg.validate().is_err());
     }
+    
+    #[test]
+    fn test_parse_chg_icao_format() {
+        let input = "(CHG-AFR123-LFPG0900-EGLL-DOF/261017-15/N0460F370 UM184 RESMI)";
+        let chg = ChgMessage::parse(input).unwrap();
+        assert_eq!(chg.callsign, Some("AFR123".to_string()));
+        assert_eq!(chg.changed_field, Some("15".to_string()));
+        assert_eq!(chg.new_value, Some("N0460F370 UM184 RESMI".to_string()));
+        assert_eq!(chg.ats.unwrap().amendments.len(), 1);
+    }
 }
-
@@ -6,6 +6,7 @@ use crate::aftn::categories::MessageCategory;
 use crate::aftn::error::AftnError;
 use crate::aftn::submessages::SubMessage;
 use crate::aftn::validation;
+use super::ats::{is_ats_format, AtsMessage};
 
 #[derive(Parser)]
 #[grammar = "aftn/categories/flight_plan/flight_plan.pest"]
@@ -21,12 +22,21 @@ pub struct CnlMessage {
     
     /// Corps brut du message
     pub raw: String,
+    
+    /// Message ATS décodé champ par champ (forme parenthésée OACI)
+    pub ats: Option<AtsMessage>,
 }
 
 impl SubMessage for CnlMessage {
     fn parse(body: &str) -> Result<Self, AftnError> {
         use pest::Parser;
         
+        // Forme parenthésée OACI: décodage champ par champ
+        if is_ats_format(body) {
+            let ats = AtsMessage::parse_as(body, &["CNL"])?;
+            return Ok(Self::from_ats(ats, body));
+        }
+        
         let result = CnlParser::parse(Rule::cnl, body);
         
         if let Ok(mut pairs) = result {
@@ -50,6 +60,7 @@ impl SubMessage for CnlMessage {
             callsign,
             reason,
             raw: body.to_string(),
+            ats: None,
         })
     }
     
@@ -72,6 +83,15 @@ impl SubMessage for CnlMessage {
 }
 
 impl CnlMessage {
+    fn from_ats(ats: AtsMessage, raw: &str) -> Self {
+        CnlMessage {
+            callsign: Some(ats.aircraft_id().to_string()),
+            reason: None,
+            raw: raw.to_string(),
+            ats: Some(ats),
+        }
+    }
+    
     fn parse_cnl_pair(pair: pest::iterators::Pair<Rule>, raw: &str) -> Result<Self, AftnError> {
         let mut callsign = None;
         let mut reason = None;
@@ -92,6 +112,7 @@ impl CnlMessage {
             callsign,
             reason,
             raw: raw.to_string(),
+            ats: None,
         })
     }
 }
@@ -113,6 +134,7 @@ mod tests {
             callsign: Some("ABC123".to_string()),
             reason: None,
             raw: "CNL ABC123".to_string(),
+            ats: None,
         };
         assert!(msg.validate().is_ok());
     }
@@ -123,6 +145,7 @@ mod tests {
             callsign: Some("".to_string()),
             reason: None,
             raw: "CNL ".to_string(),
+            ats: None,
         };
         assert!(msg.validate().is_err());
     }
@@ -133,6 +156,7 @@ mod tests {
             callsign: Some("ABCDEFGH".to_string()), // 8 caractères
             reason: None,
             raw: "CNL ABCDEFGH".to_string(),
+            ats: None,
         };
         assert!(msg.validate().is_err());
     }
@@ -143,6 +167,7 @@ mod tests {
             callsign: Some("123ABC".to_string()),
             reason: None,
             raw: "CNL 123ABC".to_string(),
+            ats: None,
         };
         assert!(msg.validate().is_err());
     }
@@ -153,6 +178,7 @@ mod tests {
             callsign: Some("AB-C123".to_string()),
             reason: None,
             raw: "CNL AB-C123".to_string(),
+            ats: None,
         };
         assert!(msg.validate().is_err());
     }
@@ -163,6 +189,7 @@ mod tests {
             callsign: Some("ABCDEFG".to_string()), // 7 caractères (max)
             reason: None,
             raw: "CNL ABCDEFG".to_string(),
+            ats: None,
         };
         assert!(msg.validate().is_ok());
     }
@@ -173,6 +200,7 @@ mod tests {
             callsign: Some("A".to_string()), // 1 caractère (min)
             reason: None,
             raw: "CNL A".to_string(),
+            ats: None,
         };
         assert!(msg.validate().is_ok());
     }
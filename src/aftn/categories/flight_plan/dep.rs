@@ -6,6 +6,7 @@ use crate::aftn::categories::MessageCategory;
 use crate::aftn::error::AftnError;
 use crate::aftn::submessages::SubMessage;
 use crate::aftn::validation;
+use super::ats::{is_ats_format, AtsMessage};
 
 #[derive(Parser)]
 #[grammar = "aftn/categories/flight_plan/flight_plan.pest"]
@@ -24,12 +25,21 @@ pub struct DepMessage {
     
     /// Corps brut du message
     pub raw: String,
+    
+    /// Message ATS décodé champ par champ (forme parenthésée OACI)
+    pub ats: Option<AtsMessage>,
 }
 
 impl SubMessage for DepMessage {
     fn parse(body: &str) -> Result<Self, AftnError> {
         use pest::Parser;
         
+        // Forme parenthésée OACI: décodage champ par champ
+        if is_ats_format(body) {
+            let ats = AtsMessage::parse_as(body, &["DEP"])?;
+            return Ok(Self::from_ats(ats, body));
+        }
+        
         let result = DepParser::parse(Rule::dep, body);
         
         if let Ok(mut pairs) = result {
@@ -51,6 +61,7 @@ impl SubMessage for DepMessage {
             departure,
             departure_time,
             raw: body.to_string(),
+            ats: None,
         })
     }
     
@@ -83,6 +94,16 @@ impl SubMessage for DepMessage {
 }
 
 impl DepMessage {
+    fn from_ats(ats: AtsMessage, raw: &str) -> Self {
+        DepMessage {
+            callsign: Some(ats.aircraft_id().to_string()),
+            departure: ats.departure.as_ref().map(|f| f.aerodrome.clone()),
+            departure_time: ats.departure.as_ref().and_then(|f| f.time).map(|t| t.serialize()),
+            raw: raw.to_string(),
+            ats: Some(ats),
+        }
+    }
+    
     fn parse_dep_pair(pair: pest::iterators::Pair<Rule>, raw: &str) -> Result<Self, AftnError> {
         let mut callsign = None;
         let mut departure = None;
@@ -108,6 +129,7 @@ impl DepMessage {
             departure,
             departure_time,
             raw: raw.to_string(),
+            ats: None,
         })
     }
 }
@@ -130,6 +152,7 @@ mod tests {
             departure: Some("LFPG".to_string()),
             departure_time: Some("1200".to_string()),
             raw: "DEP ABC123 LFPG 1200".to_string(),
+            ats: None,
         };
         assert!(msg.validate().is_ok());
         
@@ -6,6 +6,7 @@ use crate::aftn::categories::MessageCategory;
 use crate::aftn::error::AftnError;
 use crate::aftn::submessages::SubMessage;
 use crate::aftn::validation;
+use super::ats::{is_ats_format, AtsMessage};
 
 #[derive(Parser)]
 #[grammar = "aftn/categories/flight_plan/flight_plan.pest"]
@@ -24,12 +25,21 @@ pub struct DlaMessage {
     
     /// Corps brut du message
     pub raw: String,
+    
+    /// Message ATS décodé champ par champ (forme parenthésée OACI)
+    pub ats: Option<AtsMessage>,
 }
 
 impl SubMessage for DlaMessage {
     fn parse(body: &str) -> Result<Self, AftnError> {
         use pest::Parser;
         
+        // Forme parenthésée OACI: décodage champ par champ
+        if is_ats_format(body) {
+            let ats = AtsMessage::parse_as(body, &["DLA"])?;
+            return Ok(Self::from_ats(ats, body));
+        }
+        
         let result = DlaParser::parse(Rule::dla, body);
         
         if let Ok(mut pairs) = result {
@@ -55,6 +65,7 @@ impl SubMessage for DlaMessage {
             new_departure_time,
             reason,
             raw: body.to_string(),
+            ats: None,
         })
     }
     
@@ -82,6 +93,16 @@ impl SubMessage for DlaMessage {
 }
 
 impl DlaMessage {
+    fn from_ats(ats: AtsMessage, raw: &str) -> Self {
+        DlaMessage {
+            callsign: Some(ats.aircraft_id().to_string()),
+            new_departure_time: ats.departure.as_ref().and_then(|f| f.time).map(|t| t.serialize()),
+            reason: None,
+            raw: raw.to_string(),
+            ats: Some(ats),
+        }
+    }
+    
     fn parse_dla_pair(pair: pest::iterators::Pair<Rule>, raw: &str) -> Result<Self, AftnError> {
         let mut callsign = None;
         let mut new_departure_time = None;
@@ -107,6 +128,7 @@ impl DlaMessage {
             new_departure_time,
             reason,
             raw: raw.to_string(),
+            ats: None,
         })
     }
 }
@@ -121,5 +143,12 @@ mod tests {
         let result = DlaMessage::parse(input);
         assert!(result.is_ok());
     }
+    
+    #[test]
+    fn test_parse_dla_icao_format() {
+        let dla = DlaMessage::parse("(DLA-AFR123-LFPG1030-EGLL-DOF/261017)").unwrap();
+        assert_eq!(dla.callsign, Some("AFR123".to_string()));
+        assert_eq!(dla.new_departure_time, Some("1030".to_string()));
+        assert!(dla.validate().is_ok());
+    }
 }
-
@@ -6,6 +6,7 @@ use crate::aftn::categories::MessageCategory;
 use crate::aftn::error::AftnError;
 use crate::aftn::submessages::SubMessage;
 use crate::aftn::validation;
+use super::ats::{is_ats_format, AtsMessage};
 
 #[derive(Parser)]
 #[grammar = "aftn/categories/flight_plan/flight_plan.pest"]
@@ -27,12 +28,21 @@ pub struct EstMessage {
     
     /// Corps brut du message
     pub raw: String,
+    
+    /// Message ATS décodé champ par champ (forme parenthésée OACI)
+    pub ats: Option<AtsMessage>,
 }
 
 impl SubMessage for EstMessage {
     fn parse(body: &str) -> Result<Self, AftnError> {
         use pest::Parser;
         
+        // Forme parenthésée OACI: décodage champ par champ
+        if is_ats_format(body) {
+            let ats = AtsMessage::parse_as(body, &["EST"])?;
+            return Ok(Self::from_ats(ats, body));
+        }
+        
         let result = EstParser::parse(Rule::est, body);
         
         if let Ok(mut pairs) = result {
@@ -56,6 +66,7 @@ impl SubMessage for EstMessage {
             estimate_time,
             estimate_level,
             raw: body.to_string(),
+            ats: None,
         })
     }
     
@@ -93,6 +104,17 @@ impl SubMessage for EstMessage {
 }
 
 impl EstMessage {
+    fn from_ats(ats: AtsMessage, raw: &str) -> Self {
+        EstMessage {
+            callsign: Some(ats.aircraft_id().to_string()),
+            estimate_point: ats.estimate.as_ref().map(|f| f.boundary_point.clone()),
+            estimate_time: ats.estimate.as_ref().map(|f| f.time.serialize()),
+            estimate_level: ats.estimate.as_ref().map(|f| f.cleared_level.serialize()),
+            raw: raw.to_string(),
+            ats: Some(ats),
+        }
+    }
+    
     fn parse_est_pair(pair: pest::iterators::Pair<Rule>, raw: &str) -> Result<Self, AftnError> {
         let mut callsign = None;
         let mut estimate_point = None;
@@ -123,6 +145,7 @@ impl EstMessage {
             estimate_time,
             estimate_level,
             raw: raw.to_string(),
+            ats: None,
         })
     }
 }
@@ -146,6 +169,7 @@ mod tests {
             estimate_time: Some("1300".to_string()),
             estimate_level: Some("F350".to_string()),
             raw: "EST ABC123 LFPG 1300 F350".to_string(),
+            ats: None,
         };
         assert!(msg.validate().is_ok());
         
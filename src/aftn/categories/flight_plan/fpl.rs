@@ -4,6 +4,7 @@ use crate::aftn::categories::MessageCategory;
 use crate::aftn::error::AftnError;
 use crate::aftn::submessages::SubMessage;
 use crate::aftn::validation;
+use super::ats::{is_ats_format, AtsMessage};
 
 #[derive(Parser)]
 #[grammar = "aftn/categories/flight_plan/flight_plan.pest"]
@@ -28,11 +29,21 @@ pub struct FplMessage {
     
     /// Corps brut du message
     pub raw: String,
+    
+    /// Message ATS décodé champ par champ (forme parenthésée OACI)
+    pub ats: Option<AtsMessage>,
 }
 
 impl SubMessage for FplMessage {
     fn parse(body: &str) -> Result<Self, AftnError> {
         use pest::Parser;
+        
+        // Forme parenthésée OACI: décodage champ par champ
+        if is_ats_format(body) {
+            let ats = AtsMessage::parse_as(body, &["FPL", "CPL"])?;
+            return Ok(Self::from_ats(ats, body));
+        }
+        
         // Essayer de parser avec la grammaire, sinon parser manuellement
         let result = FplParser::parse(Rule::fpl, body);
         
@@ -64,6 +75,7 @@ impl SubMessage for FplMessage {
                 None
             },
             raw: body.to_string(),
+            ats: None,
         })
     }
     
@@ -101,6 +113,18 @@ impl SubMessage for FplMessage {
 }
 
 impl FplMessage {
+    fn from_ats(ats: AtsMessage, raw: &str) -> Self {
+        FplMessage {
+            callsign: Some(ats.aircraft_id().to_string()),
+            flight_type: ats.flight_rules.as_ref().map(|f| f.flight_rules.to_string()),
+            departure: ats.departure.as_ref().map(|f| f.aerodrome.clone()),
+            destination: ats.destination.as_ref().map(|f| f.aerodrome.clone()),
+            route: ats.route.as_ref().map(|r| r.serialize()),
+            raw: raw.to_string(),
+            ats: Some(ats),
+        }
+    }
+    
     fn parse_fpl_pair(pair: pest::iterators::Pair<Rule>, raw: &str) -> Result<Self, AftnError> {
         let mut callsign = None;
         let mut flight_type = None;
@@ -136,6 +160,7 @@ impl FplMessage {
             destination,
             route,
             raw: raw.to_string(),
+            ats: None,
         })
     }
 }
@@ -165,6 +190,7 @@ mod tests {
             destination: Some("LFPB".to_string()),
             route: None,
             raw: "FPL ABC123 V LFPG 151200 LFPB 1800".to_string(),
+            ats: None,
         };
         assert!(msg.validate().is_ok());
         
@@ -182,6 +208,7 @@ mod tests {
             destination: Some("LFPB".to_string()),
             route: None,
             raw: "FPL ABC123 V LFPG 151200 LFPB 1800".to_string(),
+            ats: None,
         };
         assert!(msg.validate().is_ok());
         
@@ -199,6 +226,7 @@ mod tests {
             destination: Some("LFPB".to_string()),
             route: None,
             raw: "FPL ABC123 V LFPG 151200 LFPB 1800".to_string(),
+            ats: None,
         };
         assert!(msg.validate().is_ok());
         
@@ -206,5 +234,19 @@ mod tests {
         msg.flight_type = Some("X".to_string());
         assert!(msg.validate().is_err());
     }
+    
+    #[test]
+    fn test_parse_fpl_icao_format() {
+        let input = "(FPL-AFR123-IS-A320/M-SDFGIRWY/S-LFPG0900-N0450F350 UM184 RESMI-EGLL0105-PBN/A1B1 DOF/261017)";
+        let fpl = FplMessage::parse(input).unwrap();
+        assert_eq!(fpl.callsign, Some("AFR123".to_string()));
+        assert_eq!(fpl.flight_type, Some("I".to_string()));
+        assert_eq!(fpl.departure, Some("LFPG".to_string()));
+        assert_eq!(fpl.destination, Some("EGLL".to_string()));
+        assert!(fpl.validate().is_ok());
+        
+        let ats = fpl.ats.unwrap();
+        assert_eq!(ats.equipment.as_ref().unwrap().surveillance, vec!["S"]);
+        assert_eq!(ats.date_of_flight(), Some("261017"));
+    }
 }
-
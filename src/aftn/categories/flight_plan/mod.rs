@@ -1,3 +1,5 @@
+pub mod ats;
+pub mod ats_fields;
 pub mod fpl;
 pub mod chg;
 pub mod cnl;
@@ -6,6 +8,7 @@ pub mod dep;
 pub mod arr;
 pub mod est;
 pub mod spl;
+pub mod store;
 
 pub use fpl::FplMessage;
 pub use chg::ChgMessage;
@@ -15,4 +18,5 @@ pub use dep::DepMessage;
 pub use arr::ArrMessage;
 pub use est::EstMessage;
 pub use spl::SplMessage;
-
+pub use ats::AtsMessage;
+pub use store::{FlightPlanStore, FlightKey, FlightRecord, FlightStatus, FlightEvent, IngestOutcome};
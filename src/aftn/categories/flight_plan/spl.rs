@@ -6,6 +6,7 @@ use crate::aftn::categories::MessageCategory;
 use crate::aftn::error::AftnError;
 use crate::aftn::submessages::SubMessage;
 use crate::aftn::validation;
+use super::ats::{is_ats_format, AtsMessage};
 
 #[derive(Parser)]
 #[grammar = "aftn/categories/flight_plan/flight_plan.pest"]
@@ -21,12 +22,21 @@ pub struct SplMessage {
     
     /// Corps brut du message
     pub raw: String,
+    
+    /// Message ATS décodé champ par champ (forme parenthésée OACI)
+    pub ats: Option<AtsMessage>,
 }
 
 impl SubMessage for SplMessage {
     fn parse(body: &str) -> Result<Self, AftnError> {
         use pest::Parser;
         
+        // Forme parenthésée OACI: décodage champ par champ
+        if is_ats_format(body) {
+            let ats = AtsMessage::parse_as(body, &["SPL"])?;
+            return Ok(Self::from_ats(ats, body));
+        }
+        
         let result = SplParser::parse(Rule::spl, body);
         
         if let Ok(mut pairs) = result {
@@ -50,6 +60,7 @@ impl SubMessage for SplMessage {
             callsign,
             supplementary_data,
             raw: body.to_string(),
+            ats: None,
         })
     }
     
@@ -72,6 +83,15 @@ impl SubMessage for SplMessage {
 }
 
 impl SplMessage {
+    fn from_ats(ats: AtsMessage, raw: &str) -> Self {
+        SplMessage {
+            callsign: Some(ats.aircraft_id().to_string()),
+            supplementary_data: ats.supplementary_information.as_ref().map(|f| f.serialize()),
+            raw: raw.to_string(),
+            ats: Some(ats),
+        }
+    }
+    
     fn parse_spl_pair(pair: pest::iterators::Pair<Rule>, raw: &str) -> Result<Self, AftnError> {
         let mut callsign = None;
         let mut supplementary_data = None;
@@ -92,6 +112,7 @@ impl SplMessage {
             callsign,
             supplementary_data,
             raw: raw.to_string(),
+            ats: None,
         })
     }
 }
@@ -0,0 +1,433 @@
+//! Suivi du cycle de vie des plans de vol (FPL, CHG, DLA, CNL, DEP, ARR)
+//!
+//! Le `FlightPlanStore` reçoit les messages dans l'ordre de réception et
+//! tient à jour le plan de vol courant de chaque vol, identifié par ARCID,
+//! ADEP, ADES et EOBT/DOF :
+//! - FPL/CPL crée le plan (ou le remplace s'il est redéposé)
+//! - CHG applique les amendements du Field Type 22
+//! - DLA met à jour l'EOBT
+//! - CNL clôt le plan
+//! - DEP/ARR enregistrent les heures réelles
+//!
+//! Les messages ADEXP sont ramenés à leur équivalent OACI avant d'être appliqués.
+
+use std::fmt;
+use serde::{Deserialize, Serialize};
+use crate::adexp::AdexpMessage;
+use crate::aftn::error::AftnError;
+use crate::aftn::message::AftnMessage;
+use crate::aftn::submessages::SubMessageData;
+use super::ats::AtsMessage;
+use super::ats_fields::{AerodromeTime, Hhmm};
+
+/// Field Types comparés pour l'historique d'un plan
+const TRACKED_FIELDS: &[u8] = &[7, 8, 9, 10, 13, 15, 16, 18, 19];
+
+/// Identification d'un vol
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FlightKey {
+    /// Identification de l'aéronef (Field Type 7a)
+    pub arcid: String,
+
+    /// Aérodrome de départ
+    pub adep: String,
+
+    /// Aérodrome de destination
+    pub ades: String,
+
+    /// Heure estimée de départ du poste (EOBT)
+    pub eobt: Option<Hhmm>,
+
+    /// Date du vol (DOF/, AAMMJJ)
+    pub dof: Option<String>,
+}
+
+impl FlightKey {
+    /// Identification du vol portée par un message ATS
+    pub fn from_ats(ats: &AtsMessage) -> Result<Self, AftnError> {
+        let departure = ats.departure.as_ref().ok_or_else(|| {
+            AftnError::InvalidFormat(format!("{}: missing departure aerodrome", ats.message_type.designator))
+        })?;
+        // Sans Field Type 16 (ARR sans déroutement), la destination est l'aérodrome d'arrivée
+        let ades = ats.destination.as_ref().map(|d| d.aerodrome.clone())
+            .or_else(|| ats.arrival.as_ref().map(|a| a.aerodrome.clone()))
+            .ok_or_else(|| {
+                AftnError::InvalidFormat(format!("{}: missing destination aerodrome", ats.message_type.designator))
+            })?;
+        Ok(FlightKey {
+            arcid: ats.aircraft_id().to_string(),
+            adep: departure.aerodrome.clone(),
+            ades,
+            eobt: departure.time,
+            dof: ats.date_of_flight().map(str::to_string),
+        })
+    }
+
+    /// Indique si deux identifications désignent le même vol ; l'EOBT et la
+    /// date ne sont comparées que si elles sont connues des deux côtés
+    fn matches(&self, other: &FlightKey, compare_eobt: bool) -> bool {
+        self.arcid == other.arcid
+            && self.adep == other.adep
+            && self.ades == other.ades
+            && compatible(&self.dof, &other.dof)
+            && (!compare_eobt || compatible(&self.eobt, &other.eobt))
+    }
+}
+
+fn compatible<T: PartialEq>(a: &Option<T>, b: &Option<T>) -> bool {
+    a.is_none() || b.is_none() || a == b
+}
+
+impl fmt::Display for FlightKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}-{}", self.arcid, self.adep, self.ades)?;
+        if let Some(eobt) = self.eobt {
+            write!(f, " {}", eobt.serialize())?;
+        }
+        if let Some(dof) = &self.dof {
+            write!(f, " DOF/{}", dof)?;
+        }
+        Ok(())
+    }
+}
+
+/// État d'un plan de vol
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FlightStatus {
+    /// Plan déposé
+    Filed,
+
+    /// Départ retardé (DLA)
+    Delayed,
+
+    /// Vol parti (DEP)
+    Departed,
+
+    /// Vol arrivé (ARR)
+    Arrived,
+
+    /// Plan annulé (CNL)
+    Cancelled,
+}
+
+impl FlightStatus {
+    /// Un plan clos ne reçoit plus de messages
+    pub fn is_closed(&self) -> bool {
+        matches!(self, FlightStatus::Arrived | FlightStatus::Cancelled)
+    }
+}
+
+/// Modification d'un Field Type du plan
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FieldChange {
+    /// Numéro du Field Type
+    pub field: u8,
+
+    /// Contenu avant le message
+    pub old: Option<String>,
+
+    /// Contenu après le message
+    pub new: Option<String>,
+}
+
+/// Message appliqué à un plan de vol
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FlightEvent {
+    /// Type de message (FPL, CHG, DLA, ...)
+    pub designator: String,
+
+    /// Message en forme parenthésée OACI
+    pub message: String,
+
+    /// Field Types modifiés par le message
+    pub changes: Vec<FieldChange>,
+
+    /// État du plan après le message
+    pub status: FlightStatus,
+}
+
+/// Plan de vol courant et son historique
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FlightRecord {
+    /// Identification courante du vol
+    pub key: FlightKey,
+
+    /// Plan de vol courant, amendements appliqués
+    pub plan: AtsMessage,
+
+    /// État du plan
+    pub status: FlightStatus,
+
+    /// Heure réelle de départ (DEP)
+    pub actual_departure: Option<Hhmm>,
+
+    /// Aérodrome et heure réelle d'arrivée (ARR)
+    pub actual_arrival: Option<AerodromeTime>,
+
+    /// Messages appliqués, dans l'ordre
+    pub history: Vec<FlightEvent>,
+}
+
+/// Message reçu pour un vol sans plan actif
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UnknownFlightMessage {
+    /// Identification du vol portée par le message
+    pub key: FlightKey,
+
+    /// Message en forme parenthésée OACI
+    pub message: String,
+}
+
+/// Effet d'un message sur le store
+#[derive(Debug, Clone, PartialEq)]
+pub enum IngestOutcome {
+    /// Nouveau plan de vol
+    Created(FlightKey),
+
+    /// Plan existant mis à jour (identification après mise à jour)
+    Updated(FlightKey),
+
+    /// Aucun plan actif pour ce vol ; le message est conservé dans `unknown_messages`
+    UnknownFlight(FlightKey),
+
+    /// Message sans effet sur les plans de vol (autre type ou forme non OACI)
+    Ignored,
+}
+
+/// Plans de vol courants, alimentés par les messages AFTN et ADEXP
+#[derive(Debug, Clone, Default)]
+pub struct FlightPlanStore {
+    flights: Vec<FlightRecord>,
+    unknown: Vec<UnknownFlightMessage>,
+}
+
+impl FlightPlanStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Applique un message AFTN de plan de vol (forme parenthésée OACI)
+    pub fn ingest_aftn(&mut self, message: &AftnMessage) -> Result<IngestOutcome, AftnError> {
+        use crate::aftn::categories::MessageCategory::*;
+        if !matches!(message.category, FlightPlan | Change | Delay | Cancel | Departure | Arrival) {
+            return Ok(IngestOutcome::Ignored);
+        }
+        if let Some(error) = message.submessage.error() {
+            return Err(error.clone());
+        }
+        let ats = match message.submessage.parsed() {
+            Some(SubMessageData::FlightPlan(m)) => m.ats.as_ref(),
+            Some(SubMessageData::Change(m)) => m.ats.as_ref(),
+            Some(SubMessageData::Delay(m)) => m.ats.as_ref(),
+            Some(SubMessageData::Cancel(m)) => m.ats.as_ref(),
+            Some(SubMessageData::Departure(m)) => m.ats.as_ref(),
+            Some(SubMessageData::Arrival(m)) => m.ats.as_ref(),
+            _ => None,
+        };
+        match ats {
+            Some(ats) => self.ingest_ats(ats),
+            None => Ok(IngestOutcome::Ignored),
+        }
+    }
+
+    /// Applique un message ADEXP, via son équivalent OACI
+    pub fn ingest_adexp(&mut self, message: &AdexpMessage) -> Result<IngestOutcome, AftnError> {
+        let conversion = message.to_ats()
+            .map_err(|e| AftnError::InvalidFormat(format!("ADEXP message: {}", e)))?;
+        self.ingest_ats(&conversion.message)
+    }
+
+    /// Applique un message ATS OACI
+    pub fn ingest_ats(&mut self, ats: &AtsMessage) -> Result<IngestOutcome, AftnError> {
+        let designator = ats.message_type.designator.as_str();
+        if !matches!(designator, "FPL" | "CPL" | "CHG" | "DLA" | "CNL" | "DEP" | "ARR") {
+            return Ok(IngestOutcome::Ignored);
+        }
+
+        let key = FlightKey::from_ats(ats)?;
+        // DLA, DEP et ARR portent une autre heure que l'EOBT du plan dans le Field Type 13
+        let compare_eobt = matches!(designator, "FPL" | "CPL" | "CHG" | "CNL");
+        let index = self.flights.iter()
+            .position(|f| !f.status.is_closed() && f.key.matches(&key, compare_eobt));
+
+        let Some(index) = index else {
+            if matches!(designator, "FPL" | "CPL") {
+                self.flights.push(FlightRecord {
+                    key: key.clone(),
+                    plan: ats.clone(),
+                    status: FlightStatus::Filed,
+                    actual_departure: None,
+                    actual_arrival: None,
+                    history: vec![FlightEvent {
+                        designator: designator.to_string(),
+                        message: ats.serialize(),
+                        changes: Vec::new(),
+                        status: FlightStatus::Filed,
+                    }],
+                });
+                return Ok(IngestOutcome::Created(key));
+            }
+            self.unknown.push(UnknownFlightMessage { key: key.clone(), message: ats.serialize() });
+            return Ok(IngestOutcome::UnknownFlight(key));
+        };
+
+        // Le message s'applique à une copie: le plan reste inchangé si l'un
+        // des amendements est refusé
+        let mut record = self.flights[index].clone();
+        let before = record.plan.clone();
+        match designator {
+            "FPL" | "CPL" => {
+                // Plan redéposé: le nouveau contenu remplace l'ancien
+                let message_type = record.plan.message_type.clone();
+                record.plan = ats.clone();
+                record.plan.message_type = message_type;
+                record.status = FlightStatus::Filed;
+            }
+            "CHG" => {
+                for amendment in &ats.amendments {
+                    record.plan.set_field(amendment.field_number, &amendment.content)?;
+                }
+            }
+            "DLA" => {
+                if let Some(departure) = record.plan.departure.as_mut() {
+                    departure.time = ats.departure.as_ref().and_then(|d| d.time);
+                }
+                record.status = FlightStatus::Delayed;
+            }
+            "CNL" => record.status = FlightStatus::Cancelled,
+            "DEP" => {
+                record.actual_departure = ats.departure.as_ref().and_then(|d| d.time);
+                record.status = FlightStatus::Departed;
+            }
+            _ => {
+                record.actual_arrival = ats.arrival.clone();
+                record.status = FlightStatus::Arrived;
+            }
+        }
+
+        record.key = FlightKey::from_ats(&record.plan)?;
+        let changes = diff_fields(&before, &record.plan);
+        record.history.push(FlightEvent {
+            designator: designator.to_string(),
+            message: ats.serialize(),
+            changes,
+            status: record.status,
+        });
+        let key = record.key.clone();
+        self.flights[index] = record;
+        Ok(IngestOutcome::Updated(key))
+    }
+
+    /// Tous les plans de vol, clos compris, dans l'ordre de création
+    pub fn flights(&self) -> &[FlightRecord] {
+        &self.flights
+    }
+
+    /// Plan actif (ou, à défaut, le dernier plan clos) pour une identification
+    pub fn get(&self, key: &FlightKey) -> Option<&FlightRecord> {
+        self.flights.iter()
+            .find(|f| !f.status.is_closed() && f.key.matches(key, true))
+            .or_else(|| self.flights.iter().rev().find(|f| f.key.matches(key, true)))
+    }
+
+    /// Plans de vol d'un indicatif, dans l'ordre de création
+    pub fn find_by_arcid<'a>(&'a self, arcid: &'a str) -> impl Iterator<Item = &'a FlightRecord> + 'a {
+        self.flights.iter().filter(move |f| f.key.arcid == arcid)
+    }
+
+    /// Messages reçus pour des vols sans plan actif
+    pub fn unknown_messages(&self) -> &[UnknownFlightMessage] {
+        &self.unknown
+    }
+}
+
+/// Field Types dont le contenu diffère entre deux versions du plan
+fn diff_fields(before: &AtsMessage, after: &AtsMessage) -> Vec<FieldChange> {
+    let text = |ats: &AtsMessage, number: u8| {
+        let parts = ats.field_text(number);
+        (!parts.is_empty()).then(|| parts.join("-"))
+    };
+    TRACKED_FIELDS.iter()
+        .filter_map(|&field| {
+            let old = text(before, field);
+            let new = text(after, field);
+            (old != new).then_some(FieldChange { field, old, new })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ats(text: &str) -> AtsMessage {
+        AtsMessage::parse(text).unwrap()
+    }
+
+    const FPL: &str = "(FPL-AFR123-IS-A320/M-SDFGIRWY/S-LFPG0900-N0450F350 UM184 RESMI-EGLL0105-DOF/261017)";
+
+    #[test]
+    fn test_lifecycle() {
+        let mut store = FlightPlanStore::new();
+        assert!(matches!(store.ingest_ats(&ats(FPL)).unwrap(), IngestOutcome::Created(_)));
+
+        store.ingest_ats(&ats("(CHG-AFR123-LFPG0900-EGLL-DOF/261017-15/N0460F370 DCT)")).unwrap();
+        store.ingest_ats(&ats("(DLA-AFR123-LFPG0945-EGLL-DOF/261017)")).unwrap();
+        store.ingest_ats(&ats("(DEP-AFR123-LFPG0952-EGLL-DOF/261017)")).unwrap();
+        let outcome = store.ingest_ats(&ats("(ARR-AFR123-LFPG0945-EGLL1050)")).unwrap();
+
+        let key = match outcome {
+            IngestOutcome::Updated(key) => key,
+            other => panic!("Unexpected outcome: {:?}", other),
+        };
+        assert_eq!(key.eobt, Some(Hhmm { hour: 9, minute: 45 }));
+
+        let record = store.get(&key).unwrap();
+        assert_eq!(record.status, FlightStatus::Arrived);
+        assert_eq!(record.plan.route.as_ref().unwrap().serialize(), "N0460F370 DCT");
+        assert_eq!(record.actual_departure, Some(Hhmm { hour: 9, minute: 52 }));
+        assert_eq!(record.actual_arrival.as_ref().unwrap().serialize(), "EGLL1050");
+
+        let designators: Vec<&str> = record.history.iter().map(|e| e.designator.as_str()).collect();
+        assert_eq!(designators, vec!["FPL", "CHG", "DLA", "DEP", "ARR"]);
+        assert_eq!(record.history[1].changes[0].field, 15);
+        assert_eq!(record.history[2].changes[0].new.as_deref(), Some("LFPG0945"));
+        assert!(store.unknown_messages().is_empty());
+    }
+
+    #[test]
+    fn test_unknown_and_cancelled_flights() {
+        let mut store = FlightPlanStore::new();
+        let outcome = store.ingest_ats(&ats("(DLA-BAW456-EGLL1000-LFPG)")).unwrap();
+        assert!(matches!(outcome, IngestOutcome::UnknownFlight(_)));
+
+        store.ingest_ats(&ats(FPL)).unwrap();
+        // Autre EOBT: autre vol
+        let outcome = store.ingest_ats(&ats("(CNL-AFR123-LFPG1500-EGLL-DOF/261017)")).unwrap();
+        assert!(matches!(outcome, IngestOutcome::UnknownFlight(_)));
+
+        store.ingest_ats(&ats("(CNL-AFR123-LFPG0900-EGLL-DOF/261017)")).unwrap();
+        assert_eq!(store.flights()[0].status, FlightStatus::Cancelled);
+        // Un plan annulé ne reçoit plus de messages
+        let outcome = store.ingest_ats(&ats("(DEP-AFR123-LFPG0910-EGLL-DOF/261017)")).unwrap();
+        assert!(matches!(outcome, IngestOutcome::UnknownFlight(_)));
+        assert_eq!(store.unknown_messages().len(), 3);
+
+        // Un nouveau dépôt crée un nouveau plan
+        assert!(matches!(store.ingest_ats(&ats(FPL)).unwrap(), IngestOutcome::Created(_)));
+        assert_eq!(store.find_by_arcid("AFR123").count(), 2);
+    }
+
+    #[test]
+    fn test_rejected_change_leaves_plan_untouched() {
+        let mut store = FlightPlanStore::new();
+        store.ingest_ats(&ats(FPL)).unwrap();
+        let before = store.flights()[0].clone();
+
+        // Le second amendement est invalide: le premier ne doit pas être appliqué
+        let change = ats("(CHG-AFR123-LFPG0900-EGLL-DOF/261017-15/N0460F370 DCT-16/EGLL01)");
+        assert!(store.ingest_ats(&change).is_err());
+        assert_eq!(store.flights()[0], before);
+        assert_eq!(store.flights()[0].history.len(), 1);
+    }
+}
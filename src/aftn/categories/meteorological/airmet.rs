@@ -3,7 +3,9 @@ use pest::Parser;
 use pest_derive::Parser;
 use crate::aftn::categories::MessageCategory;
 use crate::aftn::error::AftnError;
+use crate::diagnostic::SyntaxError;
 use crate::aftn::submessages::SubMessage;
+use super::weather_warning::WeatherWarning;
 
 #[derive(Parser)]
 #[grammar = "aftn/categories/meteorological/meteorological.pest"]
@@ -14,6 +16,9 @@ pub struct AirmetMessage {
     /// Contenu du AIRMET
     pub content: String,
     
+    /// En-tête, phénomène, zone et évolution décodés (Annexe 3 OACI)
+    pub warning: WeatherWarning,
+    
     /// Corps brut du message
     pub raw: String,
 }
@@ -21,7 +26,7 @@ pub struct AirmetMessage {
 impl SubMessage for AirmetMessage {
     fn parse(body: &str) -> Result<Self, AftnError> {
         let mut pairs = AirmetParser::parse(Rule::airmet, body)
-            .map_err(|e| AftnError::ParseError(format!("AIRMET parse error: {}", e)))?;
+            .map_err(|e| AftnError::from(SyntaxError::from_pest(&e, body, "AIRMET")))?;
         
         let airmet_pair = pairs.next().ok_or_else(|| {
             AftnError::ParseError("Empty AIRMET parse result".to_string())
@@ -59,8 +64,11 @@ impl AirmetMessage {
             content = raw.to_string();
         }
         
+        let warning = WeatherWarning::decode(raw, "AIRMET")?;
+        
         Ok(AirmetMessage {
             content,
+            warning,
             raw: raw.to_string(),
         })
     }
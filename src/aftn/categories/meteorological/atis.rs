@@ -3,6 +3,7 @@ use pest::Parser;
 use pest_derive::Parser;
 use crate::aftn::categories::MessageCategory;
 use crate::aftn::error::AftnError;
+use crate::diagnostic::SyntaxError;
 use crate::aftn::submessages::SubMessage;
 
 #[derive(Parser)]
@@ -21,7 +22,7 @@ pub struct AtisMessage {
 impl SubMessage for AtisMessage {
     fn parse(body: &str) -> Result<Self, AftnError> {
         let mut pairs = AtisParser::parse(Rule::atis, body)
-            .map_err(|e| AftnError::ParseError(format!("ATIS parse error: {}", e)))?;
+            .map_err(|e| AftnError::from(SyntaxError::from_pest(&e, body, "ATIS")))?;
         
         let atis_pair = pairs.next().ok_or_else(|| {
             AftnError::ParseError("Empty ATIS parse result".to_string())
@@ -0,0 +1,561 @@
+//! Parser des NOTAM au format OACI (Annexe 15, PANS-AIM Doc 10066)
+//!
+//! Exemple:
+//! ```text
+//! (A1234/24 NOTAMR A1200/24
+//! Q) LFFF/QMRLC/IV/NBO/A/000/999/4900N00233E005
+//! A) LFPG B) 2404150600 C) 2404201800 EST
+//! D) DAILY 0600-1800
+//! E) RWY 09L/27R CLSD
+//! F) SFC G) FL100)
+//! ```
+
+use std::fmt;
+use serde::{Deserialize, Serialize};
+use pest::Parser;
+use pest_derive::Parser;
+use crate::aftn::error::AftnError;
+use crate::diagnostic::SyntaxError;
+use crate::datetime::UtcDateTime;
+
+#[derive(Parser)]
+#[grammar = "aftn/categories/meteorological/icao_notam.pest"]
+struct IcaoNotamParser;
+
+/// Rayon moyen de la Terre en milles nautiques
+const EARTH_RADIUS_NM: f64 = 3440.065;
+
+/// Indique si un corps de message est un NOTAM au format OACI (`(A1234/24 NOTAMN ...`)
+pub fn is_icao_notam(body: &str) -> bool {
+    let text = body.trim_start();
+    let text = text.strip_prefix('(').unwrap_or(text).trim_start();
+    let mut parts = text.split_whitespace();
+    match (parts.next(), parts.next()) {
+        (Some(id), Some(kind)) => NotamId::parse(id).is_ok() && matches!(kind, "NOTAMN" | "NOTAMR" | "NOTAMC"),
+        _ => false,
+    }
+}
+
+/// Identification d'un NOTAM: série, numéro et année (ex: `A1234/24`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct NotamId {
+    pub series: char,
+    pub number: u16,
+    pub year: u8,
+}
+
+impl NotamId {
+    pub fn parse(text: &str) -> Result<Self, AftnError> {
+        let invalid = || AftnError::InvalidFormat(format!("NOTAM identifier must be SNNNN/YY, got: {}", text));
+        let (series_number, year) = text.split_once('/').ok_or_else(invalid)?;
+        let mut chars = series_number.chars();
+        let series = chars.next().filter(|c| c.is_ascii_uppercase()).ok_or_else(invalid)?;
+        let number = chars.as_str();
+        if number.len() != 4 || year.len() != 2
+            || !number.chars().all(|c| c.is_ascii_digit())
+            || !year.chars().all(|c| c.is_ascii_digit())
+        {
+            return Err(invalid());
+        }
+        Ok(NotamId {
+            series,
+            number: number.parse().map_err(|_| invalid())?,
+            year: year.parse().map_err(|_| invalid())?,
+        })
+    }
+}
+
+impl fmt::Display for NotamId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{:04}/{:02}", self.series, self.number, self.year)
+    }
+}
+
+/// Type de NOTAM
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum NotamKind {
+    /// NOTAMN - nouveau NOTAM
+    New,
+
+    /// NOTAMR - remplace le NOTAM visé
+    Replace,
+
+    /// NOTAMC - annule le NOTAM visé
+    Cancel,
+}
+
+/// Position géographique en degrés décimaux
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Coordinate {
+    pub latitude: f64,
+    pub longitude: f64,
+}
+
+impl Coordinate {
+    /// Parse une position `DDMM[N|S]DDDMM[E|W]` (ex: `4900N00233E`)
+    pub fn parse(text: &str) -> Result<Self, AftnError> {
+        let invalid = || AftnError::InvalidFormat(format!("Coordinate must be DDMMNDDDMME, got: {}", text));
+        if text.len() != 11 || !text.is_ascii() {
+            return Err(invalid());
+        }
+        let degrees_minutes = |digits: &str, degree_len: usize| -> Option<f64> {
+            if !digits.chars().all(|c| c.is_ascii_digit()) {
+                return None;
+            }
+            let degrees: f64 = digits[..degree_len].parse().ok()?;
+            let minutes: f64 = digits[degree_len..].parse().ok()?;
+            (minutes < 60.0).then_some(degrees + minutes / 60.0)
+        };
+
+        let latitude = degrees_minutes(&text[0..4], 2).ok_or_else(invalid)?;
+        let latitude = match &text[4..5] {
+            "N" => latitude,
+            "S" => -latitude,
+            _ => return Err(invalid()),
+        };
+        let longitude = degrees_minutes(&text[5..10], 3).ok_or_else(invalid)?;
+        let longitude = match &text[10..11] {
+            "E" => longitude,
+            "W" => -longitude,
+            _ => return Err(invalid()),
+        };
+        if latitude.abs() > 90.0 || longitude.abs() > 180.0 {
+            return Err(invalid());
+        }
+        Ok(Coordinate { latitude, longitude })
+    }
+
+    /// Distance orthodromique en milles nautiques
+    pub fn distance_nm(&self, other: &Coordinate) -> f64 {
+        let (lat1, lat2) = (self.latitude.to_radians(), other.latitude.to_radians());
+        let delta_lat = lat2 - lat1;
+        let delta_lon = (other.longitude - self.longitude).to_radians();
+        let a = (delta_lat / 2.0).sin().powi(2) + lat1.cos() * lat2.cos() * (delta_lon / 2.0).sin().powi(2);
+        2.0 * EARTH_RADIUS_NM * a.sqrt().asin()
+    }
+}
+
+/// Ligne de qualification (item Q)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QLine {
+    /// FIR (ou indicateur d'emplacement) concernée
+    pub fir: String,
+
+    /// Code NOTAM (ex: `QMRLC`)
+    pub code: String,
+
+    /// Trafic concerné: I (IFR), V (VFR), IV, K (liste de contrôle)
+    pub traffic: String,
+
+    /// Objet: N, B, O, M, K (et combinaisons)
+    pub purpose: String,
+
+    /// Portée: A (aérodrome), E (en route), W (avertissement de navigation), AE, AW, K
+    pub scope: String,
+
+    /// Limite inférieure en niveau de vol (centaines de pieds)
+    pub lower_fl: u16,
+
+    /// Limite supérieure en niveau de vol (centaines de pieds)
+    pub upper_fl: u16,
+
+    /// Centre de la zone d'influence
+    pub centre: Option<Coordinate>,
+
+    /// Rayon de la zone d'influence en milles nautiques
+    pub radius_nm: Option<u16>,
+}
+
+impl QLine {
+    pub fn parse(text: &str) -> Result<Self, AftnError> {
+        let invalid = |detail: &str| AftnError::InvalidFormat(format!("Invalid Q) line ({}): {}", detail, text));
+
+        let fields: Vec<&str> = text.split('/').map(str::trim).collect();
+        if fields.len() != 8 {
+            return Err(invalid("8 fields expected"));
+        }
+
+        let fir = fields[0];
+        if !(fir.len() == 4 && fir.chars().all(|c| c.is_ascii_uppercase())) {
+            return Err(invalid("FIR must be 4 letters"));
+        }
+
+        let code = fields[1];
+        if !(code.len() == 5 && code.starts_with('Q') && code.chars().all(|c| c.is_ascii_uppercase())) {
+            return Err(invalid("NOTAM code must be Q followed by 4 letters"));
+        }
+
+        // Trafic (I, V, IV, K), objet (N, B, O, M, K) et portée (A, E, W, K)
+        let letters = |value: &str, max_len: usize, allowed: &str, detail: &str| -> Result<String, AftnError> {
+            if value.is_empty() || value.len() > max_len || !value.chars().all(|c| allowed.contains(c)) {
+                return Err(invalid(detail));
+            }
+            Ok(value.to_string())
+        };
+        let traffic = letters(fields[2], 2, "IVK", "traffic must be I, V, IV or K")?;
+        let purpose = letters(fields[3], 3, "NBOMK", "purpose must be N, B, O, M or K")?;
+        let scope = letters(fields[4], 2, "AEWK", "scope must be A, E, W or K")?;
+
+        let level = |value: &str| -> Result<u16, AftnError> {
+            if value.len() == 3 && value.chars().all(|c| c.is_ascii_digit()) {
+                value.parse().map_err(|_| invalid("level"))
+            } else {
+                Err(invalid("levels must be 3 digits"))
+            }
+        };
+        let lower_fl = level(fields[5])?;
+        let upper_fl = level(fields[6])?;
+        if lower_fl > upper_fl {
+            return Err(invalid("lower limit above upper limit"));
+        }
+
+        let (centre, radius_nm) = match fields[7] {
+            "" => (None, None),
+            area if area.len() == 14 && area.is_ascii() => {
+                let radius = &area[11..];
+                if !radius.chars().all(|c| c.is_ascii_digit()) {
+                    return Err(invalid("radius must be 3 digits"));
+                }
+                let radius = radius.parse().map_err(|_| invalid("radius"))?;
+                (Some(Coordinate::parse(&area[..11])?), Some(radius))
+            }
+            _ => return Err(invalid("area must be DDMMNDDDMMERRR")),
+        };
+
+        Ok(QLine {
+            fir: fir.to_string(),
+            code: code.to_string(),
+            traffic,
+            purpose,
+            scope,
+            lower_fl,
+            upper_fl,
+            centre,
+            radius_nm,
+        })
+    }
+
+    /// Sujet du code NOTAM (2e et 3e lettres, ex: `MR` piste)
+    pub fn subject(&self) -> &str {
+        self.code.get(1..3).unwrap_or("")
+    }
+
+    /// État du sujet (4e et 5e lettres, ex: `LC` fermé)
+    pub fn condition(&self) -> &str {
+        self.code.get(3..5).unwrap_or("")
+    }
+
+    /// Indique si la zone d'influence recoupe le cercle donné (centre, rayon en NM)
+    pub fn intersects(&self, centre: &Coordinate, radius_nm: f64) -> bool {
+        match (self.centre, self.radius_nm) {
+            (Some(own_centre), Some(own_radius)) => {
+                own_centre.distance_nm(centre) <= own_radius as f64 + radius_nm
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Fin de validité (item C)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum NotamValidityEnd {
+    /// PERM - permanent
+    Permanent,
+
+    /// Date de fin, éventuellement estimée (EST)
+    At { time: UtcDateTime, estimated: bool },
+}
+
+/// NOTAM décodé item par item
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IcaoNotam {
+    pub id: NotamId,
+
+    pub kind: NotamKind,
+
+    /// NOTAM remplacé (NOTAMR) ou annulé (NOTAMC)
+    pub referenced: Option<NotamId>,
+
+    /// Item Q - ligne de qualification
+    pub qualifier: Option<QLine>,
+
+    /// Item A - indicateurs d'emplacement
+    pub locations: Vec<String>,
+
+    /// Item B - début de validité
+    pub valid_from: Option<UtcDateTime>,
+
+    /// Item C - fin de validité
+    pub valid_until: Option<NotamValidityEnd>,
+
+    /// Item D - horaires
+    pub schedule: Option<String>,
+
+    /// Item E - texte du NOTAM
+    pub text: String,
+
+    /// Item F - limite inférieure
+    pub lower_limit: Option<String>,
+
+    /// Item G - limite supérieure
+    pub upper_limit: Option<String>,
+}
+
+impl IcaoNotam {
+    pub fn parse(body: &str) -> Result<Self, AftnError> {
+        let mut pairs = IcaoNotamParser::parse(Rule::notam, body)
+            .map_err(|e| AftnError::from(SyntaxError::from_pest(&e, body, "NOTAM")))?;
+
+        let notam_pair = pairs.next().ok_or_else(|| {
+            AftnError::ParseError("Empty NOTAM parse result".to_string())
+        })?;
+
+        let mut id = None;
+        let mut kind = None;
+        let mut referenced = None;
+        let mut notam = IcaoNotam {
+            id: NotamId { series: 'A', number: 0, year: 0 },
+            kind: NotamKind::New,
+            referenced: None,
+            qualifier: None,
+            locations: Vec::new(),
+            valid_from: None,
+            valid_until: None,
+            schedule: None,
+            text: String::new(),
+            lower_limit: None,
+            upper_limit: None,
+        };
+
+        for inner_pair in notam_pair.into_inner() {
+            match inner_pair.as_rule() {
+                Rule::header => {
+                    for header_pair in inner_pair.into_inner() {
+                        match header_pair.as_rule() {
+                            Rule::notam_id => id = Some(NotamId::parse(header_pair.as_str())?),
+                            Rule::referenced_id => referenced = Some(NotamId::parse(header_pair.as_str())?),
+                            Rule::notam_kind => {
+                                kind = Some(match header_pair.as_str() {
+                                    "NOTAMR" => NotamKind::Replace,
+                                    "NOTAMC" => NotamKind::Cancel,
+                                    _ => NotamKind::New,
+                                });
+                            }
+                            _ => {}
+                        }
+                    }
+                }
+                Rule::item => {
+                    let mut item_pairs = inner_pair.into_inner();
+                    let letter = item_pairs.next().map(|p| p.as_str()).unwrap_or("");
+                    let text = item_pairs.next().map(|p| p.as_str().trim()).unwrap_or("");
+                    notam.decode_item(letter, text)?;
+                }
+                _ => {}
+            }
+        }
+
+        notam.id = id.ok_or_else(|| AftnError::InvalidFormat("Missing NOTAM identifier".to_string()))?;
+        notam.kind = kind.ok_or_else(|| AftnError::InvalidFormat("Missing NOTAM type".to_string()))?;
+        notam.referenced = referenced;
+        notam.validate()?;
+        Ok(notam)
+    }
+
+    fn decode_item(&mut self, letter: &str, text: &str) -> Result<(), AftnError> {
+        let optional = |text: &str| (!text.is_empty()).then(|| text.to_string());
+        match letter {
+            "Q" => self.qualifier = Some(QLine::parse(text)?),
+            "A" => self.locations = text.split_whitespace().map(str::to_string).collect(),
+            "B" => self.valid_from = Some(parse_notam_time(text)?),
+            "C" => {
+                self.valid_until = Some(match text {
+                    "PERM" => NotamValidityEnd::Permanent,
+                    _ => match text.strip_suffix("EST") {
+                        Some(time) => NotamValidityEnd::At { time: parse_notam_time(time.trim())?, estimated: true },
+                        None => NotamValidityEnd::At { time: parse_notam_time(text)?, estimated: false },
+                    },
+                });
+            }
+            "D" => self.schedule = optional(text),
+            "E" => self.text = text.to_string(),
+            "F" => self.lower_limit = optional(text),
+            "G" => self.upper_limit = optional(text),
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Valide la cohérence du NOTAM (NOTAM visé, items obligatoires, période)
+    pub fn validate(&self) -> Result<(), AftnError> {
+        match (self.kind, self.referenced) {
+            (NotamKind::New, Some(referenced)) => {
+                return Err(AftnError::InvalidFormat(format!(
+                    "NOTAMN cannot reference another NOTAM, got: {}",
+                    referenced
+                )));
+            }
+            (NotamKind::Replace | NotamKind::Cancel, None) => {
+                return Err(AftnError::InvalidFormat(
+                    "NOTAMR/NOTAMC must reference the NOTAM being replaced or cancelled".to_string(),
+                ));
+            }
+            _ => {}
+        }
+
+        if self.kind != NotamKind::Cancel {
+            if self.locations.is_empty() {
+                return Err(AftnError::InvalidFormat("NOTAM item A) is required".to_string()));
+            }
+            if self.valid_from.is_none() {
+                return Err(AftnError::InvalidFormat("NOTAM item B) is required".to_string()));
+            }
+            if self.text.is_empty() {
+                return Err(AftnError::InvalidFormat("NOTAM item E) is required".to_string()));
+            }
+        }
+
+        if let (Some(from), Some(NotamValidityEnd::At { time, .. })) = (self.valid_from, self.valid_until) {
+            if time <= from {
+                return Err(AftnError::InvalidDateTime(format!(
+                    "NOTAM validity ends ({}) before it starts ({})",
+                    time, from
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Indique si la période de validité recoupe l'intervalle [from, to)
+    pub fn is_active_between(&self, from: &UtcDateTime, to: &UtcDateTime) -> bool {
+        let starts_before_end = self.valid_from.is_none_or(|start| start < *to);
+        let ends_after_start = match self.valid_until {
+            Some(NotamValidityEnd::At { time, .. }) => time > *from,
+            Some(NotamValidityEnd::Permanent) | None => true,
+        };
+        starts_before_end && ends_after_start
+    }
+
+    /// Indique si le code NOTAM commence par le préfixe donné (ex: `QMR`, `QMRLC`)
+    pub fn matches_code(&self, prefix: &str) -> bool {
+        self.qualifier.as_ref().is_some_and(|q| q.code.starts_with(prefix))
+    }
+}
+
+/// Parse un groupe date-heure NOTAM `YYMMDDHHMM`
+fn parse_notam_time(text: &str) -> Result<UtcDateTime, AftnError> {
+    if text.len() != 10 || !text.chars().all(|c| c.is_ascii_digit()) {
+        return Err(AftnError::InvalidDateTime(format!(
+            "NOTAM date-time must be 10 digits (YYMMDDHHMM), got: {}",
+            text
+        )));
+    }
+    let value = |range: std::ops::Range<usize>| text[range].parse::<u8>().unwrap_or(0);
+    Ok(UtcDateTime::new(2000 + value(0..2) as u16, value(2..4), value(4..6), value(6..8), value(8..10))?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOTAM: &str = "(A1234/24 NOTAMR A1200/24\nQ) LFFF/QMRLC/IV/NBO/A/000/999/4900N00233E005\nA) LFPG B) 2404150600 C) 2404201800 EST\nD) DAILY 0600-1800\nE) RWY 09L/27R CLSD DUE TO WIP (WORK IN PROGRESS)\nF) SFC G) FL100)";
+
+    #[test]
+    fn test_is_icao_notam() {
+        assert!(is_icao_notam(NOTAM));
+        assert!(is_icao_notam("A1234/24 NOTAMN Q) LFFF/QMRLC/IV/NBO/A/000/999/"));
+        assert!(!is_icao_notam("NOTAM A1234/24 LFPG RWY 09/27 CLOSED"));
+        assert!(!is_icao_notam("(FPL-AFR123-IS"));
+    }
+
+    #[test]
+    fn test_parse_icao_notam() {
+        let notam = IcaoNotam::parse(NOTAM).unwrap();
+
+        assert_eq!(notam.id.to_string(), "A1234/24");
+        assert_eq!(notam.kind, NotamKind::Replace);
+        assert_eq!(notam.referenced.unwrap().number, 1200);
+
+        let q = notam.qualifier.as_ref().unwrap();
+        assert_eq!(q.fir, "LFFF");
+        assert_eq!(q.subject(), "MR");
+        assert_eq!(q.condition(), "LC");
+        assert_eq!(q.traffic, "IV");
+        assert_eq!(q.purpose, "NBO");
+        assert_eq!(q.scope, "A");
+        assert_eq!((q.lower_fl, q.upper_fl), (0, 999));
+        assert_eq!(q.radius_nm, Some(5));
+        let centre = q.centre.unwrap();
+        assert!((centre.latitude - 49.0).abs() < 1e-9);
+        assert!((centre.longitude - 2.55).abs() < 1e-9);
+
+        assert_eq!(notam.locations, vec!["LFPG"]);
+        assert_eq!(notam.valid_from, Some(UtcDateTime::new(2024, 4, 15, 6, 0).unwrap()));
+        assert_eq!(
+            notam.valid_until,
+            Some(NotamValidityEnd::At { time: UtcDateTime::new(2024, 4, 20, 18, 0).unwrap(), estimated: true })
+        );
+        assert_eq!(notam.schedule, Some("DAILY 0600-1800".to_string()));
+        assert_eq!(notam.text, "RWY 09L/27R CLSD DUE TO WIP (WORK IN PROGRESS)");
+        assert_eq!(notam.lower_limit, Some("SFC".to_string()));
+        assert_eq!(notam.upper_limit, Some("FL100".to_string()));
+    }
+
+    #[test]
+    fn test_parse_icao_notam_perm_and_cancel() {
+        let notam = IcaoNotam::parse("(B0012/25 NOTAMN Q) EGTT/QOBCE/IV/M/E/000/005/5129N00028W002 A) EGLL B) 2501010000 C) PERM E) CRANE ERECTED)").unwrap();
+        assert_eq!(notam.valid_until, Some(NotamValidityEnd::Permanent));
+
+        let cancel = IcaoNotam::parse("(A1300/24 NOTAMC A1234/24 Q) LFFF/QMRXX/IV/NBO/A/000/999/4900N00233E005 A) LFPG B) 2404161200 E) REF NOTAM CANCELLED)").unwrap();
+        assert_eq!(cancel.kind, NotamKind::Cancel);
+        assert_eq!(cancel.valid_until, None);
+    }
+
+    #[test]
+    fn test_parse_icao_notam_invalid() {
+        // NOTAMR sans NOTAM visé
+        assert!(IcaoNotam::parse("(A1234/24 NOTAMR Q) LFFF/QMRLC/IV/NBO/A/000/999/4900N00233E005 A) LFPG B) 2404150600 E) RWY CLSD)").is_err());
+        // 31 avril
+        assert!(IcaoNotam::parse("(A1234/24 NOTAMN A) LFPG B) 2404310600 E) RWY CLSD)").is_err());
+        // Fin avant début
+        assert!(IcaoNotam::parse("(A1234/24 NOTAMN A) LFPG B) 2404150600 C) 2404140600 E) RWY CLSD)").is_err());
+        // Ligne Q incomplète
+        assert!(QLine::parse("LFFF/QMRLC/IV").is_err());
+    }
+
+    #[test]
+    fn test_qline_segment_lengths() {
+        assert!(QLine::parse("LFFF/QMRLC/IV/NBO/A/000/999/4900N00233E005").is_ok());
+        // Rayon signé ou non numérique
+        assert!(QLine::parse("LFFF/QMRLC/IV/NBO/A/000/999/4900N00233E+05").is_err());
+        assert!(QLine::parse("LFFF/QMRLC/IV/NBO/A/000/999/4900N00233E 05").is_err());
+        // Zone avec caractères multi-octets de 14 octets
+        assert!(QLine::parse("LFFF/QMRLC/IV/NBO/A/000/999/4900N0023é005").is_err());
+        // Segments trop longs, vides ou hors alphabet
+        assert!(QLine::parse("LFFFF/QMRLC/IV/NBO/A/000/999/").is_err());
+        assert!(QLine::parse("LFFF/QMRLC/IVK/NBO/A/000/999/").is_err());
+        assert!(QLine::parse("LFFF/QMRLC/IV/NBOM/A/000/999/").is_err());
+        assert!(QLine::parse("LFFF/QMRLC/IV/NBO//000/999/").is_err());
+        assert!(QLine::parse("LFFF/QMRLC/IV/NBO/X/000/999/").is_err());
+    }
+
+    #[test]
+    fn test_notam_filters() {
+        let notam = IcaoNotam::parse(NOTAM).unwrap();
+        let at = |day, hour| UtcDateTime::new(2024, 4, day, hour, 0).unwrap();
+
+        assert!(notam.is_active_between(&at(14, 0), &at(16, 0)));
+        assert!(!notam.is_active_between(&at(21, 0), &at(22, 0)));
+        assert!(!notam.is_active_between(&at(14, 0), &at(15, 6)));
+
+        assert!(notam.matches_code("QMR"));
+        assert!(!notam.matches_code("QFA"));
+
+        let paris = Coordinate::parse("4851N00221E").unwrap();
+        let london = Coordinate::parse("5129N00028W").unwrap();
+        let q = notam.qualifier.as_ref().unwrap();
+        assert!(q.intersects(&paris, 20.0));
+        assert!(!q.intersects(&london, 20.0));
+        assert!((paris.distance_nm(&london) - 191.6).abs() < 0.5);
+    }
+}
@@ -3,41 +3,74 @@ use pest::Parser;
 use pest_derive::Parser;
 use crate::aftn::categories::MessageCategory;
 use crate::aftn::error::AftnError;
+use crate::diagnostic::SyntaxError;
 use crate::aftn::submessages::SubMessage;
+use crate::aftn::datetime;
+use crate::datetime::UtcDateTime;
+use super::weather_groups::*;
 
 #[derive(Parser)]
 #[grammar = "aftn/categories/meteorological/meteorological.pest"]
 struct MetarParser;
 
+/// Type de rapport d'observation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MetarReportType {
+    /// METAR - observation régulière
+    Metar,
+    
+    /// SPECI - observation spéciale
+    Speci,
+}
+
+/// Message METAR/SPECI décodé selon l'OMM FM 15
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct MetarMessage {
+    /// METAR ou SPECI
+    pub report_type: MetarReportType,
+    
     /// Identifiant de l'aérodrome (ex: LFPG)
     pub station: Option<String>,
     
     /// Date et heure d'observation (format: DDHHMMZ)
     pub observation_time: Option<String>,
     
-    /// Vent (format: DDDSSKT)
-    pub wind: Option<String>,
-    
-    /// Visibilité
-    pub visibility: Option<String>,
+    /// Indicateurs de rapport (COR, AUTO, NIL)
+    pub modifiers: Vec<String>,
     
-    /// Conditions météorologiques
-    pub weather: Option<String>,
+    /// Vent, visibilité, CAVOK, temps présent et nuages
+    pub conditions: ForecastConditions,
     
-    /// Nuages
-    pub clouds: Option<String>,
+    /// Portées visuelles de piste
+    pub runway_visual_ranges: Vec<RunwayVisualRange>,
     
     /// Température / Point de rosée
-    pub temperature: Option<String>,
+    pub temperature: Option<Temperature>,
     
     /// Pression QNH
-    pub qnh: Option<String>,
+    pub qnh: Option<Pressure>,
+    
+    /// Temps récent (REw'w')
+    pub recent_weather: Vec<PresentWeather>,
+    
+    /// Cisaillement du vent (WS)
+    pub wind_shear: Vec<WindShear>,
+    
+    /// État des pistes
+    pub runway_states: Vec<RunwayState>,
+    
+    /// Aérodrome fermé pour cause de neige (SNOCLO)
+    pub snow_closed: bool,
     
-    /// Indicateurs spéciaux (NOSIG, etc.)
+    /// Prévisions de tendance (NOSIG, BECMG, TEMPO)
+    pub trends: Vec<Trend>,
+    
+    /// Remarques (après RMK)
     pub remarks: Option<String>,
     
+    /// Groupes non reconnus, conservés tels quels
+    pub unrecognized: Vec<String>,
+    
     /// Corps brut du message
     pub raw: String,
 }
@@ -45,7 +78,7 @@ pub struct MetarMessage {
 impl SubMessage for MetarMessage {
     fn parse(body: &str) -> Result<Self, AftnError> {
         let mut pairs = MetarParser::parse(Rule::metar, body)
-            .map_err(|e| AftnError::ParseError(format!("METAR parse error: {}", e)))?;
+            .map_err(|e| AftnError::from(SyntaxError::from_pest(&e, body, "METAR")))?;
         
         let metar_pair = pairs.next().ok_or_else(|| {
             AftnError::ParseError("Empty METAR parse result".to_string())
@@ -63,21 +96,25 @@ impl SubMessage for MetarMessage {
     }
     
     fn category(&self) -> MessageCategory {
-        MessageCategory::Metar
+        match self.report_type {
+            MetarReportType::Metar => MessageCategory::Metar,
+            MetarReportType::Speci => MessageCategory::Speci,
+        }
     }
 }
 
 impl MetarMessage {
+    /// Résout l'heure d'observation (DDHHMMZ) en instant UTC par rapport à une date de référence
+    pub fn observation_datetime(&self, reference: &UtcDateTime) -> Result<Option<UtcDateTime>, AftnError> {
+        self.observation_time
+            .as_deref()
+            .map(|time| datetime::resolve_ddhhmm(time, reference))
+            .transpose()
+    }
+    
     fn parse_metar_pair(pair: pest::iterators::Pair<Rule>, raw: &str) -> Result<Self, AftnError> {
         let mut station = None;
         let mut observation_time = None;
-        let mut wind = None;
-        let mut visibility = None;
-        let mut weather = None;
-        let mut clouds = None;
-        let mut temperature = None;
-        let mut qnh = None;
-        let mut remarks = None;
         
         for inner_pair in pair.into_inner() {
             match inner_pair.as_rule() {
@@ -87,43 +124,130 @@ impl MetarMessage {
                 Rule::observation_time => {
                     observation_time = Some(inner_pair.as_str().trim().to_string());
                 }
-                Rule::wind => {
-                    wind = Some(inner_pair.as_str().trim().to_string());
-                }
-                Rule::visibility => {
-                    visibility = Some(inner_pair.as_str().trim().to_string());
-                }
-                Rule::weather => {
-                    weather = Some(inner_pair.as_str().trim().to_string());
-                }
-                Rule::clouds => {
-                    clouds = Some(inner_pair.as_str().trim().to_string());
-                }
-                Rule::temperature => {
-                    temperature = Some(inner_pair.as_str().trim().to_string());
-                }
-                Rule::qnh => {
-                    qnh = Some(inner_pair.as_str().trim().to_string());
-                }
-                Rule::remarks => {
-                    remarks = Some(inner_pair.as_str().trim().to_string());
-                }
                 _ => {}
             }
         }
         
-        Ok(MetarMessage {
+        let report_type = if raw.trim_start().starts_with("SPECI") {
+            MetarReportType::Speci
+        } else {
+            MetarReportType::Metar
+        };
+        
+        let mut metar = MetarMessage {
+            report_type,
             station,
             observation_time,
-            wind,
-            visibility,
-            weather,
-            clouds,
-            temperature,
-            qnh,
-            remarks,
+            modifiers: Vec::new(),
+            conditions: ForecastConditions::default(),
+            runway_visual_ranges: Vec::new(),
+            temperature: None,
+            qnh: None,
+            recent_weather: Vec::new(),
+            wind_shear: Vec::new(),
+            runway_states: Vec::new(),
+            snow_closed: false,
+            trends: Vec::new(),
+            remarks: None,
+            unrecognized: Vec::new(),
             raw: raw.to_string(),
-        })
+        };
+        metar.decode_groups(raw);
+        Ok(metar)
+    }
+    
+    /// Décode les groupes du rapport, dans l'ordre du code FM 15
+    fn decode_groups(&mut self, raw: &str) {
+        let tokens: Vec<&str> = raw.split_whitespace().collect();
+        
+        // En-tête: type de rapport, COR, indicateur d'emplacement, heure, AUTO/NIL
+        let mut i = 1;
+        while let Some(&token) = tokens.get(i) {
+            if matches!(token, "COR" | "AUTO" | "NIL") {
+                self.modifiers.push(token.to_string());
+            } else if Some(token) != self.station.as_deref() && Some(token) != self.observation_time.as_deref() {
+                break;
+            }
+            i += 1;
+        }
+        
+        while let Some(&token) = tokens.get(i) {
+            if token == "RMK" {
+                self.remarks = Some(tokens[i + 1..].join(" "));
+                break;
+            }
+            if let Some(trend) = Trend::from_indicator(token) {
+                self.trends.push(trend);
+                i += 1;
+                continue;
+            }
+            
+            let consumed = match self.trends.last_mut() {
+                Some(trend) => trend.decode_group(&tokens[i..]),
+                None => self.decode_group(&tokens[i..]),
+            };
+            if consumed == 0 {
+                self.unrecognized.push(token.to_string());
+            }
+            i += consumed.max(1);
+        }
+    }
+    
+    /// Décode un groupe de la partie observation, renvoie le nombre de groupes consommés
+    fn decode_group(&mut self, tokens: &[&str]) -> usize {
+        let token = tokens[0];
+        
+        if token == "SNOCLO" {
+            self.snow_closed = true;
+            return 1;
+        }
+        if token == "WS" {
+            return match tokens.get(1..) {
+                Some(["ALL", "RWY", ..]) => {
+                    self.wind_shear.push(WindShear::AllRunways);
+                    3
+                }
+                // Forme ancienne: WS TKOF RWY20 / WS LDG RWY20
+                Some(["TKOF" | "LDG", runway, ..]) => self.push_wind_shear(runway, 3),
+                Some([runway, ..]) => self.push_wind_shear(runway, 2),
+                _ => 0,
+            };
+        }
+        if let Ok(rvr) = RunwayVisualRange::parse(token) {
+            self.runway_visual_ranges.push(rvr);
+            return 1;
+        }
+        if let Ok(state) = RunwayState::parse(token) {
+            self.runway_states.push(state);
+            return 1;
+        }
+        if let Ok(weather) = PresentWeather::parse_recent(token) {
+            self.recent_weather.push(weather);
+            return 1;
+        }
+        if self.temperature.is_none() {
+            if let Ok(temperature) = Temperature::parse(token) {
+                self.temperature = Some(temperature);
+                return 1;
+            }
+        }
+        if self.qnh.is_none() {
+            if let Ok(qnh) = Pressure::parse(token) {
+                self.qnh = Some(qnh);
+                return 1;
+            }
+        }
+        self.conditions.decode_group(tokens)
+    }
+    
+    fn push_wind_shear(&mut self, runway: &str, consumed: usize) -> usize {
+        match runway.strip_prefix("RWY").or_else(|| runway.strip_prefix('R')) {
+            Some(runway) => {
+                self.wind_shear.push(WindShear::Runway(runway.to_string()));
+                consumed
+            }
+            None => 0,
+        }
     }
 }
 
@@ -140,5 +264,77 @@ mod tests {
         let metar = result.unwrap();
         assert_eq!(metar.station, Some("LFPG".to_string()));
     }
+
+    #[test]
+    fn test_decode_metar_groups() {
+        let metar = MetarMessage::parse(
+            "METAR LFPG 151230Z 28015G25KT 250V310 4000 1500SW R27L/P1500U R09/0600V1000FT +TSRAGR VCFG \
+             FEW008 BKN030CB M02/M05 Q1013 RERA WS R27L R27L/450293 BECMG FM1300 TL1400 31010KT 9999 NSW NSC RMK AO2"
+        ).unwrap();
+        
+        assert_eq!(metar.report_type, MetarReportType::Metar);
+        let wind = metar.conditions.wind.as_ref().unwrap();
+        assert_eq!(wind.direction, Some(280));
+        assert_eq!(wind.gust, Some(25));
+        assert_eq!(wind.variable_sector, Some((250, 310)));
+        
+        let visibility = metar.conditions.visibility.as_ref().unwrap();
+        assert_eq!(visibility.prevailing, VisibilityDistance::Meters(4000));
+        assert_eq!(visibility.minimum, Some((1500, Some("SW".to_string()))));
+        assert_eq!(metar.runway_visual_ranges.len(), 2);
+        assert_eq!(metar.runway_visual_ranges[1].max_value, Some(1000));
+        
+        assert_eq!(metar.conditions.weather.len(), 2);
+        assert_eq!(metar.conditions.weather[0].intensity, WeatherIntensity::Heavy);
+        assert_eq!(metar.conditions.weather[1].intensity, WeatherIntensity::InVicinity);
+        assert_eq!(metar.conditions.clouds.len(), 2);
+        assert_eq!(metar.temperature, Some(Temperature { air: -2, dew_point: Some(-5) }));
+        assert_eq!(metar.qnh, Some(Pressure::Hectopascals(1013)));
+        assert_eq!(metar.recent_weather[0].phenomena, vec!["RA"]);
+        assert_eq!(metar.wind_shear, vec![WindShear::Runway("27L".to_string())]);
+        assert_eq!(metar.runway_states[0].friction, Some(93));
+        
+        let trend = &metar.trends[0];
+        assert_eq!(trend.kind, TrendKind::Becoming);
+        assert_eq!(trend.from.map(|t| t.hour), Some(13));
+        assert_eq!(trend.until.map(|t| t.hour), Some(14));
+        assert!(trend.conditions.no_significant_weather);
+        assert_eq!(trend.conditions.clouds, vec![CloudGroup::NoSignificantCloud]);
+        assert_eq!(metar.remarks, Some("AO2".to_string()));
+        assert!(metar.unrecognized.is_empty(), "{:?}", metar.unrecognized);
+    }
+
+    #[test]
+    fn test_decode_speci_north_american() {
+        let metar = MetarMessage::parse("SPECI KJFK 151251Z AUTO VRB03KT 1 1/2SM -SN BR OVC008 M01/M02 A2992 NOSIG").unwrap();
+        
+        assert_eq!(metar.report_type, MetarReportType::Speci);
+        assert_eq!(metar.category(), MessageCategory::Speci);
+        assert_eq!(metar.modifiers, vec!["AUTO"]);
+        assert_eq!(metar.conditions.wind.as_ref().unwrap().direction, None);
+        assert_eq!(metar.conditions.visibility.as_ref().unwrap().prevailing, VisibilityDistance::StatuteMiles(1.5));
+        assert_eq!(metar.qnh, Some(Pressure::InchesOfMercury(2992)));
+        assert_eq!(metar.trends[0].kind, TrendKind::NoSignificantChange);
+        assert!(metar.unrecognized.is_empty(), "{:?}", metar.unrecognized);
+    }
+
+    #[test]
+    fn test_decode_metar_cavok() {
+        let metar = MetarMessage::parse("METAR LFPG 151230Z 00000KT CAVOK 22/12 Q1020 NOSIG").unwrap();
+        assert!(metar.conditions.cavok);
+        assert!(metar.conditions.wind.as_ref().unwrap().is_calm());
+        assert!(metar.conditions.visibility.is_none());
+    }
+
+    #[test]
+    fn test_metar_observation_datetime() {
+        let metar = MetarMessage::parse("METAR LFPG 312330Z 28015KT 9999 FEW030 12/08 Q1013").unwrap();
+        
+        let reference = UtcDateTime::new(2024, 1, 1, 0, 5).unwrap();
+        assert_eq!(metar.observation_datetime(&reference).unwrap(), Some(UtcDateTime::new(2023, 12, 31, 23, 30).unwrap()));
+        
+        let reference = UtcDateTime::new(2024, 5, 1, 0, 5).unwrap();
+        assert!(metar.observation_datetime(&reference).is_err()); // 31 avril
+    }
 }
 
@@ -1,13 +1,16 @@
 pub mod notam;
+pub mod icao_notam;
 pub mod metar;
 pub mod taf;
 pub mod sigmet;
 pub mod airmet;
 pub mod atis;
 pub mod volmet;
+pub mod weather_groups;
+pub mod weather_warning;
 
 pub use notam::NotamMessage;
-pub use metar::MetarMessage;
+pub use metar::{MetarMessage, MetarReportType};
 pub use taf::TafMessage;
 pub use sigmet::SigmetMessage;
 pub use airmet::AirmetMessage;
@@ -3,7 +3,9 @@ use pest::Parser;
 use pest_derive::Parser;
 use crate::aftn::categories::MessageCategory;
 use crate::aftn::error::AftnError;
+use crate::diagnostic::SyntaxError;
 use crate::aftn::submessages::SubMessage;
+use super::icao_notam::{is_icao_notam, IcaoNotam, NotamKind};
 
 #[derive(Parser)]
 #[grammar = "aftn/categories/meteorological/meteorological.pest"]
@@ -23,14 +25,21 @@ pub struct NotamMessage {
     /// Contenu du NOTAM
     pub content: String,
     
+    /// NOTAM décodé item par item, s'il est au format OACI (`(A1234/24 NOTAMN ...`)
+    pub icao: Option<IcaoNotam>,
+    
     /// Corps brut du message
     pub raw: String,
 }
 
 impl SubMessage for NotamMessage {
     fn parse(body: &str) -> Result<Self, AftnError> {
+        if is_icao_notam(body) {
+            return Ok(Self::from_icao(IcaoNotam::parse(body)?, body));
+        }
+        
         let mut pairs = NotamParser::parse(Rule::notam, body)
-            .map_err(|e| AftnError::ParseError(format!("NOTAM parse error: {}", e)))?;
+            .map_err(|e| AftnError::from(SyntaxError::from_pest(&e, body, "NOTAM")))?;
         
         let notam_pair = pairs.next().ok_or_else(|| {
             AftnError::ParseError("Empty NOTAM parse result".to_string())
@@ -53,6 +62,22 @@ impl SubMessage for NotamMessage {
 }
 
 impl NotamMessage {
+    fn from_icao(notam: IcaoNotam, raw: &str) -> Self {
+        let notam_type = match notam.kind {
+            NotamKind::New => "NOTAMN",
+            NotamKind::Replace => "NOTAMR",
+            NotamKind::Cancel => "NOTAMC",
+        };
+        NotamMessage {
+            number: Some(notam.id.to_string()),
+            aerodrome: notam.locations.first().cloned(),
+            notam_type: Some(notam_type.to_string()),
+            content: notam.text.clone(),
+            icao: Some(notam),
+            raw: raw.to_string(),
+        }
+    }
+    
     fn parse_notam_pair(pair: pest::iterators::Pair<Rule>, raw: &str) -> Result<Self, AftnError> {
         let mut number = None;
         let mut aerodrome = None;
@@ -87,6 +112,7 @@ impl NotamMessage {
             aerodrome,
             notam_type,
             content,
+            icao: None,
             raw: raw.to_string(),
         })
     }
@@ -107,6 +133,18 @@ mod tests {
         assert!(!notam.raw.is_empty());
     }
 
+    #[test]
+    fn test_parse_icao_notam() {
+        let input = "(A1234/24 NOTAMN\nQ) LFFF/QMRLC/IV/NBO/A/000/999/4900N00233E005\nA) LFPG B) 2404150600 C) 2404201800\nE) RWY 09L/27R CLSD)";
+        let notam = NotamMessage::parse(input).unwrap();
+        
+        assert_eq!(notam.number, Some("A1234/24".to_string()));
+        assert_eq!(notam.aerodrome, Some("LFPG".to_string()));
+        assert_eq!(notam.notam_type, Some("NOTAMN".to_string()));
+        assert_eq!(notam.content, "RWY 09L/27R CLSD");
+        assert!(notam.icao.unwrap().matches_code("QMRLC"));
+    }
+
     #[test]
     fn test_parse_notam_with_number() {
         let input = "NOTAM A1234/24 LFPG";
@@ -3,7 +3,9 @@ use pest::Parser;
 use pest_derive::Parser;
 use crate::aftn::categories::MessageCategory;
 use crate::aftn::error::AftnError;
+use crate::diagnostic::SyntaxError;
 use crate::aftn::submessages::SubMessage;
+use super::weather_warning::WeatherWarning;
 
 #[derive(Parser)]
 #[grammar = "aftn/categories/meteorological/meteorological.pest"]
@@ -14,6 +16,9 @@ pub struct SigmetMessage {
     /// Contenu du SIGMET
     pub content: String,
     
+    /// En-tête, phénomène, zone et évolution décodés (Annexe 3 OACI)
+    pub warning: WeatherWarning,
+    
     /// Corps brut du message
     pub raw: String,
 }
@@ -21,7 +26,7 @@ pub struct SigmetMessage {
 impl SubMessage for SigmetMessage {
     fn parse(body: &str) -> Result<Self, AftnError> {
         let mut pairs = SigmetParser::parse(Rule::sigmet, body)
-            .map_err(|e| AftnError::ParseError(format!("SIGMET parse error: {}", e)))?;
+            .map_err(|e| AftnError::from(SyntaxError::from_pest(&e, body, "SIGMET")))?;
         
         let sigmet_pair = pairs.next().ok_or_else(|| {
             AftnError::ParseError("Empty SIGMET parse result".to_string())
@@ -59,8 +64,11 @@ impl SigmetMessage {
             content = raw.to_string();
         }
         
+        let warning = WeatherWarning::decode(raw, "SIGMET")?;
+        
         Ok(SigmetMessage {
             content,
+            warning,
             raw: raw.to_string(),
         })
     }
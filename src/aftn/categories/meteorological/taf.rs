@@ -3,12 +3,95 @@ use pest::Parser;
 use pest_derive::Parser;
 use crate::aftn::categories::MessageCategory;
 use crate::aftn::error::AftnError;
+use crate::diagnostic::SyntaxError;
 use crate::aftn::submessages::SubMessage;
+use crate::aftn::datetime;
+use crate::datetime::UtcDateTime;
+use super::weather_groups::*;
 
 #[derive(Parser)]
 #[grammar = "aftn/categories/meteorological/meteorological.pest"]
 struct TafParser;
 
+/// Type de groupe d'évolution d'un TAF
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TafChangeKind {
+    /// FMDDHHMM - changement rapide: remplace toutes les conditions
+    From,
+    
+    /// BECMG - évolution régulière pendant la période
+    Becoming,
+    
+    /// TEMPO - fluctuations temporaires
+    Temporary,
+    
+    /// PROB30 / PROB40, éventuellement suivi de TEMPO
+    Probability { percent: u8, temporary: bool },
+}
+
+/// Groupe d'évolution d'un TAF avec sa période et les conditions prévues
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TafChangeGroup {
+    pub kind: TafChangeKind,
+    
+    /// Début de la période
+    pub start: DayTime,
+    
+    /// Fin de la période (`None` pour FM: jusqu'au groupe FM suivant ou à la fin de validité)
+    pub end: Option<DayTime>,
+    
+    pub conditions: ForecastConditions,
+}
+
+/// Groupe d'évolution mal formé, conservé tel quel avec l'erreur de décodage
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TafMalformedGroup {
+    /// Texte du groupe, indicateur et conditions jusqu'au groupe suivant
+    pub raw: String,
+    
+    /// Erreur de décodage de l'indicateur ou de la période
+    pub error: AftnError,
+}
+
+/// Température extrême prévue (TXTFTF/YFYFGFGFZ, TNTFTF/YFYFGFGFZ)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TafTemperature {
+    /// `true` pour TX (maximale), `false` pour TN (minimale)
+    pub maximum: bool,
+    
+    /// Température en degrés Celsius
+    pub celsius: i8,
+    
+    /// Jour et heure prévus
+    pub at: DayTime,
+}
+
+impl TafTemperature {
+    pub fn parse(text: &str) -> Result<Self, AftnError> {
+        let invalid = || AftnError::InvalidFormat(format!("Invalid TAF temperature group: {}", text));
+        let maximum = match text.get(..2) {
+            Some("TX") => true,
+            Some("TN") => false,
+            _ => return Err(invalid()),
+        };
+        let (value, at) = text[2..].strip_suffix('Z')
+            .and_then(|t| t.split_once('/'))
+            .ok_or_else(invalid)?;
+        let celsius = parse_celsius(value).ok_or_else(invalid)?;
+        Ok(TafTemperature { maximum, celsius, at: DayTime::parse(at)? })
+    }
+}
+
+/// Prévision en vigueur à un instant donné
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ForecastInForce {
+    /// Conditions dominantes: prévision de base, dernier FM et évolutions BECMG achevées
+    pub prevailing: ForecastConditions,
+    
+    /// Variations possibles à cet instant: TEMPO, PROB et BECMG en cours
+    pub variations: Vec<TafChangeGroup>,
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TafMessage {
     /// Identifiant de l'aérodrome
@@ -20,9 +103,30 @@ pub struct TafMessage {
     /// Période de validité
     pub valid_period: Option<String>,
     
-    /// Conditions météorologiques
+    /// Texte de la prévision (après la période de validité)
     pub forecast: String,
     
+    /// Indicateurs de rapport (AMD, COR, CNL, NIL)
+    pub modifiers: Vec<String>,
+    
+    /// Prévision de base
+    pub base: ForecastConditions,
+    
+    /// Groupes d'évolution, dans l'ordre du message
+    pub change_groups: Vec<TafChangeGroup>,
+    
+    /// Groupes d'évolution qui n'ont pas pu être décodés (ignorés par `conditions_at`)
+    pub malformed_groups: Vec<TafMalformedGroup>,
+    
+    /// Températures maximale et minimale prévues
+    pub temperatures: Vec<TafTemperature>,
+    
+    /// Remarques (après RMK)
+    pub remarks: Option<String>,
+    
+    /// Groupes non reconnus, conservés tels quels
+    pub unrecognized: Vec<String>,
+    
     /// Corps brut du message
     pub raw: String,
 }
@@ -30,7 +134,7 @@ pub struct TafMessage {
 impl SubMessage for TafMessage {
     fn parse(body: &str) -> Result<Self, AftnError> {
         let mut pairs = TafParser::parse(Rule::taf, body)
-            .map_err(|e| AftnError::ParseError(format!("TAF parse error: {}", e)))?;
+            .map_err(|e| AftnError::from(SyntaxError::from_pest(&e, body, "TAF")))?;
         
         let taf_pair = pairs.next().ok_or_else(|| {
             AftnError::ParseError("Empty TAF parse result".to_string())
@@ -52,6 +156,73 @@ impl SubMessage for TafMessage {
 }
 
 impl TafMessage {
+    /// Résout l'heure d'émission (DDHHMMZ) en instant UTC par rapport à une date de référence
+    pub fn issue_datetime(&self, reference: &UtcDateTime) -> Result<Option<UtcDateTime>, AftnError> {
+        self.issue_time
+            .as_deref()
+            .map(|time| datetime::resolve_ddhhmm(time, reference))
+            .transpose()
+    }
+    
+    /// Résout la période de validité (DDHH/DDHH) en couple (début, fin) UTC
+    pub fn validity(&self, reference: &UtcDateTime) -> Result<Option<(UtcDateTime, UtcDateTime)>, AftnError> {
+        self.valid_period
+            .as_deref()
+            .map(|period| datetime::resolve_validity_period(period, reference))
+            .transpose()
+    }
+    
+    /// Prévision en vigueur à l'instant donné
+    /// 
+    /// L'instant sert aussi de référence pour situer le TAF dans le mois. Renvoie
+    /// `None` si l'instant est hors de la période de validité. Les groupes FM
+    /// remplacent toutes les conditions, les groupes BECMG modifient les éléments
+    /// annoncés à l'issue de leur période ; les groupes TEMPO/PROB (et les BECMG
+    /// en cours) couvrant l'instant sont renvoyés comme variations.
+    pub fn conditions_at(&self, instant: &UtcDateTime) -> Result<Option<ForecastInForce>, AftnError> {
+        let (start, end) = match self.validity(instant)? {
+            Some(validity) => validity,
+            None => return Ok(None),
+        };
+        if *instant < start || *instant >= end {
+            return Ok(None);
+        }
+        
+        let mut prevailing = self.base.clone();
+        let mut variations = Vec::new();
+        
+        for group in &self.change_groups {
+            let group_start = group.start.resolve(&start)?;
+            let group_end = match &group.end {
+                Some(group_end) => group_end.resolve(&start)?,
+                None => end,
+            };
+            
+            match group.kind {
+                TafChangeKind::From => {
+                    if *instant >= group_start {
+                        prevailing = group.conditions.clone();
+                        variations.clear();
+                    }
+                }
+                TafChangeKind::Becoming => {
+                    if *instant >= group_end {
+                        prevailing.apply_changes(&group.conditions);
+                    } else if *instant >= group_start {
+                        variations.push(group.clone());
+                    }
+                }
+                TafChangeKind::Temporary | TafChangeKind::Probability { .. } => {
+                    if *instant >= group_start && *instant < group_end {
+                        variations.push(group.clone());
+                    }
+                }
+            }
+        }
+        
+        Ok(Some(ForecastInForce { prevailing, variations }))
+    }
+    
     fn parse_taf_pair(pair: pest::iterators::Pair<Rule>, raw: &str) -> Result<Self, AftnError> {
         let mut station = None;
         let mut issue_time = None;
@@ -73,7 +244,7 @@ impl TafMessage {
                     if forecast.is_empty() {
                         forecast = inner_pair.as_str().trim().to_string();
                     } else {
-                        forecast.push_str(" ");
+                        forecast.push(' ');
                         forecast.push_str(inner_pair.as_str().trim());
                     }
                 }
@@ -85,13 +256,126 @@ impl TafMessage {
             forecast = raw.to_string();
         }
         
-        Ok(TafMessage {
+        let mut taf = TafMessage {
             station,
             issue_time,
             valid_period,
             forecast,
+            modifiers: Vec::new(),
+            base: ForecastConditions::default(),
+            change_groups: Vec::new(),
+            malformed_groups: Vec::new(),
+            temperatures: Vec::new(),
+            remarks: None,
+            unrecognized: Vec::new(),
             raw: raw.to_string(),
-        })
+        };
+        taf.decode_groups(raw);
+        Ok(taf)
+    }
+    
+    /// Décode la prévision de base et les groupes d'évolution (OMM FM 51)
+    /// 
+    /// Un groupe d'évolution mal formé n'empêche pas le décodage du reste du
+    /// message : il est conservé dans `malformed_groups` avec les conditions
+    /// qui le suivent.
+    fn decode_groups(&mut self, raw: &str) {
+        let tokens: Vec<&str> = raw.split_whitespace().collect();
+        
+        // En-tête: TAF, AMD/COR/CNL/NIL, indicateur d'emplacement, heure d'émission, validité
+        let mut i = 1;
+        while let Some(&token) = tokens.get(i) {
+            if matches!(token, "AMD" | "COR" | "CNL" | "NIL") {
+                self.modifiers.push(token.to_string());
+            } else if ![&self.station, &self.issue_time, &self.valid_period].iter().any(|v| v.as_deref() == Some(token)) {
+                break;
+            }
+            i += 1;
+        }
+        
+        while let Some(&token) = tokens.get(i) {
+            if token == "RMK" {
+                self.remarks = Some(tokens[i + 1..].join(" "));
+                break;
+            }
+            
+            match Self::change_group(&tokens[i..]) {
+                Ok(Some((group, consumed))) => {
+                    self.change_groups.push(group);
+                    i += consumed;
+                    continue;
+                }
+                Ok(None) => {}
+                Err(error) => {
+                    let end = tokens[i + 1..].iter()
+                        .position(|token| *token == "RMK" || Self::is_change_indicator(token))
+                        .map_or(tokens.len(), |position| i + 1 + position);
+                    self.malformed_groups.push(TafMalformedGroup {
+                        raw: tokens[i..end].join(" "),
+                        error,
+                    });
+                    i = end;
+                    continue;
+                }
+            }
+            
+            if let Ok(temperature) = TafTemperature::parse(token) {
+                self.temperatures.push(temperature);
+                i += 1;
+                continue;
+            }
+            
+            let conditions = match self.change_groups.last_mut() {
+                Some(group) => &mut group.conditions,
+                None => &mut self.base,
+            };
+            let consumed = conditions.decode_group(&tokens[i..]);
+            if consumed == 0 {
+                self.unrecognized.push(token.to_string());
+            }
+            i += consumed.max(1);
+        }
+    }
+    
+    /// Indicateur de début d'un groupe d'évolution (FMDDHHMM, BECMG, TEMPO, PROBnn)
+    fn is_change_indicator(token: &str) -> bool {
+        matches!(token, "BECMG" | "TEMPO")
+            || token.starts_with("PROB")
+            || (token.starts_with("FM") && token.len() == 8)
+    }
+    
+    /// Reconnaît un indicateur d'évolution et sa période, renvoie le groupe et le nombre de groupes consommés
+    fn change_group(tokens: &[&str]) -> Result<Option<(TafChangeGroup, usize)>, AftnError> {
+        let group = |kind, start, end| TafChangeGroup {
+            kind,
+            start,
+            end,
+            conditions: ForecastConditions::default(),
+        };
+        
+        let token = tokens[0];
+        if let Some(time) = token.strip_prefix("FM").filter(|t| t.len() == 6) {
+            return Ok(Some((group(TafChangeKind::From, DayTime::parse(time)?, None), 1)));
+        }
+        
+        let (kind, period_index) = match tokens {
+            ["BECMG", ..] => (TafChangeKind::Becoming, 1),
+            ["TEMPO", ..] => (TafChangeKind::Temporary, 1),
+            [prob, "TEMPO", ..] | [prob, ..] if prob.starts_with("PROB") => {
+                let percent = prob[4..].parse::<u8>().map_err(|_| {
+                    AftnError::InvalidFormat(format!("Invalid probability group: {}", prob))
+                })?;
+                let temporary = tokens.get(1) == Some(&"TEMPO");
+                (TafChangeKind::Probability { percent, temporary }, if temporary { 2 } else { 1 })
+            }
+            _ => return Ok(None),
+        };
+        
+        let period = tokens.get(period_index).ok_or_else(|| {
+            AftnError::InvalidFormat(format!("Missing period after {}", token))
+        })?;
+        let (start, end) = DayTime::parse_period(period)?;
+        Ok(Some((group(kind, start, Some(end)), period_index + 1)))
     }
 }
 
@@ -108,5 +392,103 @@ mod tests {
         let taf = result.unwrap();
         assert_eq!(taf.station, Some("LFPG".to_string()));
     }
+
+    const TAF: &str = "TAF LFPG 301100Z 3012/0118 24010KT 9999 SCT030 TX18/3014Z TN08/0105Z \
+                       BECMG 3014/3016 27015G25KT \
+                       TEMPO 3016/3020 4000 SHRA BKN012CB \
+                       FM302200 30008KT CAVOK \
+                       PROB30 TEMPO 0106/0109 0800 FG VV002";
+
+    #[test]
+    fn test_decode_taf_groups() {
+        let taf = TafMessage::parse(TAF).unwrap();
+        
+        assert_eq!(taf.base.wind.as_ref().unwrap().speed, 10);
+        assert_eq!(taf.base.visibility.as_ref().unwrap().prevailing, VisibilityDistance::Meters(9999));
+        assert_eq!(taf.base.clouds.len(), 1);
+        assert_eq!(taf.temperatures.len(), 2);
+        assert!(taf.temperatures[0].maximum);
+        assert_eq!(taf.temperatures[1].celsius, 8);
+        
+        let kinds: Vec<TafChangeKind> = taf.change_groups.iter().map(|g| g.kind).collect();
+        assert_eq!(kinds, vec![
+            TafChangeKind::Becoming,
+            TafChangeKind::Temporary,
+            TafChangeKind::From,
+            TafChangeKind::Probability { percent: 30, temporary: true },
+        ]);
+        assert_eq!(taf.change_groups[1].start, DayTime { day: 30, hour: 16, minute: 0 });
+        assert_eq!(taf.change_groups[1].conditions.weather[0].descriptor, Some("SH".to_string()));
+        assert_eq!(taf.change_groups[2].start, DayTime { day: 30, hour: 22, minute: 0 });
+        assert_eq!(taf.change_groups[2].end, None);
+        assert!(taf.change_groups[2].conditions.cavok);
+        assert_eq!(taf.change_groups[3].conditions.clouds, vec![CloudGroup::VerticalVisibility(Some(200))]);
+        assert!(taf.unrecognized.is_empty(), "{:?}", taf.unrecognized);
+    }
+
+    #[test]
+    fn test_taf_conditions_at() {
+        let taf = TafMessage::parse(TAF).unwrap();
+        let at = |day, hour, minute| {
+            let (month, day) = if day == 1 { (5, 1) } else { (4, day) };
+            UtcDateTime::new(2024, month, day, hour, minute).unwrap()
+        };
+        
+        // Prévision de base
+        let forecast = taf.conditions_at(&at(30, 13, 0)).unwrap().unwrap();
+        assert_eq!(forecast.prevailing.wind.as_ref().unwrap().speed, 10);
+        assert!(forecast.variations.is_empty());
+        
+        // BECMG en cours puis achevé, TEMPO en vigueur
+        let forecast = taf.conditions_at(&at(30, 15, 0)).unwrap().unwrap();
+        assert_eq!(forecast.prevailing.wind.as_ref().unwrap().speed, 10);
+        assert_eq!(forecast.variations[0].kind, TafChangeKind::Becoming);
+        
+        let forecast = taf.conditions_at(&at(30, 17, 0)).unwrap().unwrap();
+        assert_eq!(forecast.prevailing.wind.as_ref().unwrap().gust, Some(25));
+        assert_eq!(forecast.prevailing.clouds.len(), 1); // Les nuages de base restent en vigueur
+        assert_eq!(forecast.variations.len(), 1);
+        assert_eq!(forecast.variations[0].kind, TafChangeKind::Temporary);
+        
+        // FM remplace toutes les conditions, y compris au-delà du changement de mois
+        let forecast = taf.conditions_at(&at(1, 3, 0)).unwrap().unwrap();
+        assert!(forecast.prevailing.cavok);
+        assert_eq!(forecast.prevailing.wind.as_ref().unwrap().speed, 8);
+        assert!(forecast.variations.is_empty());
+        
+        let forecast = taf.conditions_at(&at(1, 7, 0)).unwrap().unwrap();
+        assert_eq!(forecast.variations[0].kind, TafChangeKind::Probability { percent: 30, temporary: true });
+        
+        // Hors validité
+        assert!(taf.conditions_at(&at(30, 11, 0)).unwrap().is_none());
+        assert!(taf.conditions_at(&at(1, 18, 0)).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_taf_invalid_change_group() {
+        let taf = TafMessage::parse(
+            "TAF LFPG 151100Z 1512/1618 24010KT 9999 SCT030 BECMG 24015KT TEMPO 1514/1518 3000 RA",
+        ).unwrap();
+        
+        assert_eq!(taf.malformed_groups.len(), 1);
+        assert_eq!(taf.malformed_groups[0].raw, "BECMG 24015KT");
+        assert!(matches!(taf.malformed_groups[0].error, AftnError::InvalidDateTime(_)));
+        // Les conditions du groupe mal formé ne modifient pas la prévision de base
+        assert_eq!(taf.base.wind.as_ref().unwrap().speed, 10);
+        assert_eq!(taf.change_groups.len(), 1);
+        assert_eq!(taf.change_groups[0].kind, TafChangeKind::Temporary);
+        assert!(taf.unrecognized.is_empty(), "{:?}", taf.unrecognized);
+    }
+
+    #[test]
+    fn test_taf_validity() {
+        let taf = TafMessage::parse("TAF LFPG 302300Z 3100/0106 28015KT 9999 FEW030").unwrap();
+        let reference = UtcDateTime::new(2024, 3, 30, 23, 10).unwrap();
+        
+        assert_eq!(taf.issue_datetime(&reference).unwrap(), Some(UtcDateTime::new(2024, 3, 30, 23, 0).unwrap()));
+        let (start, end) = taf.validity(&reference).unwrap().unwrap();
+        assert_eq!(start, UtcDateTime::new(2024, 3, 31, 0, 0).unwrap());
+        assert_eq!(end, UtcDateTime::new(2024, 4, 1, 6, 0).unwrap());
+    }
 }
 
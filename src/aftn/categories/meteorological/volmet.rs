@@ -3,6 +3,7 @@ use pest::Parser;
 use pest_derive::Parser;
 use crate::aftn::categories::MessageCategory;
 use crate::aftn::error::AftnError;
+use crate::diagnostic::SyntaxError;
 use crate::aftn::submessages::SubMessage;
 
 #[derive(Parser)]
@@ -21,7 +22,7 @@ pub struct VolmetMessage {
 impl SubMessage for VolmetMessage {
     fn parse(body: &str) -> Result<Self, AftnError> {
         let mut pairs = VolmetParser::parse(Rule::volmet, body)
-            .map_err(|e| AftnError::ParseError(format!("VOLMET parse error: {}", e)))?;
+            .map_err(|e| AftnError::from(SyntaxError::from_pest(&e, body, "VOLMET")))?;
         
         let volmet_pair = pairs.next().ok_or_else(|| {
             AftnError::ParseError("Empty VOLMET parse result".to_string())
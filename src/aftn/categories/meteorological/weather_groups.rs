@@ -0,0 +1,1003 @@
+//! Groupes des messages météorologiques selon l'OMM FM 15 (METAR/SPECI) et FM 51 (TAF)
+//!
+//! Chaque structure correspond à un groupe du code et fournit une fonction
+//! `parse` depuis le texte du groupe. `ForecastConditions` regroupe les
+//! éléments communs aux observations, tendances et prévisions (vent,
+//! visibilité, temps présent, nuages).
+
+use serde::{Deserialize, Serialize};
+use crate::aftn::error::AftnError;
+use crate::aftn::datetime;
+use crate::datetime::UtcDateTime;
+use crate::aftn::categories::flight_plan::ats_fields::Hhmm;
+
+/// Unité de vitesse du vent
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WindUnit {
+    /// KT - nœuds
+    Knots,
+
+    /// MPS - mètres par seconde
+    MetersPerSecond,
+}
+
+/// Groupe vent `dddff(Gfmfm)KT` et secteur de variation `dndndnVdxdxdx`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Wind {
+    /// Direction en degrés vrais (`None` si VRB)
+    pub direction: Option<u16>,
+
+    /// Vitesse moyenne
+    pub speed: u16,
+
+    /// Rafales
+    pub gust: Option<u16>,
+
+    pub unit: WindUnit,
+
+    /// Secteur de variation de la direction (de, à), en degrés
+    pub variable_sector: Option<(u16, u16)>,
+}
+
+impl Wind {
+    pub fn parse(text: &str) -> Result<Self, AftnError> {
+        let invalid = || AftnError::InvalidFormat(format!("Invalid wind group: {}", text));
+
+        let (body, unit) = if let Some(body) = text.strip_suffix("KT") {
+            (body, WindUnit::Knots)
+        } else if let Some(body) = text.strip_suffix("MPS") {
+            (body, WindUnit::MetersPerSecond)
+        } else {
+            return Err(invalid());
+        };
+
+        let direction_text = body.get(..3).ok_or_else(invalid)?;
+        let direction = match direction_text {
+            "VRB" => None,
+            d if is_digits(d) => {
+                let direction: u16 = d.parse().map_err(|_| invalid())?;
+                if direction > 360 {
+                    return Err(invalid());
+                }
+                Some(direction)
+            }
+            _ => return Err(invalid()),
+        };
+
+        let speeds = &body[3..];
+        let (speed_text, gust_text) = match speeds.split_once('G') {
+            Some((speed, gust)) => (speed, Some(gust)),
+            None => (speeds, None),
+        };
+        let speed = parse_speed(speed_text).ok_or_else(invalid)?;
+        let gust = match gust_text {
+            Some(g) => Some(parse_speed(g).ok_or_else(invalid)?),
+            None => None,
+        };
+
+        Ok(Wind { direction, speed, gust, unit, variable_sector: None })
+    }
+
+    /// Parse un secteur de variation `dndndnVdxdxdx` (ex: `280V350`)
+    pub fn parse_variable_sector(text: &str) -> Result<(u16, u16), AftnError> {
+        let invalid = || AftnError::InvalidFormat(format!("Invalid wind variation group: {}", text));
+        let (from, to) = text.split_once('V').ok_or_else(invalid)?;
+        if from.len() != 3 || to.len() != 3 || !is_digits(from) || !is_digits(to) {
+            return Err(invalid());
+        }
+        Ok((from.parse().map_err(|_| invalid())?, to.parse().map_err(|_| invalid())?))
+    }
+
+    /// Vent calme (`00000KT`)
+    pub fn is_calm(&self) -> bool {
+        self.direction == Some(0) && self.speed == 0
+    }
+}
+
+/// Distance de visibilité
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum VisibilityDistance {
+    /// Mètres (9999 = 10 km ou plus)
+    Meters(u16),
+
+    /// Milles terrestres (forme nord-américaine `SM`)
+    StatuteMiles(f32),
+}
+
+impl VisibilityDistance {
+    /// Distance en mètres
+    pub fn to_meters(&self) -> f32 {
+        match self {
+            VisibilityDistance::Meters(m) => *m as f32,
+            VisibilityDistance::StatuteMiles(sm) => sm * 1609.344,
+        }
+    }
+}
+
+/// Visibilité dominante et visibilité minimale (avec sa direction)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Visibility {
+    pub prevailing: VisibilityDistance,
+
+    /// Qualificatif de la forme `SM`: `M` (inférieure à) ou `P` (supérieure à)
+    pub qualifier: Option<char>,
+
+    /// Pas de variation directionnelle (NDV)
+    pub no_directional_variation: bool,
+
+    /// Visibilité minimale en mètres et sa direction (ex: `NE`)
+    pub minimum: Option<(u16, Option<String>)>,
+}
+
+impl Visibility {
+    pub fn parse(text: &str) -> Result<Self, AftnError> {
+        if let Some(miles) = text.strip_suffix("SM") {
+            let (qualifier, miles) = match miles.chars().next() {
+                Some(q @ ('M' | 'P')) => (Some(q), &miles[1..]),
+                _ => (None, miles),
+            };
+            let distance = parse_miles(miles).ok_or_else(|| {
+                AftnError::InvalidFormat(format!("Invalid visibility group: {}", text))
+            })?;
+            return Ok(Visibility {
+                prevailing: VisibilityDistance::StatuteMiles(distance),
+                qualifier,
+                no_directional_variation: false,
+                minimum: None,
+            });
+        }
+
+        if !text.is_ascii() {
+            return Err(AftnError::InvalidFormat(format!("Invalid visibility group: {}", text)));
+        }
+        let (meters, suffix) = text.split_at(text.len().min(4));
+        if meters.len() != 4 || !is_digits(meters) || !(suffix.is_empty() || suffix == "NDV") {
+            return Err(AftnError::InvalidFormat(format!("Invalid visibility group: {}", text)));
+        }
+        Ok(Visibility {
+            prevailing: VisibilityDistance::Meters(meters.parse().unwrap_or(0)),
+            qualifier: None,
+            no_directional_variation: suffix == "NDV",
+            minimum: None,
+        })
+    }
+
+    /// Parse un groupe de visibilité minimale `VVVVDv` (ex: `1500SW`)
+    pub fn parse_minimum(text: &str) -> Result<(u16, Option<String>), AftnError> {
+        if !text.is_ascii() {
+            return Err(AftnError::InvalidFormat(format!("Invalid minimum visibility group: {}", text)));
+        }
+        let (meters, direction) = text.split_at(text.len().min(4));
+        if meters.len() != 4 || !is_digits(meters) {
+            return Err(AftnError::InvalidFormat(format!("Invalid minimum visibility group: {}", text)));
+        }
+        let direction = match direction {
+            "" => None,
+            "N" | "NE" | "E" | "SE" | "S" | "SW" | "W" | "NW" => Some(direction.to_string()),
+            _ => return Err(AftnError::InvalidFormat(format!("Invalid minimum visibility group: {}", text))),
+        };
+        Ok((meters.parse().unwrap_or(0), direction))
+    }
+}
+
+/// Tendance de la portée visuelle de piste
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RvrTendency {
+    /// U - en augmentation
+    Upward,
+
+    /// D - en diminution
+    Downward,
+
+    /// N - sans changement
+    NoChange,
+}
+
+/// Groupe de portée visuelle de piste `RDRDR/VRVRVRVRi` (ex: `R27L/P1500U`)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RunwayVisualRange {
+    /// Désignateur de piste (ex: `27L`)
+    pub runway: String,
+
+    /// Portée (ou portée minimale si variable)
+    pub value: u16,
+
+    /// Portée maximale si la RVR est variable (`V`)
+    pub max_value: Option<u16>,
+
+    /// Qualificatif: `P` (supérieure à) ou `M` (inférieure à)
+    pub qualifier: Option<char>,
+
+    /// Valeurs exprimées en pieds (`FT`) plutôt qu'en mètres
+    pub in_feet: bool,
+
+    pub tendency: Option<RvrTendency>,
+}
+
+impl RunwayVisualRange {
+    pub fn parse(text: &str) -> Result<Self, AftnError> {
+        let invalid = || AftnError::InvalidFormat(format!("Invalid RVR group: {}", text));
+
+        let (runway, value) = text.strip_prefix('R')
+            .and_then(|t| t.split_once('/'))
+            .ok_or_else(invalid)?;
+        if !is_runway(runway) {
+            return Err(invalid());
+        }
+
+        let (value, tendency) = match value.chars().last() {
+            Some('U') => (&value[..value.len() - 1], Some(RvrTendency::Upward)),
+            Some('D') => (&value[..value.len() - 1], Some(RvrTendency::Downward)),
+            Some('N') => (&value[..value.len() - 1], Some(RvrTendency::NoChange)),
+            _ => (value, None),
+        };
+        let (value, in_feet) = match value.strip_suffix("FT") {
+            Some(v) => (v, true),
+            None => (value, false),
+        };
+        let (low, high) = match value.split_once('V') {
+            Some((low, high)) => (low, Some(high)),
+            None => (value, None),
+        };
+
+        let (qualifier, value) = parse_rvr_value(low).ok_or_else(invalid)?;
+        let max_value = match high {
+            Some(high) => Some(parse_rvr_value(high).ok_or_else(invalid)?.1),
+            None => None,
+        };
+
+        Ok(RunwayVisualRange {
+            runway: runway.to_string(),
+            value,
+            max_value,
+            qualifier,
+            in_feet,
+            tendency,
+        })
+    }
+}
+
+/// Intensité ou proximité d'un phénomène de temps présent
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WeatherIntensity {
+    /// `-` - faible
+    Light,
+
+    /// Modérée (pas de qualificatif)
+    Moderate,
+
+    /// `+` - forte
+    Heavy,
+
+    /// `VC` - au voisinage
+    InVicinity,
+}
+
+/// Descripteurs du temps présent (FM 15, table 4678)
+const WEATHER_DESCRIPTORS: &[&str] = &["MI", "BC", "PR", "DR", "BL", "SH", "TS", "FZ"];
+
+/// Phénomènes du temps présent (FM 15, table 4678)
+const WEATHER_PHENOMENA: &[&str] = &[
+    "DZ", "RA", "SN", "SG", "IC", "PL", "GR", "GS", "UP",
+    "BR", "FG", "FU", "VA", "DU", "SA", "HZ", "PY",
+    "PO", "SQ", "FC", "SS", "DS",
+];
+
+/// Groupe de temps présent `w'w'` (ex: `-SHRA`, `+TSRAGR`, `VCFG`)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PresentWeather {
+    pub intensity: WeatherIntensity,
+
+    /// Descripteur (ex: `SH`, `TS`, `FZ`)
+    pub descriptor: Option<String>,
+
+    /// Phénomènes (ex: `RA`, `SN`)
+    pub phenomena: Vec<String>,
+}
+
+impl PresentWeather {
+    pub fn parse(text: &str) -> Result<Self, AftnError> {
+        let (intensity, codes) = if let Some(codes) = text.strip_prefix('-') {
+            (WeatherIntensity::Light, codes)
+        } else if let Some(codes) = text.strip_prefix('+') {
+            (WeatherIntensity::Heavy, codes)
+        } else if let Some(codes) = text.strip_prefix("VC") {
+            (WeatherIntensity::InVicinity, codes)
+        } else {
+            (WeatherIntensity::Moderate, text)
+        };
+
+        let mut weather = Self::parse_codes(codes)
+            .ok_or_else(|| AftnError::InvalidFormat(format!("Invalid weather group: {}", text)))?;
+        weather.intensity = intensity;
+        Ok(weather)
+    }
+
+    /// Parse un groupe de temps récent `REw'w'` (ex: `RETSRA`)
+    pub fn parse_recent(text: &str) -> Result<Self, AftnError> {
+        text.strip_prefix("RE")
+            .and_then(Self::parse_codes)
+            .ok_or_else(|| AftnError::InvalidFormat(format!("Invalid recent weather group: {}", text)))
+    }
+
+    fn parse_codes(codes: &str) -> Option<Self> {
+        if codes.is_empty() || !codes.len().is_multiple_of(2) || !codes.is_ascii() {
+            return None;
+        }
+
+        let mut descriptor = None;
+        let mut phenomena = Vec::new();
+        for (i, code) in codes.as_bytes().chunks(2).enumerate() {
+            let code = std::str::from_utf8(code).ok()?;
+            if i == 0 && WEATHER_DESCRIPTORS.contains(&code) {
+                descriptor = Some(code.to_string());
+            } else if WEATHER_PHENOMENA.contains(&code) {
+                phenomena.push(code.to_string());
+            } else {
+                return None;
+            }
+        }
+
+        Some(PresentWeather {
+            intensity: WeatherIntensity::Moderate,
+            descriptor,
+            phenomena,
+        })
+    }
+}
+
+/// Nébulosité d'une couche nuageuse
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CloudAmount {
+    /// FEW - 1 à 2 octas
+    Few,
+
+    /// SCT - 3 à 4 octas
+    Scattered,
+
+    /// BKN - 5 à 7 octas
+    Broken,
+
+    /// OVC - 8 octas
+    Overcast,
+}
+
+/// Type de nuage convectif
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ConvectiveCloud {
+    /// CB - cumulonimbus
+    Cumulonimbus,
+
+    /// TCU - cumulus bourgeonnant
+    ToweringCumulus,
+}
+
+/// Couche nuageuse `NsNsNshshshs(CC)` (ex: `BKN030CB`)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CloudLayer {
+    pub amount: CloudAmount,
+
+    /// Hauteur de la base en pieds (`None` si `///`)
+    pub height_ft: Option<u32>,
+
+    pub convective: Option<ConvectiveCloud>,
+}
+
+/// Groupe nuages
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CloudGroup {
+    Layer(CloudLayer),
+
+    /// VVhshshs - visibilité verticale en pieds (`None` si `VV///`)
+    VerticalVisibility(Option<u32>),
+
+    /// NSC - pas de nuages significatifs
+    NoSignificantCloud,
+
+    /// SKC / CLR - ciel clair
+    SkyClear,
+
+    /// NCD - pas de nuages détectés (station automatique)
+    NoCloudDetected,
+}
+
+impl CloudGroup {
+    pub fn parse(text: &str) -> Result<Self, AftnError> {
+        let invalid = || AftnError::InvalidFormat(format!("Invalid cloud group: {}", text));
+
+        match text {
+            "NSC" => return Ok(CloudGroup::NoSignificantCloud),
+            "SKC" | "CLR" => return Ok(CloudGroup::SkyClear),
+            "NCD" => return Ok(CloudGroup::NoCloudDetected),
+            _ => {}
+        }
+
+        if let Some(height) = text.strip_prefix("VV") {
+            return Ok(CloudGroup::VerticalVisibility(parse_height(height).ok_or_else(invalid)?));
+        }
+
+        let amount = match text.get(..3) {
+            Some("FEW") => CloudAmount::Few,
+            Some("SCT") => CloudAmount::Scattered,
+            Some("BKN") => CloudAmount::Broken,
+            Some("OVC") => CloudAmount::Overcast,
+            _ => return Err(invalid()),
+        };
+        let height_ft = parse_height(text.get(3..6).ok_or_else(invalid)?).ok_or_else(invalid)?;
+        let convective = match &text[6..] {
+            "" | "///" => None,
+            "CB" => Some(ConvectiveCloud::Cumulonimbus),
+            "TCU" => Some(ConvectiveCloud::ToweringCumulus),
+            _ => return Err(invalid()),
+        };
+
+        Ok(CloudGroup::Layer(CloudLayer { amount, height_ft, convective }))
+    }
+
+    /// Plafond: hauteur de la plus basse couche BKN/OVC ou de la visibilité verticale
+    pub fn ceiling_ft(groups: &[CloudGroup]) -> Option<u32> {
+        groups.iter()
+            .filter_map(|group| match group {
+                CloudGroup::Layer(CloudLayer { amount: CloudAmount::Broken | CloudAmount::Overcast, height_ft, .. }) => *height_ft,
+                CloudGroup::VerticalVisibility(height) => *height,
+                _ => None,
+            })
+            .min()
+    }
+}
+
+/// Température de l'air et point de rosée `T'T'/T'dT'd` (ex: `M02/M05`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Temperature {
+    /// Température de l'air en degrés Celsius
+    pub air: i8,
+
+    /// Point de rosée en degrés Celsius (`None` si non disponible)
+    pub dew_point: Option<i8>,
+}
+
+impl Temperature {
+    pub fn parse(text: &str) -> Result<Self, AftnError> {
+        let invalid = || AftnError::InvalidFormat(format!("Invalid temperature group: {}", text));
+        let (air, dew_point) = text.split_once('/').ok_or_else(invalid)?;
+        let air = parse_celsius(air).ok_or_else(invalid)?;
+        let dew_point = match dew_point {
+            "" | "//" => None,
+            d => Some(parse_celsius(d).ok_or_else(invalid)?),
+        };
+        Ok(Temperature { air, dew_point })
+    }
+}
+
+/// Calage altimétrique QNH
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Pressure {
+    /// `QPHPHPHPH` - hectopascals
+    Hectopascals(u16),
+
+    /// `APHPHPHPH` - centièmes de pouce de mercure (ex: 2992)
+    InchesOfMercury(u16),
+}
+
+impl Pressure {
+    pub fn parse(text: &str) -> Result<Self, AftnError> {
+        let value = text.get(1..)
+            .filter(|v| v.len() == 4 && is_digits(v))
+            .and_then(|v| v.parse().ok());
+        match (text.chars().next(), value) {
+            (Some('Q'), Some(value)) => Ok(Pressure::Hectopascals(value)),
+            (Some('A'), Some(value)) => Ok(Pressure::InchesOfMercury(value)),
+            _ => Err(AftnError::InvalidFormat(format!("Invalid pressure group: {}", text))),
+        }
+    }
+
+    /// Valeur en hectopascals
+    pub fn to_hectopascals(&self) -> f32 {
+        match self {
+            Pressure::Hectopascals(hpa) => *hpa as f32,
+            Pressure::InchesOfMercury(hundredths) => *hundredths as f32 / 100.0 * 33.8639,
+        }
+    }
+}
+
+/// Cisaillement du vent (`WS R27L`, `WS ALL RWY`)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WindShear {
+    Runway(String),
+    AllRunways,
+}
+
+/// État de la piste `RDRDR/ERCReReRBRBR` (ex: `R27L/450293`, `R88/CLRD//`)
+///
+/// Les valeurs sont les chiffres de code de l'OMM (tables 0919, 0519, 1079, 0366),
+/// `None` lorsque le code est `/`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RunwayState {
+    /// Désignateur de piste (88 = toutes les pistes, 99 = répétition du dernier message)
+    pub runway: String,
+
+    /// Piste dégagée (CLRD)
+    pub cleared: bool,
+
+    /// Nature des dépôts (ER)
+    pub deposit: Option<u8>,
+
+    /// Étendue de la contamination (CR)
+    pub extent: Option<u8>,
+
+    /// Épaisseur du dépôt (eReR)
+    pub depth: Option<u8>,
+
+    /// Coefficient de frottement ou efficacité de freinage (BRBR)
+    pub friction: Option<u8>,
+}
+
+impl RunwayState {
+    pub fn parse(text: &str) -> Result<Self, AftnError> {
+        let invalid = || AftnError::InvalidFormat(format!("Invalid runway state group: {}", text));
+        if !text.is_ascii() {
+            return Err(invalid());
+        }
+
+        // Forme 8 chiffres de l'ancienne version du code (ex: 88290195)
+        let (runway, state) = match text.strip_prefix('R').and_then(|t| t.split_once('/')) {
+            Some((runway, state)) if is_runway(runway) => (runway, state),
+            Some(_) => return Err(invalid()),
+            None if text.len() == 8 && is_digits(&text[..2]) => text.split_at(2),
+            None => return Err(invalid()),
+        };
+
+        if let Some(friction) = state.strip_prefix("CLRD") {
+            if friction.len() != 2 {
+                return Err(invalid());
+            }
+            return Ok(RunwayState {
+                runway: runway.to_string(),
+                cleared: true,
+                deposit: None,
+                extent: None,
+                depth: None,
+                friction: parse_code(friction).ok_or_else(invalid)?,
+            });
+        }
+
+        if state.len() != 6 {
+            return Err(invalid());
+        }
+        Ok(RunwayState {
+            runway: runway.to_string(),
+            cleared: false,
+            deposit: parse_code(&state[0..1]).ok_or_else(invalid)?,
+            extent: parse_code(&state[1..2]).ok_or_else(invalid)?,
+            depth: parse_code(&state[2..4]).ok_or_else(invalid)?,
+            friction: parse_code(&state[4..6]).ok_or_else(invalid)?,
+        })
+    }
+}
+
+/// Conditions communes aux observations, tendances et prévisions
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ForecastConditions {
+    pub wind: Option<Wind>,
+
+    pub visibility: Option<Visibility>,
+
+    /// CAVOK - visibilité, nuages et temps présent OK
+    pub cavok: bool,
+
+    pub weather: Vec<PresentWeather>,
+
+    /// NSW - fin du temps significatif
+    pub no_significant_weather: bool,
+
+    pub clouds: Vec<CloudGroup>,
+}
+
+impl ForecastConditions {
+    /// Décode le groupe en tête de `tokens` s'il appartient aux conditions
+    ///
+    /// Renvoie le nombre de groupes consommés (0 si le groupe n'est pas reconnu).
+    pub fn decode_group(&mut self, tokens: &[&str]) -> usize {
+        let Some(&token) = tokens.first() else {
+            return 0;
+        };
+
+        match token {
+            "CAVOK" => {
+                self.cavok = true;
+                return 1;
+            }
+            "NSW" => {
+                self.no_significant_weather = true;
+                return 1;
+            }
+            _ => {}
+        }
+
+        if self.wind.is_none() {
+            if let Ok(wind) = Wind::parse(token) {
+                self.wind = Some(wind);
+                return 1;
+            }
+        }
+        if let Some(wind) = self.wind.as_mut().filter(|w| w.variable_sector.is_none()) {
+            if let Ok(sector) = Wind::parse_variable_sector(token) {
+                wind.variable_sector = Some(sector);
+                return 1;
+            }
+        }
+
+        match self.visibility.as_mut() {
+            None => {
+                // Forme `1 1/2SM`: partie entière suivie d'une fraction
+                if token.len() == 1 && is_digits(token) {
+                    if let Some(fraction) = tokens.get(1).filter(|t| t.contains('/') && t.ends_with("SM")) {
+                        if let Ok(visibility) = Visibility::parse(&format!("{}{}", token, fraction)) {
+                            self.visibility = Some(visibility);
+                            return 2;
+                        }
+                    }
+                }
+                if let Ok(visibility) = Visibility::parse(token) {
+                    self.visibility = Some(visibility);
+                    return 1;
+                }
+            }
+            Some(visibility) if visibility.minimum.is_none() => {
+                if let Ok(minimum) = Visibility::parse_minimum(token) {
+                    visibility.minimum = Some(minimum);
+                    return 1;
+                }
+            }
+            Some(_) => {}
+        }
+
+        if let Ok(weather) = PresentWeather::parse(token) {
+            self.weather.push(weather);
+            return 1;
+        }
+        if let Ok(cloud) = CloudGroup::parse(token) {
+            self.clouds.push(cloud);
+            return 1;
+        }
+        0
+    }
+
+    /// Indique si aucun élément n'a été décodé
+    pub fn is_empty(&self) -> bool {
+        *self == ForecastConditions::default()
+    }
+
+    /// Applique les éléments d'une évolution (BECMG): seuls les éléments
+    /// annoncés remplacent les éléments en vigueur
+    pub fn apply_changes(&mut self, changes: &ForecastConditions) {
+        if changes.wind.is_some() {
+            self.wind = changes.wind.clone();
+        }
+        if changes.cavok {
+            self.cavok = true;
+            self.visibility = None;
+            self.weather.clear();
+            self.clouds.clear();
+        } else if changes.visibility.is_some() {
+            self.cavok = false;
+            self.visibility = changes.visibility.clone();
+        }
+        if changes.no_significant_weather {
+            self.weather.clear();
+            self.no_significant_weather = true;
+        } else if !changes.weather.is_empty() {
+            self.weather = changes.weather.clone();
+            self.no_significant_weather = false;
+        }
+        if !changes.clouds.is_empty() {
+            self.cavok = false;
+            self.clouds = changes.clouds.clone();
+        }
+    }
+}
+
+/// Groupe jour-heure(-minute) sans mois ni année (`DDHH` ou `DDHHMM`)
+///
+/// L'heure 24 est admise pour désigner la fin d'une journée (ex: `1524`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DayTime {
+    pub day: u8,
+    pub hour: u8,
+    pub minute: u8,
+}
+
+impl DayTime {
+    pub fn parse(text: &str) -> Result<Self, AftnError> {
+        let invalid = || AftnError::InvalidDateTime(format!("Invalid day-time group (DDHH or DDHHMM): {}", text));
+        if !(text.len() == 4 || text.len() == 6) || !is_digits(text) {
+            return Err(invalid());
+        }
+        let value = |range: std::ops::Range<usize>| text[range].parse::<u8>().unwrap_or(0);
+        let time = DayTime {
+            day: value(0..2),
+            hour: value(2..4),
+            minute: if text.len() == 6 { value(4..6) } else { 0 },
+        };
+        if !(1..=31).contains(&time.day) || time.hour > 24 || time.minute > 59 || (time.hour == 24 && time.minute != 0) {
+            return Err(invalid());
+        }
+        Ok(time)
+    }
+
+    /// Parse une période `DDHH/DDHH`
+    pub fn parse_period(text: &str) -> Result<(Self, Self), AftnError> {
+        let (start, end) = text.split_once('/').ok_or_else(|| {
+            AftnError::InvalidDateTime(format!("Period must be DDHH/DDHH, got: {}", text))
+        })?;
+        Ok((Self::parse(start)?, Self::parse(end)?))
+    }
+
+    /// Résout le groupe en instant UTC par rapport à une date de référence proche
+    pub fn resolve(&self, reference: &UtcDateTime) -> Result<UtcDateTime, AftnError> {
+        if self.hour == 24 {
+            Ok(datetime::resolve_day_time(self.day, 0, 0, reference)?.add_minutes(24 * 60))
+        } else {
+            datetime::resolve_day_time(self.day, self.hour, self.minute, reference)
+        }
+    }
+}
+
+/// Type de prévision de tendance d'un METAR/SPECI
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TrendKind {
+    /// NOSIG - pas de changement significatif
+    NoSignificantChange,
+
+    /// BECMG - évolution
+    Becoming,
+
+    /// TEMPO - fluctuations temporaires
+    Temporary,
+}
+
+/// Prévision de tendance (`NOSIG`, `BECMG FM1100 ...`, `TEMPO TL1200 ...`)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Trend {
+    pub kind: TrendKind,
+
+    /// Début du changement (FM)
+    pub from: Option<Hhmm>,
+
+    /// Fin du changement (TL)
+    pub until: Option<Hhmm>,
+
+    /// Heure du changement (AT)
+    pub at: Option<Hhmm>,
+
+    /// Conditions prévues
+    pub conditions: ForecastConditions,
+}
+
+impl Trend {
+    /// Reconnaît l'indicateur de tendance (`NOSIG`, `BECMG`, `TEMPO`)
+    pub fn from_indicator(text: &str) -> Option<Self> {
+        let kind = match text {
+            "NOSIG" => TrendKind::NoSignificantChange,
+            "BECMG" => TrendKind::Becoming,
+            "TEMPO" => TrendKind::Temporary,
+            _ => return None,
+        };
+        Some(Trend {
+            kind,
+            from: None,
+            until: None,
+            at: None,
+            conditions: ForecastConditions::default(),
+        })
+    }
+
+    /// Décode le groupe en tête de `tokens` (indicateur horaire ou condition prévue)
+    pub fn decode_group(&mut self, tokens: &[&str]) -> usize {
+        let Some(&token) = tokens.first() else {
+            return 0;
+        };
+        let time = token.get(2..).filter(|t| t.len() == 4).and_then(|t| Hhmm::parse_time(t).ok());
+        match (token.get(..2), time) {
+            (Some("FM"), Some(time)) => self.from = Some(time),
+            (Some("TL"), Some(time)) => self.until = Some(time),
+            (Some("AT"), Some(time)) => self.at = Some(time),
+            _ => return self.conditions.decode_group(tokens),
+        }
+        1
+    }
+}
+
+fn is_digits(text: &str) -> bool {
+    !text.is_empty() && text.chars().all(|c| c.is_ascii_digit())
+}
+
+fn is_runway(text: &str) -> bool {
+    let digits = text.trim_end_matches(['L', 'C', 'R']);
+    digits.len() == 2 && is_digits(digits) && text.len() <= 3
+}
+
+/// Vitesse sur 2 ou 3 chiffres, avec `P` optionnel (supérieure à)
+fn parse_speed(text: &str) -> Option<u16> {
+    let text = text.strip_prefix('P').unwrap_or(text);
+    if (2..=3).contains(&text.len()) && is_digits(text) {
+        text.parse().ok()
+    } else {
+        None
+    }
+}
+
+/// Distance en milles: entier, fraction (`1/2`) ou nombre mixte (`11/2` = 1 1/2)
+fn parse_miles(text: &str) -> Option<f32> {
+    match text.split_once('/') {
+        None if is_digits(text) => text.parse().ok(),
+        None => None,
+        Some((numerator, denominator)) => {
+            if !is_digits(numerator) || !is_digits(denominator) {
+                return None;
+            }
+            let denominator: f32 = denominator.parse().ok()?;
+            let (whole, numerator) = if numerator.len() > 1 {
+                numerator.split_at(numerator.len() - 1)
+            } else {
+                ("0", numerator)
+            };
+            let whole: f32 = whole.parse().ok()?;
+            let numerator: f32 = numerator.parse().ok()?;
+            (denominator > 0.0).then(|| whole + numerator / denominator)
+        }
+    }
+}
+
+fn parse_rvr_value(text: &str) -> Option<(Option<char>, u16)> {
+    let (qualifier, value) = match text.chars().next() {
+        Some(q @ ('P' | 'M')) => (Some(q), &text[1..]),
+        _ => (None, text),
+    };
+    if value.len() == 4 && is_digits(value) {
+        Some((qualifier, value.parse().ok()?))
+    } else {
+        None
+    }
+}
+
+/// Hauteur en centaines de pieds (`030` = 3000 ft), `///` si non observée
+fn parse_height(text: &str) -> Option<Option<u32>> {
+    match text {
+        "///" => Some(None),
+        h if h.len() == 3 && is_digits(h) => Some(Some(h.parse::<u32>().ok()? * 100)),
+        _ => None,
+    }
+}
+
+/// Température en degrés Celsius, `M` pour les valeurs négatives
+pub(crate) fn parse_celsius(text: &str) -> Option<i8> {
+    let (sign, digits) = match text.strip_prefix('M') {
+        Some(digits) => (-1, digits),
+        None => (1, text),
+    };
+    if digits.len() == 2 && is_digits(digits) {
+        Some(sign * digits.parse::<i8>().ok()?)
+    } else {
+        None
+    }
+}
+
+/// Chiffre de code, `/` pour une valeur non disponible
+fn parse_code(text: &str) -> Option<Option<u8>> {
+    if text.chars().all(|c| c == '/') {
+        Some(None)
+    } else if is_digits(text) {
+        Some(Some(text.parse().ok()?))
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_wind() {
+        let wind = Wind::parse("28015G25KT").unwrap();
+        assert_eq!(wind.direction, Some(280));
+        assert_eq!(wind.speed, 15);
+        assert_eq!(wind.gust, Some(25));
+        assert_eq!(wind.unit, WindUnit::Knots);
+
+        let wind = Wind::parse("VRB02MPS").unwrap();
+        assert_eq!(wind.direction, None);
+        assert_eq!(wind.unit, WindUnit::MetersPerSecond);
+
+        assert!(Wind::parse("00000KT").unwrap().is_calm());
+        assert_eq!(Wind::parse_variable_sector("280V350").unwrap(), (280, 350));
+        assert!(Wind::parse("28015").is_err());
+        assert!(Wind::parse("37015KT").is_err());
+    }
+
+    #[test]
+    fn test_parse_visibility() {
+        assert_eq!(Visibility::parse("0800").unwrap().prevailing, VisibilityDistance::Meters(800));
+        assert!(Visibility::parse("9999NDV").unwrap().no_directional_variation);
+        assert_eq!(Visibility::parse_minimum("1500SW").unwrap(), (1500, Some("SW".to_string())));
+
+        let visibility = Visibility::parse("M1/4SM").unwrap();
+        assert_eq!(visibility.prevailing, VisibilityDistance::StatuteMiles(0.25));
+        assert_eq!(visibility.qualifier, Some('M'));
+        assert_eq!(Visibility::parse("11/2SM").unwrap().prevailing, VisibilityDistance::StatuteMiles(1.5));
+        assert_eq!(Visibility::parse("10SM").unwrap().prevailing, VisibilityDistance::StatuteMiles(10.0));
+        assert!(Visibility::parse("080").is_err());
+    }
+
+    #[test]
+    fn test_parse_rvr() {
+        let rvr = RunwayVisualRange::parse("R27L/P1500U").unwrap();
+        assert_eq!(rvr.runway, "27L");
+        assert_eq!(rvr.value, 1500);
+        assert_eq!(rvr.qualifier, Some('P'));
+        assert_eq!(rvr.tendency, Some(RvrTendency::Upward));
+
+        let rvr = RunwayVisualRange::parse("R09/0600V1000FT").unwrap();
+        assert_eq!(rvr.max_value, Some(1000));
+        assert!(rvr.in_feet);
+        assert!(RunwayVisualRange::parse("R27L/450293").is_err());
+    }
+
+    #[test]
+    fn test_parse_weather() {
+        let weather = PresentWeather::parse("+TSRAGR").unwrap();
+        assert_eq!(weather.intensity, WeatherIntensity::Heavy);
+        assert_eq!(weather.descriptor, Some("TS".to_string()));
+        assert_eq!(weather.phenomena, vec!["RA", "GR"]);
+
+        assert_eq!(PresentWeather::parse("VCSH").unwrap().intensity, WeatherIntensity::InVicinity);
+        assert_eq!(PresentWeather::parse("-DZ").unwrap().intensity, WeatherIntensity::Light);
+        assert_eq!(PresentWeather::parse_recent("RETSRA").unwrap().phenomena, vec!["RA"]);
+        assert!(PresentWeather::parse("LFPG").is_err());
+        assert!(PresentWeather::parse("FEW030").is_err());
+    }
+
+    #[test]
+    fn test_parse_clouds() {
+        assert_eq!(
+            CloudGroup::parse("BKN030CB").unwrap(),
+            CloudGroup::Layer(CloudLayer {
+                amount: CloudAmount::Broken,
+                height_ft: Some(3000),
+                convective: Some(ConvectiveCloud::Cumulonimbus),
+            })
+        );
+        assert_eq!(CloudGroup::parse("VV002").unwrap(), CloudGroup::VerticalVisibility(Some(200)));
+        assert_eq!(CloudGroup::parse("NSC").unwrap(), CloudGroup::NoSignificantCloud);
+
+        let clouds = vec![
+            CloudGroup::parse("FEW008").unwrap(),
+            CloudGroup::parse("OVC025").unwrap(),
+            CloudGroup::parse("BKN015TCU").unwrap(),
+        ];
+        assert_eq!(CloudGroup::ceiling_ft(&clouds), Some(1500));
+    }
+
+    #[test]
+    fn test_parse_temperature_and_pressure() {
+        assert_eq!(Temperature::parse("M02/M05").unwrap(), Temperature { air: -2, dew_point: Some(-5) });
+        assert_eq!(Temperature::parse("12///").unwrap().dew_point, None);
+        assert_eq!(Pressure::parse("Q1013").unwrap(), Pressure::Hectopascals(1013));
+        assert_eq!(Pressure::parse("A2992").unwrap(), Pressure::InchesOfMercury(2992));
+        assert!((Pressure::InchesOfMercury(2992).to_hectopascals() - 1013.2).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_parse_runway_state() {
+        let state = RunwayState::parse("R27L/450293").unwrap();
+        assert_eq!(state.runway, "27L");
+        assert_eq!(state.deposit, Some(4));
+        assert_eq!(state.extent, Some(5));
+        assert_eq!(state.depth, Some(2));
+        assert_eq!(state.friction, Some(93));
+
+        assert!(RunwayState::parse("R88/CLRD//").unwrap().cleared);
+        assert_eq!(RunwayState::parse("88290195").unwrap().runway, "88");
+    }
+}
@@ -0,0 +1,1153 @@
+//! Décodage des SIGMET et AIRMET selon l'Annexe 3 de l'OACI
+//!
+//! Exemple:
+//! ```text
+//! YUCC SIGMET 5 VALID 221215/221600 YUDO-
+//! YUCC AMSWELL FIR SEV TURB OBS AT 1210Z
+//! WI N2020 W07005 - N2020 W06950 - N1950 W06950 - N1950 W07005 - N2020 W07005
+//! FL250/370 MOV E 20KT WKN=
+//! ```
+//!
+//! Les SIGMET et AIRMET partagent la même structure: en-tête (unité émettrice,
+//! numéro, période de validité, centre de veille météorologique), FIR,
+//! phénomène, zone géographique, étendue verticale, déplacement et évolution.
+
+use serde::{Deserialize, Serialize};
+use crate::aftn::error::AftnError;
+use crate::datetime::UtcDateTime;
+use crate::aftn::categories::MessageCategory;
+use crate::aftn::categories::flight_plan::ats_fields::Hhmm;
+use super::icao_notam::Coordinate;
+use super::weather_groups::{DayTime, WindUnit};
+
+/// Nombre de kilomètres dans un mille nautique
+const KM_PER_NM: f64 = 1.852;
+
+/// Catégorie d'un message commençant par un en-tête OACI `CCCC SIGMET` / `CCCC AIRMET`
+pub fn warning_category(body: &str) -> Option<MessageCategory> {
+    let mut tokens = body.split_whitespace();
+    let unit = tokens.next()?;
+    if unit.len() != 4 || !unit.chars().all(|c| c.is_ascii_uppercase()) {
+        return None;
+    }
+    match tokens.next()? {
+        "SIGMET" => Some(MessageCategory::Sigmet),
+        "AIRMET" => Some(MessageCategory::Airmet),
+        _ => None,
+    }
+}
+
+/// Phénomène météorologique signalé
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum PhenomenonKind {
+    /// TS - orages
+    Thunderstorm,
+
+    /// TSGR - orages avec grêle
+    ThunderstormWithHail,
+
+    /// TC - cyclone tropical
+    TropicalCyclone,
+
+    /// TURB - turbulence
+    Turbulence,
+
+    /// ICE - givrage (`freezing_rain` si `(FZRA)`)
+    Icing { freezing_rain: bool },
+
+    /// MTW - ondes orographiques
+    MountainWave,
+
+    /// DS - tempête de poussière
+    DustStorm,
+
+    /// SS - tempête de sable
+    Sandstorm,
+
+    /// VA - cendres volcaniques
+    VolcanicAsh,
+
+    /// RDOACT CLD - nuage radioactif
+    RadioactiveCloud,
+
+    /// SFC WSPD - vent de surface fort (AIRMET)
+    SurfaceWind { speed: u16, unit: WindUnit },
+
+    /// SFC VIS - visibilité de surface réduite (AIRMET)
+    SurfaceVisibility { metres: u32, cause: Option<String> },
+
+    /// MT OBSC - montagnes masquées (AIRMET)
+    MountainObscuration,
+
+    /// CLD - nuages bas (AIRMET), avec l'étendue verticale telle que transmise
+    Cloud { extent: Option<String> },
+
+    /// CB - cumulonimbus (AIRMET)
+    Cumulonimbus,
+
+    /// TCU - cumulus bourgeonnants (AIRMET)
+    ToweringCumulus,
+}
+
+/// Phénomène avec son qualificatif (`OBSC TS`, `SEV ICE`, `TC GLORIA`...)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Phenomenon {
+    /// Qualificatif: OBSC, EMBD, FRQ, SQL, SEV, HVY, ISOL, OCNL, MOD
+    pub qualifier: Option<String>,
+
+    pub kind: PhenomenonKind,
+
+    /// Nom du cyclone tropical ou du volcan
+    pub name: Option<String>,
+}
+
+impl Phenomenon {
+    /// Décode le phénomène en tête de `tokens`, renvoie le nombre de groupes consommés
+    fn decode(tokens: &[&str]) -> Option<(Self, usize)> {
+        let (qualifier, offset) = match tokens.first() {
+            Some(&q @ ("OBSC" | "EMBD" | "FRQ" | "SQL" | "SEV" | "HVY" | "ISOL" | "OCNL" | "MOD")) => {
+                (Some(q.to_string()), 1)
+            }
+            _ => (None, 0),
+        };
+        let rest = tokens.get(offset..)?;
+        let simple = |kind| (kind, None, 1);
+
+        let (kind, name, consumed) = match rest {
+            ["TS", ..] => simple(PhenomenonKind::Thunderstorm),
+            ["TSGR", ..] => simple(PhenomenonKind::ThunderstormWithHail),
+            ["TURB", ..] => simple(PhenomenonKind::Turbulence),
+            ["ICE", "(FZRA)", ..] => (PhenomenonKind::Icing { freezing_rain: true }, None, 2),
+            ["ICE", ..] => simple(PhenomenonKind::Icing { freezing_rain: false }),
+            ["MTW", ..] => simple(PhenomenonKind::MountainWave),
+            ["DS", ..] => simple(PhenomenonKind::DustStorm),
+            ["SS", ..] => simple(PhenomenonKind::Sandstorm),
+            ["CB", ..] => simple(PhenomenonKind::Cumulonimbus),
+            ["TCU", ..] => simple(PhenomenonKind::ToweringCumulus),
+            ["RDOACT", "CLD", ..] => (PhenomenonKind::RadioactiveCloud, None, 2),
+            ["MT", "OBSC", ..] => (PhenomenonKind::MountainObscuration, None, 2),
+            ["CLD", extent, ..] if extent.contains('/') => {
+                (PhenomenonKind::Cloud { extent: Some(extent.to_string()) }, None, 2)
+            }
+            ["CLD", ..] => simple(PhenomenonKind::Cloud { extent: None }),
+            ["TC", name, ..] if !is_keyword(name) => {
+                (PhenomenonKind::TropicalCyclone, Some(name.to_string()), 2)
+            }
+            ["TC", ..] => simple(PhenomenonKind::TropicalCyclone),
+            ["VA", "ERUPTION", rest @ ..] => {
+                // VA ERUPTION [MT nom] [PSN ...] VA CLD
+                let mut consumed = 2;
+                let mut name = None;
+                if let ["MT", words @ ..] = rest {
+                    let words: Vec<&str> = words.iter().copied().take_while(|w| !is_keyword(w)).collect();
+                    consumed += 1 + words.len();
+                    if !words.is_empty() {
+                        name = Some(words.join(" "));
+                    }
+                }
+                (PhenomenonKind::VolcanicAsh, name, consumed)
+            }
+            ["VA", "CLD", ..] => (PhenomenonKind::VolcanicAsh, None, 2),
+            ["SFC", "WSPD", speed, ..] => {
+                let (value, unit) = if let Some(value) = speed.strip_suffix("KT") {
+                    (value, WindUnit::Knots)
+                } else {
+                    (speed.strip_suffix("MPS")?, WindUnit::MetersPerSecond)
+                };
+                (PhenomenonKind::SurfaceWind { speed: value.parse().ok()?, unit }, None, 3)
+            }
+            ["SFC", "VIS", visibility, rest @ ..] => {
+                let metres = visibility.strip_suffix('M')?.parse().ok()?;
+                let cause = rest
+                    .first()
+                    .and_then(|c| c.strip_prefix('(')?.strip_suffix(')'))
+                    .map(str::to_string);
+                let consumed = if cause.is_some() { 4 } else { 3 };
+                (PhenomenonKind::SurfaceVisibility { metres, cause }, None, consumed)
+            }
+            _ => return None,
+        };
+
+        Some((Phenomenon { qualifier, kind, name }, offset + consumed))
+    }
+}
+
+/// Phénomène observé ou prévu
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ObservationKind {
+    /// OBS - observé
+    Observed,
+
+    /// FCST - prévu
+    Forecast,
+}
+
+/// Indication `OBS [AT HHMMZ]` / `FCST [AT HHMMZ]`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Observation {
+    pub kind: ObservationKind,
+
+    /// Heure d'observation ou de prévision
+    pub at: Option<Hhmm>,
+}
+
+/// Unité de distance
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DistanceUnit {
+    /// KM - kilomètres
+    Kilometres,
+
+    /// NM - milles nautiques
+    NauticalMiles,
+}
+
+/// Distance (`30KM`, `50NM`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Distance {
+    pub value: u16,
+    pub unit: DistanceUnit,
+}
+
+impl Distance {
+    pub fn parse(text: &str) -> Result<Self, AftnError> {
+        let invalid = || AftnError::InvalidFormat(format!("Distance must be nnnKM or nnnNM, got: {}", text));
+        let (value, unit) = if let Some(value) = text.strip_suffix("KM") {
+            (value, DistanceUnit::Kilometres)
+        } else if let Some(value) = text.strip_suffix("NM") {
+            (value, DistanceUnit::NauticalMiles)
+        } else {
+            return Err(invalid());
+        };
+        Ok(Distance { value: value.parse().map_err(|_| invalid())?, unit })
+    }
+
+    /// Distance en milles nautiques
+    pub fn to_nm(&self) -> f64 {
+        match self.unit {
+            DistanceUnit::Kilometres => f64::from(self.value) / KM_PER_NM,
+            DistanceUnit::NauticalMiles => f64::from(self.value),
+        }
+    }
+}
+
+/// Côté d'une limite (`N OF`, `NE OF`...)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Side {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Side {
+    fn parse(text: &str) -> Option<Self> {
+        Some(match text {
+            "N" => Side::North,
+            "NE" => Side::NorthEast,
+            "E" => Side::East,
+            "SE" => Side::SouthEast,
+            "S" => Side::South,
+            "SW" => Side::SouthWest,
+            "W" => Side::West,
+            "NW" => Side::NorthWest,
+            _ => return None,
+        })
+    }
+
+    /// Vecteur (latitude, longitude) pointant vers ce côté
+    fn vector(&self) -> (f64, f64) {
+        match self {
+            Side::North => (1.0, 0.0),
+            Side::NorthEast => (1.0, 1.0),
+            Side::East => (0.0, 1.0),
+            Side::SouthEast => (-1.0, 1.0),
+            Side::South => (-1.0, 0.0),
+            Side::SouthWest => (-1.0, -1.0),
+            Side::West => (0.0, -1.0),
+            Side::NorthWest => (1.0, -1.0),
+        }
+    }
+}
+
+/// Limite d'une relation géographique
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Boundary {
+    /// Parallèle (`N4530`)
+    Latitude(f64),
+
+    /// Méridien (`E00200`)
+    Longitude(f64),
+
+    /// Ligne brisée (`LINE N4530 E00200 - N4600 E00500`)
+    Line(Vec<Coordinate>),
+}
+
+/// Relation `<côté> OF <limite>` (ex: `N OF N4530`)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AreaRelation {
+    pub side: Side,
+    pub boundary: Boundary,
+}
+
+impl AreaRelation {
+    /// Indique si le point est du côté indiqué de la limite
+    ///
+    /// `None` si la relation est incohérente (ex: `E OF N4530`).
+    pub fn contains(&self, point: &Coordinate) -> Option<bool> {
+        match &self.boundary {
+            Boundary::Latitude(latitude) => match self.side {
+                Side::North => Some(point.latitude >= *latitude),
+                Side::South => Some(point.latitude <= *latitude),
+                _ => None,
+            },
+            Boundary::Longitude(longitude) => match self.side {
+                Side::East => Some(point.longitude >= *longitude),
+                Side::West => Some(point.longitude <= *longitude),
+                _ => None,
+            },
+            Boundary::Line(line) => {
+                // Le côté est évalué par rapport au segment le plus proche du point,
+                // prolongé jusqu'aux limites de la FIR
+                let (a, b) = line
+                    .windows(2)
+                    .map(|segment| (segment[0], segment[1]))
+                    .min_by(|x, y| {
+                        segment_distance_nm(point, &x.0, &x.1)
+                            .total_cmp(&segment_distance_nm(point, &y.0, &y.1))
+                    })?;
+                let direction = (b.latitude - a.latitude, b.longitude - a.longitude);
+                let cross = |v: (f64, f64)| direction.1 * v.0 - direction.0 * v.1;
+                let point_side = cross((point.latitude - a.latitude, point.longitude - a.longitude));
+                let wanted_side = cross(self.side.vector());
+                if wanted_side == 0.0 {
+                    return None;
+                }
+                Some(point_side == 0.0 || point_side.signum() == wanted_side.signum())
+            }
+        }
+    }
+}
+
+/// Zone géographique concernée
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum WarningArea {
+    /// WI - polygone (le premier point est répété en fin de liste)
+    Polygon(Vec<Coordinate>),
+
+    /// Une ou plusieurs relations combinées par AND
+    Relative(Vec<AreaRelation>),
+
+    /// ENTIRE FIR / UIR / CTA
+    EntireFir,
+
+    /// WI nnnKM OF [TC] CENTRE - cercle autour de la position signalée
+    WithinRadius { radius: Distance, centre: Option<Coordinate> },
+
+    /// APRX nnnKM WID LINE BTN ... - couloir autour d'une ligne
+    Corridor { width: Distance, line: Vec<Coordinate> },
+}
+
+impl WarningArea {
+    /// Indique si le point est dans la zone
+    ///
+    /// `None` lorsque la réponse dépend des limites de la FIR (ENTIRE FIR)
+    /// ou que la géométrie est incomplète.
+    pub fn contains(&self, point: &Coordinate) -> Option<bool> {
+        match self {
+            WarningArea::Polygon(points) => {
+                if points.len() < 3 {
+                    return None;
+                }
+                let mut inside = false;
+                let mut previous = points[points.len() - 1];
+                for current in points {
+                    if (current.latitude > point.latitude) != (previous.latitude > point.latitude) {
+                        let crossing = previous.longitude
+                            + (point.latitude - previous.latitude) / (current.latitude - previous.latitude)
+                                * (current.longitude - previous.longitude);
+                        if point.longitude < crossing {
+                            inside = !inside;
+                        }
+                    }
+                    previous = *current;
+                }
+                Some(inside)
+            }
+            WarningArea::Relative(relations) => {
+                let mut inside = true;
+                for relation in relations {
+                    inside &= relation.contains(point)?;
+                }
+                Some(inside)
+            }
+            WarningArea::EntireFir => None,
+            WarningArea::WithinRadius { radius, centre } => {
+                Some(centre.as_ref()?.distance_nm(point) <= radius.to_nm())
+            }
+            WarningArea::Corridor { width, line } => {
+                let half_width = width.to_nm() / 2.0;
+                let closest = line
+                    .windows(2)
+                    .map(|segment| segment_distance_nm(point, &segment[0], &segment[1]))
+                    .min_by(f64::total_cmp)?;
+                Some(closest <= half_width)
+            }
+        }
+    }
+}
+
+/// Niveau de l'étendue verticale
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Level {
+    /// SFC - surface
+    Surface,
+
+    /// FLnnn - niveau de vol
+    FlightLevel(u16),
+
+    /// nnnnFT - altitude en pieds
+    Feet(u32),
+
+    /// nnnnM - altitude en mètres
+    Metres(u32),
+}
+
+impl Level {
+    pub fn parse(text: &str) -> Result<Self, AftnError> {
+        let invalid = || AftnError::InvalidFormat(format!("Invalid level: {}", text));
+        if text == "SFC" {
+            return Ok(Level::Surface);
+        }
+        if let Some(level) = text.strip_prefix("FL") {
+            return Ok(Level::FlightLevel(parse_number(level).ok_or_else(invalid)?));
+        }
+        if let Some(feet) = text.strip_suffix("FT") {
+            return Ok(Level::Feet(parse_number(feet).ok_or_else(invalid)?));
+        }
+        if let Some(metres) = text.strip_suffix('M') {
+            return Ok(Level::Metres(parse_number(metres).ok_or_else(invalid)?));
+        }
+        Err(invalid())
+    }
+}
+
+/// Étendue verticale du phénomène
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum VerticalExtent {
+    /// Niveau unique (`FL180`)
+    At(Level),
+
+    /// Couche (`FL250/350`, `SFC/FL100`, `3000M/FL100`)
+    Layer { lower: Level, upper: Level },
+
+    /// TOP - sommet (`TOP FL390`)
+    Top(Level),
+
+    /// TOP ABV - sommet au-dessus de (`TOP ABV FL390`)
+    TopAbove(Level),
+
+    /// ABV - au-dessus de
+    Above(Level),
+
+    /// BLW - au-dessous de
+    Below(Level),
+}
+
+impl VerticalExtent {
+    /// Décode l'étendue verticale en tête de `tokens`, renvoie le nombre de groupes consommés
+    fn decode(tokens: &[&str]) -> Option<(Self, usize)> {
+        match tokens {
+            ["TOP", "ABV", level, ..] => Some((VerticalExtent::TopAbove(Level::parse(level).ok()?), 3)),
+            ["TOP", level, ..] => Some((VerticalExtent::Top(Level::parse(level).ok()?), 2)),
+            ["ABV", level, ..] => Some((VerticalExtent::Above(Level::parse(level).ok()?), 2)),
+            ["BLW", level, ..] => Some((VerticalExtent::Below(Level::parse(level).ok()?), 2)),
+            [layer, ..] if layer.contains('/') => {
+                let (lower, upper) = layer.split_once('/')?;
+                let lower = Level::parse(lower).ok()?;
+                // FL250/350: le second niveau hérite du préfixe FL
+                let upper = match (lower, parse_number(upper)) {
+                    (Level::FlightLevel(_), Some(level)) => Level::FlightLevel(level),
+                    _ => Level::parse(upper).ok()?,
+                };
+                Some((VerticalExtent::Layer { lower, upper }, 1))
+            }
+            [level, ..] if level.starts_with("FL") => Some((VerticalExtent::At(Level::parse(level).ok()?), 1)),
+            _ => None,
+        }
+    }
+}
+
+/// Déplacement prévu de la zone
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Movement {
+    /// STNR - stationnaire
+    Stationary,
+
+    /// MOV - déplacement vers un point cardinal (16 directions), vitesse en `unit`
+    Moving {
+        direction: String,
+        speed: Option<u16>,
+        unit: SpeedUnit,
+    },
+}
+
+/// Unité de vitesse de déplacement
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SpeedUnit {
+    /// KT - nœuds
+    Knots,
+
+    /// KMH - kilomètres par heure
+    KilometresPerHour,
+}
+
+impl Movement {
+    /// Direction de déplacement en degrés vrais
+    pub fn bearing(&self) -> Option<f64> {
+        const POINTS: [&str; 16] = [
+            "N", "NNE", "NE", "ENE", "E", "ESE", "SE", "SSE",
+            "S", "SSW", "SW", "WSW", "W", "WNW", "NW", "NNW",
+        ];
+        match self {
+            Movement::Stationary => None,
+            Movement::Moving { direction, .. } => {
+                POINTS.iter().position(|p| p == direction).map(|i| i as f64 * 22.5)
+            }
+        }
+    }
+}
+
+/// Évolution de l'intensité
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum IntensityChange {
+    /// INTSF - intensification
+    Intensifying,
+
+    /// WKN - affaiblissement
+    Weakening,
+
+    /// NC - sans changement
+    NoChange,
+}
+
+/// Référence au message annulé (`CNL SIGMET 2 101200/101600`)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CancelledWarning {
+    pub sequence: String,
+    pub valid_from: Option<DayTime>,
+    pub valid_until: Option<DayTime>,
+}
+
+/// Position prévue en fin de validité (`FCST AT 1600Z ...`)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ForecastPosition {
+    pub at: Option<Hhmm>,
+
+    /// Zone prévue
+    pub area: Option<WarningArea>,
+
+    /// Position prévue du centre (cyclone tropical)
+    pub position: Option<Coordinate>,
+}
+
+/// Contenu décodé d'un SIGMET ou d'un AIRMET
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct WeatherWarning {
+    /// Unité ATS desservant la FIR (indicateur d'emplacement en tête)
+    pub issuing_unit: Option<String>,
+
+    /// Numéro de séquence (ex: `5`, `A3`)
+    pub sequence: Option<String>,
+
+    /// Début de validité (DDHHMM)
+    pub valid_from: Option<DayTime>,
+
+    /// Fin de validité (DDHHMM)
+    pub valid_until: Option<DayTime>,
+
+    /// Centre de veille météorologique d'origine (MWO)
+    pub originator: Option<String>,
+
+    /// Indicateur d'emplacement de la FIR
+    pub fir: Option<String>,
+
+    /// Nom de la FIR (ex: `AMSWELL FIR`)
+    pub fir_name: Option<String>,
+
+    /// Message de test (TEST) ou d'exercice (EXER)
+    pub test_or_exercise: Option<String>,
+
+    /// Message annulé (CNL)
+    pub cancelled: Option<CancelledWarning>,
+
+    pub phenomenon: Option<Phenomenon>,
+
+    /// Position du cyclone tropical ou du volcan (PSN)
+    pub position: Option<Coordinate>,
+
+    pub observation: Option<Observation>,
+
+    pub area: Option<WarningArea>,
+
+    pub vertical_extent: Option<VerticalExtent>,
+
+    pub movement: Option<Movement>,
+
+    pub intensity_change: Option<IntensityChange>,
+
+    pub forecast: Option<ForecastPosition>,
+
+    /// Groupes non reconnus, conservés tels quels
+    pub unrecognized: Vec<String>,
+}
+
+impl WeatherWarning {
+    /// Décode le corps d'un SIGMET (`keyword` = "SIGMET") ou d'un AIRMET ("AIRMET")
+    pub fn decode(body: &str, keyword: &str) -> Result<Self, AftnError> {
+        let tokens = tokenize(body);
+        let tokens: Vec<&str> = tokens.iter().map(String::as_str).collect();
+        let mut warning = WeatherWarning::default();
+
+        let mut i = match tokens.iter().position(|t| *t == keyword) {
+            Some(0) => 1,
+            Some(1) => {
+                warning.issuing_unit = Some(tokens[0].to_string());
+                2
+            }
+            _ => 0,
+        };
+        if let Some(&sequence) = tokens.get(i).filter(|t| i > 0 && **t != "VALID") {
+            warning.sequence = Some(sequence.to_string());
+            i += 1;
+        }
+        if tokens.get(i) == Some(&"VALID") {
+            i += 1;
+            if let Some(period) = tokens.get(i).filter(|t| t.contains('/')) {
+                let (from, until) = parse_validity(period)?;
+                warning.valid_from = Some(from);
+                warning.valid_until = Some(until);
+                i += 1;
+            }
+        }
+        if let Some(originator) = tokens.get(i).filter(|t| is_location_indicator(t)) {
+            warning.originator = Some(originator.to_string());
+            i += 1;
+        }
+        while tokens.get(i) == Some(&"-") {
+            i += 1;
+        }
+        if let Some(fir) = tokens.get(i).filter(|t| is_location_indicator(t)) {
+            warning.fir = Some(fir.to_string());
+            i += 1;
+            let name_end = tokens[i..]
+                .iter()
+                .take(6)
+                .position(|t| matches!(*t, "FIR" | "UIR" | "CTA" | "FIR/UIR" | "UIR/FIR" | "FIR/CTA"));
+            if let Some(end) = name_end {
+                warning.fir_name = Some(tokens[i..=i + end].join(" "));
+                i += end + 1;
+            }
+        }
+        if let Some(&flag) = tokens.get(i).filter(|t| matches!(**t, "TEST" | "EXER")) {
+            warning.test_or_exercise = Some(flag.to_string());
+            i += 1;
+        }
+
+        if let ["CNL", "SIGMET" | "AIRMET", sequence, rest @ ..] = &tokens[i.min(tokens.len())..] {
+            let mut cancelled = CancelledWarning {
+                sequence: sequence.to_string(),
+                valid_from: None,
+                valid_until: None,
+            };
+            i += 3;
+            if let Some(period) = rest.first().filter(|t| t.contains('/')) {
+                let (from, until) = parse_validity(period)?;
+                cancelled.valid_from = Some(from);
+                cancelled.valid_until = Some(until);
+                i += 1;
+            }
+            warning.cancelled = Some(cancelled);
+        } else if let Some((phenomenon, consumed)) = tokens.get(i..).and_then(Phenomenon::decode) {
+            warning.phenomenon = Some(phenomenon);
+            i += consumed;
+        }
+
+        while i < tokens.len() {
+            let consumed = warning.decode_group(&tokens[i..]);
+            if consumed == 0 {
+                warning.unrecognized.push(tokens[i].to_string());
+            }
+            i += consumed.max(1);
+        }
+
+        // Le cercle est centré sur la position signalée
+        if let Some(WarningArea::WithinRadius { centre: centre @ None, .. }) = &mut warning.area {
+            *centre = warning.position;
+        }
+        Ok(warning)
+    }
+
+    /// Indique si le message est en vigueur à l'instant donné
+    ///
+    /// Un message d'annulation ou sans période de validité n'est jamais en vigueur.
+    pub fn is_active_at(&self, instant: &UtcDateTime) -> Result<bool, AftnError> {
+        let (from, until) = match (&self.valid_from, &self.valid_until) {
+            (Some(from), Some(until)) if self.cancelled.is_none() => (from, until),
+            _ => return Ok(false),
+        };
+        let start = from.resolve(instant)?;
+        let mut end = until.resolve(&start)?;
+        if end < start {
+            end = until.resolve(&start.add_minutes(24 * 60))?;
+        }
+        Ok(start <= *instant && *instant < end)
+    }
+
+    /// Décode un groupe après le phénomène, renvoie le nombre de groupes consommés
+    fn decode_group(&mut self, tokens: &[&str]) -> usize {
+        match tokens {
+            ["-" | "AND", ..] => 1,
+            ["FCST", rest @ ..] if self.observation.is_some() => {
+                let (at, consumed) = parse_at(rest);
+                self.forecast = Some(ForecastPosition { at, area: None, position: None });
+                1 + consumed
+            }
+            [kind @ ("OBS" | "FCST"), rest @ ..] => {
+                let (at, consumed) = parse_at(rest);
+                let kind = if *kind == "OBS" { ObservationKind::Observed } else { ObservationKind::Forecast };
+                self.observation = Some(Observation { kind, at });
+                1 + consumed
+            }
+            // Rappel du phénomène après la position (VA CLD, CB d'un cyclone)
+            ["VA", "CLD", ..] if self.phenomenon.is_some() => 2,
+            ["CB", ..] if self.phenomenon.is_some() => 1,
+            ["TC", "CENTRE" | "CENTER", ..] => 2,
+            ["PSN", rest @ ..] => match parse_point(rest) {
+                Some((point, consumed)) => {
+                    match &mut self.forecast {
+                        Some(forecast) => forecast.position = Some(point),
+                        None => self.position = Some(point),
+                    }
+                    1 + consumed
+                }
+                None => 0,
+            },
+            ["ENTIRE", "FIR" | "UIR" | "CTA" | "FIR/UIR" | "UIR/FIR" | "FIR/CTA", ..] => {
+                self.set_area(WarningArea::EntireFir);
+                2
+            }
+            ["WI", distance, "OF", rest @ ..] => match Distance::parse(distance) {
+                Ok(radius) => {
+                    let centre_words = rest
+                        .iter()
+                        .take(2)
+                        .take_while(|w| matches!(**w, "TC" | "CENTRE" | "CENTER"))
+                        .count();
+                    self.set_area(WarningArea::WithinRadius { radius, centre: None });
+                    3 + centre_words
+                }
+                Err(_) => 0,
+            },
+            ["WI", rest @ ..] => {
+                let (points, consumed) = parse_points(rest);
+                if points.len() < 3 {
+                    return 0;
+                }
+                self.set_area(WarningArea::Polygon(points));
+                1 + consumed
+            }
+            ["APRX", width, "WID", "LINE", "BTN", rest @ ..] => {
+                let (line, consumed) = parse_points(rest);
+                match Distance::parse(width) {
+                    Ok(width) if line.len() >= 2 => {
+                        self.set_area(WarningArea::Corridor { width, line });
+                        5 + consumed
+                    }
+                    _ => 0,
+                }
+            }
+            [side, "OF", ..] if Side::parse(side).is_some() => {
+                let (relations, consumed) = parse_relations(tokens);
+                if relations.is_empty() {
+                    return 0;
+                }
+                self.set_area(WarningArea::Relative(relations));
+                consumed
+            }
+            ["MOV", direction, rest @ ..] if is_compass_point(direction) => {
+                let speed = rest.first().and_then(|s| {
+                    s.strip_suffix("KT")
+                        .map(|v| (v, SpeedUnit::Knots))
+                        .or_else(|| s.strip_suffix("KMH").map(|v| (v, SpeedUnit::KilometresPerHour)))
+                        .and_then(|(v, unit)| Some((v.parse::<u16>().ok()?, unit)))
+                });
+                let (speed, unit, consumed) = match speed {
+                    Some((speed, unit)) => (Some(speed), unit, 3),
+                    None => (None, SpeedUnit::Knots, 2),
+                };
+                self.movement = Some(Movement::Moving { direction: direction.to_string(), speed, unit });
+                consumed
+            }
+            ["STNR", ..] => {
+                self.movement = Some(Movement::Stationary);
+                1
+            }
+            ["INTSF", ..] => {
+                self.intensity_change = Some(IntensityChange::Intensifying);
+                1
+            }
+            ["WKN", ..] => {
+                self.intensity_change = Some(IntensityChange::Weakening);
+                1
+            }
+            ["NC", ..] => {
+                self.intensity_change = Some(IntensityChange::NoChange);
+                1
+            }
+            _ => match VerticalExtent::decode(tokens) {
+                Some((extent, consumed)) if self.vertical_extent.is_none() => {
+                    self.vertical_extent = Some(extent);
+                    consumed
+                }
+                _ => 0,
+            },
+        }
+    }
+
+    fn set_area(&mut self, area: WarningArea) {
+        match &mut self.forecast {
+            Some(forecast) => forecast.area = Some(area),
+            None => self.area = Some(area),
+        }
+    }
+}
+
+/// Découpe le corps en groupes, en isolant les séparateurs `-` et en retirant le `=` final
+fn tokenize(body: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    for word in body.split_whitespace() {
+        let word = word.trim_end_matches('=');
+        let mut parts = word.split('-').peekable();
+        while let Some(part) = parts.next() {
+            if !part.is_empty() {
+                tokens.push(part.to_string());
+            }
+            if parts.peek().is_some() {
+                tokens.push("-".to_string());
+            }
+        }
+    }
+    tokens
+}
+
+/// Parse une période de validité `DDHHMM/DDHHMM`
+fn parse_validity(text: &str) -> Result<(DayTime, DayTime), AftnError> {
+    let invalid = || AftnError::InvalidDateTime(format!("Validity period must be DDHHMM/DDHHMM, got: {}", text));
+    let (from, until) = text.split_once('/').ok_or_else(invalid)?;
+    if from.len() != 6 || until.len() != 6 {
+        return Err(invalid());
+    }
+    Ok((DayTime::parse(from)?, DayTime::parse(until)?))
+}
+
+/// Parse `AT HHMMZ` en tête de `tokens`
+fn parse_at(tokens: &[&str]) -> (Option<Hhmm>, usize) {
+    match tokens {
+        ["AT", time, ..] => match time.strip_suffix('Z').map(Hhmm::parse_time) {
+            Some(Ok(time)) => (Some(time), 2),
+            _ => (None, 0),
+        },
+        _ => (None, 0),
+    }
+}
+
+/// Parse une latitude `Nnn[nn]` / `Snn[nn]`
+fn parse_latitude(text: &str) -> Option<f64> {
+    let sign = match text.get(..1)? {
+        "N" => 1.0,
+        "S" => -1.0,
+        _ => return None,
+    };
+    let value = parse_degrees(&text[1..], 2)?;
+    (value <= 90.0).then_some(sign * value)
+}
+
+/// Parse une longitude `Ennn[nn]` / `Wnnn[nn]`
+fn parse_longitude(text: &str) -> Option<f64> {
+    let sign = match text.get(..1)? {
+        "E" => 1.0,
+        "W" => -1.0,
+        _ => return None,
+    };
+    let value = parse_degrees(&text[1..], 3)?;
+    (value <= 180.0).then_some(sign * value)
+}
+
+fn parse_degrees(digits: &str, degree_len: usize) -> Option<f64> {
+    if !(digits.len() == degree_len || digits.len() == degree_len + 2) || !digits.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let degrees: f64 = digits[..degree_len].parse().ok()?;
+    let minutes: f64 = if digits.len() > degree_len { digits[degree_len..].parse().ok()? } else { 0.0 };
+    (minutes < 60.0).then_some(degrees + minutes / 60.0)
+}
+
+/// Parse un point `N4530 E00200` (ou `N4530E00200`), renvoie le nombre de groupes consommés
+fn parse_point(tokens: &[&str]) -> Option<(Coordinate, usize)> {
+    let first = tokens.first()?;
+    if let Some(split) = first.get(1..).and_then(|rest| rest.find(['E', 'W'])) {
+        let (latitude, longitude) = first.split_at(split + 1);
+        return Some((
+            Coordinate { latitude: parse_latitude(latitude)?, longitude: parse_longitude(longitude)? },
+            1,
+        ));
+    }
+    let latitude = parse_latitude(first)?;
+    let longitude = parse_longitude(tokens.get(1)?)?;
+    Some((Coordinate { latitude, longitude }, 2))
+}
+
+/// Parse une suite de points séparés par `-`
+fn parse_points(tokens: &[&str]) -> (Vec<Coordinate>, usize) {
+    let mut points = Vec::new();
+    let mut i = 0;
+    while let Some((point, consumed)) = parse_point(&tokens[i..]) {
+        points.push(point);
+        i += consumed;
+        if tokens.get(i) == Some(&"-") && parse_point(&tokens[i + 1..]).is_some() {
+            i += 1;
+        } else {
+            break;
+        }
+    }
+    (points, i)
+}
+
+/// Parse des relations `<côté> OF <limite>` combinées par AND
+fn parse_relations(tokens: &[&str]) -> (Vec<AreaRelation>, usize) {
+    let mut relations = Vec::new();
+    let mut i = 0;
+    while let Some(side) = tokens.get(i).and_then(|s| Side::parse(s)) {
+        if tokens.get(i + 1) != Some(&"OF") {
+            break;
+        }
+        let rest = &tokens[i + 2..];
+        let (boundary, consumed) = if rest.first() == Some(&"LINE") {
+            let (line, consumed) = parse_points(&rest[1..]);
+            if line.len() < 2 {
+                break;
+            }
+            (Boundary::Line(line), 1 + consumed)
+        } else if let Some(latitude) = rest.first().and_then(|t| parse_latitude(t)) {
+            (Boundary::Latitude(latitude), 1)
+        } else if let Some(longitude) = rest.first().and_then(|t| parse_longitude(t)) {
+            (Boundary::Longitude(longitude), 1)
+        } else {
+            break;
+        };
+        relations.push(AreaRelation { side, boundary });
+        i += 2 + consumed;
+
+        if tokens.get(i) == Some(&"AND") {
+            i += 1;
+        } else {
+            break;
+        }
+    }
+    (relations, i)
+}
+
+/// Distance approximative d'un point à un segment, en milles nautiques
+/// (projection équirectangulaire locale)
+fn segment_distance_nm(point: &Coordinate, a: &Coordinate, b: &Coordinate) -> f64 {
+    let scale = point.latitude.to_radians().cos();
+    let to_nm = |c: &Coordinate| ((c.longitude - point.longitude) * 60.0 * scale, (c.latitude - point.latitude) * 60.0);
+    let (ax, ay) = to_nm(a);
+    let (bx, by) = to_nm(b);
+    let (dx, dy) = (bx - ax, by - ay);
+    let length = dx * dx + dy * dy;
+    let t = if length == 0.0 { 0.0 } else { (-(ax * dx + ay * dy) / length).clamp(0.0, 1.0) };
+    let (x, y) = (ax + t * dx, ay + t * dy);
+    (x * x + y * y).sqrt()
+}
+
+fn parse_number<T: std::str::FromStr>(text: &str) -> Option<T> {
+    if text.is_empty() || !text.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    text.parse().ok()
+}
+
+fn is_location_indicator(text: &str) -> bool {
+    text.len() == 4 && text.chars().all(|c| c.is_ascii_uppercase())
+}
+
+fn is_compass_point(text: &str) -> bool {
+    !text.is_empty() && text.len() <= 3 && text.chars().all(|c| matches!(c, 'N' | 'S' | 'E' | 'W'))
+}
+
+/// Mots-clés qui terminent un nom de cyclone ou de volcan
+fn is_keyword(text: &str) -> bool {
+    matches!(text, "PSN" | "OBS" | "FCST" | "CB" | "VA" | "WI" | "ENTIRE" | "-")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TURBULENCE: &str = "YUCC SIGMET 5 VALID 221215/221600 YUDO-\n\
+        YUCC AMSWELL FIR SEV TURB OBS AT 1210Z\n\
+        WI N2020 W07005 - N2020 W06950 - N1950 W06950 - N1950 W07005 - N2020 W07005\n\
+        FL250/370 MOV E 20KT WKN=";
+
+    #[test]
+    fn test_decode_sigmet_polygon() {
+        let warning = WeatherWarning::decode(TURBULENCE, "SIGMET").unwrap();
+        assert_eq!(warning.issuing_unit.as_deref(), Some("YUCC"));
+        assert_eq!(warning.sequence.as_deref(), Some("5"));
+        assert_eq!(warning.valid_from, Some(DayTime { day: 22, hour: 12, minute: 15 }));
+        assert_eq!(warning.valid_until, Some(DayTime { day: 22, hour: 16, minute: 0 }));
+        assert_eq!(warning.originator.as_deref(), Some("YUDO"));
+        assert_eq!(warning.fir.as_deref(), Some("YUCC"));
+        assert_eq!(warning.fir_name.as_deref(), Some("AMSWELL FIR"));
+
+        let phenomenon = warning.phenomenon.as_ref().unwrap();
+        assert_eq!(phenomenon.qualifier.as_deref(), Some("SEV"));
+        assert_eq!(phenomenon.kind, PhenomenonKind::Turbulence);
+        assert_eq!(
+            warning.observation,
+            Some(Observation { kind: ObservationKind::Observed, at: Some(Hhmm { hour: 12, minute: 10 }) })
+        );
+        assert_eq!(
+            warning.vertical_extent,
+            Some(VerticalExtent::Layer { lower: Level::FlightLevel(250), upper: Level::FlightLevel(370) })
+        );
+        assert_eq!(
+            warning.movement,
+            Some(Movement::Moving { direction: "E".to_string(), speed: Some(20), unit: SpeedUnit::Knots })
+        );
+        assert_eq!(warning.intensity_change, Some(IntensityChange::Weakening));
+        assert!(warning.unrecognized.is_empty(), "{:?}", warning.unrecognized);
+
+        let area = warning.area.as_ref().unwrap();
+        assert!(matches!(area, WarningArea::Polygon(points) if points.len() == 5));
+        let inside = Coordinate { latitude: 20.0, longitude: -69.9 };
+        let outside = Coordinate { latitude: 21.0, longitude: -69.9 };
+        assert_eq!(area.contains(&inside), Some(true));
+        assert_eq!(area.contains(&outside), Some(false));
+    }
+
+    #[test]
+    fn test_decode_sigmet_line_relations() {
+        let warning = WeatherWarning::decode(
+            "EINN SIGMET 2 VALID 101200/101600 EGRR- EINN SHANNON FIR/UIR OBSC TS FCST S OF N54 AND E OF W012 TOP FL390 STNR NC=",
+            "SIGMET",
+        )
+        .unwrap();
+        assert_eq!(warning.fir_name.as_deref(), Some("SHANNON FIR/UIR"));
+        assert_eq!(warning.phenomenon.as_ref().unwrap().kind, PhenomenonKind::Thunderstorm);
+        assert_eq!(warning.observation.unwrap().kind, ObservationKind::Forecast);
+        assert_eq!(warning.vertical_extent, Some(VerticalExtent::Top(Level::FlightLevel(390))));
+        assert_eq!(warning.movement, Some(Movement::Stationary));
+        assert_eq!(warning.intensity_change, Some(IntensityChange::NoChange));
+
+        let area = warning.area.unwrap();
+        assert_eq!(
+            area,
+            WarningArea::Relative(vec![
+                AreaRelation { side: Side::South, boundary: Boundary::Latitude(54.0) },
+                AreaRelation { side: Side::East, boundary: Boundary::Longitude(-12.0) },
+            ])
+        );
+        assert_eq!(area.contains(&Coordinate { latitude: 53.0, longitude: -8.0 }), Some(true));
+        assert_eq!(area.contains(&Coordinate { latitude: 53.0, longitude: -14.0 }), Some(false));
+    }
+
+    #[test]
+    fn test_decode_north_of_line() {
+        let warning = WeatherWarning::decode(
+            "LFFF SIGMET 1 VALID 151200/151600 LFPW- LFFF PARIS FIR SEV ICE FCST N OF LINE N4500 W00200 - N4600 E00500 FL080/150 MOV NE 15KT INTSF",
+            "SIGMET",
+        )
+        .unwrap();
+        let area = warning.area.unwrap();
+        assert_eq!(area.contains(&Coordinate { latitude: 48.8, longitude: 2.3 }), Some(true));
+        assert_eq!(area.contains(&Coordinate { latitude: 44.0, longitude: 0.0 }), Some(false));
+        assert_eq!(warning.movement.unwrap().bearing(), Some(45.0));
+        assert_eq!(warning.intensity_change, Some(IntensityChange::Intensifying));
+    }
+
+    #[test]
+    fn test_decode_tropical_cyclone() {
+        let warning = WeatherWarning::decode(
+            "YUCC SIGMET 3 VALID 251600/252200 YUDO- YUCC AMSWELL FIR TC GLORIA PSN N2706 W07306 CB OBS AT 1600Z WI 250NM OF TC CENTRE TOP FL500 NC FCST AT 2200Z TC CENTRE PSN N2740 W07345=",
+            "SIGMET",
+        )
+        .unwrap();
+        let phenomenon = warning.phenomenon.as_ref().unwrap();
+        assert_eq!(phenomenon.kind, PhenomenonKind::TropicalCyclone);
+        assert_eq!(phenomenon.name.as_deref(), Some("GLORIA"));
+        let centre = warning.position.unwrap();
+        assert!((centre.latitude - 27.1).abs() < 1e-9);
+
+        match &warning.area {
+            Some(WarningArea::WithinRadius { radius, centre: Some(c) }) => {
+                assert_eq!(radius.to_nm(), 250.0);
+                assert_eq!(*c, centre);
+            }
+            other => panic!("Cercle attendu, obtenu {:?}", other),
+        }
+        let forecast = warning.forecast.as_ref().unwrap();
+        assert_eq!(forecast.at, Some(Hhmm { hour: 22, minute: 0 }));
+        assert!(forecast.position.is_some());
+        assert!(warning.unrecognized.is_empty(), "{:?}", warning.unrecognized);
+    }
+
+    #[test]
+    fn test_decode_cancellation() {
+        let warning = WeatherWarning::decode(
+            "YUCC SIGMET 6 VALID 221430/221600 YUDO- YUCC AMSWELL FIR CNL SIGMET 5 221215/221600=",
+            "SIGMET",
+        )
+        .unwrap();
+        let cancelled = warning.cancelled.as_ref().unwrap();
+        assert_eq!(cancelled.sequence, "5");
+        assert_eq!(cancelled.valid_from, Some(DayTime { day: 22, hour: 12, minute: 15 }));
+        assert!(warning.phenomenon.is_none());
+        let instant = UtcDateTime::new(2024, 3, 22, 15, 0).unwrap();
+        assert!(!warning.is_active_at(&instant).unwrap());
+    }
+
+    #[test]
+    fn test_decode_airmet_and_validity() {
+        let warning = WeatherWarning::decode(
+            "YUDD AIRMET 1 VALID 151520/151800 YUSO- YUDD SHANLON FIR ISOL TS OBS N OF S50 ABV FL100 STNR WKN",
+            "AIRMET",
+        )
+        .unwrap();
+        assert_eq!(warning.phenomenon.as_ref().unwrap().qualifier.as_deref(), Some("ISOL"));
+        assert_eq!(warning.vertical_extent, Some(VerticalExtent::Above(Level::FlightLevel(100))));
+
+        let visibility = WeatherWarning::decode("YUDD AIRMET 2 VALID 151520/151800 YUSO- YUDD SHANLON FIR SFC VIS 3000M (BR) OBS ENTIRE FIR", "AIRMET").unwrap();
+        assert_eq!(
+            visibility.phenomenon.unwrap().kind,
+            PhenomenonKind::SurfaceVisibility { metres: 3000, cause: Some("BR".to_string()) }
+        );
+        assert_eq!(visibility.area, Some(WarningArea::EntireFir));
+        assert_eq!(visibility.area.unwrap().contains(&Coordinate { latitude: 0.0, longitude: 0.0 }), None);
+
+        let during = UtcDateTime::new(2024, 3, 15, 16, 0).unwrap();
+        let after = UtcDateTime::new(2024, 3, 15, 18, 0).unwrap();
+        assert!(warning.is_active_at(&during).unwrap());
+        assert!(!warning.is_active_at(&after).unwrap());
+    }
+
+    #[test]
+    fn test_decode_invalid_validity() {
+        assert!(WeatherWarning::decode("YUCC SIGMET 5 VALID 2212/2216 YUDO-", "SIGMET").is_err());
+    }
+}
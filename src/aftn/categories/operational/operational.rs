@@ -3,6 +3,7 @@ use pest::Parser;
 use pest_derive::Parser;
 use crate::aftn::categories::MessageCategory;
 use crate::aftn::error::AftnError;
+use crate::diagnostic::SyntaxError;
 use crate::aftn::submessages::SubMessage;
 
 #[derive(Parser)]
@@ -24,7 +25,7 @@ pub struct OperationalMessage {
 impl SubMessage for OperationalMessage {
     fn parse(body: &str) -> Result<Self, AftnError> {
         let mut pairs = OperationalParser::parse(Rule::operational, body)
-            .map_err(|e| AftnError::ParseError(format!("Operational parse error: {}", e)))?;
+            .map_err(|e| AftnError::from(SyntaxError::from_pest(&e, body, "Operational")))?;
         
         let op_pair = pairs.next().ok_or_else(|| {
             AftnError::ParseError("Empty operational parse result".to_string())
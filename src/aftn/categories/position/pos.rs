@@ -3,6 +3,7 @@ use pest::Parser;
 use pest_derive::Parser;
 use crate::aftn::categories::MessageCategory;
 use crate::aftn::error::AftnError;
+use crate::diagnostic::SyntaxError;
 use crate::aftn::submessages::SubMessage;
 
 #[derive(Parser)]
@@ -21,7 +22,7 @@ pub struct PosMessage {
 impl SubMessage for PosMessage {
     fn parse(body: &str) -> Result<Self, AftnError> {
         let mut pairs = PosParser::parse(Rule::pos, body)
-            .map_err(|e| AftnError::ParseError(format!("POS parse error: {}", e)))?;
+            .map_err(|e| AftnError::from(SyntaxError::from_pest(&e, body, "POS")))?;
         
         let pos_pair = pairs.next().ok_or_else(|| {
             AftnError::ParseError("Empty POS parse result".to_string())
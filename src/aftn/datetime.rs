@@ -0,0 +1,259 @@
+//! Résolution des groupes date-heure AFTN en instants UTC absolus
+//!
+//! Les messages AFTN ne portent que des groupes partiels (`DDHHMM`, `HHMM`,
+//! `YYMMDD`). Pour obtenir une date complète, le groupe est rapproché d'une date
+//! de référence (typiquement l'heure de réception du message) : le mois et
+//! l'année sont ceux de la référence, avec passage au mois précédent ou suivant
+//! lorsque le jour est à plus d'une demi-période de la référence.
+
+use crate::aftn::error::AftnError;
+use crate::datetime::UtcDateTime;
+
+/// Écart maximal (en jours) entre un groupe DDHHMM et la référence avant de
+/// basculer sur le mois précédent ou suivant
+const MONTH_ROLLOVER_DAYS: i64 = 15;
+
+/// Fenêtre de résolution d'une EOBT sans DOF/: de 6 heures avant à 18 heures
+/// après la référence (un plan de vol sans DOF/ est déposé moins de 24 heures
+/// avant l'heure de départ)
+const EOBT_LOOKBACK_MINUTES: i64 = 6 * 60;
+
+/// Résout un groupe jour-heure-minute (DDHHMM) par rapport à une date de référence
+///
+/// Le mois retenu est celui de la référence, sauf si le jour en est éloigné de
+/// plus de 15 jours : un jour 01 reçu le 31 désigne le mois suivant, un jour 31
+/// reçu le 01 le mois précédent (avec changement d'année si nécessaire). Une
+/// date inexistante dans le mois retenu (ex: 31 avril) est rejetée.
+pub fn resolve_day_time(day: u8, hour: u8, minute: u8, reference: &UtcDateTime) -> Result<UtcDateTime, AftnError> {
+    if !(1..=31).contains(&day) {
+        return Err(AftnError::InvalidDateTime(format!(
+            "Day must be between 01-31, got {}",
+            day
+        )));
+    }
+
+    let offset = day as i64 - reference.day as i64;
+    let (year, month) = if offset > MONTH_ROLLOVER_DAYS {
+        previous_month(reference.year, reference.month)?
+    } else if offset < -MONTH_ROLLOVER_DAYS {
+        next_month(reference.year, reference.month)?
+    } else {
+        (reference.year, reference.month)
+    };
+
+    Ok(UtcDateTime::new(year, month, day, hour, minute)?)
+}
+
+/// Résout un groupe `DDHHMM` (avec ou sans `Z` final, ex: heure d'observation METAR)
+pub fn resolve_ddhhmm(text: &str, reference: &UtcDateTime) -> Result<UtcDateTime, AftnError> {
+    let digits = text.strip_suffix('Z').unwrap_or(text);
+    if digits.len() != 6 || !digits.chars().all(|c| c.is_ascii_digit()) {
+        return Err(AftnError::InvalidDateTime(format!(
+            "Date-time group must be 6 digits (DDHHMM), got: {}",
+            text
+        )));
+    }
+    let [day, hour, minute] = two_digit_groups(digits);
+    resolve_day_time(day, hour, minute, reference)
+}
+
+/// Résout l'élément DOF/ (YYMMDD, Doc 4444 Field Type 18) en date à 00:00 UTC
+///
+/// Le siècle est choisi de façon à placer l'année à moins de 50 ans de la référence.
+pub fn resolve_date_of_flight(dof: &str, reference: &UtcDateTime) -> Result<UtcDateTime, AftnError> {
+    if dof.len() != 6 || !dof.chars().all(|c| c.is_ascii_digit()) {
+        return Err(AftnError::InvalidDateTime(format!(
+            "DOF must be 6 digits (YYMMDD), got: {}",
+            dof
+        )));
+    }
+    let [yy, month, day] = two_digit_groups(dof);
+
+    let reference_year = reference.year as i32;
+    let mut year = reference_year - reference_year % 100 + yy as i32;
+    if year > reference_year + 50 {
+        year -= 100;
+    } else if year + 50 <= reference_year {
+        year += 100;
+    }
+    let year = u16::try_from(year).map_err(|_| year_out_of_range(year))?;
+
+    Ok(UtcDateTime::new(year, month, day, 0, 0)?)
+}
+
+/// Résout une heure estimée de départ du poste de stationnement (EOBT, HHMM)
+///
+/// Avec un DOF/, la date est celle du vol. Sans DOF/, l'EOBT est placée dans la
+/// fenêtre allant de 6 heures avant à 18 heures après la référence.
+pub fn resolve_eobt(hour: u8, minute: u8, dof: Option<&str>, reference: &UtcDateTime) -> Result<UtcDateTime, AftnError> {
+    if let Some(dof) = dof {
+        return Ok(resolve_date_of_flight(dof, reference)?.with_time(hour, minute)?);
+    }
+
+    let earliest = reference.add_minutes(-EOBT_LOOKBACK_MINUTES);
+    let candidate = earliest.with_time(hour, minute)?;
+    if candidate < earliest {
+        let next_day = earliest.add_minutes(24 * 60);
+        Ok(next_day.with_time(hour, minute)?)
+    } else {
+        Ok(candidate)
+    }
+}
+
+/// Résout une période de validité TAF (`DDHH/DDHH`) en couple (début, fin)
+///
+/// L'heure de fin peut valoir 24 (fin de journée). Le jour de fin est rapporté
+/// au mois du début, ou au mois suivant s'il lui est inférieur.
+pub fn resolve_validity_period(period: &str, reference: &UtcDateTime) -> Result<(UtcDateTime, UtcDateTime), AftnError> {
+    let (start, end) = period.split_once('/').ok_or_else(|| {
+        AftnError::InvalidDateTime(format!("Validity period must be DDHH/DDHH, got: {}", period))
+    })?;
+    for group in [start, end] {
+        if group.len() != 4 || !group.chars().all(|c| c.is_ascii_digit()) {
+            return Err(AftnError::InvalidDateTime(format!(
+                "Validity period must be DDHH/DDHH, got: {}",
+                period
+            )));
+        }
+    }
+
+    let [start_day, start_hour] = two_digit_groups(start);
+    let [end_day, end_hour] = two_digit_groups(end);
+
+    let start = resolve_day_time(start_day, start_hour, 0, reference)?;
+
+    let (year, month) = if end_day < start_day {
+        next_month(start.year, start.month)?
+    } else {
+        (start.year, start.month)
+    };
+    let end = if end_hour == 24 {
+        UtcDateTime::new(year, month, end_day, 0, 0)?.add_minutes(24 * 60)
+    } else {
+        UtcDateTime::new(year, month, end_day, end_hour, 0)?
+    };
+
+    if end <= start {
+        return Err(AftnError::InvalidDateTime(format!(
+            "Validity period ends before it starts: {}",
+            period
+        )));
+    }
+    Ok((start, end))
+}
+
+fn two_digit_groups<const N: usize>(digits: &str) -> [u8; N] {
+    let bytes = digits.as_bytes();
+    std::array::from_fn(|i| (bytes[2 * i] - b'0') * 10 + (bytes[2 * i + 1] - b'0'))
+}
+
+fn previous_month(year: u16, month: u8) -> Result<(u16, u8), AftnError> {
+    if month != 1 {
+        return Ok((year, month - 1));
+    }
+    year.checked_sub(1).map(|year| (year, 12)).ok_or_else(|| year_out_of_range(year as i32 - 1))
+}
+
+fn next_month(year: u16, month: u8) -> Result<(u16, u8), AftnError> {
+    if month != 12 {
+        return Ok((year, month + 1));
+    }
+    year.checked_add(1).map(|year| (year, 1)).ok_or_else(|| year_out_of_range(year as i32 + 1))
+}
+
+fn year_out_of_range(year: i32) -> AftnError {
+    AftnError::InvalidDateTime(format!("Year out of range: {}", year))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(year: u16, month: u8, day: u8, hour: u8, minute: u8) -> UtcDateTime {
+        UtcDateTime::new(year, month, day, hour, minute).unwrap()
+    }
+
+    #[test]
+    fn test_resolve_day_time_same_month() {
+        let reference = at(2024, 4, 15, 12, 35);
+        assert_eq!(resolve_day_time(15, 12, 30, &reference).unwrap(), at(2024, 4, 15, 12, 30));
+        assert_eq!(resolve_day_time(14, 23, 0, &reference).unwrap(), at(2024, 4, 14, 23, 0));
+    }
+
+    #[test]
+    fn test_resolve_day_time_rollover() {
+        // Jour 01 reçu en fin de mois: mois suivant
+        assert_eq!(resolve_day_time(1, 0, 5, &at(2024, 4, 30, 23, 58)).unwrap(), at(2024, 5, 1, 0, 5));
+        // Jour 31 reçu en début de mois: mois précédent
+        assert_eq!(resolve_day_time(31, 23, 55, &at(2024, 6, 1, 0, 2)).unwrap(), at(2024, 5, 31, 23, 55));
+        // Changement d'année dans les deux sens
+        assert_eq!(resolve_day_time(1, 0, 10, &at(2024, 12, 31, 23, 50)).unwrap(), at(2025, 1, 1, 0, 10));
+        assert_eq!(resolve_day_time(31, 23, 50, &at(2025, 1, 1, 0, 10)).unwrap(), at(2024, 12, 31, 23, 50));
+    }
+
+    #[test]
+    fn test_resolve_day_time_rejects_31_april() {
+        assert!(resolve_day_time(31, 12, 0, &at(2024, 4, 20, 12, 0)).is_err());
+        // 30 reçu le 1er mars: 30 février
+        assert!(resolve_day_time(30, 12, 0, &at(2024, 3, 1, 12, 0)).is_err());
+        assert!(resolve_day_time(0, 12, 0, &at(2024, 3, 1, 12, 0)).is_err());
+    }
+
+    #[test]
+    fn test_resolve_rejects_years_out_of_range() {
+        // Références construites champ par champ, hors de la plage de `UtcDateTime::new`
+        let first = UtcDateTime { year: 0, month: 1, day: 1, hour: 0, minute: 0 };
+        let last = UtcDateTime { year: u16::MAX, month: 12, day: 31, hour: 0, minute: 0 };
+        assert!(resolve_day_time(31, 12, 0, &first).is_err());
+        assert!(resolve_day_time(1, 12, 0, &last).is_err());
+        assert!(resolve_date_of_flight("991231", &last).is_err());
+        assert!(resolve_date_of_flight("000101", &first).is_err());
+        assert!(resolve_validity_period("3112/0118", &last).is_err());
+
+        assert!(resolve_day_time(1, 0, 5, &at(9999, 12, 31, 23, 58)).is_err());
+        assert_eq!(resolve_day_time(31, 23, 55, &at(1, 2, 1, 0, 2)).unwrap(), at(1, 1, 31, 23, 55));
+    }
+
+    #[test]
+    fn test_resolve_ddhhmm() {
+        let reference = at(2024, 4, 15, 12, 35);
+        assert_eq!(resolve_ddhhmm("151230Z", &reference).unwrap(), at(2024, 4, 15, 12, 30));
+        assert_eq!(resolve_ddhhmm("151230", &reference).unwrap(), at(2024, 4, 15, 12, 30));
+        assert!(resolve_ddhhmm("1512Z", &reference).is_err());
+        assert!(resolve_ddhhmm("152460Z", &reference).is_err());
+    }
+
+    #[test]
+    fn test_resolve_date_of_flight() {
+        let reference = at(2026, 10, 16, 20, 0);
+        assert_eq!(resolve_date_of_flight("261017", &reference).unwrap(), at(2026, 10, 17, 0, 0));
+        assert_eq!(resolve_date_of_flight("991231", &reference).unwrap(), at(1999, 12, 31, 0, 0));
+        assert!(resolve_date_of_flight("260431", &reference).is_err());
+        assert!(resolve_date_of_flight("26101", &reference).is_err());
+    }
+
+    #[test]
+    fn test_resolve_eobt() {
+        let reference = at(2026, 10, 16, 10, 0);
+        assert_eq!(resolve_eobt(9, 0, Some("261017"), &reference).unwrap(), at(2026, 10, 17, 9, 0));
+        // Sans DOF/: dans les 18 heures suivantes ou les 6 heures précédentes
+        assert_eq!(resolve_eobt(23, 30, None, &reference).unwrap(), at(2026, 10, 16, 23, 30));
+        assert_eq!(resolve_eobt(2, 0, None, &reference).unwrap(), at(2026, 10, 17, 2, 0));
+        assert_eq!(resolve_eobt(8, 0, None, &reference).unwrap(), at(2026, 10, 16, 8, 0));
+    }
+
+    #[test]
+    fn test_resolve_validity_period() {
+        let reference = at(2024, 4, 30, 11, 0);
+        let (start, end) = resolve_validity_period("3012/0118", &reference).unwrap();
+        assert_eq!(start, at(2024, 4, 30, 12, 0));
+        assert_eq!(end, at(2024, 5, 1, 18, 0));
+
+        let (_, end) = resolve_validity_period("3006/3024", &reference).unwrap();
+        assert_eq!(end, at(2024, 5, 1, 0, 0));
+
+        assert!(resolve_validity_period("3112/0118", &reference).is_err());
+        assert!(resolve_validity_period("3012", &reference).is_err());
+        assert!(resolve_validity_period("3012/3006", &reference).is_err());
+    }
+}
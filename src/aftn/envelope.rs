@@ -0,0 +1,205 @@
+//! Parser de l'enveloppe télégraphique AFTN (ZCZC ... NNNN)
+//!
+//! Sur les circuits AFTN, un message est précédé d'un signal de début et d'une
+//! identification de transmission (`ZCZC ABC123`), les destinataires figurent
+//! sur leur propre ligne après la priorité, la ligne d'origine porte l'heure de
+//! dépôt et l'indicateur d'origine, et le message se termine par `NNNN`.
+
+use pest::Parser;
+use pest_derive::Parser;
+use crate::aftn::error::AftnError;
+use crate::diagnostic::SyntaxError;
+use crate::aftn::message::{AftnEnvelope, AftnMessage, Addresses, TransmissionTime};
+use crate::aftn::parser::AftnParser;
+
+#[derive(Parser)]
+#[grammar = "aftn/envelope.pest"]
+struct EnvelopeParser;
+
+/// Signal de début de message
+pub const START_OF_MESSAGE: &str = "ZCZC";
+
+/// Signal de fin de message
+pub const END_OF_MESSAGE: &str = "NNNN";
+
+/// Indique si l'entrée commence par un signal de début de message (ZCZC)
+pub fn is_envelope(input: &str) -> bool {
+    input.trim_start().starts_with(START_OF_MESSAGE)
+}
+
+/// Parse un message AFTN reçu dans son enveloppe télégraphique
+pub fn parse_envelope(input: &str) -> Result<AftnMessage, AftnError> {
+    let message = read_envelope(input)?;
+    message.validate()?;
+    Ok(message)
+}
+
+/// Lit un message dans son enveloppe télégraphique sans le valider
+pub(crate) fn read_envelope(input: &str) -> Result<AftnMessage, AftnError> {
+    let mut pairs = EnvelopeParser::parse(Rule::envelope, input)
+        .map_err(|e| AftnError::from(SyntaxError::from_pest(&e, input, "")))?;
+    
+    let envelope_pair = pairs.next().ok_or_else(|| {
+        AftnError::ParseError("Empty envelope parse result".to_string())
+    })?;
+    
+    let mut envelope = AftnEnvelope {
+        channel_id: String::new(),
+        channel_sequence: String::new(),
+        heading_info: None,
+        origin_info: None,
+        end_of_message: false,
+    };
+    let mut priority = String::new();
+    let mut origin = String::new();
+    let mut destinations = Vec::new();
+    let mut transmission_time = TransmissionTime { day: 0, hour: 0, minute: 0 };
+    let mut body = String::new();
+    
+    for inner_pair in envelope_pair.into_inner() {
+        match inner_pair.as_rule() {
+            Rule::heading => {
+                for heading_pair in inner_pair.into_inner() {
+                    match heading_pair.as_rule() {
+                        Rule::transmission_id => {
+                            for id_pair in heading_pair.into_inner() {
+                                match id_pair.as_rule() {
+                                    Rule::channel_id => envelope.channel_id = id_pair.as_str().to_string(),
+                                    Rule::channel_sequence => envelope.channel_sequence = id_pair.as_str().to_string(),
+                                    _ => {}
+                                }
+                            }
+                        }
+                        Rule::heading_info => {
+                            envelope.heading_info = Some(heading_pair.as_str().trim().to_string());
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            Rule::address_line | Rule::continuation_line => {
+                for address_pair in inner_pair.into_inner() {
+                    match address_pair.as_rule() {
+                        Rule::priority => priority = address_pair.as_str().to_string(),
+                        Rule::address => destinations.push(address_pair.as_str().to_string()),
+                        _ => {}
+                    }
+                }
+            }
+            Rule::origin_line => {
+                for origin_pair in inner_pair.into_inner() {
+                    match origin_pair.as_rule() {
+                        Rule::filing_time => {
+                            transmission_time = parse_filing_time(origin_pair)?;
+                        }
+                        Rule::origin => origin = origin_pair.as_str().to_string(),
+                        Rule::origin_info => {
+                            envelope.origin_info = Some(origin_pair.as_str().trim().to_string());
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            Rule::text => {
+                body = inner_pair.as_str().trim().to_string();
+            }
+            Rule::end_of_message => {
+                envelope.end_of_message = true;
+            }
+            _ => {}
+        }
+    }
+    
+    AftnParser::assemble_message(
+        priority,
+        Addresses {
+            origin,
+            destinations,
+        },
+        transmission_time,
+        body,
+        None,
+        Some(envelope),
+    )
+}
+
+fn parse_filing_time(pair: pest::iterators::Pair<Rule>) -> Result<TransmissionTime, AftnError> {
+    let mut values = [0u8; 3];
+    for (value, time_pair) in values.iter_mut().zip(pair.into_inner()) {
+        *value = time_pair.as_str().parse::<u8>()
+            .map_err(|e| AftnError::InvalidDateTime(format!("Invalid filing time: {}", e)))?;
+    }
+    Ok(TransmissionTime {
+        day: values[0],
+        hour: values[1],
+        minute: values[2],
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aftn::categories::MessageCategory;
+
+    const ENVELOPE: &str = "ZCZC ABC123 151230\r\nGG LFPOYYYX LFPBYYYX\r\n151229 LFPGYYYX\r\nNOTAM A1234/24 LFPG RWY 09/27 CLOSED\r\n\r\nNNNN";
+
+    #[test]
+    fn test_parse_envelope() {
+        let message = parse_envelope(ENVELOPE).unwrap();
+        let envelope = message.envelope.as_ref().unwrap();
+        
+        assert_eq!(envelope.channel_id, "ABC");
+        assert_eq!(envelope.channel_sequence, "123");
+        assert_eq!(envelope.heading_info, Some("151230".to_string()));
+        assert!(envelope.end_of_message);
+        assert_eq!(message.priority, "GG");
+        assert_eq!(message.addresses.origin, "LFPGYYYX");
+        assert_eq!(message.addresses.destinations, vec!["LFPOYYYX", "LFPBYYYX"]);
+        assert_eq!(message.transmission_time.day, 15);
+        assert_eq!(message.transmission_time.minute, 29);
+        assert_eq!(message.category, MessageCategory::Notam);
+        assert_eq!(message.body, "NOTAM A1234/24 LFPG RWY 09/27 CLOSED");
+    }
+
+    #[test]
+    fn test_parse_envelope_with_continuation_and_origin_info() {
+        let input = "ZCZC XYZ0042\nFF EGLLZPZX LFPGZPZX EDDFZPZX LIRFZPZX LEMDZPZX EHAMZPZX EBBRZPZX\nLSZHZPZX\n170830 LFPGZPZX ADDITIONAL INFO\n(DLA-AFR123-LFPG1030-EGLL)\nNNNN\n";
+        let message = parse_envelope(input).unwrap();
+        let envelope = message.envelope.as_ref().unwrap();
+        
+        assert_eq!(envelope.channel_sequence, "0042");
+        assert_eq!(envelope.heading_info, None);
+        assert_eq!(envelope.origin_info, Some("ADDITIONAL INFO".to_string()));
+        assert_eq!(message.addresses.destinations.len(), 8);
+        assert_eq!(message.category, MessageCategory::Delay);
+        assert!(message.submessage.is_parsed());
+    }
+
+    #[test]
+    fn test_parse_envelope_without_end_of_message() {
+        let message = parse_envelope("ZCZC ABC123\nGG LFPOYYYX\n151230 LFPGYYYX\nMETAR LFPG 151230Z 28015KT\n").unwrap();
+        assert!(!message.envelope.unwrap().end_of_message);
+        assert_eq!(message.body, "METAR LFPG 151230Z 28015KT");
+    }
+
+    #[test]
+    fn test_parse_envelope_invalid() {
+        assert!(parse_envelope("ZCZC 123\nGG LFPOYYYX\n151230 LFPGYYYX\nTEXT\nNNNN").is_err()); // Voie manquante
+        assert!(parse_envelope("ZCZC ABC123\nLFPOYYYX\n151230 LFPGYYYX\nTEXT\nNNNN").is_err()); // Priorité manquante
+        assert!(parse_envelope("ZCZC ABC123\nGG LFPOYYYX\nTEXT\nNNNN").is_err()); // Ligne d'origine manquante
+        assert!(parse_envelope("ZCZC ABC123\nXX LFPOYYYX\n151230 LFPGYYYX\nTEXT\nNNNN").is_err()); // Priorité invalide
+    }
+
+    #[test]
+    fn test_envelope_serialize_round_trip() {
+        let message = parse_envelope(ENVELOPE).unwrap();
+        let serialized = message.serialize();
+        assert_eq!(
+            serialized,
+            "ZCZC ABC123 151230\r\nGG LFPOYYYX LFPBYYYX\r\n151229 LFPGYYYX\r\nNOTAM A1234/24 LFPG RWY 09/27 CLOSED\r\nNNNN"
+        );
+        
+        let reparsed = AftnParser::parse_message(&serialized).unwrap();
+        assert_eq!(reparsed, message);
+    }
+}
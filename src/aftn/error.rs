@@ -1,6 +1,9 @@
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
+use crate::datetime::DateTimeError;
+use crate::diagnostic::{LocatedError, SyntaxError};
 
-#[derive(Error, Debug, PartialEq)]
+#[derive(Error, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum AftnError {
     #[error("Parse error: {0}")]
     ParseError(String),
@@ -25,5 +28,53 @@ pub enum AftnError {
     
     #[error("Message too short: min {min} characters, got {got}")]
     MessageTooShort { min: usize, got: usize },
+    
+    #[error("I/O error: {0}")]
+    Io(String),
+    
+    #[error("Parse error: {0}")]
+    Syntax(Box<SyntaxError>),
+    
+    /// Erreur de décodage d'un champ, localisée dans le texte analysé ; elle
+    /// garde le code de l'erreur d'origine
+    #[error("{error}")]
+    Located { error: Box<AftnError>, syntax: Box<SyntaxError> },
+}
+
+impl LocatedError for AftnError {
+    fn code(&self) -> &'static str {
+        match self {
+            AftnError::ParseError(_) => "AFTN001",
+            AftnError::InvalidFormat(_) => "AFTN002",
+            AftnError::InvalidPriority(_) => "AFTN003",
+            AftnError::InvalidAddress(_) => "AFTN004",
+            AftnError::InvalidCategory(_) => "AFTN005",
+            AftnError::InvalidDateTime(_) => "AFTN006",
+            AftnError::MessageTooLong { .. } => "AFTN007",
+            AftnError::MessageTooShort { .. } => "AFTN008",
+            AftnError::Io(_) => "AFTN009",
+            AftnError::Syntax(_) => "AFTN010",
+            AftnError::Located { error, .. } => error.code(),
+        }
+    }
+
+    fn syntax(&self) -> Option<&SyntaxError> {
+        match self {
+            AftnError::Syntax(error) => Some(error),
+            AftnError::Located { syntax, .. } => Some(syntax),
+            _ => None,
+        }
+    }
 }
 
+impl From<SyntaxError> for AftnError {
+    fn from(error: SyntaxError) -> Self {
+        AftnError::Syntax(Box::new(error))
+    }
+}
+
+impl From<DateTimeError> for AftnError {
+    fn from(error: DateTimeError) -> Self {
+        AftnError::InvalidDateTime(error.0)
+    }
+}
@@ -1,6 +1,10 @@
 use serde::{Deserialize, Serialize};
 use crate::aftn::error::AftnError;
 use crate::aftn::categories::MessageCategory;
+use crate::aftn::submessages::SubMessageStatus;
+use crate::aftn::datetime;
+use crate::datetime::UtcDateTime;
+use crate::diagnostic::Checks;
 
 /// Représente un message AFTN complet
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -14,7 +18,8 @@ pub struct AftnMessage {
     /// Catégorie du message
     pub category: MessageCategory,
     
-    /// Date et heure de transmission
+    /// Date et heure de transmission (heure de dépôt de la ligne d'origine
+    /// dans l'enveloppe télégraphique)
     pub transmission_time: TransmissionTime,
     
     /// Corps du message
@@ -22,6 +27,13 @@ pub struct AftnMessage {
     
     /// Numéro de séquence (optionnel)
     pub sequence_number: Option<String>,
+    
+    /// Enveloppe télégraphique (ZCZC ... NNNN), si le message a été reçu sous cette forme
+    pub envelope: Option<AftnEnvelope>,
+    
+    /// Sous-message typé parsé depuis le corps, ou l'erreur de parsing
+    #[serde(default)]
+    pub submessage: SubMessageStatus,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -45,6 +57,38 @@ pub struct TransmissionTime {
     pub minute: u8,
 }
 
+/// Éléments de l'enveloppe télégraphique AFTN (OACI Annexe 10, Volume II)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AftnEnvelope {
+    /// Identificateur de voie (3 lettres), ex: `ABC`
+    pub channel_id: String,
+    
+    /// Numéro de séquence de la voie (3-4 chiffres), ex: `123`
+    pub channel_sequence: String,
+    
+    /// Indication de service additionnelle sur la ligne ZCZC (optionnelle)
+    pub heading_info: Option<String>,
+    
+    /// Information additionnelle de la ligne d'origine (optionnelle)
+    pub origin_info: Option<String>,
+    
+    /// Signal de fin de message (NNNN) présent
+    pub end_of_message: bool,
+}
+
+/// Nombre maximal d'indicateurs de destinataires par ligne d'adresse
+const ADDRESSES_PER_LINE: usize = 7;
+
+impl TransmissionTime {
+    /// Résout le groupe DDHHMM en instant UTC absolu
+    /// 
+    /// `reference` est typiquement l'heure de réception du message. Les passages
+    /// de mois et d'année sont gérés ; une date inexistante (ex: 31 avril) est rejetée.
+    pub fn resolve(&self, reference: &UtcDateTime) -> Result<UtcDateTime, AftnError> {
+        datetime::resolve_day_time(self.day, self.hour, self.minute, reference)
+    }
+}
+
 impl AftnMessage {
     /// Valide la structure du message selon la spécification AFTN 3.4.
     /// 
@@ -65,15 +109,29 @@ impl AftnMessage {
     /// message.validate()?; // Valide la structure
     /// ```
     pub fn validate(&self) -> Result<(), AftnError> {
+        let mut checks = Checks::new();
+        self.run_checks(&mut checks);
+        checks.first()
+    }
+    
+    /// Contrôle le message comme [`validate`](Self::validate) mais retourne
+    /// tous les problèmes relevés au lieu du premier
+    pub fn check(&self) -> Vec<AftnError> {
+        let mut checks = Checks::new();
+        self.run_checks(&mut checks);
+        checks.0
+    }
+    
+    fn run_checks(&self, checks: &mut Checks<AftnError>) {
         // Validation de la priorité selon AFTN 3.4
         let valid_priorities = ["GG", "DD", "FF", "SS", "KK", "LL"];
         if !valid_priorities.contains(&self.priority.as_str()) {
-            return Err(AftnError::InvalidPriority(self.priority.clone()));
+            checks.push(AftnError::InvalidPriority(self.priority.clone()));
         }
         
         // Validation de l'adresse d'origine (7-8 caractères selon spécification AFTN 3.4)
-        if self.addresses.origin.len() < 7 || self.addresses.origin.len() > 8 {
-            return Err(AftnError::InvalidAddress(format!(
+        if !(7..=8).contains(&self.addresses.origin.len()) {
+            checks.push(AftnError::InvalidAddress(format!(
                 "Origin address must be 7-8 characters, got {}",
                 self.addresses.origin.len()
             )));
@@ -81,8 +139,8 @@ impl AftnMessage {
         
         // Validation des adresses de destination (7-8 caractères)
         for dest in &self.addresses.destinations {
-            if dest.len() < 7 || dest.len() > 8 {
-                return Err(AftnError::InvalidAddress(format!(
+            if !(7..=8).contains(&dest.len()) {
+                checks.push(AftnError::InvalidAddress(format!(
                     "Destination address must be 7-8 characters, got {}",
                     dest.len()
                 )));
@@ -90,28 +148,44 @@ impl AftnMessage {
         }
         
         // Validation de la date/heure
-        if self.transmission_time.day > 31 {
-            return Err(AftnError::InvalidDateTime(format!(
+        if !(1..=31).contains(&self.transmission_time.day) {
+            checks.push(AftnError::InvalidDateTime(format!(
                 "Day must be between 01-31, got {}",
                 self.transmission_time.day
             )));
         }
         
         if self.transmission_time.hour > 23 {
-            return Err(AftnError::InvalidDateTime(format!(
+            checks.push(AftnError::InvalidDateTime(format!(
                 "Hour must be between 00-23, got {}",
                 self.transmission_time.hour
             )));
         }
         
         if self.transmission_time.minute > 59 {
-            return Err(AftnError::InvalidDateTime(format!(
+            checks.push(AftnError::InvalidDateTime(format!(
                 "Minute must be between 00-59, got {}",
                 self.transmission_time.minute
             )));
         }
         
-        Ok(())
+        // Validation de l'identification de transmission
+        if let Some(ref envelope) = self.envelope {
+            if envelope.channel_id.len() != 3 || !envelope.channel_id.chars().all(|c| c.is_ascii_uppercase()) {
+                checks.push(AftnError::InvalidFormat(format!(
+                    "Channel identifier must be 3 uppercase letters, got: {}",
+                    envelope.channel_id
+                )));
+            }
+            
+            let sequence_len = envelope.channel_sequence.len();
+            if !(3..=4).contains(&sequence_len) || !envelope.channel_sequence.chars().all(|c| c.is_ascii_digit()) {
+                checks.push(AftnError::InvalidFormat(format!(
+                    "Channel sequence number must be 3-4 digits, got: {}",
+                    envelope.channel_sequence
+                )));
+            }
+        }
     }
     
     /// Sérialise le message AFTN en chaîne de caractères.
@@ -119,6 +193,9 @@ impl AftnMessage {
     /// Reconstruit le message dans le format AFTN standard sans espaces/tabulations supplémentaires.
     /// Format: `[PRIORITY] [ORIGIN] [DEST1] [DEST2] ... [DDHHMM] [BODY] [/SEQ NUMBER]`
     /// 
+    /// Si le message porte une enveloppe télégraphique, il est reproduit sous
+    /// cette forme (ZCZC, ligne d'adresse, ligne d'origine, texte, NNNN).
+    /// 
     /// # Returns
     /// * `String` - Message AFTN sérialisé
     /// 
@@ -131,6 +208,10 @@ impl AftnMessage {
     /// assert_eq!(serialized, "GG LFPGYYYX LFPOYYYX 151230 NOTAM A1234/24");
     /// ```
     pub fn serialize(&self) -> String {
+        if let Some(ref envelope) = self.envelope {
+            return self.serialize_envelope(envelope);
+        }
+        
         let mut result = String::new();
         
         // Priorité
@@ -169,6 +250,65 @@ impl AftnMessage {
         
         result
     }
+    
+    /// Sérialise le message sous forme d'enveloppe télégraphique.
+    /// 
+    /// Format (lignes séparées par CR LF):
+    /// ```text
+    /// ZCZC [CHANNEL][SEQUENCE] [HEADING_INFO]
+    /// [PRIORITY] [DEST1] ... [DEST7]
+    /// [DEST8] ...
+    /// [DDHHMM] [ORIGIN] [ORIGIN_INFO]
+    /// [BODY]
+    /// NNNN
+    /// ```
+    fn serialize_envelope(&self, envelope: &AftnEnvelope) -> String {
+        let mut lines = Vec::new();
+        
+        // Ligne de début et identification de transmission
+        let mut heading = format!("ZCZC {}{}", envelope.channel_id, envelope.channel_sequence);
+        if let Some(ref info) = envelope.heading_info {
+            heading.push(' ');
+            heading.push_str(info);
+        }
+        lines.push(heading);
+        
+        // Priorité et destinataires, 7 indicateurs au maximum par ligne
+        for (idx, chunk) in self.addresses.destinations.chunks(ADDRESSES_PER_LINE).enumerate() {
+            let addresses = chunk.join(" ");
+            if idx == 0 {
+                lines.push(format!("{} {}", self.priority, addresses));
+            } else {
+                lines.push(addresses);
+            }
+        }
+        
+        // Ligne d'origine
+        let mut origin = format!(
+            "{:02}{:02}{:02} {}",
+            self.transmission_time.day,
+            self.transmission_time.hour,
+            self.transmission_time.minute,
+            self.addresses.origin
+        );
+        if let Some(ref info) = envelope.origin_info {
+            origin.push(' ');
+            origin.push_str(info);
+        }
+        lines.push(origin);
+        
+        // Texte du message
+        if !self.body.trim().is_empty() {
+            lines.push(self.body.trim().to_string());
+        }
+        
+        // Signal de fin de message
+        if envelope.end_of_message {
+            lines.push("NNNN".to_string());
+        }
+        
+        lines.join("\r\n")
+    }
 }
 
 #[cfg(test)]
@@ -192,6 +332,11 @@ mod tests {
             },
             body: "NOTAM A1234/24 LFPG RWY 09/27 CLOSED".to_string(),
             sequence_number: None,
+            envelope: None,
+            submessage: SubMessageStatus::parse(
+                &crate::aftn::categories::MessageCategory::Notam,
+                "NOTAM A1234/24 LFPG RWY 09/27 CLOSED",
+            ),
         };
 
         let serialized = message.serialize();
@@ -218,6 +363,11 @@ mod tests {
             },
             body: "NOTAM A1234/24".to_string(),
             sequence_number: Some("001".to_string()),
+            envelope: None,
+            submessage: SubMessageStatus::parse(
+                &crate::aftn::categories::MessageCategory::Notam,
+                "NOTAM A1234/24",
+            ),
         };
 
         let serialized = message.serialize();
@@ -236,5 +386,17 @@ mod tests {
         assert_eq!(message.addresses.origin, reparsed.addresses.origin);
         assert_eq!(message.addresses.destinations, reparsed.addresses.destinations);
     }
+
+    #[test]
+    fn test_deserialize_without_submessage() {
+        let message = AftnParser::parse_message("GG LFPGYYYX LFPOYYYX 151230 NOTAM A1234/24").unwrap();
+        let mut json = serde_json::to_value(&message).unwrap();
+        json.as_object_mut().unwrap().remove("submessage");
+
+        let deserialized: AftnMessage = serde_json::from_value(json).unwrap();
+        assert_eq!(deserialized.body, message.body);
+        assert_eq!(deserialized.submessage, SubMessageStatus::NotParsed);
+        assert!(deserialized.submessage.error().is_none());
+    }
 }
 
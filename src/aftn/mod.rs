@@ -2,6 +2,8 @@
 //! 
 //! Ce module contient tous les composants liés au format AFTN :
 //! - Parser pour les messages AFTN
+//! - Lecture en flux de journaux AFTN concaténés
+//! - Construction programmatique de messages (builders)
 //! - Structures de données pour les messages
 //! - Catégories de messages
 //! - Sous-messages spécifiques par catégorie
@@ -13,9 +15,17 @@ pub mod error;
 pub mod categories;
 pub mod submessages;
 pub mod validation;
+pub mod envelope;
+pub mod stream;
+pub mod datetime;
+pub mod builder;
 
 pub use error::AftnError;
-pub use message::AftnMessage;
+pub use message::{AftnMessage, AftnEnvelope};
 pub use parser::AftnParser;
+pub use stream::AftnStreamReader;
+pub use crate::datetime::UtcDateTime;
+pub use builder::AftnMessageBuilder;
 pub use categories::MessageCategory;
+pub use submessages::{SubMessageData, SubMessageStatus};
 
@@ -1,8 +1,14 @@
 use pest::Parser;
 use pest_derive::Parser;
-use crate::aftn::message::{AftnMessage, Addresses, TransmissionTime};
+use crate::aftn::message::{AftnMessage, AftnEnvelope, Addresses, TransmissionTime};
+use crate::aftn::envelope::{is_envelope, parse_envelope, read_envelope};
+use crate::aftn::builder::{MAX_ADDRESSEES, MAX_LINE_LENGTH};
 use crate::aftn::categories::MessageCategory;
+use crate::aftn::categories::meteorological::icao_notam::is_icao_notam;
+use crate::aftn::categories::meteorological::weather_warning::warning_category;
 use crate::aftn::error::AftnError;
+use crate::diagnostic::{Diagnostic, Lenient, LocatedError, Severity, Span, SyntaxError};
+use crate::aftn::submessages::{SubMessageData, SubMessageStatus};
 
 #[cfg(test)]
 mod tests {
@@ -78,6 +84,31 @@ mod tests {
         assert_eq!(message.category, MessageCategory::Notam);
     }
 
+    #[test]
+    fn test_parse_message_keeps_submessage() {
+        use crate::aftn::submessages::SubMessageData;
+        
+        let input = "SS LFPGYYYX LFPOYYYX 151200 FPL ABC123 V LFPG 151200 LFPB 1800";
+        let message = AftnParser::parse_message(input).unwrap();
+        
+        match message.submessage.parsed() {
+            Some(SubMessageData::FlightPlan(fpl)) => {
+                assert_eq!(fpl.callsign, Some("ABC123".to_string()));
+            }
+            other => panic!("Sous-message FPL attendu, obtenu {:?}", other),
+        }
+        assert!(message.submessage.error().is_none());
+    }
+
+    #[test]
+    fn test_parse_message_reports_submessage_failure() {
+        let input = "SS LFPGYYYX LFPOYYYX 151200 FPL";
+        let message = AftnParser::parse_message(input).unwrap();
+        
+        assert!(!message.submessage.is_parsed());
+        assert!(message.submessage.error().is_some());
+    }
+
     #[test]
     fn test_all_priorities() {
         let priorities = vec!["GG", "DD", "FF", "SS", "KK", "LL"];
@@ -100,9 +131,121 @@ pub struct AftnParser;
 
 impl AftnParser {
     /// Parse un message AFTN complet
+    /// 
+    /// Accepte le format sur une ligne (`PRIORITY ORIGIN DEST... DDHHMM BODY`)
+    /// et l'enveloppe télégraphique multi-lignes (`ZCZC ... NNNN`).
     pub fn parse_message(input: &str) -> Result<AftnMessage, AftnError> {
+        if is_envelope(input) {
+            return parse_envelope(input);
+        }
+        
+        let message = Self::read_message(input)?;
+        message.validate()?;
+        Ok(message)
+    }
+    
+    /// Parse un message AFTN en mode tolérant
+    /// 
+    /// Au lieu de s'arrêter au premier problème, reconstruit le message au
+    /// mieux et relève tous les problèmes : erreurs de validation, échec du
+    /// sous-message, lignes de texte trop longues, trop de destinataires,
+    /// enveloppe sans `NNNN`, catégorie non reconnue. Seule une erreur de
+    /// syntaxe sur l'en-tête empêche de reconstruire le message.
+    pub fn parse_lenient(input: &str) -> Lenient<AftnMessage> {
+        let read = if is_envelope(input) {
+            read_envelope(input)
+        } else {
+            Self::read_message(input)
+        };
+        let message = match read {
+            Ok(message) => message,
+            Err(error) => {
+                return Lenient { message: None, diagnostics: vec![error.to_diagnostic()] };
+            }
+        };
+        
+        let mut diagnostics: Vec<Diagnostic> = message.check().iter()
+            .map(AftnError::to_diagnostic)
+            .collect();
+        
+        // Les positions du sous-message sont relatives au corps
+        let body_offset = input.find(message.body.as_str());
+        if let Some(error) = message.submessage.error() {
+            let mut diagnostic = error.to_diagnostic();
+            if let (Some(span), Some(offset)) = (diagnostic.span, body_offset) {
+                diagnostic.span = Some(Span::new(input, offset + span.start, offset + span.end));
+            }
+            diagnostics.push(diagnostic);
+        }
+        
+        let mut line_start = body_offset.unwrap_or(0);
+        for line in message.body.split_inclusive('\n') {
+            let text = line.trim_end();
+            if text.chars().count() > MAX_LINE_LENGTH {
+                let mut diagnostic = Diagnostic::new(
+                    Severity::Warning,
+                    "AFTN101",
+                    format!("Text line exceeds {} characters", MAX_LINE_LENGTH),
+                );
+                if body_offset.is_some() {
+                    diagnostic = diagnostic.with_span(Span::new(input, line_start, line_start + text.len()));
+                }
+                diagnostics.push(diagnostic);
+            }
+            line_start += line.len();
+        }
+        
+        if message.addresses.destinations.len() > MAX_ADDRESSEES {
+            diagnostics.push(Diagnostic::new(
+                Severity::Warning,
+                "AFTN102",
+                format!(
+                    "Too many addressees: {} (at most {})",
+                    message.addresses.destinations.len(),
+                    MAX_ADDRESSEES
+                ),
+            ));
+        }
+        
+        if message.envelope.as_ref().is_some_and(|envelope| !envelope.end_of_message) {
+            diagnostics.push(Diagnostic::new(
+                Severity::Warning,
+                "AFTN103",
+                "Envelope is not terminated by NNNN",
+            ));
+        }
+        
+        if let Some(SubMessageData::Taf(taf)) = message.submessage.parsed() {
+            for group in &taf.malformed_groups {
+                let mut diagnostic = Diagnostic::new(
+                    Severity::Warning,
+                    "AFTN104",
+                    format!("TAF change group '{}' kept undecoded: {}", group.raw, group.error),
+                );
+                let position = body_offset
+                    .and_then(|offset| input[offset..].find(group.raw.as_str()).map(|position| offset + position));
+                if let Some(start) = position {
+                    diagnostic = diagnostic.with_span(Span::new(input, start, start + group.raw.len()));
+                }
+                diagnostics.push(diagnostic);
+            }
+        }
+        
+        if matches!(message.category, MessageCategory::Generic | MessageCategory::Operational(_)) {
+            diagnostics.push(Diagnostic::new(
+                Severity::Info,
+                "AFTN201",
+                "Message category not recognised, body kept as text",
+            ));
+        }
+        
+        Lenient { message: Some(message), diagnostics }
+    }
+    
+    /// Lit un message sur une ligne sans le valider
+    fn read_message(input: &str) -> Result<AftnMessage, AftnError> {
         let mut pairs = AftnParser::parse(Rule::message, input)
-            .map_err(|e| AftnError::ParseError(format!("{}", e)))?;
+            .map_err(|e| AftnError::from(SyntaxError::from_pest(&e, input, "").with_field_index(input, ' ')))?;
         
         let message_pair = pairs.next().ok_or_else(|| {
             AftnError::ParseError("Empty parse result".to_string())
@@ -169,34 +312,75 @@ impl AftnParser {
             }
         }
         
-        // Détecter la catégorie depuis le corps du message
-        let category = if body.len() >= 3 {
-            MessageCategory::from_message_id(&body[..3])?
-        } else {
-            MessageCategory::Generic
-        };
-        
-        // Parser le sous-message selon la catégorie (pour validation)
-        let _submessage = crate::aftn::submessages::parse_submessage(&category, &body).ok();
-        
-        let message = AftnMessage {
+        Self::assemble_message(
             priority,
-            addresses: Addresses {
+            Addresses {
                 origin,
                 destinations,
             },
-            category,
-            transmission_time: TransmissionTime {
+            TransmissionTime {
                 day,
                 hour,
                 minute,
             },
             body,
             sequence_number,
-        };
-        
+            None,
+        )
+    }
+    
+    /// Construit et valide un message à partir de ses éléments parsés
+    /// (format plat ou enveloppe télégraphique)
+    pub(crate) fn build_message(
+        priority: String,
+        addresses: Addresses,
+        transmission_time: TransmissionTime,
+        body: String,
+        sequence_number: Option<String>,
+        envelope: Option<AftnEnvelope>,
+    ) -> Result<AftnMessage, AftnError> {
+        let message = Self::assemble_message(priority, addresses, transmission_time, body, sequence_number, envelope)?;
         message.validate()?;
         Ok(message)
     }
+    
+    /// Construit un message à partir de ses éléments parsés, sans le valider
+    pub(crate) fn assemble_message(
+        priority: String,
+        addresses: Addresses,
+        transmission_time: TransmissionTime,
+        body: String,
+        sequence_number: Option<String>,
+        envelope: Option<AftnEnvelope>,
+    ) -> Result<AftnMessage, AftnError> {
+        // Détecter la catégorie depuis le corps du message
+        // (les messages ATS OACI commencent par une parenthèse: "(FPL-...")
+        // (les NOTAM OACI commencent par leur identification: "(A1234/24 NOTAMN")
+        // (les SIGMET/AIRMET OACI commencent par l'unité ATS: "LFFF SIGMET 1 VALID...")
+        let message_id = body.strip_prefix('(').unwrap_or(&body);
+        let category = if is_icao_notam(&body) {
+            MessageCategory::Notam
+        } else if let Some(category) = warning_category(&body) {
+            category
+        } else if message_id.len() >= 3 {
+            MessageCategory::from_message_id(&message_id[..3])?
+        } else {
+            MessageCategory::Generic
+        };
+        
+        // Parser le sous-message selon la catégorie, en conservant l'erreur éventuelle
+        let submessage = SubMessageStatus::parse(&category, &body);
+        
+        Ok(AftnMessage {
+            priority,
+            addresses,
+            category,
+            transmission_time,
+            body,
+            sequence_number,
+            envelope,
+            submessage,
+        })
+    }
 }
 
@@ -0,0 +1,314 @@
+//! Lecture en flux de messages AFTN concaténés
+//!
+//! Les journaux des commutateurs AFTN contiennent des milliers de messages
+//! délimités par `ZCZC` / `NNNN`, séparés par du bruit de ligne (caractères de
+//! remplissage, sauts de page, lignes de service). `AftnStreamReader` découpe
+//! ces journaux à la volée et parse chaque message avec `AftnParser`.
+
+use std::io::{BufRead, Read};
+use crate::aftn::envelope::{END_OF_MESSAGE, START_OF_MESSAGE};
+use crate::aftn::error::AftnError;
+use crate::aftn::message::AftnMessage;
+use crate::aftn::parser::AftnParser;
+
+/// Taille maximale par défaut d'un message, enveloppe comprise (en octets)
+pub const DEFAULT_MAX_MESSAGE_SIZE: usize = 64 * 1024;
+
+/// Découpe un flux AFTN (`BufRead`) en messages, de manière paresseuse
+///
+/// Chaque message est recherché à partir de son signal de début `ZCZC` et
+/// se termine au `NNNN` suivant. Tout ce qui se trouve entre deux messages est
+/// ignoré. Un message corrompu produit une erreur sans interrompre la lecture :
+/// le lecteur se resynchronise sur le `ZCZC` suivant. Un message qui dépasse
+/// la taille maximale sans `NNNN` (terminaison perdue) est abandonné avec une
+/// erreur `MessageTooLong` au lieu d'accumuler le reste du flux en mémoire.
+///
+/// ```no_run
+/// use std::fs::File;
+/// use std::io::BufReader;
+/// use aftn::aftn::AftnStreamReader;
+///
+/// let file = File::open("switch.log").unwrap();
+/// let mut reader = AftnStreamReader::new(BufReader::new(file));
+/// while let Some((offset, result)) = reader.next_with_offset() {
+///     match result {
+///         Ok(message) => println!("{}: {:?}", offset, message.category),
+///         Err(e) => eprintln!("{}: {}", offset, e),
+///     }
+/// }
+/// ```
+pub struct AftnStreamReader<R: BufRead> {
+    reader: R,
+    /// Nombre d'octets déjà lus depuis le flux
+    position: u64,
+    /// Reste d'une ligne non encore traité, avec son offset
+    pending: Option<(u64, Vec<u8>)>,
+    /// Offset du dernier message produit
+    last_offset: Option<u64>,
+    /// Taille maximale d'un message (en octets)
+    max_message_size: usize,
+    finished: bool,
+}
+
+impl<R: BufRead> AftnStreamReader<R> {
+    /// Crée un lecteur sur un flux quelconque
+    pub fn new(reader: R) -> Self {
+        AftnStreamReader {
+            reader,
+            position: 0,
+            pending: None,
+            last_offset: None,
+            max_message_size: DEFAULT_MAX_MESSAGE_SIZE,
+            finished: false,
+        }
+    }
+    
+    /// Fixe la taille maximale d'un message (`DEFAULT_MAX_MESSAGE_SIZE` par défaut)
+    pub fn with_max_message_size(mut self, max_message_size: usize) -> Self {
+        self.max_message_size = max_message_size;
+        self
+    }
+    
+    /// Offset (en octets depuis le début du flux) du `ZCZC` du dernier message produit
+    pub fn last_offset(&self) -> Option<u64> {
+        self.last_offset
+    }
+    
+    /// Nombre d'octets lus depuis le début du flux
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+    
+    /// Restitue le flux sous-jacent
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+    
+    /// Lit le message suivant et renvoie l'offset de son `ZCZC` avec le résultat du parsing
+    pub fn next_with_offset(&mut self) -> Option<(u64, Result<AftnMessage, AftnError>)> {
+        // Message en cours: (offset du ZCZC, octets accumulés)
+        let mut current: Option<(u64, Vec<u8>)> = None;
+        
+        loop {
+            let (segment_offset, segment) = match self.next_segment() {
+                Ok(Some(segment)) => segment,
+                Ok(None) => break,
+                Err(e) => {
+                    self.finished = true;
+                    return Some((self.position, Err(e)));
+                }
+            };
+            
+            let (offset, mut buffer, search_from) = match current.take() {
+                Some((offset, buffer)) => {
+                    // Un signal peut être coupé entre deux segments
+                    let search_from = buffer.len()
+                        .saturating_sub(END_OF_MESSAGE.len() - 1)
+                        .max(START_OF_MESSAGE.len());
+                    let mut buffer = buffer;
+                    buffer.extend_from_slice(&segment);
+                    (offset, buffer, search_from)
+                }
+                None => match find(&segment, START_OF_MESSAGE.as_bytes(), 0) {
+                    // Bruit de ligne entre deux messages
+                    None => continue,
+                    Some(start) => (
+                        segment_offset + start as u64,
+                        segment[start..].to_vec(),
+                        START_OF_MESSAGE.len(),
+                    ),
+                },
+            };
+            
+            let end = find(&buffer, END_OF_MESSAGE.as_bytes(), search_from);
+            let next_start = find(&buffer, START_OF_MESSAGE.as_bytes(), search_from);
+            
+            match (end, next_start) {
+                // Un nouveau ZCZC avant le NNNN: le message courant est tronqué
+                (_, Some(start)) if end.is_none_or(|end| start < end) => {
+                    let rest = buffer.split_off(start);
+                    self.pending = Some((offset + start as u64, rest));
+                    self.last_offset = Some(offset);
+                    return Some((offset, Err(AftnError::InvalidFormat(format!(
+                        "Message at byte offset {} is truncated: {} found before {}",
+                        offset, START_OF_MESSAGE, END_OF_MESSAGE
+                    )))));
+                }
+                (Some(end), _) => {
+                    let split = end + END_OF_MESSAGE.len();
+                    let rest = buffer.split_off(split);
+                    if !rest.is_empty() {
+                        self.pending = Some((offset + split as u64, rest));
+                    }
+                    if buffer.len() > self.max_message_size {
+                        return Some(self.too_long(offset, buffer.len()));
+                    }
+                    return Some(self.emit(offset, &buffer));
+                }
+                // Pas de NNNN dans la taille maximale : le message est abandonné et
+                // le lecteur se resynchronise sur le ZCZC suivant
+                _ if buffer.len() > self.max_message_size => {
+                    return Some(self.too_long(offset, buffer.len()));
+                }
+                _ => current = Some((offset, buffer)),
+            }
+        }
+        
+        // Fin du flux: un message commencé sans NNNN est tout de même parsé
+        current.map(|(offset, buffer)| self.emit(offset, &buffer))
+    }
+    
+    fn emit(&mut self, offset: u64, buffer: &[u8]) -> (u64, Result<AftnMessage, AftnError>) {
+        self.last_offset = Some(offset);
+        let text = String::from_utf8_lossy(buffer);
+        (offset, AftnParser::parse_message(&text))
+    }
+    
+    fn too_long(&mut self, offset: u64, size: usize) -> (u64, Result<AftnMessage, AftnError>) {
+        self.last_offset = Some(offset);
+        (offset, Err(AftnError::MessageTooLong { max: self.max_message_size, got: size }))
+    }
+    
+    /// Renvoie le reste de ligne en attente, ou la ligne suivante du flux
+    fn next_segment(&mut self) -> Result<Option<(u64, Vec<u8>)>, AftnError> {
+        if let Some(pending) = self.pending.take() {
+            return Ok(Some(pending));
+        }
+        if self.finished {
+            return Ok(None);
+        }
+        
+        // Une ligne sans fin est lue par morceaux de la taille maximale d'un message
+        let mut line = Vec::new();
+        let limit = self.max_message_size as u64 + 1;
+        let read = (&mut self.reader).take(limit).read_until(b'\n', &mut line)
+            .map_err(|e| AftnError::Io(e.to_string()))?;
+        if read == 0 {
+            self.finished = true;
+            return Ok(None);
+        }
+        
+        let offset = self.position;
+        self.position += read as u64;
+        Ok(Some((offset, line)))
+    }
+}
+
+impl<R: BufRead> Iterator for AftnStreamReader<R> {
+    type Item = Result<AftnMessage, AftnError>;
+    
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_with_offset().map(|(_, result)| result)
+    }
+}
+
+fn find(haystack: &[u8], needle: &[u8], from: usize) -> Option<usize> {
+    haystack.get(from..)?
+        .windows(needle.len())
+        .position(|window| window == needle)
+        .map(|position| position + from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+    use crate::aftn::categories::MessageCategory;
+
+    const FIRST: &str = "ZCZC ABC001\r\nGG LFPOYYYX\r\n151230 LFPGYYYX\r\nNOTAM A1234/24 LFPG RWY 09/27 CLOSED\r\nNNNN\r\n";
+    const SECOND: &str = "ZCZC ABC002\r\nDD LFPBYYYX\r\n151231 LFPGYYYX\r\nMETAR LFPG 151230Z 28015KT 9999 FEW030 12/08 Q1013\r\nNNNN\r\n";
+
+    #[test]
+    fn test_stream_reader_splits_messages() {
+        let input = format!("{}\x0c\n\n{}", FIRST, SECOND);
+        let mut reader = AftnStreamReader::new(Cursor::new(input.as_bytes()));
+        
+        let (offset, first) = reader.next_with_offset().unwrap();
+        assert_eq!(offset, 0);
+        assert_eq!(first.unwrap().category, MessageCategory::Notam);
+        
+        let (offset, second) = reader.next_with_offset().unwrap();
+        assert_eq!(offset, (FIRST.len() + 3) as u64);
+        assert_eq!(second.unwrap().envelope.unwrap().channel_sequence, "002");
+        assert_eq!(reader.last_offset(), Some(offset));
+        
+        assert!(reader.next_with_offset().is_none());
+        assert_eq!(reader.position(), input.len() as u64);
+    }
+
+    #[test]
+    fn test_stream_reader_skips_noise() {
+        let input = format!("SVC QTA\n\x07\x07{}garbage\n\u{0}\u{0}{}", FIRST, SECOND);
+        let reader = AftnStreamReader::new(Cursor::new(input.as_bytes()));
+        let messages: Vec<_> = reader.collect();
+        
+        assert_eq!(messages.len(), 2);
+        assert!(messages.iter().all(|m| m.is_ok()));
+    }
+
+    #[test]
+    fn test_stream_reader_resynchronises_after_truncated_message() {
+        let truncated = "ZCZC ABC000\r\nGG LFPOYYYX\r\n151229 LFP";
+        let input = format!("{}{}", truncated, SECOND);
+        let mut reader = AftnStreamReader::new(Cursor::new(input.as_bytes()));
+        
+        let (offset, first) = reader.next_with_offset().unwrap();
+        assert_eq!(offset, 0);
+        assert!(first.is_err());
+        
+        let (offset, second) = reader.next_with_offset().unwrap();
+        assert_eq!(offset, truncated.len() as u64);
+        assert_eq!(second.unwrap().category, MessageCategory::Metar);
+        assert!(reader.next().is_none());
+    }
+
+    #[test]
+    fn test_stream_reader_continues_after_corrupt_message() {
+        let corrupt = "ZCZC ABC000\r\nXX LFPOYYYX\r\nGARBAGE\r\nNNNN\r\n";
+        let input = format!("{}{}{}", FIRST, corrupt, SECOND);
+        let results: Vec<_> = AftnStreamReader::new(Cursor::new(input.as_bytes())).collect();
+        
+        assert_eq!(results.len(), 3);
+        assert!(results[0].is_ok());
+        assert!(results[1].is_err());
+        assert!(results[2].is_ok());
+    }
+
+    #[test]
+    fn test_stream_reader_messages_on_same_line() {
+        let input = "ZCZC ABC001 GG LFPOYYYX 151230 LFPGYYYX TEST NNNNZCZC ABC002\nGG LFPOYYYX\n151231 LFPGYYYX\nTEST\nNNNN";
+        let mut reader = AftnStreamReader::new(Cursor::new(input.as_bytes()));
+        
+        let (offset, _) = reader.next_with_offset().unwrap();
+        assert_eq!(offset, 0);
+        let (offset, second) = reader.next_with_offset().unwrap();
+        assert_eq!(offset, input.find("ZCZC ABC002").unwrap() as u64);
+        assert!(second.is_ok());
+    }
+
+    #[test]
+    fn test_stream_reader_skips_oversized_message() {
+        let runaway = format!("ZCZC ABC000\r\nGG LFPOYYYX\r\n{}\r\n", "X".repeat(500));
+        let input = format!("{}{}{}", FIRST, runaway, SECOND);
+        let mut reader = AftnStreamReader::new(Cursor::new(input.as_bytes()))
+            .with_max_message_size(200);
+        
+        assert!(reader.next_with_offset().unwrap().1.is_ok());
+        let (offset, oversized) = reader.next_with_offset().unwrap();
+        assert_eq!(offset, FIRST.len() as u64);
+        assert!(matches!(oversized, Err(AftnError::MessageTooLong { max: 200, .. })));
+        let (offset, second) = reader.next_with_offset().unwrap();
+        assert_eq!(offset, (FIRST.len() + runaway.len()) as u64);
+        assert_eq!(second.unwrap().category, MessageCategory::Metar);
+        assert!(reader.next().is_none());
+    }
+
+    #[test]
+    fn test_stream_reader_last_message_without_end_signal() {
+        let input = "ZCZC ABC001\nGG LFPOYYYX\n151230 LFPGYYYX\nTEST MESSAGE\n";
+        let results: Vec<_> = AftnStreamReader::new(Cursor::new(input.as_bytes())).collect();
+        
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].as_ref().unwrap().body, "TEST MESSAGE");
+    }
+}
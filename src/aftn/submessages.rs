@@ -1,6 +1,13 @@
 //! Trait et fonctions pour les sous-messages AFTN
 
+use serde::{Deserialize, Serialize};
 use crate::aftn::categories::MessageCategory;
+use crate::aftn::categories::meteorological::*;
+use crate::aftn::categories::flight_plan::*;
+use crate::aftn::categories::coordination::*;
+use crate::aftn::categories::position::*;
+use crate::aftn::categories::alerting::*;
+use crate::aftn::categories::operational::*;
 use crate::aftn::error::AftnError;
 
 /// Trait pour les sous-messages AFTN
@@ -17,108 +24,238 @@ pub trait SubMessage: std::fmt::Debug {
     fn category(&self) -> MessageCategory;
 }
 
+/// Sous-message typé, une variante par type de message concret.
+///
+/// Les catégories sans structure dédiée (RQP, DEN, RLS, URG, ...) sont
+/// représentées par la variante `Operational`. Les messages CPL et UPL
+/// utilisent `FlightPlan` lorsque le corps suit la syntaxe FPL.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum SubMessageData {
+    // Messages météorologiques
+    Notam(NotamMessage),
+    Metar(MetarMessage),
+    Taf(TafMessage),
+    Sigmet(SigmetMessage),
+    Airmet(AirmetMessage),
+    Atis(AtisMessage),
+    Volmet(VolmetMessage),
+    
+    // Messages de plan de vol
+    FlightPlan(FplMessage),
+    Change(ChgMessage),
+    Cancel(CnlMessage),
+    Delay(DlaMessage),
+    Departure(DepMessage),
+    Arrival(ArrMessage),
+    Estimate(EstMessage),
+    SupplementaryFlightPlan(SplMessage),
+    
+    // Messages de coordination
+    Coordination(CofMessage),
+    AdvanceBoundaryInformation(AbiMessage),
+    Request(ReqMessage),
+    
+    // Messages de position, d'alerte et divers
+    PositionReport(PosMessage),
+    Alerting(AlrMessage),
+    Operational(OperationalMessage),
+    Generic(GenericMessage),
+}
+
+impl SubMessageData {
+    /// Accès au sous-message via le trait `SubMessage`
+    pub fn as_submessage(&self) -> &dyn SubMessage {
+        match self {
+            SubMessageData::Notam(msg) => msg,
+            SubMessageData::Metar(msg) => msg,
+            SubMessageData::Taf(msg) => msg,
+            SubMessageData::Sigmet(msg) => msg,
+            SubMessageData::Airmet(msg) => msg,
+            SubMessageData::Atis(msg) => msg,
+            SubMessageData::Volmet(msg) => msg,
+            SubMessageData::FlightPlan(msg) => msg,
+            SubMessageData::Change(msg) => msg,
+            SubMessageData::Cancel(msg) => msg,
+            SubMessageData::Delay(msg) => msg,
+            SubMessageData::Departure(msg) => msg,
+            SubMessageData::Arrival(msg) => msg,
+            SubMessageData::Estimate(msg) => msg,
+            SubMessageData::SupplementaryFlightPlan(msg) => msg,
+            SubMessageData::Coordination(msg) => msg,
+            SubMessageData::AdvanceBoundaryInformation(msg) => msg,
+            SubMessageData::Request(msg) => msg,
+            SubMessageData::PositionReport(msg) => msg,
+            SubMessageData::Alerting(msg) => msg,
+            SubMessageData::Operational(msg) => msg,
+            SubMessageData::Generic(msg) => msg,
+        }
+    }
+    
+    /// Convertit le sous-message en objet trait
+    pub fn into_boxed(self) -> Box<dyn SubMessage> {
+        match self {
+            SubMessageData::Notam(msg) => Box::new(msg),
+            SubMessageData::Metar(msg) => Box::new(msg),
+            SubMessageData::Taf(msg) => Box::new(msg),
+            SubMessageData::Sigmet(msg) => Box::new(msg),
+            SubMessageData::Airmet(msg) => Box::new(msg),
+            SubMessageData::Atis(msg) => Box::new(msg),
+            SubMessageData::Volmet(msg) => Box::new(msg),
+            SubMessageData::FlightPlan(msg) => Box::new(msg),
+            SubMessageData::Change(msg) => Box::new(msg),
+            SubMessageData::Cancel(msg) => Box::new(msg),
+            SubMessageData::Delay(msg) => Box::new(msg),
+            SubMessageData::Departure(msg) => Box::new(msg),
+            SubMessageData::Arrival(msg) => Box::new(msg),
+            SubMessageData::Estimate(msg) => Box::new(msg),
+            SubMessageData::SupplementaryFlightPlan(msg) => Box::new(msg),
+            SubMessageData::Coordination(msg) => Box::new(msg),
+            SubMessageData::AdvanceBoundaryInformation(msg) => Box::new(msg),
+            SubMessageData::Request(msg) => Box::new(msg),
+            SubMessageData::PositionReport(msg) => Box::new(msg),
+            SubMessageData::Alerting(msg) => Box::new(msg),
+            SubMessageData::Operational(msg) => Box::new(msg),
+            SubMessageData::Generic(msg) => Box::new(msg),
+        }
+    }
+    
+    /// Valide le sous-message
+    pub fn validate(&self) -> Result<(), AftnError> {
+        self.as_submessage().validate()
+    }
+    
+    /// Retourne la catégorie du sous-message
+    pub fn category(&self) -> MessageCategory {
+        self.as_submessage().category()
+    }
+}
+
+/// Résultat du parsing du sous-message porté par un `AftnMessage`
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub enum SubMessageStatus {
+    /// Le corps a été parsé avec succès
+    Parsed(Box<SubMessageData>),
+    
+    /// Le corps n'a pas pu être parsé, avec l'erreur rencontrée
+    Failed(AftnError),
+
+    /// Le corps n'a pas été soumis au parser, par exemple dans un message
+    /// sérialisé avant l'ajout du champ `submessage`
+    #[default]
+    NotParsed,
+}
+
+impl SubMessageStatus {
+    /// Parse le corps du message et conserve le résultat, succès ou échec
+    pub fn parse(category: &MessageCategory, body: &str) -> Self {
+        match parse_typed_submessage(category, body) {
+            Ok(data) => SubMessageStatus::Parsed(Box::new(data)),
+            Err(e) => SubMessageStatus::Failed(e),
+        }
+    }
+    
+    /// Retourne `true` si le sous-message a été parsé
+    pub fn is_parsed(&self) -> bool {
+        matches!(self, SubMessageStatus::Parsed(_))
+    }
+    
+    /// Retourne le sous-message parsé, s'il existe
+    pub fn parsed(&self) -> Option<&SubMessageData> {
+        match self {
+            SubMessageStatus::Parsed(data) => Some(data),
+            SubMessageStatus::Failed(_) | SubMessageStatus::NotParsed => None,
+        }
+    }
+    
+    /// Retourne l'erreur de parsing, s'il y en a une
+    pub fn error(&self) -> Option<&AftnError> {
+        match self {
+            SubMessageStatus::Parsed(_) | SubMessageStatus::NotParsed => None,
+            SubMessageStatus::Failed(e) => Some(e),
+        }
+    }
+}
+
 /// Parse un sous-message selon sa catégorie
 pub fn parse_submessage(category: &MessageCategory, body: &str) -> Result<Box<dyn SubMessage>, AftnError> {
-    use crate::aftn::categories::meteorological::*;
-    use crate::aftn::categories::flight_plan::*;
-    use crate::aftn::categories::coordination::*;
-    use crate::aftn::categories::position::*;
-    use crate::aftn::categories::alerting::*;
-    use crate::aftn::categories::operational::*;
-    
+    parse_typed_submessage(category, body).map(SubMessageData::into_boxed)
+}
+
+/// Parse un sous-message typé selon sa catégorie
+pub fn parse_typed_submessage(category: &MessageCategory, body: &str) -> Result<SubMessageData, AftnError> {
     match category {
         // Messages météorologiques
         MessageCategory::Notam => {
-            let msg = NotamMessage::parse(body)?;
-            Ok(Box::new(msg))
+            Ok(SubMessageData::Notam(NotamMessage::parse(body)?))
         }
-        MessageCategory::Metar => {
-            let msg = MetarMessage::parse(body)?;
-            Ok(Box::new(msg))
+        MessageCategory::Metar | MessageCategory::Speci => {
+            Ok(SubMessageData::Metar(MetarMessage::parse(body)?))
         }
         MessageCategory::Taf => {
-            let msg = TafMessage::parse(body)?;
-            Ok(Box::new(msg))
+            Ok(SubMessageData::Taf(TafMessage::parse(body)?))
         }
         MessageCategory::Sigmet => {
-            let msg = SigmetMessage::parse(body)?;
-            Ok(Box::new(msg))
+            Ok(SubMessageData::Sigmet(SigmetMessage::parse(body)?))
         }
         MessageCategory::Airmet => {
-            let msg = AirmetMessage::parse(body)?;
-            Ok(Box::new(msg))
+            Ok(SubMessageData::Airmet(AirmetMessage::parse(body)?))
         }
         MessageCategory::Atis => {
-            let msg = AtisMessage::parse(body)?;
-            Ok(Box::new(msg))
+            Ok(SubMessageData::Atis(AtisMessage::parse(body)?))
         }
         MessageCategory::Volmet => {
-            let msg = VolmetMessage::parse(body)?;
-            Ok(Box::new(msg))
+            Ok(SubMessageData::Volmet(VolmetMessage::parse(body)?))
         }
         
         // Messages de plan de vol
         MessageCategory::FlightPlan => {
-            let msg = FplMessage::parse(body)?;
-            Ok(Box::new(msg))
+            Ok(SubMessageData::FlightPlan(FplMessage::parse(body)?))
         }
         MessageCategory::Change => {
-            let msg = ChgMessage::parse(body)?;
-            Ok(Box::new(msg))
+            Ok(SubMessageData::Change(ChgMessage::parse(body)?))
         }
         MessageCategory::Cancel => {
-            let msg = CnlMessage::parse(body)?;
-            Ok(Box::new(msg))
+            Ok(SubMessageData::Cancel(CnlMessage::parse(body)?))
         }
         MessageCategory::Delay => {
-            let msg = DlaMessage::parse(body)?;
-            Ok(Box::new(msg))
+            Ok(SubMessageData::Delay(DlaMessage::parse(body)?))
         }
         MessageCategory::Departure => {
-            let msg = DepMessage::parse(body)?;
-            Ok(Box::new(msg))
+            Ok(SubMessageData::Departure(DepMessage::parse(body)?))
         }
         MessageCategory::Arrival => {
-            let msg = ArrMessage::parse(body)?;
-            Ok(Box::new(msg))
+            Ok(SubMessageData::Arrival(ArrMessage::parse(body)?))
         }
         MessageCategory::Estimate => {
-            let msg = EstMessage::parse(body)?;
-            Ok(Box::new(msg))
+            Ok(SubMessageData::Estimate(EstMessage::parse(body)?))
         }
         MessageCategory::AdvanceBoundaryInformation => {
-            let msg = AbiMessage::parse(body)?;
-            Ok(Box::new(msg))
+            Ok(SubMessageData::AdvanceBoundaryInformation(AbiMessage::parse(body)?))
         }
         MessageCategory::SupplementaryFlightPlan => {
-            let msg = SplMessage::parse(body)?;
-            Ok(Box::new(msg))
+            Ok(SubMessageData::SupplementaryFlightPlan(SplMessage::parse(body)?))
         }
         MessageCategory::CurrentFlightPlan
         | MessageCategory::UpdateFlightPlan => {
             // Utiliser le parser FPL pour les messages liés aux plans de vol
             // ou le parser opérationnel comme fallback
             match FplMessage::parse(body) {
-                Ok(msg) => Ok(Box::new(msg)),
+                Ok(msg) => Ok(SubMessageData::FlightPlan(msg)),
                 Err(_) => {
-                    let msg = OperationalMessage::parse(body)?;
-                    Ok(Box::new(msg))
+                    Ok(SubMessageData::Operational(OperationalMessage::parse(body)?))
                 }
             }
         }
         
         // Messages de coordination et autres
         MessageCategory::Coordination => {
-            let msg = CofMessage::parse(body)?;
-            Ok(Box::new(msg))
+            Ok(SubMessageData::Coordination(CofMessage::parse(body)?))
         }
         MessageCategory::Request => {
-            let msg = ReqMessage::parse(body)?;
-            Ok(Box::new(msg))
+            Ok(SubMessageData::Request(ReqMessage::parse(body)?))
         }
         MessageCategory::Alerting => {
-            let msg = AlrMessage::parse(body)?;
-            Ok(Box::new(msg))
+            Ok(SubMessageData::Alerting(AlrMessage::parse(body)?))
         }
         MessageCategory::RequestFlightPlan
         | MessageCategory::RequestSupplementaryFlightPlan
@@ -135,18 +272,15 @@ pub fn parse_submessage(category: &MessageCategory, body: &str) -> Result<Box<dy
         | MessageCategory::TransferOfControl
         | MessageCategory::AirReport
         | MessageCategory::Operational(_) => {
-            let msg = OperationalMessage::parse(body)?;
-            Ok(Box::new(msg))
+            Ok(SubMessageData::Operational(OperationalMessage::parse(body)?))
         }
         
         MessageCategory::PositionReport => {
-            let msg = PosMessage::parse(body)?;
-            Ok(Box::new(msg))
+            Ok(SubMessageData::PositionReport(PosMessage::parse(body)?))
         }
         
         MessageCategory::Generic => {
-            let msg = GenericMessage::parse(body)?;
-            Ok(Box::new(msg))
+            Ok(SubMessageData::Generic(GenericMessage::parse(body)?))
         }
     }
 }
@@ -0,0 +1,210 @@
+//! Calendrier grégorien partagé par les différents formats
+//!
+//! Les groupes date-heure AFTN et ADEXP comme les champs date des phrases NMEA
+//! (ZDA, RMC) ont besoin de connaître la longueur des mois : ces fonctions ne
+//! dépendent d'aucun format et sont donc définies à la racine de la crate, avec
+//! l'instant `UtcDateTime` auquel les résolveurs de chaque format aboutissent.
+
+use std::fmt;
+use std::time::{SystemTime, UNIX_EPOCH};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// Années représentables : quatre chiffres, comme dans l'affichage ISO 8601
+pub const MIN_YEAR: u16 = 1;
+pub const MAX_YEAR: u16 = 9999;
+
+/// Date ou heure inexistante (31 avril, 24h00...)
+#[derive(Error, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[error("{0}")]
+pub struct DateTimeError(pub String);
+
+/// Indique si l'année est bissextile (calendrier grégorien)
+pub fn is_leap_year(year: u16) -> bool {
+    (year.is_multiple_of(4) && !year.is_multiple_of(100)) || year.is_multiple_of(400)
+}
+
+/// Nombre de jours du mois (0 si le mois est invalide)
+pub fn days_in_month(year: u16, month: u8) -> u8 {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        _ => 0,
+    }
+}
+
+/// Instant UTC à la minute près
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct UtcDateTime {
+    pub year: u16,
+
+    /// Mois (1-12)
+    pub month: u8,
+
+    /// Jour du mois (1-31 selon le mois)
+    pub day: u8,
+
+    /// Heure (00-23)
+    pub hour: u8,
+
+    /// Minute (00-59)
+    pub minute: u8,
+}
+
+impl UtcDateTime {
+    /// Crée un instant UTC en vérifiant que la date existe (années bissextiles comprises)
+    pub fn new(year: u16, month: u8, day: u8, hour: u8, minute: u8) -> Result<Self, DateTimeError> {
+        if !(MIN_YEAR..=MAX_YEAR).contains(&year) {
+            return Err(DateTimeError(format!(
+                "Year must be between {:04}-{:04}, got {}",
+                MIN_YEAR, MAX_YEAR, year
+            )));
+        }
+        if !(1..=12).contains(&month) {
+            return Err(DateTimeError(format!(
+                "Month must be between 01-12, got {}",
+                month
+            )));
+        }
+        if day == 0 || day > days_in_month(year, month) {
+            return Err(DateTimeError(format!(
+                "Day {:02} does not exist in {:04}-{:02}",
+                day, year, month
+            )));
+        }
+        if hour > 23 {
+            return Err(DateTimeError(format!(
+                "Hour must be between 00-23, got {}",
+                hour
+            )));
+        }
+        if minute > 59 {
+            return Err(DateTimeError(format!(
+                "Minute must be between 00-59, got {}",
+                minute
+            )));
+        }
+        Ok(UtcDateTime { year, month, day, hour, minute })
+    }
+
+    /// Instant courant (horloge système)
+    pub fn now() -> Self {
+        let seconds = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs() as i64)
+            .unwrap_or(0);
+        Self::from_unix_timestamp(seconds)
+    }
+
+    /// Convertit un timestamp Unix (secondes) en instant UTC, secondes tronquées
+    pub fn from_unix_timestamp(seconds: i64) -> Self {
+        let minutes = seconds.div_euclid(60);
+        let (year, month, day) = civil_from_days(minutes.div_euclid(24 * 60));
+        let minute_of_day = minutes.rem_euclid(24 * 60);
+        UtcDateTime {
+            year: year as u16,
+            month,
+            day,
+            hour: (minute_of_day / 60) as u8,
+            minute: (minute_of_day % 60) as u8,
+        }
+    }
+
+    /// Timestamp Unix (secondes) de l'instant
+    pub fn unix_timestamp(&self) -> i64 {
+        let days = days_from_civil(self.year as i64, self.month, self.day);
+        (days * 24 * 60 + self.hour as i64 * 60 + self.minute as i64) * 60
+    }
+
+    /// Décale l'instant d'un nombre de minutes (positif ou négatif)
+    pub fn add_minutes(&self, minutes: i64) -> Self {
+        Self::from_unix_timestamp(self.unix_timestamp() + minutes * 60)
+    }
+
+    /// Même jour, heure et minute différentes
+    pub(crate) fn with_time(&self, hour: u8, minute: u8) -> Result<Self, DateTimeError> {
+        Self::new(self.year, self.month, self.day, hour, minute)
+    }
+}
+
+impl fmt::Display for UtcDateTime {
+    /// Format ISO 8601: `2024-04-30T12:30:00Z`
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:04}-{:02}-{:02}T{:02}:{:02}:00Z",
+            self.year, self.month, self.day, self.hour, self.minute
+        )
+    }
+}
+
+/// Nombre de jours depuis le 1970-01-01 (algorithme de H. Hinnant)
+fn days_from_civil(year: i64, month: u8, day: u8) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month = month as i64;
+    let day_of_year = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day as i64 - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
+
+/// Date civile (année, mois, jour) d'un nombre de jours depuis le 1970-01-01
+fn civil_from_days(days: i64) -> (i64, u8, u8) {
+    let days = days + 719468;
+    let era = days.div_euclid(146097);
+    let day_of_era = days - era * 146097;
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * mp + 2) / 5 + 1) as u8;
+    let month = (if mp < 10 { mp + 3 } else { mp - 9 }) as u8;
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(year: u16, month: u8, day: u8, hour: u8, minute: u8) -> UtcDateTime {
+        UtcDateTime::new(year, month, day, hour, minute).unwrap()
+    }
+
+    #[test]
+    fn test_days_in_month() {
+        assert_eq!(days_in_month(2024, 2), 29);
+        assert_eq!(days_in_month(2023, 2), 28);
+        assert_eq!(days_in_month(1900, 2), 28);
+        assert_eq!(days_in_month(2000, 2), 29);
+        assert_eq!(days_in_month(2024, 4), 30);
+        assert_eq!(days_in_month(2024, 12), 31);
+        assert_eq!(days_in_month(2024, 13), 0);
+    }
+
+    #[test]
+    fn test_unix_timestamp_round_trip() {
+        let instant = at(2024, 2, 29, 23, 59);
+        assert_eq!(instant.unix_timestamp(), 1709251140);
+        assert_eq!(UtcDateTime::from_unix_timestamp(1709251140), instant);
+        assert_eq!(UtcDateTime::from_unix_timestamp(0), at(1970, 1, 1, 0, 0));
+        assert_eq!(instant.add_minutes(1), at(2024, 3, 1, 0, 0));
+        assert_eq!(instant.to_string(), "2024-02-29T23:59:00Z");
+    }
+
+    #[test]
+    fn test_new_rejects_impossible_dates() {
+        assert!(UtcDateTime::new(2024, 4, 31, 12, 0).is_err());
+        assert!(UtcDateTime::new(2023, 2, 29, 12, 0).is_err());
+        assert!(UtcDateTime::new(2024, 2, 29, 12, 0).is_ok());
+        assert!(UtcDateTime::new(1900, 2, 29, 12, 0).is_err());
+        assert!(UtcDateTime::new(2000, 2, 29, 12, 0).is_ok());
+        assert!(UtcDateTime::new(2024, 13, 1, 12, 0).is_err());
+        assert!(UtcDateTime::new(2024, 1, 1, 24, 0).is_err());
+        assert!(UtcDateTime::new(0, 1, 1, 12, 0).is_err());
+        assert!(UtcDateTime::new(10000, 1, 1, 12, 0).is_err());
+        assert!(UtcDateTime::new(9999, 12, 31, 23, 59).is_ok());
+    }
+}
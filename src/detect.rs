@@ -0,0 +1,591 @@
+//! Formats de messages et reconnaissance automatique du format
+//!
+//! `Format` désigne l'un des quatre formats pris en charge et aiguille un
+//! texte vers le parser correspondant ; `AnyMessage` et `AnyError` regroupent
+//! les messages et les erreurs des quatre parsers.
+//!
+//! `detect_format` examine le début d'un texte et retourne le format le plus
+//! probable avec un indice de confiance entre 0 et 1 : 1 lorsque les éléments
+//! caractéristiques du format sont tous présents (checksum NMEA correct, 22
+//! champs SBS, en-tête AFTN complet, `-ADEXP`), moins lorsque seul le début
+//! correspond. `parse_any` parse ensuite le texte avec le parser du format
+//! reconnu.
+//!
+//! Les enregistreurs mélangent souvent plusieurs formats dans un même fichier :
+//! `MixedStreamReader` découpe un tel flux ligne par ligne, en regroupant les
+//! enveloppes AFTN (`ZCZC` ... `NNNN`) et les messages ADEXP multi-lignes.
+
+use std::fmt;
+use std::io::BufRead;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use crate::adexp::{AdexpError, AdexpMessage, AdexpParser};
+use crate::aftn::{AftnError, AftnMessage, AftnParser};
+use crate::diagnostic::{Diagnostic, Lenient, LocatedError, Severity};
+use crate::nmea::{NmeaError, NmeaMessage, NmeaParser};
+use crate::nmea::tag_block::split_tag_block;
+use crate::sbs::{SbsError, SbsParser, SbsRecord};
+
+/// Priorités AFTN valides
+const PRIORITIES: [&str; 6] = ["GG", "DD", "FF", "SS", "KK", "LL"];
+
+/// Types d'enregistrements SBS (BaseStation)
+const SBS_TYPES: [&str; 6] = ["MSG", "SEL", "ID", "AIR", "STA", "CLK"];
+
+/// Format d'un message
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Format {
+    Aftn,
+    Adexp,
+    Nmea,
+    Sbs,
+}
+
+impl Format {
+    /// Tous les formats reconnus
+    pub const ALL: [Format; 4] = [Format::Aftn, Format::Adexp, Format::Nmea, Format::Sbs];
+
+    /// Nom du format en minuscules (`aftn`, `adexp`, `nmea`, `sbs`)
+    pub fn name(self) -> &'static str {
+        match self {
+            Format::Aftn => "aftn",
+            Format::Adexp => "adexp",
+            Format::Nmea => "nmea",
+            Format::Sbs => "sbs",
+        }
+    }
+
+    /// Format correspondant à un nom, sans tenir compte de la casse
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|format| format.name().eq_ignore_ascii_case(name))
+    }
+
+    /// Parse le texte avec le parser de ce format
+    pub fn parse(self, input: &str) -> Result<AnyMessage, AnyError> {
+        Ok(match self {
+            Format::Aftn => AnyMessage::Aftn(Box::new(AftnParser::parse_message(input)?)),
+            Format::Adexp => AnyMessage::Adexp(Box::new(AdexpParser::parse_message(input)?)),
+            Format::Nmea => AnyMessage::Nmea(Box::new(NmeaParser::parse_message(input)?)),
+            Format::Sbs => AnyMessage::Sbs(Box::new(SbsParser::parse_record(input)?)),
+        })
+    }
+
+    /// Parse le texte en mode tolérant avec le parser de ce format
+    pub fn parse_lenient(self, input: &str) -> Lenient<AnyMessage> {
+        fn wrap<T>(result: Lenient<T>, variant: impl FnOnce(Box<T>) -> AnyMessage) -> Lenient<AnyMessage> {
+            Lenient {
+                message: result.message.map(|message| variant(Box::new(message))),
+                diagnostics: result.diagnostics,
+            }
+        }
+        match self {
+            Format::Aftn => wrap(AftnParser::parse_lenient(input), AnyMessage::Aftn),
+            Format::Adexp => wrap(AdexpParser::parse_lenient(input), AnyMessage::Adexp),
+            Format::Nmea => wrap(NmeaParser::parse_lenient(input), AnyMessage::Nmea),
+            Format::Sbs => wrap(SbsParser::parse_record_lenient(input), AnyMessage::Sbs),
+        }
+    }
+}
+
+impl fmt::Display for Format {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Format reconnu et confiance associée (entre 0 et 1)
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Detection {
+    pub format: Format,
+    pub confidence: f64,
+}
+
+/// Message de l'un des quatre formats
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum AnyMessage {
+    Aftn(Box<AftnMessage>),
+    Adexp(Box<AdexpMessage>),
+    Nmea(Box<NmeaMessage>),
+    Sbs(Box<SbsRecord>),
+}
+
+impl AnyMessage {
+    /// Format du message
+    pub fn format(&self) -> Format {
+        match self {
+            AnyMessage::Aftn(_) => Format::Aftn,
+            AnyMessage::Adexp(_) => Format::Adexp,
+            AnyMessage::Nmea(_) => Format::Nmea,
+            AnyMessage::Sbs(_) => Format::Sbs,
+        }
+    }
+
+    /// Type du message dans son format (catégorie AFTN, TITLE ADEXP,
+    /// identifiant de phrase NMEA, type SBS ou type d'enregistrement SEL, ID...)
+    pub fn kind(&self) -> String {
+        match self {
+            AnyMessage::Aftn(message) => format!("{:?}", message.category),
+            AnyMessage::Adexp(message) => format!("{:?}", message.message_type),
+            AnyMessage::Nmea(message) => message.message_type.identifier().into_owned(),
+            AnyMessage::Sbs(record) => match record.as_ref() {
+                SbsRecord::Message(message) => format!("{:?}", message.message_type),
+                _ => record.record_type().to_string(),
+            },
+        }
+    }
+}
+
+/// Message parsé par `parse_any`, avec la confiance de la reconnaissance
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ParsedAny {
+    pub message: AnyMessage,
+    pub confidence: f64,
+}
+
+/// Erreur de lecture d'un flux, format non reconnu ou erreur du parser d'un format
+#[derive(Error, Debug, PartialEq)]
+pub enum AnyError {
+    #[error("Unrecognised message format")]
+    UnknownFormat,
+
+    #[error("I/O error: {0}")]
+    Io(String),
+
+    #[error(transparent)]
+    Aftn(#[from] AftnError),
+
+    #[error(transparent)]
+    Adexp(#[from] AdexpError),
+
+    #[error(transparent)]
+    Nmea(#[from] NmeaError),
+
+    #[error(transparent)]
+    Sbs(#[from] SbsError),
+}
+
+impl AnyError {
+    /// Code stable de l'erreur (celui du parser en cause le cas échéant)
+    pub fn code(&self) -> &'static str {
+        match self {
+            AnyError::UnknownFormat => "FORMAT001",
+            AnyError::Io(_) => "FORMAT002",
+            AnyError::Aftn(error) => error.code(),
+            AnyError::Adexp(error) => error.code(),
+            AnyError::Nmea(error) => error.code(),
+            AnyError::Sbs(error) => error.code(),
+        }
+    }
+
+    /// Problème de gravité `Error` correspondant, pour le mode tolérant
+    pub fn to_diagnostic(&self) -> Diagnostic {
+        match self {
+            AnyError::Aftn(error) => error.to_diagnostic(),
+            AnyError::Adexp(error) => error.to_diagnostic(),
+            AnyError::Nmea(error) => error.to_diagnostic(),
+            AnyError::Sbs(error) => error.to_diagnostic(),
+            _ => Diagnostic::new(Severity::Error, self.code(), self.to_string()),
+        }
+    }
+}
+
+/// Reconnaît le format d'un message
+///
+/// Retourne `None` si le texte ne ressemble à aucun des quatre formats.
+///
+/// # Exemples
+/// ```
+/// use aftn::detect::{detect_format, Format};
+///
+/// let detection = detect_format("$GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*47").unwrap();
+/// assert_eq!(detection.format, Format::Nmea);
+/// assert_eq!(detection.confidence, 1.0);
+/// ```
+pub fn detect_format(input: &str) -> Option<Detection> {
+    let text = input.trim_start();
+    let first_line = text.lines().next()?.trim_end();
+    [
+        nmea_confidence(first_line).map(|c| (Format::Nmea, c)),
+        sbs_confidence(first_line).map(|c| (Format::Sbs, c)),
+        adexp_confidence(first_line).map(|c| (Format::Adexp, c)),
+        aftn_confidence(text, first_line).map(|c| (Format::Aftn, c)),
+    ]
+    .into_iter()
+    .flatten()
+    .max_by(|a, b| a.1.total_cmp(&b.1))
+    .map(|(format, confidence)| Detection { format, confidence })
+}
+
+/// Reconnaît le format d'un message puis le parse
+///
+/// # Exemples
+/// ```
+/// use aftn::detect::{parse_any, AnyMessage};
+///
+/// let parsed = parse_any("GG LFPGYYYX LFPOYYYX 151230 NOTAM A1234/24").unwrap();
+/// assert!(matches!(parsed.message, AnyMessage::Aftn(_)));
+/// ```
+pub fn parse_any(input: &str) -> Result<ParsedAny, AnyError> {
+    let detection = detect_format(input).ok_or(AnyError::UnknownFormat)?;
+    Ok(ParsedAny {
+        message: detection.format.parse(input)?,
+        confidence: detection.confidence,
+    })
+}
+
+fn nmea_confidence(line: &str) -> Option<f64> {
+    // Un bloc TAG NMEA 4.x peut précéder la phrase
+    let line = split_tag_block(line).map_or(line, |(_, sentence)| sentence);
+    let data = line.strip_prefix('$').or_else(|| line.strip_prefix('!'))?;
+    let address = data.split([',', '*']).next().unwrap_or("");
+    let address_ok = address.len() >= 3
+        && address.len() <= 6
+        && address.chars().all(|c| c.is_ascii_uppercase() || c.is_ascii_digit());
+    let base = if address_ok { 0.6 } else { 0.3 };
+
+    let Some((payload, checksum)) = data.rsplit_once('*') else {
+        return Some(base);
+    };
+    let checksum = checksum.trim();
+    if checksum.len() != 2 || !checksum.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Some(base);
+    }
+    if NmeaParser::calculate_checksum(payload).eq_ignore_ascii_case(checksum) {
+        Some(if address_ok { 1.0 } else { 0.7 })
+    } else {
+        Some(base + 0.2)
+    }
+}
+
+fn sbs_confidence(line: &str) -> Option<f64> {
+    let fields: Vec<&str> = line.split(',').collect();
+    if fields.len() < 2 || !SBS_TYPES.contains(&fields[0]) {
+        return None;
+    }
+    if fields[0] != "MSG" {
+        return Some(if fields.len() >= 10 { 0.9 } else { 0.6 });
+    }
+    match fields[1].parse::<u8>() {
+        Ok(1..=8) if fields.len() == 22 => Some(1.0),
+        Ok(1..=8) => Some(0.8),
+        _ => Some(0.5),
+    }
+}
+
+fn adexp_confidence(line: &str) -> Option<f64> {
+    let keyword = line.strip_prefix('-')?.split_whitespace().next().unwrap_or("");
+    if keyword == "ADEXP" {
+        Some(1.0)
+    } else if keyword == "TITLE" {
+        Some(0.9)
+    } else if !keyword.is_empty() && keyword.chars().all(|c| c.is_ascii_uppercase() || c.is_ascii_digit()) {
+        Some(0.5)
+    } else {
+        None
+    }
+}
+
+fn aftn_confidence(text: &str, first_line: &str) -> Option<f64> {
+    if first_line.starts_with("ZCZC") {
+        return Some(if text.contains("NNNN") { 1.0 } else { 0.9 });
+    }
+
+    let tokens: Vec<&str> = first_line.split_whitespace().collect();
+    let priority = *tokens.first()?;
+    if priority.len() != 2 || !priority.chars().all(|c| c.is_ascii_uppercase()) {
+        return None;
+    }
+    let addresses = tokens[1..].iter()
+        .take_while(|token| (7..=8).contains(&token.len()) && token.chars().all(|c| c.is_ascii_alphanumeric()))
+        .count();
+    if addresses == 0 {
+        return None;
+    }
+    let known_priority = PRIORITIES.contains(&priority);
+    let time = tokens.get(1 + addresses)
+        .is_some_and(|token| token.len() == 6 && token.chars().all(|c| c.is_ascii_digit()));
+    Some(match (addresses >= 2, time, known_priority) {
+        (true, true, true) => 1.0,
+        (true, true, false) => 0.8,
+        (_, _, true) => 0.5,
+        _ => 0.3,
+    })
+}
+
+/// Message brut découpé dans un flux mixte
+#[derive(Debug, Clone, PartialEq)]
+pub struct RawMessage {
+    /// Numéro (à partir de 1) de la première ligne du message dans le flux
+    pub line: usize,
+
+    /// Texte du message (lignes jointes par `\n`)
+    pub text: String,
+
+    /// Format reconnu, absent pour une ligne qui ne ressemble à aucun format
+    pub detection: Option<Detection>,
+}
+
+impl RawMessage {
+    /// Parse le message avec le parser du format reconnu
+    pub fn parse(&self) -> Result<ParsedAny, AnyError> {
+        let detection = self.detection.ok_or(AnyError::UnknownFormat)?;
+        Ok(ParsedAny {
+            message: detection.format.parse(&self.text)?,
+            confidence: detection.confidence,
+        })
+    }
+}
+
+/// Découpe un flux mélangeant les quatre formats, ligne par ligne
+///
+/// NMEA, SBS et AFTN sur une ligne : un message par ligne. Enveloppe AFTN :
+/// de `ZCZC` jusqu'au `NNNN` (ou au `ZCZC` suivant). ADEXP : lignes `-...`
+/// consécutives, un nouveau message commençant à chaque `-ADEXP` ou au
+/// second `-TITLE`. Les lignes vides sont ignorées.
+///
+/// ```
+/// use std::io::Cursor;
+/// use aftn::detect::{Format, MixedStreamReader};
+///
+/// let log = "MSG,3,145,29315,4CA2E6,27215,2015/02/05,14:53:22.734,2015/02/05,14:53:22.734,,37025,,,51.4703,-0.4543,,,,,,0\n\
+///            $GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*47\n";
+/// let formats: Vec<Format> = MixedStreamReader::new(Cursor::new(log))
+///     .map(|message| message.unwrap().message.format())
+///     .collect();
+/// assert_eq!(formats, vec![Format::Sbs, Format::Nmea]);
+/// ```
+pub struct MixedStreamReader<R: BufRead> {
+    reader: R,
+    /// Format imposé, au lieu de la reconnaissance automatique
+    format: Option<Format>,
+    /// Ligne lue mais pas encore consommée, avec son numéro
+    pending: Option<(usize, String)>,
+    line: usize,
+    finished: bool,
+}
+
+impl<R: BufRead> MixedStreamReader<R> {
+    /// Crée un lecteur reconnaissant le format de chaque message
+    pub fn new(reader: R) -> Self {
+        MixedStreamReader {
+            reader,
+            format: None,
+            pending: None,
+            line: 0,
+            finished: false,
+        }
+    }
+
+    /// Crée un lecteur dont tous les messages sont du format donné
+    pub fn with_format(reader: R, format: Format) -> Self {
+        MixedStreamReader {
+            format: Some(format),
+            ..Self::new(reader)
+        }
+    }
+
+    /// Lit le message brut suivant sans le parser
+    pub fn next_raw(&mut self) -> Option<Result<RawMessage, AnyError>> {
+        let (line, first) = loop {
+            match self.next_line() {
+                Ok(Some((_, text))) if text.trim().is_empty() => continue,
+                Ok(Some(line)) => break line,
+                Ok(None) => return None,
+                Err(e) => return Some(Err(e)),
+            }
+        };
+
+        let detection = match self.format {
+            Some(format) => Some(Detection { format, confidence: 1.0 }),
+            None => detect_format(&first),
+        };
+        let trimmed = first.trim_start().to_string();
+        let mut lines = vec![first];
+
+        match detection.map(|d| d.format) {
+            Some(Format::Aftn) if trimmed.starts_with("ZCZC") && !trimmed.contains("NNNN") => loop {
+                match self.next_line() {
+                    Ok(Some((number, text))) => {
+                        if text.trim().starts_with("ZCZC") {
+                            self.pending = Some((number, text));
+                            break;
+                        }
+                        let end = text.trim().ends_with("NNNN");
+                        lines.push(text);
+                        if end {
+                            break;
+                        }
+                    }
+                    Ok(None) => break,
+                    Err(e) => return Some(Err(e)),
+                }
+            },
+            Some(Format::Adexp) => {
+                let mut has_title = trimmed.starts_with("-TITLE");
+                loop {
+                    match self.next_line() {
+                        Ok(Some((number, text))) => {
+                            let next = text.trim_start();
+                            if !next.starts_with('-') || next.starts_with("-ADEXP") || (has_title && next.starts_with("-TITLE")) {
+                                self.pending = Some((number, text));
+                                break;
+                            }
+                            has_title |= next.starts_with("-TITLE");
+                            lines.push(text);
+                        }
+                        Ok(None) => break,
+                        Err(e) => return Some(Err(e)),
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        // Le format d'une enveloppe ou d'un message ADEXP se juge sur le message entier
+        let text = lines.join("\n");
+        let detection = match self.format {
+            Some(_) => detection,
+            None if lines.len() > 1 => detect_format(&text),
+            None => detection,
+        };
+        Some(Ok(RawMessage { line, text, detection }))
+    }
+
+    fn next_line(&mut self) -> Result<Option<(usize, String)>, AnyError> {
+        if let Some(pending) = self.pending.take() {
+            return Ok(Some(pending));
+        }
+        if self.finished {
+            return Ok(None);
+        }
+
+        // Les journaux peuvent contenir des octets hors UTF-8 (bruit de ligne)
+        let mut buffer = Vec::new();
+        let read = self.reader.read_until(b'\n', &mut buffer)
+            .map_err(|e| AnyError::Io(e.to_string()))?;
+        if read == 0 {
+            self.finished = true;
+            return Ok(None);
+        }
+        self.line += 1;
+        let text = String::from_utf8_lossy(&buffer).trim_end_matches(['\r', '\n']).to_string();
+        Ok(Some((self.line, text)))
+    }
+}
+
+impl<R: BufRead> Iterator for MixedStreamReader<R> {
+    type Item = Result<ParsedAny, AnyError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_raw().map(|raw| raw.and_then(|raw| raw.parse()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    #[test]
+    fn test_format_from_name() {
+        assert_eq!(Format::from_name("NMEA"), Some(Format::Nmea));
+        assert_eq!(Format::from_name("adexp"), Some(Format::Adexp));
+        assert_eq!(Format::from_name("csv"), None);
+    }
+
+    #[test]
+    fn test_parse_with_format() {
+        let message = Format::Nmea.parse("$GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*47").unwrap();
+        assert_eq!(message.format(), Format::Nmea);
+        assert_eq!(message.kind(), "GPGGA");
+        assert!(matches!(Format::Aftn.parse("hello world"), Err(AnyError::Aftn(_))));
+    }
+
+    #[test]
+    fn test_detect_each_format() {
+        let cases = [
+            ("GG LFPGYYYX LFPOYYYX 151230 NOTAM A1234/24", Format::Aftn, 1.0),
+            ("ZCZC ABC123 151230\r\nGG LFPOYYYX\r\n151229 LFPGYYYX\r\nNOTAM A1234/24\r\nNNNN", Format::Aftn, 1.0),
+            ("-ADEXP\n-TITLE FPL\n-ARCID ABC123", Format::Adexp, 1.0),
+            ("-TITLE FPL\n-ARCID ABC123", Format::Adexp, 0.9),
+            ("$GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*47", Format::Nmea, 1.0),
+            ("$GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*00", Format::Nmea, 0.8),
+            ("\\s:station1,c:1697000000*7E\\!AIVDM,1,1,,A,13HOI:0P0000VOHLCnHQKwvL05Ip,0*23", Format::Nmea, 1.0),
+            ("MSG,3,145,29315,4CA2E6,27215,2015/02/05,14:53:22.734,2015/02/05,14:53:22.734,,37025,,,51.4703,-0.4543,,,,,,0", Format::Sbs, 1.0),
+            ("MSG,3,145", Format::Sbs, 0.8),
+        ];
+        for (input, format, confidence) in cases {
+            let detection = detect_format(input).unwrap_or_else(|| panic!("Format attendu pour {}", input));
+            assert_eq!(detection.format, format, "{}", input);
+            assert_eq!(detection.confidence, confidence, "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_detect_rejects_noise() {
+        assert_eq!(detect_format(""), None);
+        assert_eq!(detect_format("   \n"), None);
+        assert_eq!(detect_format("hello world"), None);
+        assert_eq!(parse_any("hello world"), Err(AnyError::UnknownFormat));
+    }
+
+    #[test]
+    fn test_parse_any_reports_parser_error() {
+        let error = parse_any("$GPGGA,123519*00").unwrap_err();
+        assert!(matches!(error, AnyError::Nmea(NmeaError::InvalidChecksum { .. })));
+        assert_eq!(error.code(), "NMEA003");
+    }
+
+    #[test]
+    fn test_parse_any_sbs_event_record() {
+        let parsed = parse_any("STA,,5,179,400AE7,10103,2008/11/28,14:58:51.153,2008/11/28,14:58:51.153,RM").unwrap();
+        assert_eq!(parsed.message.format(), Format::Sbs);
+        assert_eq!(parsed.message.kind(), "STA");
+    }
+
+    #[test]
+    fn test_mixed_stream_groups_multiline_messages() {
+        let log = "\
+GG LFPGYYYX LFPOYYYX 151230 NOTAM A1234/24
+
+ZCZC ABC123 151230
+GG LFPOYYYX
+151229 LFPGYYYX
+NOTAM A1234/24 LFPG RWY 09/27 CLOSED
+NNNN
+-ADEXP
+-TITLE FPL
+-ARCID ABC123
+-TITLE CNL
+-ARCID DEF456
+line noise
+$GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*47";
+        let mut reader = MixedStreamReader::new(Cursor::new(log));
+        let mut raws = Vec::new();
+        while let Some(raw) = reader.next_raw() {
+            raws.push(raw.unwrap());
+        }
+
+        let lines: Vec<usize> = raws.iter().map(|r| r.line).collect();
+        assert_eq!(lines, vec![1, 3, 8, 11, 13, 14]);
+        let formats: Vec<Option<Format>> = raws.iter().map(|r| r.detection.map(|d| d.format)).collect();
+        assert_eq!(formats, vec![
+            Some(Format::Aftn), Some(Format::Aftn), Some(Format::Adexp),
+            Some(Format::Adexp), None, Some(Format::Nmea),
+        ]);
+        assert!(raws[1].text.ends_with("NNNN"));
+        assert_eq!(raws[2].text, "-ADEXP\n-TITLE FPL\n-ARCID ABC123");
+        assert_eq!(raws[3].text, "-TITLE CNL\n-ARCID DEF456");
+        assert_eq!(raws[4].parse(), Err(AnyError::UnknownFormat));
+    }
+
+    #[test]
+    fn test_forced_format() {
+        let reader = MixedStreamReader::with_format(Cursor::new("hello world\n"), Format::Aftn);
+        let results: Vec<_> = reader.collect();
+        assert_eq!(results.len(), 1);
+        assert!(matches!(results[0], Err(AnyError::Aftn(_))));
+    }
+}
@@ -0,0 +1,458 @@
+//! Localisation précise des erreurs d'analyse et rendu pour les opérateurs
+//!
+//! Les quatre parsers (AFTN, ADEXP, NMEA, SBS) produisent une `SyntaxError`
+//! lorsqu'un texte est rejeté par leur grammaire : elle conserve la position
+//! de l'erreur (octets, ligne et colonne), les éléments attendus et le champ
+//! en cause. Les positions sont relatives au texte donné au parser qui a
+//! rejeté le message (le corps pour un sous-message AFTN).
+//!
+//! Chaque variante d'erreur porte un code stable (`AFTN001`, `ADEXP004`, ...)
+//! qui ne change pas d'une version à l'autre.
+//!
+//! En mode tolérant (`parse_lenient`), les parsers retournent le message
+//! reconstruit au mieux et la liste de tous les problèmes relevés
+//! (`Diagnostic`). Les erreurs reprennent le code de l'erreur correspondante ;
+//! les avertissements utilisent les codes `xxx1nn` et les informations `xxx2nn`.
+
+use std::fmt;
+use serde::{Deserialize, Serialize};
+
+/// Position d'une erreur dans le texte analysé
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Span {
+    /// Début (octet, inclus)
+    pub start: usize,
+
+    /// Fin (octet, exclue)
+    pub end: usize,
+
+    /// Ligne du début (à partir de 1)
+    pub line: usize,
+
+    /// Colonne du début, en caractères (à partir de 1)
+    pub column: usize,
+}
+
+impl Span {
+    /// Construit une position à partir d'un intervalle d'octets du texte
+    pub fn new(input: &str, start: usize, end: usize) -> Self {
+        let start = floor_char_boundary(input, start.min(input.len()));
+        let end = floor_char_boundary(input, end.clamp(start, input.len()));
+        let before = &input[..start];
+        let line = before.matches('\n').count() + 1;
+        let line_start = before.rfind('\n').map(|i| i + 1).unwrap_or(0);
+        let column = input[line_start..start].chars().count() + 1;
+        Span { start, end, line, column }
+    }
+}
+
+/// Champ en cause dans une erreur
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FieldRef {
+    /// Champ nommé (ex: `ARCID`, `Field 15`)
+    Name(String),
+
+    /// Rang du champ dans le message (à partir de 0)
+    Index(usize),
+}
+
+impl fmt::Display for FieldRef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FieldRef::Name(name) => write!(f, "{}", name),
+            FieldRef::Index(index) => write!(f, "#{}", index),
+        }
+    }
+}
+
+/// Erreur de syntaxe localisée
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SyntaxError {
+    /// Description de l'erreur
+    pub message: String,
+
+    /// Position de l'erreur
+    pub span: Span,
+
+    /// Éléments attendus à cette position (règles de la grammaire)
+    pub expected: Vec<String>,
+
+    /// Champ en cause, s'il est connu
+    pub field: Option<FieldRef>,
+}
+
+impl SyntaxError {
+    pub fn new(message: impl Into<String>, span: Span) -> Self {
+        SyntaxError {
+            message: message.into(),
+            span,
+            expected: Vec::new(),
+            field: None,
+        }
+    }
+
+    /// Convertit une erreur pest ; `context` préfixe la description (ex: `METAR`)
+    pub fn from_pest<R: pest::RuleType>(error: &pest::error::Error<R>, input: &str, context: &str) -> Self {
+        let (start, end) = match error.location {
+            pest::error::InputLocation::Pos(pos) => {
+                let next = input[pos.min(input.len())..].chars().next().map_or(0, char::len_utf8);
+                (pos, pos + next)
+            }
+            pest::error::InputLocation::Span(span) => span,
+        };
+        let expected = match error.variant {
+            pest::error::ErrorVariant::ParsingError { ref positives, .. } => {
+                positives.iter().map(|rule| format!("{:?}", rule)).collect()
+            }
+            pest::error::ErrorVariant::CustomError { .. } => Vec::new(),
+        };
+        let description = error.variant.message();
+        let message = if context.is_empty() {
+            description.into_owned()
+        } else {
+            format!("{} parse error: {}", context, description)
+        };
+
+        SyntaxError {
+            message,
+            span: Span::new(input, start, end),
+            expected,
+            field: None,
+        }
+    }
+
+    /// Reporte la position dans `input`, dont le texte analysé commence à l'octet `offset`
+    pub fn offset_in(mut self, input: &str, offset: usize) -> Self {
+        self.span = Span::new(input, self.span.start + offset, self.span.end + offset);
+        self
+    }
+
+    /// Renseigne le champ en cause
+    pub fn with_field(mut self, field: FieldRef) -> Self {
+        self.field = Some(field);
+        self
+    }
+
+    /// Renseigne le rang du champ en cause d'après les séparateurs qui
+    /// précèdent l'erreur sur sa ligne (`,` en NMEA et SBS)
+    pub fn with_field_index(self, input: &str, separator: char) -> Self {
+        let line_start = input[..self.span.start].rfind('\n').map(|i| i + 1).unwrap_or(0);
+        let index = input[line_start..self.span.start].matches(separator).count();
+        self.with_field(FieldRef::Index(index))
+    }
+
+    /// Affiche le texte analysé avec la zone en erreur soulignée
+    pub fn render(&self, code: &str, input: &str) -> String {
+        render(code, &self.message, Some(self), input)
+    }
+}
+
+impl fmt::Display for SyntaxError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (line {}, column {})", self.message, self.span.line, self.span.column)
+    }
+}
+
+/// Gravité d'un problème relevé en mode tolérant
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum Severity {
+    /// Le message (ou une partie) est invalide et a été reconstruit au mieux
+    Error,
+
+    /// Le message est exploitable mais s'écarte de la spécification
+    Warning,
+
+    /// Remarque sans incidence sur l'exploitation du message
+    Info,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Severity::Error => write!(f, "error"),
+            Severity::Warning => write!(f, "warning"),
+            Severity::Info => write!(f, "info"),
+        }
+    }
+}
+
+/// Problème relevé lors d'une analyse tolérante
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Diagnostic {
+    pub severity: Severity,
+
+    /// Code stable du problème
+    pub code: String,
+
+    /// Description du problème
+    pub message: String,
+
+    /// Position dans le texte analysé, si elle est connue
+    pub span: Option<Span>,
+
+    /// Champ en cause, s'il est connu
+    pub field: Option<FieldRef>,
+
+    /// Éléments attendus, pour une erreur de syntaxe
+    pub expected: Vec<String>,
+}
+
+impl Diagnostic {
+    pub fn new(severity: Severity, code: &str, message: impl Into<String>) -> Self {
+        Diagnostic {
+            severity,
+            code: code.to_string(),
+            message: message.into(),
+            span: None,
+            field: None,
+            expected: Vec::new(),
+        }
+    }
+
+    /// Problème localisé par une erreur de syntaxe
+    pub fn from_syntax(severity: Severity, code: &str, message: impl Into<String>, syntax: Option<&SyntaxError>) -> Self {
+        let mut diagnostic = Self::new(severity, code, message);
+        if let Some(syntax) = syntax {
+            diagnostic.span = Some(syntax.span);
+            diagnostic.field = syntax.field.clone();
+            diagnostic.expected = syntax.expected.clone();
+        }
+        diagnostic
+    }
+
+    pub fn with_span(mut self, span: Span) -> Self {
+        self.span = Some(span);
+        self
+    }
+
+    pub fn with_field(mut self, field: FieldRef) -> Self {
+        self.field = Some(field);
+        self
+    }
+
+    /// Affiche le problème avec, s'il est localisé, la zone soulignée
+    pub fn render(&self, input: &str) -> String {
+        let syntax = self.span.map(|span| SyntaxError {
+            message: self.message.clone(),
+            span,
+            expected: self.expected.clone(),
+            field: self.field.clone(),
+        });
+        let rendered = render(&self.code, &self.message, syntax.as_ref(), input);
+        match self.severity {
+            Severity::Error => rendered,
+            severity => rendered.replacen("error[", &format!("{}[", severity), 1),
+        }
+    }
+}
+
+/// Résultat d'une analyse tolérante : message reconstruit au mieux et problèmes relevés
+#[derive(Debug, Clone, PartialEq)]
+pub struct Lenient<T> {
+    /// Message reconstruit, absent si le texte est inexploitable
+    pub message: Option<T>,
+
+    /// Tous les problèmes relevés, dans l'ordre des contrôles
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+impl<T> Lenient<T> {
+    /// Indique si au moins un problème de gravité `Error` a été relevé
+    pub fn has_errors(&self) -> bool {
+        self.diagnostics.iter().any(|d| d.severity == Severity::Error)
+    }
+
+    /// Problèmes d'une gravité donnée
+    pub fn with_severity(&self, severity: Severity) -> impl Iterator<Item = &Diagnostic> {
+        self.diagnostics.iter().filter(move |d| d.severity == severity)
+    }
+}
+
+/// Exécute une suite de contrôles en conservant tous les échecs
+pub(crate) struct Checks<E>(pub(crate) Vec<E>);
+
+impl<E> Checks<E> {
+    pub(crate) fn new() -> Self {
+        Checks(Vec::new())
+    }
+
+    /// Conserve une erreur relevée directement
+    pub(crate) fn push(&mut self, error: E) {
+        self.0.push(error);
+    }
+
+    /// Exécute un contrôle et conserve son erreur éventuelle
+    pub(crate) fn run(&mut self, check: impl FnOnce() -> Result<(), E>) {
+        if let Err(error) = check() {
+            self.0.push(error);
+        }
+    }
+
+    /// Premier échec, comme un contrôle qui s'arrête à la première erreur
+    pub(crate) fn first(self) -> Result<(), E> {
+        match self.0.into_iter().next() {
+            Some(error) => Err(error),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Code stable et localisation des erreurs des quatre parsers
+///
+/// Chaque type d'erreur fournit son code et, pour une erreur de syntaxe, la
+/// `SyntaxError` qui la localise ; le reste s'en déduit.
+pub trait LocatedError: fmt::Display {
+    /// Code stable de l'erreur, inchangé d'une version à l'autre
+    fn code(&self) -> &'static str;
+
+    /// Erreur de syntaxe localisée, si le texte a été rejeté par la grammaire
+    fn syntax(&self) -> Option<&SyntaxError>;
+
+    /// Position de l'erreur dans le texte analysé
+    fn span(&self) -> Option<Span> {
+        self.syntax().map(|error| error.span)
+    }
+
+    /// Éléments attendus à la position de l'erreur
+    fn expected(&self) -> &[String] {
+        self.syntax().map(|error| error.expected.as_slice()).unwrap_or(&[])
+    }
+
+    /// Champ en cause, s'il est connu
+    fn field(&self) -> Option<FieldRef> {
+        self.syntax().and_then(|error| error.field.clone())
+    }
+
+    /// Problème de gravité `Error` correspondant, pour le mode tolérant
+    fn to_diagnostic(&self) -> Diagnostic {
+        let mut diagnostic = Diagnostic::from_syntax(Severity::Error, self.code(), self.to_string(), self.syntax());
+        if diagnostic.field.is_none() {
+            diagnostic.field = self.field();
+        }
+        diagnostic
+    }
+
+    /// Affiche le texte analysé avec la zone en erreur soulignée
+    fn render(&self, input: &str) -> String {
+        render(self.code(), &self.to_string(), self.syntax(), input)
+    }
+}
+
+/// Rendu d'une erreur pour un opérateur :
+///
+/// ```text
+/// error[AFTN010]: Parse error: expected address (line 1, column 13)
+///  --> line 1, column 13
+///   |
+/// 1 | GG LFPGYYYX 12 151230 NOTAM
+///   |             ^^
+///   = expected: address
+/// ```
+pub fn render(code: &str, message: &str, syntax: Option<&SyntaxError>, input: &str) -> String {
+    let mut out = format!("error[{}]: {}\n", code, message);
+    let syntax = match syntax {
+        Some(syntax) => syntax,
+        None => return out,
+    };
+    let span = syntax.span;
+
+    // Une position hors du texte (ou au milieu d'un caractère) ne permet pas
+    // de placer le curseur: seul le message est rendu
+    let line_text = span.line.checked_sub(1).and_then(|index| input.lines().nth(index));
+    let (Some(before), Some(line_text)) = (input.get(..span.start), line_text) else {
+        return out;
+    };
+    let gutter = span.line.to_string().len();
+    let line_start = before.rfind('\n').map(|i| i + 1).unwrap_or(0);
+    let line_end = line_start + line_text.len();
+    let end = floor_char_boundary(input, span.end.min(line_end).max(span.start));
+    let width = input.get(span.start..end).map_or(0, |text| text.chars().count()).max(1);
+
+    out.push_str(&format!("{:gutter$}--> line {}, column {}\n", "", span.line, span.column));
+    out.push_str(&format!("{:gutter$} |\n", ""));
+    out.push_str(&format!("{} | {}\n", span.line, line_text));
+    out.push_str(&format!(
+        "{:gutter$} | {}{}\n",
+        "",
+        " ".repeat(span.column.saturating_sub(1)),
+        "^".repeat(width)
+    ));
+    if let Some(ref field) = syntax.field {
+        out.push_str(&format!("{:gutter$} = field: {}\n", "", field));
+    }
+    if !syntax.expected.is_empty() {
+        out.push_str(&format!("{:gutter$} = expected: {}\n", "", syntax.expected.join(", ")));
+    }
+    out
+}
+
+/// Plus grande frontière de caractère inférieure ou égale à `index`
+fn floor_char_boundary(input: &str, mut index: usize) -> usize {
+    while !input.is_char_boundary(index) {
+        index -= 1;
+    }
+    index
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_span_line_and_column() {
+        let span = Span::new("ABC\nDEF GHI", 8, 11);
+        assert_eq!(span.line, 2);
+        assert_eq!(span.column, 5);
+        assert_eq!(span.end, 11);
+    }
+
+    #[test]
+    fn test_render_underlines_span() {
+        let input = "GG LFPGYYYX\nXX 151230";
+        let error = SyntaxError::new("bad token", Span::new(input, 12, 14))
+            .with_field(FieldRef::Index(0));
+        let rendered = error.render("AFTN010", input);
+        let lines: Vec<&str> = rendered.lines().collect();
+        assert_eq!(lines[0], "error[AFTN010]: bad token");
+        assert_eq!(lines[3], "2 | XX 151230");
+        assert_eq!(lines[4], "  | ^^");
+        assert_eq!(lines[5], "  = field: #0");
+    }
+
+    #[test]
+    fn test_render_span_outside_input() {
+        // Position calculée sur un autre texte que celui rendu
+        let error = SyntaxError::new("bad token", Span::new("GG LFPGYYYX\nXX 151230", 12, 14));
+        assert_eq!(error.render("AFTN010", "GG"), "error[AFTN010]: bad token\n");
+
+        // Position au milieu d'un caractère multi-octet
+        let span = Span { start: 2, end: 3, line: 1, column: 2 };
+        let error = SyntaxError::new("bad token", span);
+        assert_eq!(error.render("AFTN010", "Aé B"), "error[AFTN010]: bad token\n");
+    }
+
+    #[test]
+    fn test_checks_keep_every_failure_in_order() {
+        let mut checks = Checks::new();
+        checks.run(|| Err("first"));
+        checks.run(|| Ok(()));
+        checks.push("second");
+        assert_eq!(checks.0, vec!["first", "second"]);
+        assert_eq!(checks.first(), Err("first"));
+    }
+
+    #[test]
+    fn test_warning_render_and_severity_filter() {
+        let input = "GG LFPGYYYX";
+        let warning = Diagnostic::new(Severity::Warning, "AFTN101", "line too long")
+            .with_span(Span::new(input, 3, 11));
+        assert!(warning.render(input).starts_with("warning[AFTN101]: line too long"));
+
+        let result: Lenient<()> = Lenient {
+            message: Some(()),
+            diagnostics: vec![warning, Diagnostic::new(Severity::Info, "AFTN201", "generic")],
+        };
+        assert!(!result.has_errors());
+        assert_eq!(result.with_severity(Severity::Info).count(), 1);
+    }
+}
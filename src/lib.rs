@@ -5,11 +5,26 @@
 //! - **ADEXP 3.4** : Format de présentation des données ATS
 //! - **NMEA 0183** : Format de données de navigation
 //! - **SBS (Mode-S/ADS-B)** : Format de données ADS-B
+//!
+//! Les erreurs de syntaxe des quatre parsers sont localisées (`diagnostic`).
+//! Le format d'un message peut être reconnu automatiquement (`detect`).
 
 pub mod aftn;
 pub mod adexp;
 pub mod nmea;
 pub mod sbs;
+pub mod datetime;
+pub mod diagnostic;
+pub mod detect;
+
+// Ré-exporter la localisation des erreurs
+pub use diagnostic::{Diagnostic, FieldRef, Lenient, LocatedError, Severity, Span, SyntaxError};
+
+// Ré-exporter la reconnaissance du format
+pub use detect::{detect_format, parse_any, AnyError, AnyMessage, Format, MixedStreamReader};
+
+// Ré-exporter l'instant UTC commun aux formats
+pub use datetime::UtcDateTime;
 
 // Ré-exporter AFTN
 pub use aftn::{AftnParser, AftnMessage, AftnError, MessageCategory};
@@ -23,7 +38,8 @@ pub use adexp::types::MessageType as AdexpMessageType;
 // Ré-exporter NMEA
 pub use nmea::{NmeaParser, NmeaMessage, NmeaError};
 pub use nmea::types::NmeaMessageType;
-pub use nmea::{GgaMessage, RmcMessage, GsaMessage, VtgMessage};
+pub use nmea::{GgaMessage, RmcMessage, GsaMessage, VtgMessage, GsvMessage, ZdaMessage, GllMessage, GstMessage};
+pub use nmea::{WplMessage, BodMessage, XteMessage, RmbMessage, HdtMessage, HdgMessage, NmeaSentence};
 pub use nmea::{AisMessage, AisDecodedData, AisMessageData};
 
 // Ré-exporter SBS
@@ -1,28 +1,364 @@
-use aftn::{AftnParser, AftnError};
-
-fn main() -> Result<(), AftnError> {
-    // Exemple de message AFTN
-    let example = "GG LFPGYYYX LFPOYYYX 151230 NOTAM A1234/24 LFPG RWY 09/27 CLOSED";
-    
-    match AftnParser::parse_message(example) {
-        Ok(message) => {
-            println!("Message AFTN parsé avec succès:");
-            println!("  Priorité: {}", message.priority);
-            println!("  Origine: {}", message.addresses.origin);
-            println!("  Destinations: {:?}", message.addresses.destinations);
-            println!("  Date/Heure: {:02}/{:02}:{:02}", 
-                message.transmission_time.day,
-                message.transmission_time.hour,
-                message.transmission_time.minute);
-            println!("  Catégorie: {:?}", message.category);
-            println!("  Corps: {}", message.body);
-        }
-        Err(e) => {
-            eprintln!("Erreur de parsing: {}", e);
-            return Err(e);
-        }
-    }
-    
+//! Outil en ligne de commande `aftn`
+//!
+//! Parse, valide, convertit et compte les messages AFTN, ADEXP, NMEA 0183 et
+//! SBS contenus dans des fichiers journaux ou lus sur l'entrée standard.
+//!
+//! ```text
+//! aftn parse    [--format F] [--output json|ndjson] [FICHIER...]
+//! aftn validate [--format F] [--quiet] [FICHIER...]
+//! aftn convert  --to icao|adexp [--format F] [FICHIER...]
+//! aftn stats    [--format F] [--output text|json] [FICHIER...]
+//! ```
+//!
+//! Sans fichier (ou avec `-`), l'entrée standard est lue. `--format` vaut
+//! `auto` par défaut : le format est reconnu message par message. Les
+//! messages sont traités et écrits au fil de la lecture. Codes de sortie : 0
+//! si tout est valide, 1 si un message est invalide, 2 pour une erreur
+//! d'utilisation ou de lecture.
+
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Write};
+use std::process::ExitCode;
+use serde::Serialize;
+use aftn::{AdexpMessage, AdexpParser, AftnParser, AnyError, AnyMessage, Diagnostic, Format, Lenient, MixedStreamReader, Severity};
+use aftn::detect::RawMessage;
+use aftn::aftn::categories::flight_plan::ats::AtsMessage;
+
+const USAGE: &str = "\
+Usage: aftn <COMMANDE> [OPTIONS] [FICHIER...]
+
+Commandes:
+  parse     Parse les messages et les écrit en JSON
+  validate  Vérifie les messages et liste les problèmes relevés
+  convert   Convertit entre ADEXP et messages ATS OACI
+  stats     Compte les messages par format et par type
+
+Options:
+  --format <auto|aftn|adexp|nmea|sbs>  Format des messages (auto par défaut)
+  --output <json|ndjson|text>          Format de sortie (parse: json, stats: text)
+  --to <icao|adexp>                    Format cible de convert
+  --quiet                              validate: n'affiche que le résumé
+  -h, --help                           Affiche cette aide
+
+Sans FICHIER, ou avec -, l'entrée standard est lue.";
+
+/// Message brut extrait d'un fichier
+struct Record {
+    /// Origine du message (`fichier:ligne`)
+    source: String,
+    raw: RawMessage,
+}
+
+impl Record {
+    fn format(&self) -> Option<Format> {
+        self.raw.detection.map(|detection| detection.format)
+    }
+}
+
+/// Résultat de l'analyse tolérante d'un message
+#[derive(Debug, Serialize)]
+struct Outcome {
+    source: String,
+    format: Option<Format>,
+    confidence: Option<f64>,
+    message: Option<AnyMessage>,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl Outcome {
+    fn has_errors(&self) -> bool {
+        self.diagnostics.iter().any(|d| d.severity == Severity::Error)
+    }
+}
+
+fn analyse(record: &Record) -> Outcome {
+    let result = match record.format() {
+        Some(format) => format.parse_lenient(&record.raw.text),
+        None => Lenient {
+            message: None,
+            diagnostics: vec![AnyError::UnknownFormat.to_diagnostic()],
+        },
+    };
+    Outcome {
+        source: record.source.clone(),
+        format: record.format(),
+        confidence: record.raw.detection.map(|detection| detection.confidence),
+        message: result.message,
+        diagnostics: result.diagnostics,
+    }
+}
+
+/// Options de la ligne de commande
+#[derive(Debug, Default)]
+struct Options {
+    format: Option<Format>,
+    output: Option<String>,
+    to: Option<String>,
+    quiet: bool,
+    files: Vec<String>,
+}
+
+fn parse_options(args: &[String]) -> Result<Options, String> {
+    let mut options = Options::default();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = |name: &str| args.next().cloned().ok_or_else(|| format!("{} attend une valeur", name));
+        match arg.as_str() {
+            "--format" => {
+                let name = value("--format")?;
+                options.format = match name.as_str() {
+                    "auto" => None,
+                    _ => Some(Format::from_name(&name).ok_or_else(|| format!("Format inconnu: {}", name))?),
+                };
+            }
+            "--output" => options.output = Some(value("--output")?),
+            "--to" => options.to = Some(value("--to")?),
+            "--quiet" | "-q" => options.quiet = true,
+            "-" => options.files.push(arg.clone()),
+            _ if arg.starts_with('-') => return Err(format!("Option inconnue: {}", arg)),
+            _ => options.files.push(arg.clone()),
+        }
+    }
+    Ok(options)
+}
+
+/// Lit les fichiers (ou l'entrée standard) et passe chaque message à
+/// `handle` dès qu'il est découpé
+fn for_each_record(options: &Options, mut handle: impl FnMut(Record) -> Result<(), String>) -> Result<(), String> {
+    let files = if options.files.is_empty() { vec!["-".to_string()] } else { options.files.clone() };
+    for file in &files {
+        let (name, reader): (&str, Box<dyn BufRead>) = if file == "-" {
+            ("<stdin>", Box::new(io::stdin().lock()))
+        } else {
+            let handle = File::open(file).map_err(|e| format!("{}: {}", file, e))?;
+            (file.as_str(), Box::new(BufReader::new(handle)))
+        };
+        let mut reader = match options.format {
+            Some(format) => MixedStreamReader::with_format(reader, format),
+            None => MixedStreamReader::new(reader),
+        };
+        while let Some(raw) = reader.next_raw() {
+            let raw = raw.map_err(|e| format!("{}: {}", name, e))?;
+            handle(Record {
+                source: format!("{}:{}", name, raw.line),
+                raw,
+            })?;
+        }
+    }
     Ok(())
 }
 
+fn json<T: Serialize>(value: &T, pretty: bool) -> Result<String, String> {
+    let result = if pretty { serde_json::to_string_pretty(value) } else { serde_json::to_string(value) };
+    result.map_err(|e| e.to_string())
+}
+
+fn run_parse(options: &Options, out: &mut impl Write) -> Result<bool, String> {
+    let pretty = match options.output.as_deref().unwrap_or("json") {
+        "json" => true,
+        "ndjson" => false,
+        other => return Err(format!("Sortie inconnue pour parse: {}", other)),
+    };
+    let mut all_parsed = true;
+    let mut count = 0;
+    for_each_record(options, |record| {
+        let outcome = analyse(&record);
+        all_parsed &= outcome.message.is_some();
+        // json : tableau écrit élément par élément ; ndjson : un objet par ligne
+        let text = json(&outcome, pretty)?;
+        let written = if pretty {
+            write!(out, "{}{}", if count == 0 { "[\n" } else { ",\n" }, text)
+        } else {
+            writeln!(out, "{}", text)
+        };
+        count += 1;
+        written.map_err(|e| e.to_string())
+    })?;
+    if pretty {
+        writeln!(out, "{}", if count == 0 { "[]" } else { "\n]" }).map_err(|e| e.to_string())?;
+    }
+    Ok(all_parsed)
+}
+
+fn run_validate(options: &Options, out: &mut impl Write) -> Result<bool, String> {
+    let mut counts: BTreeMap<Severity, usize> = BTreeMap::new();
+    let mut messages = 0;
+    let mut invalid = 0;
+    for_each_record(options, |record| {
+        let outcome = analyse(&record);
+        messages += 1;
+        for diagnostic in &outcome.diagnostics {
+            *counts.entry(diagnostic.severity).or_default() += 1;
+            if !options.quiet {
+                writeln!(out, "{}: {}", outcome.source, diagnostic.render(&record.raw.text).trim_end()).map_err(|e| e.to_string())?;
+            }
+        }
+        if outcome.has_errors() {
+            invalid += 1;
+        }
+        Ok(())
+    })?;
+    let count = |severity| counts.get(&severity).copied().unwrap_or(0);
+    writeln!(
+        out,
+        "{} message(s), {} invalide(s) : {} erreur(s), {} avertissement(s), {} information(s)",
+        messages,
+        invalid,
+        count(Severity::Error),
+        count(Severity::Warning),
+        count(Severity::Info),
+    ).map_err(|e| e.to_string())?;
+    Ok(invalid == 0)
+}
+
+/// Convertit un message vers le format cible, avec les données perdues
+fn convert(record: &Record, target: &str) -> Result<(String, Vec<String>), String> {
+    let losses = |losses: &[aftn::adexp::icao::ConversionLoss]| {
+        losses.iter()
+            .map(|l| format!("{} '{}' non repris : {}", l.field, l.value, l.reason))
+            .collect::<Vec<_>>()
+    };
+    let text = record.raw.text.as_str();
+    match (record.format(), target) {
+        (Some(Format::Adexp), "icao") => {
+            let message = AdexpParser::parse_message(text).map_err(|e| e.to_string())?;
+            let conversion = message.to_ats().map_err(|e| e.to_string())?;
+            Ok((conversion.message.serialize(), losses(&conversion.losses)))
+        }
+        (Some(Format::Aftn), "adexp") => {
+            // Message AFTN complet, ou message ATS seul "(FPL-...)"
+            let body = if text.trim_start().starts_with('(') {
+                text.trim().to_string()
+            } else {
+                AftnParser::parse_message(text).map_err(|e| e.to_string())?.body
+            };
+            let ats = AtsMessage::parse(&body).map_err(|e| e.to_string())?;
+            let conversion = AdexpMessage::from_ats(&ats).map_err(|e| e.to_string())?;
+            Ok((conversion.message.serialize(), losses(&conversion.losses)))
+        }
+        (Some(format), target) => Err(format!("Conversion {} vers {} non prise en charge", format, target)),
+        (None, _) => Err(AnyError::UnknownFormat.to_string()),
+    }
+}
+
+fn run_convert(options: &Options, out: &mut impl Write) -> Result<bool, String> {
+    let target = match options.to.as_deref() {
+        Some(target @ ("icao" | "adexp")) => target,
+        Some(other) => return Err(format!("Format cible inconnu: {}", other)),
+        None => return Err("convert attend --to icao|adexp".to_string()),
+    };
+    let mut success = true;
+    for_each_record(options, |record| {
+        match convert(&record, target) {
+            Ok((text, losses)) => {
+                writeln!(out, "{}\n", text).map_err(|e| e.to_string())?;
+                for loss in losses {
+                    eprintln!("{}: warning: {}", record.source, loss);
+                }
+            }
+            Err(error) => {
+                eprintln!("{}: error: {}", record.source, error);
+                success = false;
+            }
+        }
+        Ok(())
+    })?;
+    Ok(success)
+}
+
+/// Compteurs d'un format
+#[derive(Debug, Default, Serialize)]
+struct FormatStats {
+    messages: usize,
+    failures: usize,
+    failure_rate: f64,
+    types: BTreeMap<String, usize>,
+}
+
+fn run_stats(options: &Options, out: &mut impl Write) -> Result<bool, String> {
+    let mut stats: BTreeMap<&str, FormatStats> = BTreeMap::new();
+    for_each_record(options, |record| {
+        let outcome = analyse(&record);
+        let entry = stats.entry(outcome.format.map_or("unknown", Format::name)).or_default();
+        entry.messages += 1;
+        if outcome.has_errors() {
+            entry.failures += 1;
+        }
+        let kind = outcome.message.as_ref().map_or_else(|| "unparsed".to_string(), AnyMessage::kind);
+        *entry.types.entry(kind).or_default() += 1;
+        Ok(())
+    })?;
+    for entry in stats.values_mut() {
+        entry.failure_rate = entry.failures as f64 / entry.messages as f64;
+    }
+
+    match options.output.as_deref().unwrap_or("text") {
+        "json" => writeln!(out, "{}", json(&stats, true)?).map_err(|e| e.to_string())?,
+        "text" => {
+            for (format, entry) in &stats {
+                writeln!(
+                    out,
+                    "{}: {} message(s), {} en échec ({:.1} %)",
+                    format,
+                    entry.messages,
+                    entry.failures,
+                    entry.failure_rate * 100.0
+                ).map_err(|e| e.to_string())?;
+                for (kind, count) in &entry.types {
+                    writeln!(out, "  {:<32} {}", kind, count).map_err(|e| e.to_string())?;
+                }
+            }
+        }
+        other => return Err(format!("Sortie inconnue pour stats: {}", other)),
+    }
+    Ok(true)
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let Some(command) = args.first() else {
+        eprintln!("{}", USAGE);
+        return ExitCode::from(2);
+    };
+    if command == "-h" || command == "--help" || args.iter().any(|a| a == "-h" || a == "--help") {
+        println!("{}", USAGE);
+        return ExitCode::SUCCESS;
+    }
+
+    let result = parse_options(&args[1..]).and_then(|options| {
+        let mut out = io::stdout().lock();
+        match command.as_str() {
+            "parse" => run_parse(&options, &mut out),
+            "validate" => run_validate(&options, &mut out),
+            "convert" => run_convert(&options, &mut out),
+            "stats" => run_stats(&options, &mut out),
+            other => Err(format!("Commande inconnue: {}\n\n{}", other, USAGE)),
+        }
+    });
+
+    match result {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::from(1),
+        Err(error) => {
+            eprintln!("aftn: {}", error);
+            ExitCode::from(2)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_options() {
+        let args: Vec<String> = ["--format", "nmea", "--output", "ndjson", "a.log", "-"].iter().map(|s| s.to_string()).collect();
+        let options = parse_options(&args).unwrap();
+        assert_eq!(options.format, Some(Format::Nmea));
+        assert_eq!(options.output.as_deref(), Some("ndjson"));
+        assert_eq!(options.files, vec!["a.log", "-"]);
+        assert!(parse_options(&["--format".to_string()]).is_err());
+        assert!(parse_options(&["--bogus".to_string()]).is_err());
+    }
+}
@@ -9,18 +9,20 @@
 use serde::{Deserialize, Serialize};
 use crate::nmea::error::NmeaError;
 use crate::nmea::message::NmeaMessage;
+use crate::nmea::tag_block::TagBlock;
 
 /// Message AIS (AIVDM ou AIVDO)
-/// Format: $AIVDM,seq_num,frag_num,radio_channel,payload,fill_bits*checksum
+/// Format: !AIVDM,frag_count,frag_num,seq_id,radio_channel,payload,fill_bits*checksum
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AisMessage {
     /// Type de message AIS (AIVDM ou AIVDO)
     pub message_type: String,
-    /// Numéro de séquence (1-9)
+    /// Identifiant séquentiel du message multi-fragments (0-9, vide pour un
+    /// message en un seul fragment)
     pub sequence_number: Option<u8>,
-    /// Numéro de fragment (1-9)
+    /// Numéro du fragment (1-9)
     pub fragment_number: Option<u8>,
-    /// Numéro de fragment total (optionnel, pour messages multi-fragments)
+    /// Nombre total de fragments du message (1-9)
     pub total_fragments: Option<u8>,
     /// Canal radio (A ou B)
     pub radio_channel: Option<char>,
@@ -30,6 +32,9 @@ pub struct AisMessage {
     pub fill_bits: Option<u8>,
     /// Données décodées (si disponible)
     pub decoded_data: Option<AisDecodedData>,
+    /// Bloc TAG de la phrase (du premier fragment pour un message réassemblé)
+    #[serde(default)]
+    pub tag_block: Option<TagBlock>,
 }
 
 /// Données AIS décodées
@@ -88,10 +93,191 @@ pub enum AisMessageData {
         /// Destination
         destination: Option<String>,
     },
+    /// Message de type 4 (et 11) : Base Station Report (UTC/Date Response)
+    BaseStationReport {
+        /// Date et heure UTC de la station (absente si non disponible)
+        utc: Option<AisUtcTime>,
+        /// Précision de position (true = haute précision)
+        position_accuracy: bool,
+        /// Longitude (degrés décimaux)
+        longitude: Option<f64>,
+        /// Latitude (degrés décimaux)
+        latitude: Option<f64>,
+        /// Type de système de positionnement
+        position_fix_type: u8,
+    },
+    /// Message de type 9 : Standard SAR Aircraft Position Report
+    SarAircraftReport {
+        /// Altitude (mètres)
+        altitude: Option<u16>,
+        /// Vitesse sur le fond (noeuds)
+        speed_over_ground: Option<f64>,
+        /// Précision de position
+        position_accuracy: bool,
+        /// Longitude (degrés décimaux)
+        longitude: Option<f64>,
+        /// Latitude (degrés décimaux)
+        latitude: Option<f64>,
+        /// Route sur le fond (degrés)
+        course_over_ground: Option<f64>,
+        /// Time stamp (secondes UTC)
+        timestamp: Option<u8>,
+    },
+    /// Message de type 18 : Standard Class B Position Report
+    ClassBPositionReport {
+        /// Vitesse sur le fond (noeuds)
+        speed_over_ground: Option<f64>,
+        /// Précision de position
+        position_accuracy: bool,
+        /// Longitude (degrés décimaux)
+        longitude: Option<f64>,
+        /// Latitude (degrés décimaux)
+        latitude: Option<f64>,
+        /// Route sur le fond (degrés)
+        course_over_ground: Option<f64>,
+        /// Cap vrai (degrés)
+        true_heading: Option<u16>,
+        /// Time stamp (secondes UTC)
+        timestamp: Option<u8>,
+    },
+    /// Message de type 19 : Extended Class B Position Report
+    ExtendedClassBPositionReport {
+        /// Vitesse sur le fond (noeuds)
+        speed_over_ground: Option<f64>,
+        /// Précision de position
+        position_accuracy: bool,
+        /// Longitude (degrés décimaux)
+        longitude: Option<f64>,
+        /// Latitude (degrés décimaux)
+        latitude: Option<f64>,
+        /// Route sur le fond (degrés)
+        course_over_ground: Option<f64>,
+        /// Cap vrai (degrés)
+        true_heading: Option<u16>,
+        /// Time stamp (secondes UTC)
+        timestamp: Option<u8>,
+        /// Nom du navire
+        vessel_name: Option<String>,
+        /// Type de navire
+        ship_type: Option<u8>,
+        /// Dimensions du navire
+        dimensions: Option<AisDimensions>,
+        /// Type de système de positionnement
+        position_fix_type: u8,
+    },
+    /// Message de type 21 : Aid-to-Navigation Report
+    AidToNavigation {
+        /// Type d'aide à la navigation (0-31)
+        aid_type: u8,
+        /// Nom (extension de nom comprise)
+        name: Option<String>,
+        /// Précision de position
+        position_accuracy: bool,
+        /// Longitude (degrés décimaux)
+        longitude: Option<f64>,
+        /// Latitude (degrés décimaux)
+        latitude: Option<f64>,
+        /// Dimensions de l'aide
+        dimensions: Option<AisDimensions>,
+        /// Type de système de positionnement
+        position_fix_type: u8,
+        /// Time stamp (secondes UTC)
+        timestamp: Option<u8>,
+        /// Aide hors de sa position assignée
+        off_position: bool,
+        /// Aide virtuelle (sans support physique)
+        virtual_aid: bool,
+    },
+    /// Message de type 24 partie A : Static Data Report (nom)
+    StaticDataReportA {
+        /// Nom du navire
+        vessel_name: Option<String>,
+    },
+    /// Message de type 24 partie B : Static Data Report (type, identifiants, dimensions)
+    StaticDataReportB {
+        /// Type de navire
+        ship_type: Option<u8>,
+        /// Identifiant du fabricant de l'équipement
+        vendor_id: Option<String>,
+        /// Indicatif d'appel
+        call_sign: Option<String>,
+        /// Dimensions du navire (navires autres qu'auxiliaires)
+        dimensions: Option<AisDimensions>,
+        /// MMSI du navire porteur (embarcations auxiliaires, MMSI 98xxxxxxx)
+        mothership_mmsi: Option<u32>,
+    },
+    /// Message de type 27 : Long Range AIS Broadcast
+    LongRangePosition {
+        /// Précision de position
+        position_accuracy: bool,
+        /// Statut de navigation
+        navigation_status: u8,
+        /// Longitude (degrés décimaux, résolution 1/10 minute)
+        longitude: Option<f64>,
+        /// Latitude (degrés décimaux, résolution 1/10 minute)
+        latitude: Option<f64>,
+        /// Vitesse sur le fond (noeuds)
+        speed_over_ground: Option<f64>,
+        /// Route sur le fond (degrés)
+        course_over_ground: Option<f64>,
+        /// Position GNSS courante (false si la position est ancienne)
+        gnss_position_current: bool,
+    },
+    /// Messages de type 6 (adressé) et 8 (diffusé) : enveloppe binaire
+    BinaryMessage {
+        /// MMSI destinataire (type 6 uniquement)
+        destination_mmsi: Option<u32>,
+        /// Message retransmis (type 6 uniquement)
+        retransmitted: bool,
+        /// Designated Area Code
+        dac: u16,
+        /// Function Identifier
+        fi: u8,
+        /// Données applicatives, alignées à gauche
+        data: Vec<u8>,
+        /// Nombre de bits significatifs de `data`
+        data_bits: usize,
+    },
     /// Message générique (pour types non encore implémentés)
     Generic(Vec<u8>),
 }
 
+impl AisMessageData {
+    /// Position (latitude, longitude) en degrés décimaux, si le message en
+    /// porte une et qu'elle est disponible (sentinelles 91/181 exclues)
+    pub fn position(&self) -> Option<(f64, f64)> {
+        match self {
+            AisMessageData::PositionReport { latitude, longitude, .. } => Some((
+                ais_latitude(*latitude.as_ref()?, 600_000.0)?,
+                ais_longitude(*longitude.as_ref()?, 600_000.0)?,
+            )),
+            AisMessageData::BaseStationReport { latitude, longitude, .. }
+            | AisMessageData::SarAircraftReport { latitude, longitude, .. }
+            | AisMessageData::ClassBPositionReport { latitude, longitude, .. }
+            | AisMessageData::ExtendedClassBPositionReport { latitude, longitude, .. }
+            | AisMessageData::AidToNavigation { latitude, longitude, .. }
+            | AisMessageData::LongRangePosition { latitude, longitude, .. } => Some(((*latitude)?, (*longitude)?)),
+            _ => None,
+        }
+    }
+}
+
+/// Date et heure UTC d'une station de base AIS
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AisUtcTime {
+    pub year: u16,
+    /// Mois (1-12)
+    pub month: u8,
+    /// Jour (1-31)
+    pub day: u8,
+    /// Heure (0-23)
+    pub hour: u8,
+    /// Minute (0-59)
+    pub minute: u8,
+    /// Seconde (0-59)
+    pub second: u8,
+}
+
 /// Dimensions d'un navire AIS
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct AisDimensions {
@@ -123,15 +309,15 @@ impl AisMessage {
             ));
         }
         
-        let message_type = message.message_type.identifier().to_string();
+        let message_type = message.message_type.identifier().into_owned();
         
         Ok(AisMessage {
             message_type: message_type.clone(),
-            sequence_number: message.get_field(0)
+            total_fragments: message.get_field(0)
                 .and_then(|s| if s.is_empty() { None } else { s.parse::<u8>().ok() }),
             fragment_number: message.get_field(1)
                 .and_then(|s| if s.is_empty() { None } else { s.parse::<u8>().ok() }),
-            total_fragments: message.get_field(2)
+            sequence_number: message.get_field(2)
                 .and_then(|s| if s.is_empty() { None } else { s.parse::<u8>().ok() }),
             radio_channel: message.get_field(3)
                 .and_then(|s| if s.is_empty() { None } else { s.chars().next() }),
@@ -141,6 +327,7 @@ impl AisMessage {
             fill_bits: message.get_field(5)
                 .and_then(|s| if s.is_empty() { None } else { s.parse::<u8>().ok() }),
             decoded_data: None, // Sera décodé si nécessaire
+            tag_block: message.tag_block.clone(),
         })
     }
     
@@ -157,6 +344,7 @@ impl AisMessage {
             return Err(NmeaError::InvalidFormat("Empty AIS payload".to_string()));
         }
         
+        let bit_length = (self.payload.len() * 6).saturating_sub(self.fill_bits.unwrap_or(0) as usize);
         let message_id = (binary_data[0] >> 2) & 0x3F;
         let mmsi = extract_mmsi(&binary_data)?;
         
@@ -189,6 +377,14 @@ impl AisMessage {
                     destination: extract_destination(&binary_data),
                 }
             }
+            4 | 11 => decode_base_station_report(message_id, &binary_data, bit_length)?,
+            9 => decode_sar_aircraft_report(&binary_data, bit_length)?,
+            18 => decode_class_b_position_report(&binary_data, bit_length)?,
+            19 => decode_extended_class_b_position_report(&binary_data, bit_length)?,
+            21 => decode_aid_to_navigation(&binary_data, bit_length)?,
+            24 => decode_static_data_report(&binary_data, bit_length)?,
+            27 => decode_long_range_position(&binary_data, bit_length)?,
+            6 | 8 => decode_binary_message(message_id, &binary_data, bit_length)?,
             _ => AisMessageData::Generic(binary_data),
         };
         
@@ -205,26 +401,16 @@ impl AisMessage {
 /// Décode une chaîne AIS 6-bit en données binaires
 fn decode_ais_6bit(encoded: &str, fill_bits: u8) -> Result<Vec<u8>, NmeaError> {
     // Table de conversion 6-bit ASCII vers binaire
-    // Les caractères ASCII 0x30-0x77 sont convertis en valeurs 0-63
+    // Les caractères ASCII 0x30-0x57 donnent les valeurs 0-39 et 0x60-0x77
+    // les valeurs 40-63 (0x58-0x5F ne sont pas utilisés)
     let mut result = Vec::new();
     let mut bit_buffer: u32 = 0;
-    let mut bit_count = 0;
+    let mut bit_count: u8 = 0;
     
     for ch in encoded.chars() {
         let value = match ch as u8 {
-            b @ 0x30..=0x77 => {
-                let decoded = if b < 0x40 {
-                    b - 0x30
-                } else {
-                    b - 0x38
-                };
-                if decoded > 63 {
-                    return Err(NmeaError::InvalidFormat(
-                        format!("Invalid 6-bit ASCII character: {}", ch)
-                    ));
-                }
-                decoded
-            }
+            b @ 0x30..=0x57 => b - 0x30,
+            b @ 0x60..=0x77 => b - 0x38,
             _ => {
                 return Err(NmeaError::InvalidFormat(
                     format!("Invalid character in AIS payload: {}", ch)
@@ -241,13 +427,10 @@ fn decode_ais_6bit(encoded: &str, fill_bits: u8) -> Result<Vec<u8>, NmeaError> {
         }
     }
     
-    // Gérer les bits de remplissage
-    if fill_bits > 0 && bit_count > 0 {
-        bit_buffer >>= fill_bits;
-        bit_count -= fill_bits;
-        if bit_count >= 8 {
-            result.push((bit_buffer >> (bit_count - 8)) as u8);
-        }
+    // Bits restants hors bits de remplissage, alignés à gauche dans un dernier octet
+    let remaining = bit_count.saturating_sub(fill_bits);
+    if remaining > 0 {
+        result.push(((bit_buffer >> (bit_count - remaining)) << (8 - remaining)) as u8);
     }
     
     Ok(result)
@@ -282,7 +465,7 @@ fn extract_int(data: &[u8], start_bit: usize, length: usize) -> i32 {
     }
 }
 
-/// Extrait une chaîne ASCII depuis les données binaires AIS
+/// Extrait une chaîne ASCII de `length` caractères 6 bits depuis les données binaires AIS
 fn extract_string(data: &[u8], start_bit: usize, length: usize) -> String {
     let mut result = String::new();
     for i in 0..length {
@@ -377,14 +560,14 @@ fn extract_call_sign(data: &[u8]) -> Option<String> {
     if data.len() < 8 {
         return None;
     }
-    Some(extract_string(data, 112, 42))
+    Some(extract_string(data, 70, 7))
 }
 
 fn extract_vessel_name(data: &[u8]) -> Option<String> {
     if data.len() < 8 {
         return None;
     }
-    Some(extract_string(data, 112, 120))
+    Some(extract_string(data, 112, 20))
 }
 
 fn extract_ship_type(data: &[u8]) -> Option<u8> {
@@ -398,9 +581,10 @@ fn extract_dimensions(data: &[u8]) -> Option<AisDimensions> {
     if data.len() < 8 {
         return None;
     }
+    // Distances de l'antenne à l'avant/arrière (9 bits) et bâbord/tribord (6 bits)
     Some(AisDimensions {
-        length: extract_uint(data, 240, 9) as u16,
-        width: extract_uint(data, 249, 9) as u16,
+        length: (extract_uint(data, 240, 9) + extract_uint(data, 249, 9)) as u16,
+        width: (extract_uint(data, 258, 6) + extract_uint(data, 264, 6)) as u16,
     })
 }
 
@@ -408,7 +592,7 @@ fn extract_position_fix_type(data: &[u8]) -> Option<u8> {
     if data.len() < 8 {
         return None;
     }
-    Some(extract_uint(data, 258, 4) as u8)
+    Some(extract_uint(data, 270, 4) as u8)
 }
 
 fn extract_eta(data: &[u8]) -> Option<AisEta> {
@@ -416,10 +600,10 @@ fn extract_eta(data: &[u8]) -> Option<AisEta> {
         return None;
     }
     Some(AisEta {
-        month: extract_uint(data, 262, 4) as u8,
-        day: extract_uint(data, 266, 5) as u8,
-        hour: extract_uint(data, 271, 5) as u8,
-        minute: extract_uint(data, 276, 6) as u8,
+        month: extract_uint(data, 274, 4) as u8,
+        day: extract_uint(data, 278, 5) as u8,
+        hour: extract_uint(data, 283, 5) as u8,
+        minute: extract_uint(data, 288, 6) as u8,
     })
 }
 
@@ -427,13 +611,238 @@ fn extract_draught(data: &[u8]) -> Option<u16> {
     if data.len() < 8 {
         return None;
     }
-    Some(extract_uint(data, 282, 8) as u16)
+    Some(extract_uint(data, 294, 8) as u16)
 }
 
 fn extract_destination(data: &[u8]) -> Option<String> {
     if data.len() < 8 {
         return None;
     }
-    Some(extract_string(data, 290, 120))
+    Some(extract_string(data, 302, 20))
+}
+
+
+// Décodage des types 4, 6, 8, 9, 11, 18, 19, 21, 24 et 27 (ITU-R M.1371)
+
+/// Vérifie que le message contient au moins `min_bits` bits
+fn require_bits(message_id: u8, bit_length: usize, min_bits: usize) -> Result<(), NmeaError> {
+    if bit_length < min_bits {
+        return Err(NmeaError::InvalidFormat(format!(
+            "AIS message type {} must have at least {} bits, got {}",
+            message_id, min_bits, bit_length
+        )));
+    }
+    Ok(())
+}
+
+/// Longitude en degrés décimaux (`scale` unités par degré), `None` pour la
+/// sentinelle 181 (non disponible) et les valeurs hors limites
+fn ais_longitude(raw: i32, scale: f64) -> Option<f64> {
+    let degrees = raw as f64 / scale;
+    (degrees.abs() <= 180.0).then_some(degrees)
 }
 
+/// Latitude en degrés décimaux, `None` pour la sentinelle 91 (non disponible)
+fn ais_latitude(raw: i32, scale: f64) -> Option<f64> {
+    let degrees = raw as f64 / scale;
+    (degrees.abs() <= 90.0).then_some(degrees)
+}
+
+/// Vitesse en dixièmes de noeud, `None` pour 1023 (non disponible)
+fn ais_speed(raw: u32) -> Option<f64> {
+    (raw != 1023).then(|| raw as f64 / 10.0)
+}
+
+/// Route en dixièmes de degré, `None` pour 3600 (non disponible)
+fn ais_course(raw: u32) -> Option<f64> {
+    (raw < 3600).then(|| raw as f64 / 10.0)
+}
+
+/// Cap vrai en degrés, `None` pour 511 (non disponible)
+fn ais_heading(raw: u32) -> Option<u16> {
+    (raw < 360).then_some(raw as u16)
+}
+
+/// Seconde UTC du rapport, `None` pour 60 à 63 (non disponible ou mode dégradé)
+fn ais_second(raw: u32) -> Option<u8> {
+    (raw < 60).then_some(raw as u8)
+}
+
+/// Chaîne 6 bits, `None` si vide
+fn ais_text(data: &[u8], start_bit: usize, length: usize) -> Option<String> {
+    Some(extract_string(data, start_bit, length)).filter(|text| !text.is_empty())
+}
+
+/// Dimensions depuis les distances de l'antenne (avant 9 bits, arrière 9 bits,
+/// bâbord 6 bits, tribord 6 bits), `None` si toutes nulles
+fn ais_dimensions(data: &[u8], start_bit: usize) -> Option<AisDimensions> {
+    let dimensions = AisDimensions {
+        length: (extract_uint(data, start_bit, 9) + extract_uint(data, start_bit + 9, 9)) as u16,
+        width: (extract_uint(data, start_bit + 18, 6) + extract_uint(data, start_bit + 24, 6)) as u16,
+    };
+    (dimensions.length > 0 || dimensions.width > 0).then_some(dimensions)
+}
+
+fn decode_base_station_report(message_id: u8, data: &[u8], bit_length: usize) -> Result<AisMessageData, NmeaError> {
+    require_bits(message_id, bit_length, 168)?;
+    let utc = AisUtcTime {
+        year: extract_uint(data, 38, 14) as u16,
+        month: extract_uint(data, 52, 4) as u8,
+        day: extract_uint(data, 56, 5) as u8,
+        hour: extract_uint(data, 61, 5) as u8,
+        minute: extract_uint(data, 66, 6) as u8,
+        second: extract_uint(data, 72, 6) as u8,
+    };
+    let available = utc.year > 0 && utc.month > 0 && utc.day > 0 && utc.hour < 24 && utc.minute < 60 && utc.second < 60;
+    Ok(AisMessageData::BaseStationReport {
+        utc: available.then_some(utc),
+        position_accuracy: extract_uint(data, 78, 1) != 0,
+        longitude: ais_longitude(extract_int(data, 79, 28), 600_000.0),
+        latitude: ais_latitude(extract_int(data, 107, 27), 600_000.0),
+        position_fix_type: extract_uint(data, 134, 4) as u8,
+    })
+}
+
+fn decode_sar_aircraft_report(data: &[u8], bit_length: usize) -> Result<AisMessageData, NmeaError> {
+    require_bits(9, bit_length, 168)?;
+    let altitude = extract_uint(data, 38, 12);
+    // Vitesse au noeud près (et non au dixième) pour les aéronefs
+    let speed = extract_uint(data, 50, 10);
+    Ok(AisMessageData::SarAircraftReport {
+        altitude: (altitude != 4095).then_some(altitude as u16),
+        speed_over_ground: (speed != 1023).then_some(speed as f64),
+        position_accuracy: extract_uint(data, 60, 1) != 0,
+        longitude: ais_longitude(extract_int(data, 61, 28), 600_000.0),
+        latitude: ais_latitude(extract_int(data, 89, 27), 600_000.0),
+        course_over_ground: ais_course(extract_uint(data, 116, 12)),
+        timestamp: ais_second(extract_uint(data, 128, 6)),
+    })
+}
+
+fn decode_class_b_position_report(data: &[u8], bit_length: usize) -> Result<AisMessageData, NmeaError> {
+    require_bits(18, bit_length, 168)?;
+    Ok(AisMessageData::ClassBPositionReport {
+        speed_over_ground: ais_speed(extract_uint(data, 46, 10)),
+        position_accuracy: extract_uint(data, 56, 1) != 0,
+        longitude: ais_longitude(extract_int(data, 57, 28), 600_000.0),
+        latitude: ais_latitude(extract_int(data, 85, 27), 600_000.0),
+        course_over_ground: ais_course(extract_uint(data, 112, 12)),
+        true_heading: ais_heading(extract_uint(data, 124, 9)),
+        timestamp: ais_second(extract_uint(data, 133, 6)),
+    })
+}
+
+fn decode_extended_class_b_position_report(data: &[u8], bit_length: usize) -> Result<AisMessageData, NmeaError> {
+    require_bits(19, bit_length, 312)?;
+    let ship_type = extract_uint(data, 263, 8) as u8;
+    Ok(AisMessageData::ExtendedClassBPositionReport {
+        speed_over_ground: ais_speed(extract_uint(data, 46, 10)),
+        position_accuracy: extract_uint(data, 56, 1) != 0,
+        longitude: ais_longitude(extract_int(data, 57, 28), 600_000.0),
+        latitude: ais_latitude(extract_int(data, 85, 27), 600_000.0),
+        course_over_ground: ais_course(extract_uint(data, 112, 12)),
+        true_heading: ais_heading(extract_uint(data, 124, 9)),
+        timestamp: ais_second(extract_uint(data, 133, 6)),
+        vessel_name: ais_text(data, 143, 20),
+        ship_type: (ship_type > 0).then_some(ship_type),
+        dimensions: ais_dimensions(data, 271),
+        position_fix_type: extract_uint(data, 301, 4) as u8,
+    })
+}
+
+fn decode_aid_to_navigation(data: &[u8], bit_length: usize) -> Result<AisMessageData, NmeaError> {
+    require_bits(21, bit_length, 272)?;
+    // Extension de nom : jusqu'à 14 caractères au-delà du bit 272
+    let mut name = extract_string(data, 43, 20);
+    let extension_chars = (bit_length - 272) / 6;
+    if extension_chars > 0 {
+        name.push_str(&extract_string(data, 272, extension_chars));
+    }
+    Ok(AisMessageData::AidToNavigation {
+        aid_type: extract_uint(data, 38, 5) as u8,
+        name: Some(name).filter(|name| !name.is_empty()),
+        position_accuracy: extract_uint(data, 163, 1) != 0,
+        longitude: ais_longitude(extract_int(data, 164, 28), 600_000.0),
+        latitude: ais_latitude(extract_int(data, 192, 27), 600_000.0),
+        dimensions: ais_dimensions(data, 219),
+        position_fix_type: extract_uint(data, 249, 4) as u8,
+        timestamp: ais_second(extract_uint(data, 253, 6)),
+        off_position: extract_uint(data, 259, 1) != 0,
+        virtual_aid: extract_uint(data, 269, 1) != 0,
+    })
+}
+
+fn decode_static_data_report(data: &[u8], bit_length: usize) -> Result<AisMessageData, NmeaError> {
+    require_bits(24, bit_length, 40)?;
+    match extract_uint(data, 38, 2) {
+        0 => {
+            require_bits(24, bit_length, 160)?;
+            Ok(AisMessageData::StaticDataReportA {
+                vessel_name: ais_text(data, 40, 20),
+            })
+        }
+        1 => {
+            require_bits(24, bit_length, 162)?;
+            let mmsi = extract_uint(data, 8, 30);
+            let auxiliary = mmsi / 10_000_000 == 98;
+            let ship_type = extract_uint(data, 40, 8) as u8;
+            Ok(AisMessageData::StaticDataReportB {
+                ship_type: (ship_type > 0).then_some(ship_type),
+                vendor_id: ais_text(data, 48, 3),
+                call_sign: ais_text(data, 90, 7),
+                dimensions: if auxiliary { None } else { ais_dimensions(data, 132) },
+                mothership_mmsi: if auxiliary { Some(extract_uint(data, 132, 30)) } else { None },
+            })
+        }
+        part => Err(NmeaError::InvalidFieldValue {
+            field: "AIS type 24 part number".to_string(),
+            value: part.to_string(),
+            reason: "Must be 0 (part A) or 1 (part B)".to_string(),
+        }),
+    }
+}
+
+fn decode_long_range_position(data: &[u8], bit_length: usize) -> Result<AisMessageData, NmeaError> {
+    require_bits(27, bit_length, 96)?;
+    let speed = extract_uint(data, 79, 6);
+    let course = extract_uint(data, 85, 9);
+    Ok(AisMessageData::LongRangePosition {
+        position_accuracy: extract_uint(data, 38, 1) != 0,
+        navigation_status: extract_uint(data, 40, 4) as u8,
+        longitude: ais_longitude(extract_int(data, 44, 18), 600.0),
+        latitude: ais_latitude(extract_int(data, 62, 17), 600.0),
+        speed_over_ground: (speed != 63).then_some(speed as f64),
+        course_over_ground: (course < 360).then_some(course as f64),
+        gnss_position_current: extract_uint(data, 94, 1) == 0,
+    })
+}
+
+fn decode_binary_message(message_id: u8, data: &[u8], bit_length: usize) -> Result<AisMessageData, NmeaError> {
+    // Type 6 : numéro de séquence, MMSI destinataire et retransmission
+    // précèdent DAC/FI ; type 8 : 2 bits de réserve
+    let (destination_mmsi, retransmitted, header) = if message_id == 6 {
+        (Some(extract_uint(data, 40, 30)), extract_uint(data, 70, 1) != 0, 72)
+    } else {
+        (None, false, 40)
+    };
+    require_bits(message_id, bit_length, header + 16)?;
+
+    let start = header + 16;
+    let data_bits = bit_length - start;
+    let payload = (start..bit_length)
+        .step_by(8)
+        .map(|bit| {
+            let length = (bit_length - bit).min(8);
+            (extract_uint(data, bit, length) << (8 - length)) as u8
+        })
+        .collect();
+
+    Ok(AisMessageData::BinaryMessage {
+        destination_mmsi,
+        retransmitted,
+        dac: extract_uint(data, header, 10) as u16,
+        fi: extract_uint(data, header + 10, 6) as u8,
+        data: payload,
+        data_bits,
+    })
+}
@@ -0,0 +1,282 @@
+//! Encodage des messages AIS en phrases AIVDM/AIVDO
+//!
+//! Chemin inverse du décodage : les données (`AisDecodedData`) sont écrites
+//! bit à bit selon ITU-R M.1371, armées en ASCII 6 bits, découpées en
+//! fragments avec les bits de remplissage du dernier fragment, puis
+//! encapsulées dans des phrases `!AIVDM` (navires reçus) ou `!AIVDO` (navire
+//! propre) avec checksum.
+//!
+//! Types encodables : rapports de position classe A (1-3), données
+//! statiques et de voyage (5), position classe B (18) et données statiques
+//! classe B (24 parties A et B).
+
+use crate::nmea::ais::{AisDecodedData, AisDimensions, AisMessageData};
+use crate::nmea::error::NmeaError;
+use crate::nmea::message::NmeaMessage;
+use crate::nmea::types::{NmeaMessageType, SentenceFormatter, TalkerId};
+
+/// Nombre maximal de caractères de payload par fragment (la phrase complète
+/// reste sous la limite NMEA de 82 caractères)
+pub const MAX_FRAGMENT_PAYLOAD: usize = 60;
+
+/// Tampon de bits écrit de gauche à droite
+#[derive(Debug, Default)]
+struct BitWriter {
+    bits: Vec<bool>,
+}
+
+impl BitWriter {
+    fn uint(&mut self, value: u32, length: usize) -> &mut Self {
+        for i in (0..length).rev() {
+            self.bits.push((value >> i) & 1 == 1);
+        }
+        self
+    }
+
+    fn int(&mut self, value: i32, length: usize) -> &mut Self {
+        self.uint(value as u32, length)
+    }
+
+    fn flag(&mut self, value: bool) -> &mut Self {
+        self.uint(value as u32, 1)
+    }
+
+    /// Texte sur `length` caractères 6 bits, complété par `@`
+    fn text(&mut self, text: &str, length: usize) -> &mut Self {
+        let mut chars = text.chars().map(|c| c.to_ascii_uppercase());
+        for _ in 0..length {
+            let value = match chars.next() {
+                Some(c @ '@'..='_') => c as u32 - 0x40,
+                Some(c @ ' '..='?') => c as u32,
+                Some(_) => '?' as u32,
+                None => 0,
+            };
+            self.uint(value, 6);
+        }
+        self
+    }
+
+    fn dimensions(&mut self, dimensions: Option<&AisDimensions>) -> &mut Self {
+        // La longueur et la largeur sont portées en entier par l'avant et bâbord
+        let (length, width) = dimensions.map_or((0, 0), |d| (d.length.min(511), d.width.min(63)));
+        self.uint(length as u32, 9).uint(0, 9).uint(width as u32, 6).uint(0, 6)
+    }
+
+    /// Arme les bits en ASCII 6 bits ; retourne le payload et le nombre de
+    /// bits de remplissage
+    fn armor(&self) -> (String, u8) {
+        let fill = (6 - self.bits.len() % 6) % 6;
+        let payload = self.bits
+            .chunks(6)
+            .map(|chunk| {
+                let value = chunk
+                    .iter()
+                    .chain(std::iter::repeat(&false))
+                    .take(6)
+                    .fold(0u8, |acc, &bit| (acc << 1) | bit as u8);
+                (if value < 40 { value + 0x30 } else { value + 0x38 }) as char
+            })
+            .collect();
+        (payload, fill as u8)
+    }
+}
+
+/// Longitude en degrés décimaux vers 1/10000 minute, 181 si non disponible
+fn raw_longitude(longitude: Option<f64>) -> i32 {
+    (longitude.unwrap_or(181.0) * 600_000.0).round() as i32
+}
+
+/// Latitude en degrés décimaux vers 1/10000 minute, 91 si non disponible
+fn raw_latitude(latitude: Option<f64>) -> i32 {
+    (latitude.unwrap_or(91.0) * 600_000.0).round() as i32
+}
+
+impl AisDecodedData {
+    /// Encode le message en payload ASCII 6 bits ; retourne le payload et le
+    /// nombre de bits de remplissage
+    pub fn encode_payload(&self) -> Result<(String, u8), NmeaError> {
+        let mmsi = self.mmsi.ok_or_else(|| NmeaError::MissingField("MMSI".to_string()))?;
+        let mut bits = BitWriter::default();
+        bits.uint(self.message_id as u32, 6).uint(0, 2).uint(mmsi, 30);
+
+        match (self.message_id, &self.data) {
+            (1..=3, AisMessageData::PositionReport {
+                navigation_status,
+                rate_of_turn,
+                speed_over_ground,
+                position_accuracy,
+                longitude,
+                latitude,
+                course_over_ground,
+                true_heading,
+                timestamp,
+            }) => {
+                bits.uint(navigation_status.unwrap_or(15) as u32, 4)
+                    .int(rate_of_turn.unwrap_or(-128) as i32, 8)
+                    .uint(speed_over_ground.unwrap_or(1023) as u32, 10)
+                    .flag(position_accuracy.unwrap_or(false))
+                    .int(longitude.unwrap_or(108_600_000), 28)
+                    .int(latitude.unwrap_or(54_600_000), 27)
+                    .uint(course_over_ground.unwrap_or(3600) as u32, 12)
+                    .uint(true_heading.unwrap_or(511) as u32, 9)
+                    .uint(timestamp.unwrap_or(60) as u32, 6)
+                    // Manoeuvre, réserve, RAIM et état radio
+                    .uint(0, 2).uint(0, 3).flag(false).uint(0, 19);
+            }
+            (5, AisMessageData::StaticData {
+                call_sign,
+                vessel_name,
+                ship_type,
+                dimensions,
+                position_fix_type,
+                eta,
+                draught,
+                destination,
+                ..
+            }) => {
+                // Version AIS et numéro OMI
+                bits.uint(0, 2).uint(0, 30)
+                    .text(call_sign.as_deref().unwrap_or(""), 7)
+                    .text(vessel_name.as_deref().unwrap_or(""), 20)
+                    .uint(ship_type.unwrap_or(0) as u32, 8)
+                    .dimensions(dimensions.as_ref())
+                    .uint(position_fix_type.unwrap_or(0) as u32, 4);
+                match eta {
+                    Some(eta) => bits
+                        .uint(eta.month as u32, 4)
+                        .uint(eta.day as u32, 5)
+                        .uint(eta.hour as u32, 5)
+                        .uint(eta.minute as u32, 6),
+                    None => bits.uint(0, 4).uint(0, 5).uint(24, 5).uint(60, 6),
+                };
+                bits.uint(draught.unwrap_or(0) as u32, 8)
+                    .text(destination.as_deref().unwrap_or(""), 20)
+                    // DTE et réserve
+                    .flag(false).uint(0, 1);
+            }
+            (18, AisMessageData::ClassBPositionReport {
+                speed_over_ground,
+                position_accuracy,
+                longitude,
+                latitude,
+                course_over_ground,
+                true_heading,
+                timestamp,
+            }) => {
+                bits.uint(0, 8)
+                    .uint(speed_over_ground.map_or(1023, |speed| (speed * 10.0).round() as u32), 10)
+                    .flag(*position_accuracy)
+                    .int(raw_longitude(*longitude), 28)
+                    .int(raw_latitude(*latitude), 27)
+                    .uint(course_over_ground.map_or(3600, |course| (course * 10.0).round() as u32), 12)
+                    .uint(true_heading.unwrap_or(511) as u32, 9)
+                    .uint(timestamp.unwrap_or(60) as u32, 6)
+                    // Réserve régionale, unité CS, options d'affichage, DSC,
+                    // bande, message 22, mode, RAIM et état radio
+                    .uint(0, 2).flag(true).uint(0, 5).flag(false).uint(0, 20);
+            }
+            (24, AisMessageData::StaticDataReportA { vessel_name }) => {
+                bits.uint(0, 2).text(vessel_name.as_deref().unwrap_or(""), 20);
+            }
+            (24, AisMessageData::StaticDataReportB {
+                ship_type,
+                vendor_id,
+                call_sign,
+                dimensions,
+                mothership_mmsi,
+            }) => {
+                bits.uint(1, 2)
+                    .uint(ship_type.unwrap_or(0) as u32, 8)
+                    .text(vendor_id.as_deref().unwrap_or(""), 3)
+                    // Modèle et numéro de série
+                    .uint(0, 4).uint(0, 20)
+                    .text(call_sign.as_deref().unwrap_or(""), 7);
+                match mothership_mmsi {
+                    Some(mothership) => bits.uint(*mothership, 30),
+                    None => bits.dimensions(dimensions.as_ref()),
+                };
+                bits.uint(0, 6);
+            }
+            (message_id, _) => {
+                return Err(NmeaError::InvalidMessageType(format!(
+                    "AIS message type {} cannot be encoded with this data",
+                    message_id
+                )));
+            }
+        }
+
+        Ok(bits.armor())
+    }
+
+    /// Encode le message en phrases `!AIVDM` ou `!AIVDO` (selon `formatter`)
+    /// prêtes à émettre
+    ///
+    /// Un message trop long est découpé en fragments de
+    /// [`MAX_FRAGMENT_PAYLOAD`] caractères portant l'identifiant séquentiel
+    /// `sequence_id` (0-9) ; seul le dernier fragment porte des bits de
+    /// remplissage.
+    pub fn to_sentences(&self, formatter: SentenceFormatter, channel: char, sequence_id: u8) -> Result<Vec<NmeaMessage>, NmeaError> {
+        if !matches!(formatter, SentenceFormatter::VDM | SentenceFormatter::VDO) {
+            return Err(NmeaError::InvalidFieldValue {
+                field: "Sentence formatter".to_string(),
+                value: formatter.code().to_string(),
+                reason: "Must be VDM or VDO".to_string(),
+            });
+        }
+        if sequence_id > 9 {
+            return Err(NmeaError::InvalidFieldValue {
+                field: "Sequential message ID".to_string(),
+                value: sequence_id.to_string(),
+                reason: "Must be between 0 and 9".to_string(),
+            });
+        }
+        let (payload, fill) = self.encode_payload()?;
+        let chunks: Vec<&str> = payload
+            .as_bytes()
+            .chunks(MAX_FRAGMENT_PAYLOAD)
+            .map(|chunk| std::str::from_utf8(chunk).expect("6-bit armored payload is ASCII"))
+            .collect();
+        let total = chunks.len();
+        let message_type = NmeaMessageType::standard(TalkerId::AI, formatter);
+
+        Ok(chunks
+            .iter()
+            .enumerate()
+            .map(|(index, chunk)| {
+                let last = index + 1 == total;
+                let fields = vec![
+                    total.to_string(),
+                    (index + 1).to_string(),
+                    if total > 1 { sequence_id.to_string() } else { String::new() },
+                    channel.to_string(),
+                    chunk.to_string(),
+                    if last { fill.to_string() } else { "0".to_string() },
+                ];
+                NmeaMessage::build_encapsulated(message_type.clone(), fields)
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_text_encoding() {
+        let mut bits = BitWriter::default();
+        bits.text("ab 1", 5);
+        let values: Vec<u32> = bits.bits
+            .chunks(6)
+            .map(|chunk| chunk.iter().fold(0, |acc, &bit| (acc << 1) | bit as u32))
+            .collect();
+        assert_eq!(values, vec![1, 2, 32, 49, 0]);
+    }
+
+    #[test]
+    fn test_armor_fill_bits() {
+        let mut bits = BitWriter::default();
+        bits.uint(0b1111, 4);
+        assert_eq!(bits.armor(), ("t".to_string(), 2));
+    }
+}
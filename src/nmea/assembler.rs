@@ -0,0 +1,325 @@
+//! Réassemblage des messages AIS répartis sur plusieurs phrases AIVDM/AIVDO
+//!
+//! Un message AIS trop long pour une seule phrase (type 5, binaires 6/8, ...)
+//! est découpé en fragments `!AIVDM,n,k,id,canal,...` : `n` fragments au
+//! total, `k` le rang du fragment et `id` un identifiant séquentiel (0-9)
+//! partagé par les fragments d'un même message. `AisAssembler` regroupe les
+//! fragments par identifiant, canal radio et type de phrase, puis décode le
+//! message une fois tous les fragments reçus. Lorsque les phrases portent un
+//! bloc TAG, le groupe `g:` prime et la source `s:` sépare les stations.
+//!
+//! Les fragments arrivés dans le désordre sont remis en ordre. Les doublons,
+//! les groupes incomplets expirés ou remplacés, les fragments incohérents et
+//! ceux d'un groupe complet impossible à décoder sont écartés et signalés
+//! (`take_dropped`).
+
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+use serde::{Deserialize, Serialize};
+use crate::nmea::ais::AisMessage;
+use crate::nmea::error::NmeaError;
+use crate::nmea::message::NmeaMessage;
+
+/// Délai par défaut avant abandon d'un groupe incomplet
+pub const DEFAULT_FRAGMENT_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Raison de l'abandon d'un fragment
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DropReason {
+    /// Le groupe n'a pas été complété dans le délai imparti
+    Timeout,
+    /// Fragment déjà reçu (même rang, même contenu)
+    Duplicate,
+    /// Groupe incomplet remplacé par un nouveau message réutilisant le même
+    /// identifiant séquentiel
+    Superseded,
+    /// Rang ou nombre de fragments incohérent
+    Inconsistent,
+    /// Groupe complet dont le message réassemblé n'a pas pu être décodé
+    Undecodable,
+}
+
+/// Fragment écarté par l'assembleur
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DroppedFragment {
+    pub fragment: AisMessage,
+    pub reason: DropReason,
+}
+
+/// Clé d'un groupe de fragments
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum GroupKey {
+    /// Groupe de phrases du bloc TAG (`g:`) : identifiant de groupe et type
+    /// de phrase ; seul le premier fragment porte en général la source
+    Tag(u32, String),
+    /// Identifiant séquentiel, canal radio, type de phrase (AIVDM/AIVDO) et
+    /// source du bloc TAG éventuel, pour séparer les stations d'un réseau
+    Sentence(Option<u8>, Option<char>, String, Option<String>),
+}
+
+impl GroupKey {
+    fn of(fragment: &AisMessage) -> Self {
+        let tag = fragment.tag_block.as_ref();
+        match tag.and_then(|tag| tag.group.as_ref()) {
+            Some(group) => GroupKey::Tag(group.id, fragment.message_type.clone()),
+            None => GroupKey::Sentence(
+                fragment.sequence_number,
+                fragment.radio_channel,
+                fragment.message_type.clone(),
+                tag.and_then(|tag| tag.source.clone()),
+            ),
+        }
+    }
+}
+
+/// Fragments reçus d'un même message
+#[derive(Debug)]
+struct PendingGroup {
+    /// Instant de réception du premier fragment
+    started_at: Duration,
+    /// Fragments rangés par rang (index 0 = fragment 1)
+    fragments: Vec<Option<AisMessage>>,
+}
+
+impl PendingGroup {
+    fn is_complete(&self) -> bool {
+        self.fragments.iter().all(Option::is_some)
+    }
+
+    fn into_fragments(self) -> impl Iterator<Item = AisMessage> {
+        self.fragments.into_iter().flatten()
+    }
+}
+
+/// Assembleur de messages AIS multi-fragments
+///
+/// ```
+/// use aftn::NmeaParser;
+/// use aftn::nmea::AisAssembler;
+///
+/// let mut assembler = AisAssembler::new();
+/// let first = NmeaParser::parse_message(
+///     "!AIVDM,2,1,1,A,55?MbV02;H;s<HtKR20EHE:0@T4@Dn2222222216L961O5Gf0NSQEp6ClRp8,0*1C",
+/// ).unwrap();
+/// let second = NmeaParser::parse_message("!AIVDM,2,2,1,A,88888888880,2*25").unwrap();
+///
+/// assert!(assembler.push(&first).unwrap().is_none());
+/// let message = assembler.push(&second).unwrap().expect("complete message");
+/// assert_eq!(message.decoded_data.unwrap().message_id, 5);
+/// ```
+#[derive(Debug)]
+pub struct AisAssembler {
+    timeout: Duration,
+    origin: Instant,
+    pending: HashMap<GroupKey, PendingGroup>,
+    dropped: Vec<DroppedFragment>,
+}
+
+impl Default for AisAssembler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AisAssembler {
+    /// Assembleur avec le délai par défaut (60 secondes)
+    pub fn new() -> Self {
+        Self::with_timeout(DEFAULT_FRAGMENT_TIMEOUT)
+    }
+
+    /// Assembleur abandonnant les groupes incomplets après `timeout`
+    pub fn with_timeout(timeout: Duration) -> Self {
+        AisAssembler {
+            timeout,
+            origin: Instant::now(),
+            pending: HashMap::new(),
+            dropped: Vec::new(),
+        }
+    }
+
+    /// Ajoute une phrase AIVDM/AIVDO reçue maintenant (horloge système)
+    ///
+    /// Retourne le message décodé si la phrase complète un groupe (ou tient
+    /// en un seul fragment), `None` si d'autres fragments sont attendus.
+    pub fn push(&mut self, sentence: &NmeaMessage) -> Result<Option<AisMessage>, NmeaError> {
+        let now = self.origin.elapsed();
+        self.push_at(sentence, now)
+    }
+
+    /// Ajoute une phrase AIVDM/AIVDO reçue à l'instant `at`
+    ///
+    /// `at` est un instant monotone quelconque (par exemple l'horodatage
+    /// d'un enregistrement rejoué) ; seuls les écarts entre instants comptent.
+    pub fn push_at(&mut self, sentence: &NmeaMessage, at: Duration) -> Result<Option<AisMessage>, NmeaError> {
+        let fragment = AisMessage::from_nmea(sentence)?;
+        self.push_fragment(fragment, at)
+    }
+
+    /// Ajoute un fragment AIS déjà extrait de sa phrase
+    pub fn push_fragment(&mut self, fragment: AisMessage, at: Duration) -> Result<Option<AisMessage>, NmeaError> {
+        self.expire(at);
+
+        let total = fragment.total_fragments.unwrap_or(1);
+        let number = fragment.fragment_number.unwrap_or(1);
+        let tag_group = fragment.tag_block.as_ref().and_then(|tag| tag.group.as_ref());
+        let tag_mismatch = tag_group.is_some_and(|group| group.total != total as u32 || group.sentence != number as u32);
+        if total == 0 || number == 0 || number > total || tag_mismatch {
+            self.drop_fragment(fragment, DropReason::Inconsistent);
+            return Ok(None);
+        }
+        if total == 1 {
+            return decode(fragment).map(Some);
+        }
+
+        let key = GroupKey::of(&fragment);
+        let index = (number - 1) as usize;
+
+        // Un groupe existant de taille différente, ou dont le rang est déjà
+        // occupé par un autre contenu, appartient à un message précédent
+        let restart = match self.pending.get(&key) {
+            Some(group) if group.fragments.len() != total as usize => true,
+            Some(group) => match &group.fragments[index] {
+                Some(existing) if existing.payload == fragment.payload && existing.fill_bits == fragment.fill_bits => {
+                    self.drop_fragment(fragment, DropReason::Duplicate);
+                    return Ok(None);
+                }
+                Some(_) => true,
+                None => false,
+            },
+            None => false,
+        };
+        if restart {
+            if let Some(stale) = self.pending.remove(&key) {
+                self.drop_group(stale, DropReason::Superseded);
+            }
+        }
+
+        let group = self.pending.entry(key.clone()).or_insert_with(|| PendingGroup {
+            started_at: at,
+            fragments: vec![None; total as usize],
+        });
+        group.fragments[index] = Some(fragment);
+
+        if !group.is_complete() {
+            return Ok(None);
+        }
+        let fragments: Vec<AisMessage> = self.pending.remove(&key).expect("group is pending").into_fragments().collect();
+        match decode(combine(&fragments)) {
+            Ok(message) => Ok(Some(message)),
+            Err(error) => {
+                for fragment in fragments {
+                    self.drop_fragment(fragment, DropReason::Undecodable);
+                }
+                Err(error)
+            }
+        }
+    }
+
+    /// Abandonne les groupes incomplets commencés depuis plus que le délai
+    pub fn expire(&mut self, at: Duration) {
+        let timeout = self.timeout;
+        let expired: Vec<GroupKey> = self.pending
+            .iter()
+            .filter(|(_, group)| at.saturating_sub(group.started_at) > timeout)
+            .map(|(key, _)| key.clone())
+            .collect();
+        for key in expired {
+            if let Some(group) = self.pending.remove(&key) {
+                self.drop_group(group, DropReason::Timeout);
+            }
+        }
+    }
+
+    /// Nombre de groupes en attente de fragments
+    pub fn pending(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// Retourne et oublie les fragments écartés depuis le dernier appel
+    pub fn take_dropped(&mut self) -> Vec<DroppedFragment> {
+        std::mem::take(&mut self.dropped)
+    }
+
+    fn drop_fragment(&mut self, fragment: AisMessage, reason: DropReason) {
+        self.dropped.push(DroppedFragment { fragment, reason });
+    }
+
+    fn drop_group(&mut self, group: PendingGroup, reason: DropReason) {
+        for fragment in group.into_fragments() {
+            self.drop_fragment(fragment, reason);
+        }
+    }
+}
+
+/// Réunit les fragments d'un groupe complet en un seul message ; les bits de
+/// remplissage sont ceux du dernier fragment
+fn combine(fragments: &[AisMessage]) -> AisMessage {
+    let (first, rest) = fragments.split_first().expect("complete group has a first fragment");
+    let mut message = first.clone();
+    for fragment in rest {
+        message.payload.push_str(&fragment.payload);
+        message.fill_bits = fragment.fill_bits;
+    }
+    message
+}
+
+fn decode(mut message: AisMessage) -> Result<AisMessage, NmeaError> {
+    message.decode()?;
+    Ok(message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fragment(total: u8, number: u8, sequence: u8, payload: &str) -> AisMessage {
+        AisMessage {
+            message_type: "AIVDM".to_string(),
+            sequence_number: Some(sequence),
+            fragment_number: Some(number),
+            total_fragments: Some(total),
+            radio_channel: Some('A'),
+            payload: payload.to_string(),
+            fill_bits: Some(0),
+            decoded_data: None,
+            tag_block: None,
+        }
+    }
+
+    #[test]
+    fn test_timeout_drops_incomplete_group() {
+        let mut assembler = AisAssembler::with_timeout(Duration::from_secs(5));
+        let first = fragment(2, 1, 4, "55?MbV02");
+        assert_eq!(assembler.push_fragment(first, Duration::from_secs(0)).unwrap(), None);
+        assert_eq!(assembler.pending(), 1);
+
+        assembler.expire(Duration::from_secs(10));
+        assert_eq!(assembler.pending(), 0);
+        let dropped = assembler.take_dropped();
+        assert_eq!(dropped.len(), 1);
+        assert_eq!(dropped[0].reason, DropReason::Timeout);
+        assert!(assembler.take_dropped().is_empty());
+    }
+
+    #[test]
+    fn test_inconsistent_fragment_number() {
+        let mut assembler = AisAssembler::new();
+        let result = assembler.push_fragment(fragment(2, 3, 1, "55?MbV02"), Duration::ZERO).unwrap();
+        assert_eq!(result, None);
+        assert_eq!(assembler.take_dropped()[0].reason, DropReason::Inconsistent);
+    }
+
+    #[test]
+    fn test_undecodable_group_is_reported_as_dropped() {
+        let mut assembler = AisAssembler::new();
+        assert_eq!(assembler.push_fragment(fragment(2, 1, 7, "55?MbV02"), Duration::ZERO).unwrap(), None);
+        // 0x58-0x5F ne font pas partie de l'alphabet 6 bits
+        assert!(assembler.push_fragment(fragment(2, 2, 7, "XX"), Duration::ZERO).is_err());
+
+        assert_eq!(assembler.pending(), 0);
+        let dropped = assembler.take_dropped();
+        assert_eq!(dropped.len(), 2);
+        assert!(dropped.iter().all(|d| d.reason == DropReason::Undecodable));
+        assert_eq!(dropped[1].fragment.payload, "XX");
+    }
+}
@@ -1,4 +1,5 @@
 use thiserror::Error;
+use crate::diagnostic::{FieldRef, LocatedError, SyntaxError};
 
 #[derive(Error, Debug, PartialEq)]
 pub enum NmeaError {
@@ -31,5 +32,45 @@ pub enum NmeaError {
     
     #[error("Invalid date format: {0}")]
     InvalidDate(String),
+    
+    #[error("Parse error: {0}")]
+    Syntax(Box<SyntaxError>),
 }
 
+impl LocatedError for NmeaError {
+    fn code(&self) -> &'static str {
+        match self {
+            NmeaError::ParseError(_) => "NMEA001",
+            NmeaError::InvalidFormat(_) => "NMEA002",
+            NmeaError::InvalidChecksum { .. } => "NMEA003",
+            NmeaError::MissingChecksum => "NMEA004",
+            NmeaError::InvalidMessageType(_) => "NMEA005",
+            NmeaError::InvalidFieldValue { .. } => "NMEA006",
+            NmeaError::MissingField(_) => "NMEA007",
+            NmeaError::InvalidCoordinate(_) => "NMEA008",
+            NmeaError::InvalidTime(_) => "NMEA009",
+            NmeaError::InvalidDate(_) => "NMEA010",
+            NmeaError::Syntax(_) => "NMEA011",
+        }
+    }
+
+    fn syntax(&self) -> Option<&SyntaxError> {
+        match self {
+            NmeaError::Syntax(error) => Some(error),
+            _ => None,
+        }
+    }
+
+    fn field(&self) -> Option<FieldRef> {
+        match self {
+            NmeaError::InvalidFieldValue { field, .. } | NmeaError::MissingField(field) => Some(FieldRef::Name(field.clone())),
+            _ => self.syntax().and_then(|error| error.field.clone()),
+        }
+    }
+}
+
+impl From<SyntaxError> for NmeaError {
+    fn from(error: SyntaxError) -> Self {
+        NmeaError::Syntax(Box::new(error))
+    }
+}
@@ -1,8 +1,11 @@
-//! Structures de données détaillées pour les messages GPS NMEA 0183
+//! Structures de données détaillées pour les messages GNSS NMEA 0183
 
+use std::str::FromStr;
 use serde::{Deserialize, Serialize};
+use crate::datetime::UtcDateTime;
 use crate::nmea::error::NmeaError;
 use crate::nmea::message::NmeaMessage;
+use crate::nmea::types::{NmeaMessageType, SentenceFormatter, TalkerId};
 
 /// Message GPGGA (Global Positioning System Fix Data)
 /// Format: $GPGGA,hhmmss.ss,llll.ll,a,yyyyy.yy,a,x,xx,x.x,x.x,M,x.x,M,x.x,xxxx*hh
@@ -18,7 +21,7 @@ pub struct GgaMessage {
     pub longitude: Option<f64>,
     /// Longitude direction - E or W
     pub longitude_direction: Option<char>,
-    /// GPS quality indicator (0=no fix, 1=GPS fix, 2=DGPS fix)
+    /// GPS quality indicator (0=no fix, 1=GPS fix, 2=DGPS fix, 4=RTK fixed, 5=RTK float, ... 8=simulation)
     pub quality: Option<u8>,
     /// Number of satellites being tracked
     pub satellites: Option<u8>,
@@ -78,6 +81,29 @@ impl GgaMessage {
         })
     }
     
+    /// Construit la phrase GGA correspondante pour l'émetteur `talker`
+    pub fn to_nmea(&self, talker: TalkerId) -> NmeaMessage {
+        NmeaMessage::build(
+            NmeaMessageType::standard(talker, SentenceFormatter::GGA),
+            vec![
+                self.time.clone().unwrap_or_default(),
+                format_coordinate(self.latitude, 9),
+                format_field(self.latitude_direction),
+                format_coordinate(self.longitude, 10),
+                format_field(self.longitude_direction),
+                format_field(self.quality),
+                self.satellites.map(|s| format!("{:02}", s)).unwrap_or_default(),
+                format_field(self.hdop),
+                format_field(self.altitude),
+                format_field(self.altitude_units),
+                format_field(self.geoid_height),
+                format_field(self.geoid_height_units),
+                format_field(self.dgps_age),
+                self.dgps_station_id.map(|id| format!("{:04}", id)).unwrap_or_default(),
+            ],
+        )
+    }
+
     /// Convertit la latitude en degrés décimaux
     pub fn latitude_decimal(&self) -> Option<f64> {
         self.latitude.and_then(|lat| {
@@ -164,6 +190,26 @@ impl RmcMessage {
         })
     }
     
+    /// Construit la phrase RMC correspondante pour l'émetteur `talker`
+    pub fn to_nmea(&self, talker: TalkerId) -> NmeaMessage {
+        NmeaMessage::build(
+            NmeaMessageType::standard(talker, SentenceFormatter::RMC),
+            vec![
+                self.time.clone().unwrap_or_default(),
+                format_field(self.status),
+                format_coordinate(self.latitude, 9),
+                format_field(self.latitude_direction),
+                format_coordinate(self.longitude, 10),
+                format_field(self.longitude_direction),
+                format_field(self.speed),
+                format_field(self.course),
+                self.date.clone().unwrap_or_default(),
+                format_field(self.magnetic_variation),
+                format_field(self.magnetic_variation_direction),
+            ],
+        )
+    }
+
     /// Convertit la latitude en degrés décimaux
     pub fn latitude_decimal(&self) -> Option<f64> {
         self.latitude.and_then(|lat| {
@@ -277,3 +323,319 @@ impl VtgMessage {
     }
 }
 
+
+/// Satellite décrit par une phrase GSV
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GsvSatellite {
+    /// Satellite PRN number
+    pub prn: u16,
+    /// Elevation (degrees, 0-90)
+    pub elevation: Option<u8>,
+    /// Azimuth (degrees True, 0-359)
+    pub azimuth: Option<u16>,
+    /// Signal to noise ratio (dB-Hz, 0-99), absent si le satellite n'est pas suivi
+    pub snr: Option<u8>,
+}
+
+/// Message GSV (GNSS Satellites in View)
+/// Format: $GPGSV,t,n,ss,prn,el,az,snr,...(4 satellites max)...[,signal]*hh
+///
+/// La liste complète est répartie sur `total_messages` phrases : voir
+/// [`GsvMessage::combine`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GsvMessage {
+    /// Total number of messages in this cycle
+    pub total_messages: u8,
+    /// Message number (1-based)
+    pub message_number: u8,
+    /// Total number of satellites in view
+    pub satellites_in_view: Option<u8>,
+    /// Satellites décrits dans cette phrase (4 au plus)
+    pub satellites: Vec<GsvSatellite>,
+    /// Signal ID (NMEA 4.10 et suivants)
+    pub signal_id: Option<String>,
+}
+
+/// Satellites en vue, réunis depuis un cycle complet de phrases GSV
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SatellitesInView {
+    /// Nombre total annoncé de satellites en vue
+    pub satellites_in_view: Option<u8>,
+    /// Satellites des phrases du cycle, dans l'ordre
+    pub satellites: Vec<GsvSatellite>,
+}
+
+impl GsvMessage {
+    /// Parse un message GSV depuis un NmeaMessage
+    pub fn from_nmea(message: &NmeaMessage) -> Result<Self, NmeaError> {
+        if message.fields.len() < 3 {
+            return Err(NmeaError::InvalidFormat(
+                "GSV message must have at least 3 fields".to_string()
+            ));
+        }
+
+        let total_messages = parse_field::<u8>(message, 0)
+            .ok_or_else(|| NmeaError::MissingField("GSV total number of messages".to_string()))?;
+        let message_number = parse_field::<u8>(message, 1)
+            .ok_or_else(|| NmeaError::MissingField("GSV message number".to_string()))?;
+
+        // Blocs de 4 champs par satellite, suivis d'un éventuel signal ID
+        let blocks = &message.fields[3..];
+        let signal_id = if blocks.len() % 4 == 1 {
+            blocks.last().filter(|s| !s.is_empty()).cloned()
+        } else {
+            None
+        };
+        let satellites = blocks
+            .chunks_exact(4)
+            .filter_map(|block| {
+                let prn = block[0].parse::<u16>().ok()?;
+                Some(GsvSatellite {
+                    prn,
+                    elevation: block[1].parse::<u8>().ok(),
+                    azimuth: block[2].parse::<u16>().ok(),
+                    snr: block[3].parse::<u8>().ok(),
+                })
+            })
+            .collect();
+
+        Ok(GsvMessage {
+            total_messages,
+            message_number,
+            satellites_in_view: parse_field(message, 2),
+            satellites,
+            signal_id,
+        })
+    }
+
+    /// Réunit les phrases d'un cycle GSV (`1/n` à `n/n`) en une liste de satellites
+    ///
+    /// Les phrases doivent être complètes, dans l'ordre et annoncer le même
+    /// nombre total de phrases.
+    pub fn combine(parts: &[GsvMessage]) -> Result<SatellitesInView, NmeaError> {
+        let first = parts.first()
+            .ok_or_else(|| NmeaError::InvalidFormat("GSV cycle is empty".to_string()))?;
+        let total = first.total_messages;
+        if parts.len() != total as usize {
+            return Err(NmeaError::InvalidFormat(format!(
+                "GSV cycle announces {} message(s), got {}",
+                total,
+                parts.len()
+            )));
+        }
+
+        let mut satellites = Vec::new();
+        for (index, part) in parts.iter().enumerate() {
+            if part.total_messages != total || part.message_number as usize != index + 1 {
+                return Err(NmeaError::InvalidFormat(format!(
+                    "GSV message {}/{} out of sequence (expected {}/{})",
+                    part.message_number,
+                    part.total_messages,
+                    index + 1,
+                    total
+                )));
+            }
+            satellites.extend(part.satellites.iter().cloned());
+        }
+
+        Ok(SatellitesInView {
+            satellites_in_view: first.satellites_in_view,
+            satellites,
+        })
+    }
+}
+
+/// Message ZDA (Time and Date)
+/// Format: $GPZDA,hhmmss.ss,dd,mm,yyyy,zh,zm*hh
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ZdaMessage {
+    /// Time (UTC) - hhmmss.ss
+    pub time: Option<String>,
+    /// Day (01-31)
+    pub day: Option<u8>,
+    /// Month (01-12)
+    pub month: Option<u8>,
+    /// Year (4 digits)
+    pub year: Option<u16>,
+    /// Local zone hours (-13 to 13)
+    pub local_zone_hours: Option<i8>,
+    /// Local zone minutes (0-59), même signe que les heures
+    pub local_zone_minutes: Option<u8>,
+}
+
+impl ZdaMessage {
+    /// Parse un message ZDA depuis un NmeaMessage
+    pub fn from_nmea(message: &NmeaMessage) -> Result<Self, NmeaError> {
+        if message.fields.len() < 6 {
+            return Err(NmeaError::InvalidFormat(
+                "ZDA message must have at least 6 fields".to_string()
+            ));
+        }
+
+        Ok(ZdaMessage {
+            time: string_field(message, 0),
+            day: parse_field(message, 1),
+            month: parse_field(message, 2),
+            year: parse_field(message, 3),
+            local_zone_hours: parse_field(message, 4),
+            local_zone_minutes: parse_field(message, 5),
+        })
+    }
+
+    /// Instant UTC complet (à la minute), si date et heure sont renseignées et valides
+    pub fn utc_datetime(&self) -> Option<UtcDateTime> {
+        let time = self.time.as_deref()?;
+        let hour = time.get(0..2)?.parse::<u8>().ok()?;
+        let minute = time.get(2..4)?.parse::<u8>().ok()?;
+        UtcDateTime::new(self.year?, self.month?, self.day?, hour, minute).ok()
+    }
+
+    /// Timestamp Unix (secondes, fraction comprise) de l'instant UTC
+    pub fn unix_timestamp(&self) -> Option<f64> {
+        let seconds = self.time.as_deref()?.get(4..)?.parse::<f64>().ok()?;
+        Some(self.utc_datetime()?.unix_timestamp() as f64 + seconds)
+    }
+
+    /// Zone locale en minutes, avec la convention NMEA : valeur à ajouter à
+    /// l'heure locale pour obtenir UTC (négative à l'est de Greenwich)
+    pub fn utc_offset_minutes(&self) -> Option<i32> {
+        let hours = self.local_zone_hours? as i32;
+        let minutes = self.local_zone_minutes.unwrap_or(0) as i32;
+        Some(if hours < 0 { hours * 60 - minutes } else { hours * 60 + minutes })
+    }
+
+    /// Instant en heure locale (UTC moins la zone locale, cf. `utc_offset_minutes`)
+    pub fn local_datetime(&self) -> Option<UtcDateTime> {
+        Some(self.utc_datetime()?.add_minutes(-(self.utc_offset_minutes().unwrap_or(0) as i64)))
+    }
+}
+
+/// Message GLL (Geographic Position - Latitude/Longitude)
+/// Format: $GPGLL,llll.ll,a,yyyyy.yy,a,hhmmss.ss,A[,m]*hh
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GllMessage {
+    /// Latitude - llll.ll
+    pub latitude: Option<f64>,
+    /// Latitude direction - N or S
+    pub latitude_direction: Option<char>,
+    /// Longitude - yyyyy.yy
+    pub longitude: Option<f64>,
+    /// Longitude direction - E or W
+    pub longitude_direction: Option<char>,
+    /// Time (UTC) - hhmmss.ss
+    pub time: Option<String>,
+    /// Status - A=valid, V=invalid
+    pub status: Option<char>,
+    /// FAA mode indicator (NMEA 2.3 et suivants)
+    pub mode: Option<char>,
+}
+
+impl GllMessage {
+    /// Parse un message GLL depuis un NmeaMessage
+    pub fn from_nmea(message: &NmeaMessage) -> Result<Self, NmeaError> {
+        if message.fields.len() < 6 {
+            return Err(NmeaError::InvalidFormat(
+                "GLL message must have at least 6 fields".to_string()
+            ));
+        }
+
+        Ok(GllMessage {
+            latitude: parse_field(message, 0),
+            latitude_direction: char_field(message, 1),
+            longitude: parse_field(message, 2),
+            longitude_direction: char_field(message, 3),
+            time: string_field(message, 4),
+            status: char_field(message, 5),
+            mode: char_field(message, 6),
+        })
+    }
+
+    /// Convertit la latitude en degrés décimaux
+    pub fn latitude_decimal(&self) -> Option<f64> {
+        Some(to_decimal_degrees(self.latitude?, self.latitude_direction?, 'S'))
+    }
+
+    /// Convertit la longitude en degrés décimaux
+    pub fn longitude_decimal(&self) -> Option<f64> {
+        Some(to_decimal_degrees(self.longitude?, self.longitude_direction?, 'W'))
+    }
+}
+
+/// Message GST (GNSS Pseudorange Error Statistics)
+/// Format: $GPGST,hhmmss.ss,rms,smaj,smin,ori,lat,lon,alt*hh
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GstMessage {
+    /// Time (UTC) - hhmmss.ss
+    pub time: Option<String>,
+    /// RMS value of the pseudorange residuals
+    pub rms: Option<f64>,
+    /// Error ellipse semi-major axis 1-sigma error (meters)
+    pub semi_major_error: Option<f64>,
+    /// Error ellipse semi-minor axis 1-sigma error (meters)
+    pub semi_minor_error: Option<f64>,
+    /// Error ellipse orientation (degrees from True North)
+    pub orientation: Option<f64>,
+    /// Latitude 1-sigma error (meters)
+    pub latitude_error: Option<f64>,
+    /// Longitude 1-sigma error (meters)
+    pub longitude_error: Option<f64>,
+    /// Altitude 1-sigma error (meters)
+    pub altitude_error: Option<f64>,
+}
+
+impl GstMessage {
+    /// Parse un message GST depuis un NmeaMessage
+    pub fn from_nmea(message: &NmeaMessage) -> Result<Self, NmeaError> {
+        if message.fields.len() < 8 {
+            return Err(NmeaError::InvalidFormat(
+                "GST message must have at least 8 fields".to_string()
+            ));
+        }
+
+        Ok(GstMessage {
+            time: string_field(message, 0),
+            rms: parse_field(message, 1),
+            semi_major_error: parse_field(message, 2),
+            semi_minor_error: parse_field(message, 3),
+            orientation: parse_field(message, 4),
+            latitude_error: parse_field(message, 5),
+            longitude_error: parse_field(message, 6),
+            altitude_error: parse_field(message, 7),
+        })
+    }
+}
+
+/// Valeur optionnelle d'un champ (vide si absente)
+fn format_field<T: std::fmt::Display>(value: Option<T>) -> String {
+    value.map(|v| v.to_string()).unwrap_or_default()
+}
+
+/// Coordonnée NMEA (degrés et minutes) sur `width` caractères, 4 décimales
+fn format_coordinate(value: Option<f64>, width: usize) -> String {
+    value.map(|v| format!("{:0width$.4}", v, width = width)).unwrap_or_default()
+}
+
+/// Champ numérique (ou autre type) non vide et bien formé
+pub(crate) fn parse_field<T: FromStr>(message: &NmeaMessage, index: usize) -> Option<T> {
+    message.get_field(index)
+        .and_then(|s| if s.is_empty() { None } else { s.parse::<T>().ok() })
+}
+
+/// Premier caractère d'un champ non vide
+pub(crate) fn char_field(message: &NmeaMessage, index: usize) -> Option<char> {
+    message.get_field(index).and_then(|s| s.chars().next())
+}
+
+/// Champ texte non vide
+pub(crate) fn string_field(message: &NmeaMessage, index: usize) -> Option<String> {
+    message.get_field(index).filter(|s| !s.is_empty()).cloned()
+}
+
+/// Convertit une coordonnée NMEA (degrés et minutes) en degrés décimaux,
+/// négatifs pour la direction `negative` (S ou W)
+pub(crate) fn to_decimal_degrees(value: f64, direction: char, negative: char) -> f64 {
+    let degrees = (value / 100.0).floor();
+    let minutes = value - (degrees * 100.0);
+    let decimal = degrees + (minutes / 60.0);
+    if direction == negative { -decimal } else { decimal }
+}
@@ -1,8 +1,43 @@
 //! Structures de données pour les messages NMEA 0183
 
 use serde::{Deserialize, Serialize};
-use crate::nmea::types::NmeaMessageType;
+use crate::nmea::types::{NmeaMessageType, SentenceFormatter};
 use crate::nmea::error::NmeaError;
+use crate::nmea::gps::{
+    GgaMessage, GllMessage, GsaMessage, GstMessage, GsvMessage, RmcMessage, VtgMessage, ZdaMessage,
+};
+use crate::nmea::navigation::{BodMessage, HdgMessage, HdtMessage, RmbMessage, WplMessage, XteMessage};
+use crate::nmea::ais::AisMessage;
+use crate::nmea::parser::NmeaParser;
+use crate::nmea::tag_block::{split_tag_block, TagBlock};
+
+/// Contenu typé d'une phrase NMEA, déterminé par son formateur
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum NmeaSentence {
+    Gga(GgaMessage),
+    Rmc(RmcMessage),
+    Gsa(GsaMessage),
+    Vtg(VtgMessage),
+    Gsv(GsvMessage),
+    Zda(ZdaMessage),
+    Gll(GllMessage),
+    Gst(GstMessage),
+    Hdt(HdtMessage),
+    Hdg(HdgMessage),
+    Wpl(WplMessage),
+    Bod(BodMessage),
+    Xte(XteMessage),
+    Rmb(RmbMessage),
+    Ais(AisMessage),
+    /// Phrase propriétaire : code fabricant, type de phrase et champs bruts
+    Proprietary {
+        manufacturer: String,
+        sentence: String,
+        fields: Vec<String>,
+    },
+    /// Phrase sans décodage typé (champs disponibles via `fields`)
+    Unsupported,
+}
 
 /// Représente un message NMEA 0183 complet
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -16,8 +51,12 @@ pub struct NmeaMessage {
     /// Checksum fourni dans le message
     pub checksum: String,
     
-    /// Données brutes du message (pour référence)
+    /// Données brutes du message (pour référence), bloc TAG compris
     pub raw: String,
+    
+    /// Bloc TAG NMEA 4.x précédant la phrase (source, horodatage, groupe...)
+    #[serde(default)]
+    pub tag_block: Option<TagBlock>,
 }
 
 impl NmeaMessage {
@@ -28,9 +67,44 @@ impl NmeaMessage {
             fields,
             checksum,
             raw,
+            tag_block: None,
         }
     }
     
+    /// Construit une phrase `$...` depuis son type et ses champs, checksum calculé
+    pub fn build(message_type: NmeaMessageType, fields: Vec<String>) -> Self {
+        Self::build_with_marker('$', message_type, fields)
+    }
+
+    /// Construit une phrase encapsulée `!...` (AIVDM, AIVDO), checksum calculé
+    pub fn build_encapsulated(message_type: NmeaMessageType, fields: Vec<String>) -> Self {
+        Self::build_with_marker('!', message_type, fields)
+    }
+
+    fn build_with_marker(start_marker: char, message_type: NmeaMessageType, fields: Vec<String>) -> Self {
+        let mut message = NmeaMessage {
+            message_type,
+            fields,
+            checksum: String::new(),
+            raw: start_marker.to_string(),
+            tag_block: None,
+        };
+        let body = message.body();
+        message.checksum = NmeaParser::calculate_checksum(&body);
+        message.raw = format!("{}{}*{}", start_marker, body, message.checksum);
+        message
+    }
+
+    /// Partie de la phrase couverte par le checksum (type et champs)
+    fn body(&self) -> String {
+        let mut body = self.message_type.identifier().into_owned();
+        for field in &self.fields {
+            body.push(',');
+            body.push_str(field);
+        }
+        body
+    }
+
     /// Obtient un champ par index (0-based)
     pub fn get_field(&self, index: usize) -> Option<&String> {
         self.fields.get(index)
@@ -49,10 +123,40 @@ impl NmeaMessage {
         crate::nmea::validation::validate_message(self)
     }
     
+    /// Décode le contenu typé de la phrase selon son formateur, quel que
+    /// soit l'émetteur (`$GPGGA`, `$GNGGA` et `$GLGGA` donnent un `Gga`)
+    pub fn decode(&self) -> Result<NmeaSentence, NmeaError> {
+        if let NmeaMessageType::Proprietary { manufacturer, sentence } = &self.message_type {
+            return Ok(NmeaSentence::Proprietary {
+                manufacturer: manufacturer.clone(),
+                sentence: sentence.clone(),
+                fields: self.fields.clone(),
+            });
+        }
+        Ok(match self.message_type.formatter() {
+            Some(SentenceFormatter::GGA) => NmeaSentence::Gga(GgaMessage::from_nmea(self)?),
+            Some(SentenceFormatter::RMC) => NmeaSentence::Rmc(RmcMessage::from_nmea(self)?),
+            Some(SentenceFormatter::GSA) => NmeaSentence::Gsa(GsaMessage::from_nmea(self)?),
+            Some(SentenceFormatter::VTG) => NmeaSentence::Vtg(VtgMessage::from_nmea(self)?),
+            Some(SentenceFormatter::GSV) => NmeaSentence::Gsv(GsvMessage::from_nmea(self)?),
+            Some(SentenceFormatter::ZDA) => NmeaSentence::Zda(ZdaMessage::from_nmea(self)?),
+            Some(SentenceFormatter::GLL) => NmeaSentence::Gll(GllMessage::from_nmea(self)?),
+            Some(SentenceFormatter::GST) => NmeaSentence::Gst(GstMessage::from_nmea(self)?),
+            Some(SentenceFormatter::HDT) => NmeaSentence::Hdt(HdtMessage::from_nmea(self)?),
+            Some(SentenceFormatter::HDG) => NmeaSentence::Hdg(HdgMessage::from_nmea(self)?),
+            Some(SentenceFormatter::WPL) => NmeaSentence::Wpl(WplMessage::from_nmea(self)?),
+            Some(SentenceFormatter::BOD) => NmeaSentence::Bod(BodMessage::from_nmea(self)?),
+            Some(SentenceFormatter::XTE) => NmeaSentence::Xte(XteMessage::from_nmea(self)?),
+            Some(SentenceFormatter::RMB) => NmeaSentence::Rmb(RmbMessage::from_nmea(self)?),
+            Some(SentenceFormatter::VDM | SentenceFormatter::VDO) => NmeaSentence::Ais(AisMessage::from_nmea(self)?),
+            _ => NmeaSentence::Unsupported,
+        })
+    }
+
     /// Sérialise le message NMEA en chaîne de caractères.
     /// 
     /// Reconstruit le message dans le format NMEA standard sans espaces/tabulations supplémentaires.
-    /// Recalcule le checksum pour garantir sa validité.
+    /// Recalcule le checksum pour garantir sa validité, y compris celui du bloc TAG éventuel.
     /// Format: `$MESSAGE_TYPE,field1,field2,...,fieldN*CHECKSUM`
     /// 
     /// # Returns
@@ -67,27 +171,14 @@ impl NmeaMessage {
     /// ```
     pub fn serialize(&self) -> String {
         // Déterminer le marqueur de début ($ ou !)
-        let start_marker = if self.raw.starts_with('!') { '!' } else { '$' };
-        
-        // Construire la partie message (sans checksum)
-        let mut message_part = String::new();
-        message_part.push_str(&self.message_type.identifier());
-        
-        // Ajouter les champs séparés par des virgules
-        for field in &self.fields {
-            message_part.push(',');
-            message_part.push_str(field);
-        }
+        let sentence = split_tag_block(&self.raw).map_or(self.raw.as_str(), |(_, sentence)| sentence);
+        let start_marker = if sentence.starts_with('!') { '!' } else { '$' };
         
-        // Calculer le checksum (même algorithme que dans le parser)
-        let mut checksum: u8 = 0;
-        for byte in message_part.bytes() {
-            checksum ^= byte;
-        }
-        let checksum_str = format!("{:02X}", checksum);
+        let message_part = self.body();
+        let checksum_str = NmeaParser::calculate_checksum(&message_part);
+        let tag = self.tag_block.as_ref().map(TagBlock::to_string).unwrap_or_default();
         
-        // Construire le message complet
-        format!("{}{}*{}", start_marker, message_part, checksum_str)
+        format!("{}{}{}*{}", tag, start_marker, message_part, checksum_str)
     }
 }
 
@@ -99,7 +190,10 @@ mod tests {
     #[test]
     fn test_nmea_message_serialize() {
         let message = NmeaMessage::new(
-            crate::nmea::types::NmeaMessageType::GPGGA,
+            crate::nmea::types::NmeaMessageType::standard(
+                crate::nmea::types::TalkerId::GP,
+                crate::nmea::types::SentenceFormatter::GGA,
+            ),
             vec![
                 "123519".to_string(),
                 "4807.038".to_string(),
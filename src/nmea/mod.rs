@@ -6,21 +6,39 @@
 //! Format typique: $MESSAGE_TYPE,field1,field2,...,fieldN*CHECKSUM
 //!
 //! Supporte:
-//! - Messages GPS (GPGGA, GPRMC, GPGSA, GPVTG, etc.)
-//! - Messages AIS (AIVDM, AIVDO) avec décodage 6-bit
+//! - Messages GNSS de toutes constellations (GPGGA, GNRMC, GLGSV, BDGSA, etc.) :
+//!   le décodage dépend du formateur de phrase, pas de l'émetteur
+//! - Phrases de navigation (WPL, BOD, XTE, RMB) et de cap (HDT, HDG)
+//! - Phrases propriétaires `$P` exposées avec leur code fabricant
+//! - Blocs TAG NMEA 4.x (IEC 61162-450) : source, horodatage, groupe...
+//! - Messages AIS (AIVDM, AIVDO) avec décodage 6-bit et réassemblage des
+//!   messages multi-fragments (`AisAssembler`)
+//! - Encodage des phrases GGA/RMC et des messages AIS (types 1-3, 5, 18, 24)
+//!   pour la simulation et le rejeu
 
 pub mod error;
 pub mod message;
 pub mod parser;
 pub mod types;
+pub mod tag_block;
 pub mod validation;
 pub mod gps;
+pub mod navigation;
 pub mod ais;
+pub mod assembler;
+pub mod ais_encoding;
 
 pub use error::NmeaError;
-pub use message::NmeaMessage;
+pub use message::{NmeaMessage, NmeaSentence};
 pub use parser::NmeaParser;
-pub use types::NmeaMessageType;
-pub use gps::{GgaMessage, RmcMessage, GsaMessage, VtgMessage};
-pub use ais::{AisMessage, AisDecodedData, AisMessageData};
+pub use types::{NmeaMessageType, TalkerId, SentenceFormatter};
+pub use tag_block::{TagBlock, TagGroup};
+pub use gps::{
+    GgaMessage, RmcMessage, GsaMessage, VtgMessage, GsvMessage, GsvSatellite, SatellitesInView,
+    ZdaMessage, GllMessage, GstMessage,
+};
+pub use navigation::{WplMessage, BodMessage, XteMessage, RmbMessage, HdtMessage, HdgMessage};
+pub use ais::{AisMessage, AisDecodedData, AisMessageData, AisDimensions, AisEta, AisUtcTime};
+pub use assembler::{AisAssembler, DroppedFragment, DropReason};
+pub use ais_encoding::MAX_FRAGMENT_PAYLOAD;
 
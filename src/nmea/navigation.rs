@@ -0,0 +1,266 @@
+//! Structures de données pour les phrases de navigation NMEA 0183
+//! (points de route, cap, écart de route)
+
+use serde::{Deserialize, Serialize};
+use crate::nmea::error::NmeaError;
+use crate::nmea::gps::{char_field, parse_field, string_field, to_decimal_degrees};
+use crate::nmea::message::NmeaMessage;
+
+/// Message WPL (Waypoint Location)
+/// Format: $GPWPL,llll.ll,a,yyyyy.yy,a,c--c*hh
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WplMessage {
+    /// Latitude - llll.ll
+    pub latitude: Option<f64>,
+    /// Latitude direction - N or S
+    pub latitude_direction: Option<char>,
+    /// Longitude - yyyyy.yy
+    pub longitude: Option<f64>,
+    /// Longitude direction - E or W
+    pub longitude_direction: Option<char>,
+    /// Waypoint identifier
+    pub waypoint_id: Option<String>,
+}
+
+impl WplMessage {
+    /// Parse un message WPL depuis un NmeaMessage
+    pub fn from_nmea(message: &NmeaMessage) -> Result<Self, NmeaError> {
+        if message.fields.len() < 5 {
+            return Err(NmeaError::InvalidFormat(
+                "WPL message must have at least 5 fields".to_string()
+            ));
+        }
+
+        Ok(WplMessage {
+            latitude: parse_field(message, 0),
+            latitude_direction: char_field(message, 1),
+            longitude: parse_field(message, 2),
+            longitude_direction: char_field(message, 3),
+            waypoint_id: string_field(message, 4),
+        })
+    }
+
+    /// Convertit la latitude en degrés décimaux
+    pub fn latitude_decimal(&self) -> Option<f64> {
+        Some(to_decimal_degrees(self.latitude?, self.latitude_direction?, 'S'))
+    }
+
+    /// Convertit la longitude en degrés décimaux
+    pub fn longitude_decimal(&self) -> Option<f64> {
+        Some(to_decimal_degrees(self.longitude?, self.longitude_direction?, 'W'))
+    }
+}
+
+/// Message BOD (Bearing - Waypoint to Waypoint)
+/// Format: $GPBOD,x.x,T,x.x,M,c--c,c--c*hh
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BodMessage {
+    /// Bearing origin to destination (degrees True)
+    pub bearing_true: Option<f64>,
+    /// Bearing origin to destination (degrees Magnetic)
+    pub bearing_magnetic: Option<f64>,
+    /// Destination waypoint identifier
+    pub destination: Option<String>,
+    /// Origin waypoint identifier
+    pub origin: Option<String>,
+}
+
+impl BodMessage {
+    /// Parse un message BOD depuis un NmeaMessage
+    pub fn from_nmea(message: &NmeaMessage) -> Result<Self, NmeaError> {
+        if message.fields.len() < 6 {
+            return Err(NmeaError::InvalidFormat(
+                "BOD message must have at least 6 fields".to_string()
+            ));
+        }
+
+        Ok(BodMessage {
+            bearing_true: parse_field(message, 0),
+            bearing_magnetic: parse_field(message, 2),
+            destination: string_field(message, 4),
+            origin: string_field(message, 5),
+        })
+    }
+}
+
+/// Message XTE (Cross-Track Error, Measured)
+/// Format: $GPXTE,A,A,x.x,a,N[,m]*hh
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct XteMessage {
+    /// General warning flag (Loran-C blink/SNR) - A=valid, V=invalid
+    pub status: Option<char>,
+    /// Cycle lock warning flag (Loran-C) - A=valid, V=invalid
+    pub cycle_lock: Option<char>,
+    /// Cross track error magnitude
+    pub magnitude: Option<f64>,
+    /// Direction to steer - L or R
+    pub steer_direction: Option<char>,
+    /// Cross track units (N=nautical miles)
+    pub units: Option<char>,
+    /// FAA mode indicator (NMEA 2.3 et suivants)
+    pub mode: Option<char>,
+}
+
+impl XteMessage {
+    /// Parse un message XTE depuis un NmeaMessage
+    pub fn from_nmea(message: &NmeaMessage) -> Result<Self, NmeaError> {
+        if message.fields.len() < 5 {
+            return Err(NmeaError::InvalidFormat(
+                "XTE message must have at least 5 fields".to_string()
+            ));
+        }
+
+        Ok(XteMessage {
+            status: char_field(message, 0),
+            cycle_lock: char_field(message, 1),
+            magnitude: parse_field(message, 2),
+            steer_direction: char_field(message, 3),
+            units: char_field(message, 4),
+            mode: char_field(message, 5),
+        })
+    }
+}
+
+/// Message RMB (Recommended Minimum Navigation Information)
+/// Format: $GPRMB,A,x.x,a,c--c,c--c,llll.ll,a,yyyyy.yy,a,x.x,x.x,x.x,A[,m]*hh
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RmbMessage {
+    /// Status - A=active, V=void
+    pub status: Option<char>,
+    /// Cross track error (nautical miles)
+    pub cross_track_error: Option<f64>,
+    /// Direction to steer - L or R
+    pub steer_direction: Option<char>,
+    /// Origin waypoint identifier
+    pub origin: Option<String>,
+    /// Destination waypoint identifier
+    pub destination: Option<String>,
+    /// Destination latitude - llll.ll
+    pub destination_latitude: Option<f64>,
+    /// Destination latitude direction - N or S
+    pub destination_latitude_direction: Option<char>,
+    /// Destination longitude - yyyyy.yy
+    pub destination_longitude: Option<f64>,
+    /// Destination longitude direction - E or W
+    pub destination_longitude_direction: Option<char>,
+    /// Range to destination (nautical miles)
+    pub range: Option<f64>,
+    /// Bearing to destination (degrees True)
+    pub bearing: Option<f64>,
+    /// Destination closing velocity (knots)
+    pub closing_velocity: Option<f64>,
+    /// Arrival status - A=arrival circle entered, V=not entered
+    pub arrival_status: Option<char>,
+    /// FAA mode indicator (NMEA 2.3 et suivants)
+    pub mode: Option<char>,
+}
+
+impl RmbMessage {
+    /// Parse un message RMB depuis un NmeaMessage
+    pub fn from_nmea(message: &NmeaMessage) -> Result<Self, NmeaError> {
+        if message.fields.len() < 13 {
+            return Err(NmeaError::InvalidFormat(
+                "RMB message must have at least 13 fields".to_string()
+            ));
+        }
+
+        Ok(RmbMessage {
+            status: char_field(message, 0),
+            cross_track_error: parse_field(message, 1),
+            steer_direction: char_field(message, 2),
+            origin: string_field(message, 3),
+            destination: string_field(message, 4),
+            destination_latitude: parse_field(message, 5),
+            destination_latitude_direction: char_field(message, 6),
+            destination_longitude: parse_field(message, 7),
+            destination_longitude_direction: char_field(message, 8),
+            range: parse_field(message, 9),
+            bearing: parse_field(message, 10),
+            closing_velocity: parse_field(message, 11),
+            arrival_status: char_field(message, 12),
+            mode: char_field(message, 13),
+        })
+    }
+
+    /// Convertit la latitude de destination en degrés décimaux
+    pub fn destination_latitude_decimal(&self) -> Option<f64> {
+        Some(to_decimal_degrees(self.destination_latitude?, self.destination_latitude_direction?, 'S'))
+    }
+
+    /// Convertit la longitude de destination en degrés décimaux
+    pub fn destination_longitude_decimal(&self) -> Option<f64> {
+        Some(to_decimal_degrees(self.destination_longitude?, self.destination_longitude_direction?, 'W'))
+    }
+}
+
+/// Message HDT (Heading - True)
+/// Format: $HEHDT,x.x,T*hh
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HdtMessage {
+    /// Heading (degrees True)
+    pub heading: Option<f64>,
+}
+
+impl HdtMessage {
+    /// Parse un message HDT depuis un NmeaMessage
+    pub fn from_nmea(message: &NmeaMessage) -> Result<Self, NmeaError> {
+        if message.fields.len() < 2 {
+            return Err(NmeaError::InvalidFormat(
+                "HDT message must have at least 2 fields".to_string()
+            ));
+        }
+
+        Ok(HdtMessage {
+            heading: parse_field(message, 0),
+        })
+    }
+}
+
+/// Message HDG (Heading, Deviation and Variation)
+/// Format: $HCHDG,x.x,x.x,a,x.x,a*hh
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HdgMessage {
+    /// Magnetic sensor heading (degrees)
+    pub heading: Option<f64>,
+    /// Magnetic deviation (degrees)
+    pub deviation: Option<f64>,
+    /// Magnetic deviation direction - E or W
+    pub deviation_direction: Option<char>,
+    /// Magnetic variation (degrees)
+    pub variation: Option<f64>,
+    /// Magnetic variation direction - E or W
+    pub variation_direction: Option<char>,
+}
+
+impl HdgMessage {
+    /// Parse un message HDG depuis un NmeaMessage
+    pub fn from_nmea(message: &NmeaMessage) -> Result<Self, NmeaError> {
+        if message.fields.len() < 5 {
+            return Err(NmeaError::InvalidFormat(
+                "HDG message must have at least 5 fields".to_string()
+            ));
+        }
+
+        Ok(HdgMessage {
+            heading: parse_field(message, 0),
+            deviation: parse_field(message, 1),
+            deviation_direction: char_field(message, 2),
+            variation: parse_field(message, 3),
+            variation_direction: char_field(message, 4),
+        })
+    }
+
+    /// Cap vrai (cap magnétique corrigé de la déviation et de la déclinaison,
+    /// est positive), ramené dans [0, 360)
+    pub fn true_heading(&self) -> Option<f64> {
+        let signed = |value: Option<f64>, direction: Option<char>| match (value, direction) {
+            (Some(v), Some('W')) => -v,
+            (Some(v), _) => v,
+            (None, _) => 0.0,
+        };
+        let heading = self.heading?
+            + signed(self.deviation, self.deviation_direction)
+            + signed(self.variation, self.variation_direction);
+        Some(heading.rem_euclid(360.0))
+    }
+}
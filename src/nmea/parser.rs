@@ -5,6 +5,9 @@ use pest_derive::Parser;
 use crate::nmea::message::NmeaMessage;
 use crate::nmea::types::NmeaMessageType;
 use crate::nmea::error::NmeaError;
+use crate::nmea::validation::check_message;
+use crate::nmea::tag_block::{split_tag_block, TagBlock};
+use crate::diagnostic::{Diagnostic, Lenient, LocatedError, Severity, SyntaxError};
 
 #[derive(Parser)]
 #[grammar = "nmea/nmea.pest"]
@@ -16,6 +19,10 @@ impl NmeaParser {
     /// Valide la structure du message, vérifie le checksum, et extrait les champs.
     /// Format attendu: $MESSAGE_TYPE,field1,field2,...,fieldN*CHECKSUM
     /// 
+    /// La phrase peut être précédée d'un bloc TAG NMEA 4.x
+    /// (`\s:station1,c:1697000000*7E\!AIVDM,...`) dont le checksum est
+    /// vérifié ; ses paramètres sont exposés par `NmeaMessage::tag_block`.
+    /// 
     /// # Arguments
     /// * `input` - Message NMEA brut (peut contenir des espaces/retours à la ligne)
     /// 
@@ -33,8 +40,12 @@ impl NmeaParser {
         // Nettoyer l'input (supprimer les espaces en début/fin et les retours à la ligne)
         let trimmed = input.trim();
         
+        // Bloc TAG éventuel (NMEA 4.x), vérifié avec son propre checksum
+        let (tag_content, sentence) = split_tag_block(trimmed)?;
+        let tag_block = tag_content.map(TagBlock::parse).transpose()?;
+        
         // Vérifier le format de base - doit commencer par $ ou !
-        if !trimmed.starts_with('$') && !trimmed.starts_with('!') {
+        if !sentence.starts_with('$') && !sentence.starts_with('!') {
             return Err(NmeaError::InvalidFormat(
                 "Message must start with $ or !".to_string()
             ));
@@ -42,30 +53,30 @@ impl NmeaParser {
         
         // Vérifier qu'il n'y a pas d'espaces entre virgules (non conforme à NMEA 0183)
         // Les espaces entre virgules sont interdits selon la spécification
-        if trimmed.contains(", ") || trimmed.contains(" ,") {
+        if sentence.contains(", ") || sentence.contains(" ,") {
             return Err(NmeaError::InvalidFormat(
                 "NMEA 0183 does not allow spaces between commas. Fields must be separated by commas without spaces.".to_string()
             ));
         }
         
         // Trouver le checksum
-        let checksum_pos = trimmed.rfind('*')
-            .ok_or_else(|| NmeaError::MissingChecksum)?;
+        let checksum_pos = sentence.rfind('*')
+            .ok_or(NmeaError::MissingChecksum)?;
         
-        // PANIC: trimmed[checksum_pos + 1..] peut panic si checksum_pos + 1 > trimmed.len(),
-        // mais trimmed.rfind('*') retourne un index valide ou None (ici géré par ok_or_else)
+        // PANIC: sentence[checksum_pos + 1..] peut panic si checksum_pos + 1 > sentence.len(),
+        // mais sentence.rfind('*') retourne un index valide ou None (ici géré par ok_or_else)
         // et on prend seulement 2 caractères avec .take(2), donc même si la chaîne est courte, c'est sûr
-        let checksum_provided = trimmed[checksum_pos + 1..]
+        let checksum_provided = sentence[checksum_pos + 1..]
             .chars()
             .take(2)
             .collect::<String>()
             .to_uppercase();
         
         // Calculer le checksum attendu
-        // PANIC: trimmed[1..checksum_pos] peut panic si checksum_pos < 1 ou checksum_pos > trimmed.len(),
+        // PANIC: sentence[1..checksum_pos] peut panic si checksum_pos < 1 ou checksum_pos > sentence.len(),
         // mais le format NMEA garantit qu'il y a au moins un caractère entre $ et * (le type de message),
-        // et trimmed.rfind('*') retourne un index valide, donc checksum_pos >= 1
-        let checksum_expected = Self::calculate_checksum(&trimmed[1..checksum_pos]);
+        // et sentence.rfind('*') retourne un index valide, donc checksum_pos >= 1
+        let checksum_expected = Self::calculate_checksum(&sentence[1..checksum_pos]);
         
         // Valider le checksum
         if checksum_provided != checksum_expected {
@@ -77,13 +88,118 @@ impl NmeaParser {
         
         // Parser avec PEST
         let mut pairs = NmeaParser::parse(Rule::nmea_message, trimmed)
-            .map_err(|e| NmeaError::ParseError(format!("{}", e)))?;
+            .map_err(|e| {
+                let leading = input.len() - input.trim_start().len();
+                NmeaError::from(SyntaxError::from_pest(&e, trimmed, "")
+                    .offset_in(input, leading)
+                    .with_field_index(input, ','))
+            })?;
         
         let message_pair = pairs.next().ok_or_else(|| {
             NmeaError::ParseError("Empty parse result".to_string())
         })?;
         
-        Self::parse_message_pair(message_pair, trimmed)
+        let mut message = Self::parse_message_pair(message_pair, sentence)?;
+        message.tag_block = tag_block;
+        message.raw = trimmed.to_string();
+        Ok(message)
+    }
+    
+    /// Parse un message NMEA 0183 en mode tolérant.
+    /// 
+    /// Les écarts réparables ne bloquent pas l'analyse : les espaces autour
+    /// des virgules sont retirés et un checksum absent est recalculé
+    /// (avertissements), un checksum faux est signalé comme erreur mais le
+    /// message est tout de même décodé. Les erreurs de validation des champs
+    /// sont toutes relevées. Après une réparation, les positions sont
+    /// relatives au texte réparé. Le checksum d'un bloc TAG est réparé de la
+    /// même façon.
+    /// 
+    /// # Exemples
+    /// ```
+    /// use aftn::NmeaParser;
+    /// let result = NmeaParser::parse_lenient("$GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*00");
+    /// assert!(result.message.is_some());
+    /// assert!(result.has_errors());
+    /// ```
+    pub fn parse_lenient(input: &str) -> Lenient<NmeaMessage> {
+        let mut diagnostics = Vec::new();
+        let trimmed = input.trim();
+        // Un bloc TAG non terminé est laissé tel quel et signalé par parse_message
+        let (tag_content, sentence) = split_tag_block(trimmed).unwrap_or((None, trimmed));
+        let mut repaired = sentence.to_string();
+        
+        if repaired.contains(", ") || repaired.contains(" ,") {
+            diagnostics.push(Diagnostic::new(
+                Severity::Warning,
+                "NMEA101",
+                "Spaces around commas removed",
+            ));
+            repaired = repaired.split(',').map(str::trim).collect::<Vec<_>>().join(",");
+        }
+        
+        if repaired.len() > 1 && (repaired.starts_with('$') || repaired.starts_with('!')) {
+            match repaired.rfind('*') {
+                Some(checksum_pos) => {
+                    let provided = repaired[checksum_pos + 1..].chars().take(2).collect::<String>().to_uppercase();
+                    let expected = Self::calculate_checksum(&repaired[1..checksum_pos]);
+                    if provided != expected {
+                        diagnostics.push(NmeaError::InvalidChecksum {
+                            expected: expected.clone(),
+                            got: provided,
+                        }.to_diagnostic());
+                        repaired = format!("{}*{}", &repaired[..checksum_pos], expected);
+                    }
+                }
+                None => {
+                    let expected = Self::calculate_checksum(&repaired[1..]);
+                    diagnostics.push(Diagnostic::new(
+                        Severity::Warning,
+                        "NMEA102",
+                        format!("Missing checksum, computed {}", expected),
+                    ));
+                    repaired = format!("{}*{}", repaired, expected);
+                }
+            }
+        }
+        
+        if let Some(content) = tag_content {
+            let content = match content.rfind('*') {
+                Some(checksum_pos) => {
+                    let provided = content[checksum_pos + 1..].to_uppercase();
+                    let expected = Self::calculate_checksum(&content[..checksum_pos]);
+                    if provided != expected {
+                        diagnostics.push(NmeaError::InvalidChecksum {
+                            expected: expected.clone(),
+                            got: provided,
+                        }.to_diagnostic());
+                    }
+                    format!("{}*{}", &content[..checksum_pos], expected)
+                }
+                None => {
+                    let expected = Self::calculate_checksum(content);
+                    diagnostics.push(Diagnostic::new(
+                        Severity::Warning,
+                        "NMEA103",
+                        format!("Missing TAG block checksum, computed {}", expected),
+                    ));
+                    format!("{}*{}", content, expected)
+                }
+            };
+            repaired = format!("\\{}\\{}", content, repaired);
+        }
+        
+        let text = if diagnostics.is_empty() { input } else { repaired.as_str() };
+        match Self::parse_message(text) {
+            Ok(message) => {
+                diagnostics.extend(check_message(&message).iter().map(NmeaError::to_diagnostic));
+                Lenient { message: Some(message), diagnostics }
+            }
+            Err(error) => {
+                diagnostics.push(error.to_diagnostic());
+                Lenient { message: None, diagnostics }
+            }
+        }
     }
     
     /// Calcule le checksum NMEA (XOR de tous les caractères entre $ et *).
@@ -104,7 +220,7 @@ impl NmeaParser {
     /// let checksum = NmeaParser::calculate_checksum(data);
     /// assert_eq!(checksum, "47");
     /// ```
-    fn calculate_checksum(data: &str) -> String {
+    pub(crate) fn calculate_checksum(data: &str) -> String {
         // Initialiser le checksum à 0
         let mut checksum: u8 = 0;
         // XOR de tous les octets du message
@@ -185,6 +301,7 @@ impl NmeaParser {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::nmea::types::{SentenceFormatter, TalkerId};
 
     #[test]
     fn test_calculate_checksum() {
@@ -202,7 +319,7 @@ mod tests {
         assert!(result.is_ok(), "Should parse successfully");
         
         let message = result.unwrap();
-        assert_eq!(message.message_type, NmeaMessageType::GPGGA);
+        assert_eq!(message.message_type, NmeaMessageType::standard(TalkerId::GP, SentenceFormatter::GGA));
         assert_eq!(message.checksum, "47");
         assert!(message.fields.len() > 0);
     }
@@ -0,0 +1,245 @@
+//! Blocs TAG NMEA 4.x (IEC 61162-450)
+//!
+//! Un bloc TAG précède la phrase entre deux barres obliques inverses :
+//! `\s:station1,c:1697000000*7E\!AIVDM,...`. Il porte des paramètres
+//! `code:valeur` séparés par des virgules et son propre checksum (XOR des
+//! caractères entre la première barre et `*`). Codes reconnus :
+//!
+//! - `s` : source (station émettrice)
+//! - `c` : horodatage UNIX
+//! - `d` : destination
+//! - `n` : compteur de lignes
+//! - `r` : temps relatif
+//! - `g` : groupe de phrases `rang-total-identifiant`
+//! - `t` : texte libre
+//!
+//! Les autres codes sont conservés tels quels. L'ordre des paramètres lus est
+//! mémorisé pour que le bloc soit réécrit à l'identique.
+
+use std::fmt;
+use serde::{Deserialize, Serialize};
+use crate::nmea::error::NmeaError;
+use crate::nmea::parser::NmeaParser;
+
+/// Groupe de phrases (`g:1-2-1234`) : les phrases d'un même message
+/// partagent l'identifiant de groupe
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TagGroup {
+    /// Rang de la phrase dans le groupe (à partir de 1)
+    pub sentence: u32,
+    /// Nombre de phrases du groupe
+    pub total: u32,
+    /// Identifiant du groupe
+    pub id: u32,
+}
+
+/// Bloc TAG précédant une phrase NMEA
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TagBlock {
+    /// Source (`s`)
+    pub source: Option<String>,
+    /// Horodatage UNIX (`c`), en secondes ou millisecondes selon l'émetteur
+    pub unix_time: Option<u64>,
+    /// Destination (`d`)
+    pub destination: Option<String>,
+    /// Compteur de lignes (`n`)
+    pub line_count: Option<u32>,
+    /// Temps relatif (`r`)
+    pub relative_time: Option<u64>,
+    /// Groupe de phrases (`g`)
+    pub group: Option<TagGroup>,
+    /// Texte libre (`t`)
+    pub text: Option<String>,
+    /// Paramètres non reconnus, dans l'ordre d'origine
+    pub other: Vec<(String, String)>,
+    /// Codes des paramètres dans l'ordre de lecture ; les paramètres absents
+    /// de cette liste sont écrits ensuite dans l'ordre s, c, d, n, r, g, t
+    #[serde(default)]
+    pub order: Vec<String>,
+}
+
+impl PartialEq for TagBlock {
+    /// Deux blocs portant les mêmes paramètres sont égaux quel que soit
+    /// l'ordre dans lequel ils ont été écrits
+    fn eq(&self, other: &Self) -> bool {
+        self.source == other.source
+            && self.unix_time == other.unix_time
+            && self.destination == other.destination
+            && self.line_count == other.line_count
+            && self.relative_time == other.relative_time
+            && self.group == other.group
+            && self.text == other.text
+            && self.other == other.other
+    }
+}
+
+impl TagBlock {
+    /// Parse le contenu d'un bloc TAG (entre les deux `\`) et vérifie son
+    /// checksum
+    pub fn parse(content: &str) -> Result<Self, NmeaError> {
+        let checksum_pos = content.rfind('*').ok_or_else(|| {
+            NmeaError::InvalidFormat("TAG block must end with a checksum".to_string())
+        })?;
+        let provided = content[checksum_pos + 1..].to_uppercase();
+        let expected = NmeaParser::calculate_checksum(&content[..checksum_pos]);
+        if provided != expected {
+            return Err(NmeaError::InvalidChecksum { expected, got: provided });
+        }
+
+        let mut tag = TagBlock::default();
+        for parameter in content[..checksum_pos].split(',') {
+            let (code, value) = parameter.split_once(':').ok_or_else(|| {
+                NmeaError::InvalidFormat(format!("TAG block parameter '{}' must be code:value", parameter))
+            })?;
+            match code {
+                "s" => tag.source = Some(value.to_string()),
+                "c" => tag.unix_time = Some(parse_number(code, value)?),
+                "d" => tag.destination = Some(value.to_string()),
+                "n" => tag.line_count = Some(parse_number(code, value)?),
+                "r" => tag.relative_time = Some(parse_number(code, value)?),
+                "g" => tag.group = Some(parse_group(value)?),
+                "t" => tag.text = Some(value.to_string()),
+                _ => tag.other.push((code.to_string(), value.to_string())),
+            }
+            tag.order.push(code.to_string());
+        }
+        Ok(tag)
+    }
+
+    /// Paramètres du bloc, sans les barres ni le checksum
+    fn content(&self) -> String {
+        let mut parameters = Vec::new();
+        let mut written = Vec::new();
+        let mut others = self.other.iter();
+        for code in &self.order {
+            match code.as_str() {
+                "s" | "c" | "d" | "n" | "r" | "g" | "t" => {
+                    if !written.contains(&code.as_str()) {
+                        written.push(code.as_str());
+                        parameters.extend(self.parameter(code));
+                    }
+                }
+                _ => parameters.extend(others.next().map(|(code, value)| format!("{}:{}", code, value))),
+            }
+        }
+        for code in ["s", "c", "d", "n", "r", "g", "t"] {
+            if !written.contains(&code) {
+                parameters.extend(self.parameter(code));
+            }
+        }
+        parameters.extend(others.map(|(code, value)| format!("{}:{}", code, value)));
+        parameters.join(",")
+    }
+
+    /// Paramètre reconnu `code:valeur`, s'il est renseigné
+    fn parameter(&self, code: &str) -> Option<String> {
+        let value = match code {
+            "s" => self.source.clone()?,
+            "c" => self.unix_time?.to_string(),
+            "d" => self.destination.clone()?,
+            "n" => self.line_count?.to_string(),
+            "r" => self.relative_time?.to_string(),
+            "g" => {
+                let group = self.group.as_ref()?;
+                format!("{}-{}-{}", group.sentence, group.total, group.id)
+            }
+            "t" => self.text.clone()?,
+            _ => return None,
+        };
+        Some(format!("{}:{}", code, value))
+    }
+}
+
+impl fmt::Display for TagBlock {
+    /// Bloc complet avec checksum recalculé : `\s:station1,c:1697000000*7E\`
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let content = self.content();
+        write!(f, "\\{}*{}\\", content, NmeaParser::calculate_checksum(&content))
+    }
+}
+
+/// Sépare le contenu d'un bloc TAG éventuel (sans les `\`) de la phrase qui
+/// le suit
+pub(crate) fn split_tag_block(text: &str) -> Result<(Option<&str>, &str), NmeaError> {
+    let Some(rest) = text.strip_prefix('\\') else {
+        return Ok((None, text));
+    };
+    let end = rest.find('\\').ok_or_else(|| {
+        NmeaError::InvalidFormat("TAG block must be terminated by \\".to_string())
+    })?;
+    Ok((Some(&rest[..end]), &rest[end + 1..]))
+}
+
+fn parse_number<T: std::str::FromStr>(code: &str, value: &str) -> Result<T, NmeaError> {
+    value.parse().map_err(|_| NmeaError::InvalidFieldValue {
+        field: format!("TAG {}", code),
+        value: value.to_string(),
+        reason: "Must be a non-negative integer".to_string(),
+    })
+}
+
+fn parse_group(value: &str) -> Result<TagGroup, NmeaError> {
+    let parts: Vec<&str> = value.split('-').collect();
+    let invalid = || NmeaError::InvalidFieldValue {
+        field: "TAG g".to_string(),
+        value: value.to_string(),
+        reason: "Must be sentence-total-id with 1 <= sentence <= total".to_string(),
+    };
+    let [sentence, total, id] = parts.as_slice() else {
+        return Err(invalid());
+    };
+    let group = TagGroup {
+        sentence: sentence.parse().map_err(|_| invalid())?,
+        total: total.parse().map_err(|_| invalid())?,
+        id: id.parse().map_err(|_| invalid())?,
+    };
+    if group.sentence == 0 || group.sentence > group.total {
+        return Err(invalid());
+    }
+    Ok(group)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_tag_block() {
+        let tag = TagBlock::parse("g:1-2-73874,n:157036,s:r003669945,c:1241544035*4A").unwrap();
+        assert_eq!(tag.source.as_deref(), Some("r003669945"));
+        assert_eq!(tag.unix_time, Some(1241544035));
+        assert_eq!(tag.line_count, Some(157036));
+        assert_eq!(tag.group, Some(TagGroup { sentence: 1, total: 2, id: 73874 }));
+    }
+
+    #[test]
+    fn test_tag_block_checksum() {
+        assert!(matches!(
+            TagBlock::parse("s:station1,c:1697000000*00"),
+            Err(NmeaError::InvalidChecksum { .. })
+        ));
+        assert!(TagBlock::parse("s:station1,c:1697000000").is_err());
+    }
+
+    #[test]
+    fn test_display_round_trip() {
+        let tag = TagBlock {
+            source: Some("station1".to_string()),
+            unix_time: Some(1697000000),
+            ..TagBlock::default()
+        };
+        assert_eq!(tag.to_string(), "\\s:station1,c:1697000000*7E\\");
+        assert_eq!(TagBlock::parse("s:station1,c:1697000000*7E").unwrap(), tag);
+    }
+
+    #[test]
+    fn test_round_trip_keeps_parameter_order() {
+        let tag = TagBlock::parse("g:1-2-73874,n:157036,s:r003669945,c:1241544035*4A").unwrap();
+        assert_eq!(tag.to_string(), "\\g:1-2-73874,n:157036,s:r003669945,c:1241544035*4A\\");
+
+        // Un paramètre ajouté après lecture est écrit à la suite
+        let mut tag = TagBlock::parse("n:12,x:abc,s:station1*77").unwrap();
+        tag.text = Some("hello".to_string());
+        assert!(tag.to_string().starts_with("\\n:12,x:abc,s:station1,t:hello*"));
+    }
+}
@@ -1,67 +1,306 @@
 //! Types de messages NMEA 0183
+//!
+//! L'adresse d'une phrase standard est formée d'un identifiant d'émetteur
+//! (talker ID, 2 caractères : `GP`, `GN`, `GL`, ...) suivi d'un formateur de
+//! phrase (3 caractères : `GGA`, `RMC`, ...). Le formateur seul détermine le
+//! contenu de la phrase : `$GNGGA` et `$GPGGA` se décodent de la même façon.
+//! Les phrases propriétaires commencent par `P` suivi du code fabricant sur
+//! 3 caractères (`$PGRME` : Garmin, phrase `E`).
 
+use std::borrow::Cow;
+use std::fmt;
 use serde::{Deserialize, Serialize};
 use crate::nmea::error::NmeaError;
 
-/// Types de messages NMEA 0183 supportés
+/// Identifiant d'émetteur (talker ID)
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
-pub enum NmeaMessageType {
+pub enum TalkerId {
+    /// GPS
+    GP,
+    /// GLONASS
+    GL,
+    /// Galileo
+    GA,
+    /// BeiDou (identifiant NMEA 4.11)
+    GB,
+    /// BeiDou (identifiant historique)
+    BD,
+    /// QZSS
+    GQ,
+    /// NavIC (IRNSS)
+    GI,
+    /// Solution combinant plusieurs constellations
+    GN,
+    /// Station AIS mobile
+    AI,
+    /// Station AIS de base
+    AB,
+    /// Autre émetteur (compas, sondeur, ...)
+    Other(String),
+}
+
+impl TalkerId {
+    /// Émetteur correspondant à un code de 2 caractères
+    pub fn from_code(code: &str) -> Self {
+        match code {
+            "GP" => TalkerId::GP,
+            "GL" => TalkerId::GL,
+            "GA" => TalkerId::GA,
+            "GB" => TalkerId::GB,
+            "BD" => TalkerId::BD,
+            "GQ" => TalkerId::GQ,
+            "GI" => TalkerId::GI,
+            "GN" => TalkerId::GN,
+            "AI" => TalkerId::AI,
+            "AB" => TalkerId::AB,
+            _ => TalkerId::Other(code.to_string()),
+        }
+    }
+
+    /// Code de l'émetteur
+    pub fn code(&self) -> &str {
+        match self {
+            TalkerId::GP => "GP",
+            TalkerId::GL => "GL",
+            TalkerId::GA => "GA",
+            TalkerId::GB => "GB",
+            TalkerId::BD => "BD",
+            TalkerId::GQ => "GQ",
+            TalkerId::GI => "GI",
+            TalkerId::GN => "GN",
+            TalkerId::AI => "AI",
+            TalkerId::AB => "AB",
+            TalkerId::Other(code) => code,
+        }
+    }
+
+    /// Constellation GNSS de l'émetteur, `None` hors GNSS
+    pub fn constellation(&self) -> Option<&'static str> {
+        match self {
+            TalkerId::GP => Some("GPS"),
+            TalkerId::GL => Some("GLONASS"),
+            TalkerId::GA => Some("Galileo"),
+            TalkerId::GB | TalkerId::BD => Some("BeiDou"),
+            TalkerId::GQ => Some("QZSS"),
+            TalkerId::GI => Some("NavIC"),
+            TalkerId::GN => Some("GNSS"),
+            _ => None,
+        }
+    }
+}
+
+/// Formateur de phrase (type de contenu, indépendant de l'émetteur)
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum SentenceFormatter {
     /// Global Positioning System Fix Data
-    GPGGA,
-    /// Recommended Minimum Specific GPS/Transit Data
-    GPRMC,
-    /// GPS DOP and Active Satellites
-    GPGSA,
-    /// GPS Satellites in View
-    GPGSV,
+    GGA,
+    /// Recommended Minimum Specific GNSS Data
+    RMC,
+    /// GNSS DOP and Active Satellites
+    GSA,
+    /// GNSS Satellites in View
+    GSV,
+    /// Geographic Position - Latitude/Longitude
+    GLL,
+    /// GNSS Pseudorange Error Statistics
+    GST,
+    /// Heading - True
+    HDT,
+    /// Heading, Deviation and Variation
+    HDG,
     /// Track Made Good and Ground Speed
-    GPVTG,
+    VTG,
     /// Waypoint Location
-    GPWPL,
+    WPL,
     /// Bearing and Distance to Waypoint
-    GPBOD,
+    BOD,
     /// Cross Track Error
-    GPXTE,
+    XTE,
+    /// Recommended Minimum Navigation Information
+    RMB,
     /// Time and Date
-    GPZDA,
-    /// Generic message type (non-standard or unknown)
+    ZDA,
+    /// Message AIS reçu
+    VDM,
+    /// Message AIS de la station elle-même
+    VDO,
+    /// Autre formateur
+    Other(String),
+}
+
+impl SentenceFormatter {
+    /// Formateur correspondant à un code de 3 caractères
+    pub fn from_code(code: &str) -> Self {
+        match code {
+            "GGA" => SentenceFormatter::GGA,
+            "RMC" => SentenceFormatter::RMC,
+            "GSA" => SentenceFormatter::GSA,
+            "GSV" => SentenceFormatter::GSV,
+            "GLL" => SentenceFormatter::GLL,
+            "GST" => SentenceFormatter::GST,
+            "HDT" => SentenceFormatter::HDT,
+            "HDG" => SentenceFormatter::HDG,
+            "VTG" => SentenceFormatter::VTG,
+            "WPL" => SentenceFormatter::WPL,
+            "BOD" => SentenceFormatter::BOD,
+            "XTE" => SentenceFormatter::XTE,
+            "RMB" => SentenceFormatter::RMB,
+            "ZDA" => SentenceFormatter::ZDA,
+            "VDM" => SentenceFormatter::VDM,
+            "VDO" => SentenceFormatter::VDO,
+            _ => SentenceFormatter::Other(code.to_string()),
+        }
+    }
+
+    /// Code du formateur
+    pub fn code(&self) -> &str {
+        match self {
+            SentenceFormatter::GGA => "GGA",
+            SentenceFormatter::RMC => "RMC",
+            SentenceFormatter::GSA => "GSA",
+            SentenceFormatter::GSV => "GSV",
+            SentenceFormatter::GLL => "GLL",
+            SentenceFormatter::GST => "GST",
+            SentenceFormatter::HDT => "HDT",
+            SentenceFormatter::HDG => "HDG",
+            SentenceFormatter::VTG => "VTG",
+            SentenceFormatter::WPL => "WPL",
+            SentenceFormatter::BOD => "BOD",
+            SentenceFormatter::XTE => "XTE",
+            SentenceFormatter::RMB => "RMB",
+            SentenceFormatter::ZDA => "ZDA",
+            SentenceFormatter::VDM => "VDM",
+            SentenceFormatter::VDO => "VDO",
+            SentenceFormatter::Other(code) => code,
+        }
+    }
+}
+
+/// Type d'une phrase NMEA 0183
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum NmeaMessageType {
+    /// Phrase standard : émetteur et formateur (`$GNGGA`, `!AIVDM`, ...)
+    Standard {
+        talker: TalkerId,
+        formatter: SentenceFormatter,
+    },
+    /// Phrase propriétaire `$P` : code fabricant (3 caractères) et type de
+    /// phrase propre au fabricant (éventuellement vide)
+    Proprietary {
+        manufacturer: String,
+        sentence: String,
+    },
+    /// Identifiant non conforme (ni 5 caractères, ni phrase propriétaire)
     Generic(String),
 }
 
 impl NmeaMessageType {
+    /// Phrase standard
+    pub fn standard(talker: TalkerId, formatter: SentenceFormatter) -> Self {
+        NmeaMessageType::Standard { talker, formatter }
+    }
+
     /// Détermine le type de message depuis l'identifiant NMEA
     pub fn from_identifier(identifier: &str) -> Result<Self, NmeaError> {
-        match identifier {
-            // GPS messages
-            "GPGGA" => Ok(NmeaMessageType::GPGGA),
-            "GPRMC" => Ok(NmeaMessageType::GPRMC),
-            "GPGSA" => Ok(NmeaMessageType::GPGSA),
-            "GPGSV" => Ok(NmeaMessageType::GPGSV),
-            "GPVTG" => Ok(NmeaMessageType::GPVTG),
-            "GPWPL" => Ok(NmeaMessageType::GPWPL),
-            "GPBOD" => Ok(NmeaMessageType::GPBOD),
-            "GPXTE" => Ok(NmeaMessageType::GPXTE),
-            "GPZDA" => Ok(NmeaMessageType::GPZDA),
-            // AIS messages
-            "AIVDM" | "AIVDO" => Ok(NmeaMessageType::Generic(identifier.to_string())),
-            _ => Ok(NmeaMessageType::Generic(identifier.to_string())),
+        let valid = identifier.chars().all(|c| c.is_ascii_uppercase() || c.is_ascii_digit());
+        if let Some(rest) = identifier.strip_prefix('P').filter(|rest| valid && rest.len() >= 3) {
+            return Ok(NmeaMessageType::Proprietary {
+                manufacturer: rest[..3].to_string(),
+                sentence: rest[3..].to_string(),
+            });
         }
+        if valid && identifier.len() == 5 {
+            return Ok(NmeaMessageType::standard(
+                TalkerId::from_code(&identifier[..2]),
+                SentenceFormatter::from_code(&identifier[2..]),
+            ));
+        }
+        Ok(NmeaMessageType::Generic(identifier.to_string()))
+    }
+
+    /// Retourne l'identifiant du type de message (`GNGGA`, `PGRME`, ...)
+    ///
+    /// L'identifiant n'est emprunté que pour un type non conforme : celui
+    /// d'une phrase standard ou propriétaire est reconstitué à partir de ses
+    /// composants.
+    pub fn identifier(&self) -> Cow<'_, str> {
+        match self {
+            NmeaMessageType::Generic(identifier) => Cow::Borrowed(identifier),
+            _ => Cow::Owned(self.to_string()),
+        }
+    }
+
+    /// Émetteur d'une phrase standard
+    pub fn talker(&self) -> Option<&TalkerId> {
+        match self {
+            NmeaMessageType::Standard { talker, .. } => Some(talker),
+            _ => None,
+        }
+    }
+
+    /// Formateur d'une phrase standard
+    pub fn formatter(&self) -> Option<&SentenceFormatter> {
+        match self {
+            NmeaMessageType::Standard { formatter, .. } => Some(formatter),
+            _ => None,
+        }
+    }
+
+    /// Code fabricant d'une phrase propriétaire
+    pub fn manufacturer(&self) -> Option<&str> {
+        match self {
+            NmeaMessageType::Proprietary { manufacturer, .. } => Some(manufacturer),
+            _ => None,
+        }
+    }
+
+    /// Indique si la phrase est propriétaire (`$P...`)
+    pub fn is_proprietary(&self) -> bool {
+        matches!(self, NmeaMessageType::Proprietary { .. })
     }
-    
-    /// Retourne l'identifiant du type de message
-    pub fn identifier(&self) -> &str {
+}
+
+impl fmt::Display for NmeaMessageType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            NmeaMessageType::GPGGA => "GPGGA",
-            NmeaMessageType::GPRMC => "GPRMC",
-            NmeaMessageType::GPGSA => "GPGSA",
-            NmeaMessageType::GPGSV => "GPGSV",
-            NmeaMessageType::GPVTG => "GPVTG",
-            NmeaMessageType::GPWPL => "GPWPL",
-            NmeaMessageType::GPBOD => "GPBOD",
-            NmeaMessageType::GPXTE => "GPXTE",
-            NmeaMessageType::GPZDA => "GPZDA",
-            NmeaMessageType::Generic(id) => id,
+            NmeaMessageType::Standard { talker, formatter } => write!(f, "{}{}", talker.code(), formatter.code()),
+            NmeaMessageType::Proprietary { manufacturer, sentence } => write!(f, "P{}{}", manufacturer, sentence),
+            NmeaMessageType::Generic(id) => write!(f, "{}", id),
         }
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_talker_and_formatter_split() {
+        let message_type = NmeaMessageType::from_identifier("GNGGA").unwrap();
+        assert_eq!(message_type.talker(), Some(&TalkerId::GN));
+        assert_eq!(message_type.formatter(), Some(&SentenceFormatter::GGA));
+        assert_eq!(message_type.identifier(), "GNGGA");
+
+        for (identifier, constellation) in [("GLGSV", "GLONASS"), ("GAGSA", "Galileo"), ("BDGSV", "BeiDou"), ("GBGSV", "BeiDou"), ("GQRMC", "QZSS")] {
+            let message_type = NmeaMessageType::from_identifier(identifier).unwrap();
+            assert_eq!(message_type.talker().unwrap().constellation(), Some(constellation));
+            assert_eq!(message_type.identifier(), identifier);
+        }
+    }
+
+    #[test]
+    fn test_proprietary_sentence() {
+        let message_type = NmeaMessageType::from_identifier("PGRME").unwrap();
+        assert!(message_type.is_proprietary());
+        assert_eq!(message_type.manufacturer(), Some("GRM"));
+        assert_eq!(message_type.identifier(), "PGRME");
+        assert_eq!(message_type.formatter(), None);
+    }
+
+    #[test]
+    fn test_non_conforming_identifier() {
+        let message_type = NmeaMessageType::from_identifier("INVALID").unwrap();
+        assert_eq!(message_type, NmeaMessageType::Generic("INVALID".to_string()));
+        assert_eq!(message_type.identifier(), "INVALID");
+    }
+}
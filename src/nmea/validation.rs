@@ -2,382 +2,729 @@
 
 use crate::nmea::message::NmeaMessage;
 use crate::nmea::error::NmeaError;
+use crate::nmea::types::SentenceFormatter;
+use crate::diagnostic::Checks;
+use crate::datetime::days_in_month;
 
-/// Valide un message NMEA selon son type
+/// Valide un message NMEA selon son type (premier problème relevé)
 pub fn validate_message(message: &NmeaMessage) -> Result<(), NmeaError> {
-    match message.message_type {
-        crate::nmea::types::NmeaMessageType::GPGGA => validate_gga(message),
-        crate::nmea::types::NmeaMessageType::GPRMC => validate_rmc(message),
-        crate::nmea::types::NmeaMessageType::GPGSA => validate_gsa(message),
-        crate::nmea::types::NmeaMessageType::GPGSV => validate_gsv(message),
-        crate::nmea::types::NmeaMessageType::GPVTG => validate_vtg(message),
-        crate::nmea::types::NmeaMessageType::GPWPL => validate_wpl(message),
-        crate::nmea::types::NmeaMessageType::GPBOD => validate_bod(message),
-        crate::nmea::types::NmeaMessageType::GPXTE => validate_xte(message),
-        crate::nmea::types::NmeaMessageType::GPZDA => validate_zda(message),
-        crate::nmea::types::NmeaMessageType::Generic(ref id) => {
-            // Valider les messages AIS
-            if id == "AIVDM" || id == "AIVDO" {
-                validate_ais(message)
-            } else {
-                Ok(()) // Pour les autres types génériques, on accepte sans validation spécifique
-            }
-        }
+    let mut checks = Checks::new();
+    run_checks(message, &mut checks);
+    checks.first()
+}
+
+/// Contrôle un message NMEA et retourne tous les problèmes relevés
+pub fn check_message(message: &NmeaMessage) -> Vec<NmeaError> {
+    let mut checks = Checks::new();
+    run_checks(message, &mut checks);
+    checks.0
+}
+
+fn run_checks(message: &NmeaMessage, checks: &mut Checks<NmeaError>) {
+    // Le contenu dépend du seul formateur : $GNGGA se valide comme $GPGGA
+    // Phrases propriétaires et non conformes : pas de validation spécifique
+    let Some(formatter) = message.message_type.formatter() else {
+        return;
+    };
+    match formatter {
+        SentenceFormatter::GGA => check_gga(message, checks),
+        SentenceFormatter::RMC => check_rmc(message, checks),
+        SentenceFormatter::GSA => check_gsa(message, checks),
+        SentenceFormatter::GSV => check_gsv(message, checks),
+        SentenceFormatter::VTG => check_vtg(message, checks),
+        SentenceFormatter::WPL => check_wpl(message, checks),
+        SentenceFormatter::BOD => check_bod(message, checks),
+        SentenceFormatter::XTE => check_xte(message, checks),
+        SentenceFormatter::RMB => check_rmb(message, checks),
+        SentenceFormatter::GLL => check_gll(message, checks),
+        SentenceFormatter::GST => check_gst(message, checks),
+        SentenceFormatter::HDT => check_hdt(message, checks),
+        SentenceFormatter::HDG => check_hdg(message, checks),
+        SentenceFormatter::ZDA => check_zda(message, checks),
+        SentenceFormatter::VDM | SentenceFormatter::VDO => check_ais(message, checks),
+        SentenceFormatter::Other(_) => {}
     }
 }
 
 /// Valide un message GPGGA (Global Positioning System Fix Data)
 /// Format: $GPGGA,hhmmss.ss,llll.ll,a,yyyyy.yy,a,x,xx,x.x,x.x,M,x.x,M,x.x,xxxx*hh
-fn validate_gga(message: &NmeaMessage) -> Result<(), NmeaError> {
-    if message.fields.len() < 14 {
-        return Err(NmeaError::InvalidFormat(
-            "GPGGA message must have at least 14 fields".to_string()
-        ));
-    }
+fn check_gga(message: &NmeaMessage, checks: &mut Checks<NmeaError>) {
+    checks.run(|| {
+        if message.fields.len() < 14 {
+            return Err(NmeaError::InvalidFormat(
+                "GPGGA message must have at least 14 fields".to_string()
+            ));
+        }
+        Ok(())
+    });
     
     // Field 0: Time (hhmmss.ss)
-    if let Some(time) = message.get_field(0) {
-        if !time.is_empty() {
-            validate_time(time)?;
+    checks.run(|| {
+        if let Some(time) = message.get_field(0) {
+            if !time.is_empty() {
+                validate_time(time)?;
+            }
         }
-    }
+        Ok(())
+    });
     
     // Field 1: Latitude (llll.ll)
-    if let Some(lat) = message.get_field(1) {
-        if !lat.is_empty() {
-            validate_latitude(lat)?;
+    checks.run(|| {
+        if let Some(lat) = message.get_field(1) {
+            if !lat.is_empty() {
+                validate_latitude(lat)?;
+            }
         }
-    }
+        Ok(())
+    });
     
     // Field 2: Latitude direction (N/S)
-    if let Some(lat_dir) = message.get_field(2) {
-        if !lat_dir.is_empty() && lat_dir != "N" && lat_dir != "S" {
-            return Err(NmeaError::InvalidFieldValue {
-                field: "Latitude direction".to_string(),
-                value: lat_dir.clone(),
-                reason: "Must be N or S".to_string(),
-            });
+    checks.run(|| {
+        if let Some(lat_dir) = message.get_field(2) {
+            if !lat_dir.is_empty() && lat_dir != "N" && lat_dir != "S" {
+                return Err(NmeaError::InvalidFieldValue {
+                    field: "Latitude direction".to_string(),
+                    value: lat_dir.clone(),
+                    reason: "Must be N or S".to_string(),
+                });
+            }
         }
-    }
+        Ok(())
+    });
     
     // Field 3: Longitude (yyyyy.yy)
-    if let Some(lon) = message.get_field(3) {
-        if !lon.is_empty() {
-            validate_longitude(lon)?;
+    checks.run(|| {
+        if let Some(lon) = message.get_field(3) {
+            if !lon.is_empty() {
+                validate_longitude(lon)?;
+            }
         }
-    }
+        Ok(())
+    });
     
     // Field 4: Longitude direction (E/W)
-    if let Some(lon_dir) = message.get_field(4) {
-        if !lon_dir.is_empty() && lon_dir != "E" && lon_dir != "W" {
-            return Err(NmeaError::InvalidFieldValue {
-                field: "Longitude direction".to_string(),
-                value: lon_dir.clone(),
-                reason: "Must be E or W".to_string(),
-            });
-        }
-    }
-    
-    // Field 5: GPS quality indicator (0-2)
-    if let Some(quality) = message.get_field(5) {
-        if !quality.is_empty() {
-            let q = quality.parse::<u8>()
-                .map_err(|_| NmeaError::InvalidFieldValue {
-                    field: "GPS quality".to_string(),
-                    value: quality.clone(),
-                    reason: "Must be a number 0-2".to_string(),
-                })?;
-            if q > 2 {
+    checks.run(|| {
+        if let Some(lon_dir) = message.get_field(4) {
+            if !lon_dir.is_empty() && lon_dir != "E" && lon_dir != "W" {
                 return Err(NmeaError::InvalidFieldValue {
-                    field: "GPS quality".to_string(),
-                    value: quality.clone(),
-                    reason: "Must be 0 (no fix), 1 (GPS fix), or 2 (DGPS fix)".to_string(),
+                    field: "Longitude direction".to_string(),
+                    value: lon_dir.clone(),
+                    reason: "Must be E or W".to_string(),
                 });
             }
         }
-    }
-    
-    // Field 6: Number of satellites (00-12)
-    if let Some(satellites) = message.get_field(6) {
-        if !satellites.is_empty() {
-            let s = satellites.parse::<u8>()
-                .map_err(|_| NmeaError::InvalidFieldValue {
-                    field: "Number of satellites".to_string(),
-                    value: satellites.clone(),
-                    reason: "Must be a number".to_string(),
-                })?;
-            if s > 12 {
-                return Err(NmeaError::InvalidFieldValue {
-                    field: "Number of satellites".to_string(),
-                    value: satellites.clone(),
-                    reason: "Must be 0-12".to_string(),
-                });
+        Ok(())
+    });
+    
+    // Field 5: GPS quality indicator (0-8)
+    checks.run(|| {
+        if let Some(quality) = message.get_field(5) {
+            if !quality.is_empty() {
+                let q = quality.parse::<u8>()
+                    .map_err(|_| NmeaError::InvalidFieldValue {
+                        field: "GPS quality".to_string(),
+                        value: quality.clone(),
+                        reason: "Must be a number 0-8".to_string(),
+                    })?;
+                if q > 8 {
+                    return Err(NmeaError::InvalidFieldValue {
+                        field: "GPS quality".to_string(),
+                        value: quality.clone(),
+                        reason: "Must be between 0 (no fix) and 8 (simulation)".to_string(),
+                    });
+                }
             }
         }
-    }
+        Ok(())
+    });
+    
+    // Field 6: Number of satellites (00-99)
+    checks.run(|| {
+        if let Some(satellites) = message.get_field(6) {
+            if !satellites.is_empty() {
+                let s = satellites.parse::<u8>()
+                    .map_err(|_| NmeaError::InvalidFieldValue {
+                        field: "Number of satellites".to_string(),
+                        value: satellites.clone(),
+                        reason: "Must be a number".to_string(),
+                    })?;
+                if s > 99 {
+                    return Err(NmeaError::InvalidFieldValue {
+                        field: "Number of satellites".to_string(),
+                        value: satellites.clone(),
+                        reason: "Must be 0-99".to_string(),
+                    });
+                }
+            }
+        }
+        Ok(())
+    });
     
     // Field 8: Altitude (x.x)
-    if let Some(altitude) = message.get_field(8) {
-        if !altitude.is_empty() {
-            altitude.parse::<f64>()
-                .map_err(|_| NmeaError::InvalidFieldValue {
-                    field: "Altitude".to_string(),
-                    value: altitude.clone(),
-                    reason: "Must be a number".to_string(),
-                })?;
+    checks.run(|| {
+        if let Some(altitude) = message.get_field(8) {
+            if !altitude.is_empty() {
+                altitude.parse::<f64>()
+                    .map_err(|_| NmeaError::InvalidFieldValue {
+                        field: "Altitude".to_string(),
+                        value: altitude.clone(),
+                        reason: "Must be a number".to_string(),
+                    })?;
+            }
         }
-    }
-    
-    Ok(())
+        Ok(())
+    });
 }
 
 /// Valide un message GPRMC (Recommended Minimum Specific GPS/Transit Data)
 /// Format: $GPRMC,hhmmss.ss,A,llll.ll,a,yyyyy.yy,a,x.x,x.x,ddmmyy,x.x,a*hh
-fn validate_rmc(message: &NmeaMessage) -> Result<(), NmeaError> {
-    if message.fields.len() < 11 {
-        return Err(NmeaError::InvalidFormat(
-            "GPRMC message must have at least 11 fields".to_string()
-        ));
-    }
+fn check_rmc(message: &NmeaMessage, checks: &mut Checks<NmeaError>) {
+    checks.run(|| {
+        if message.fields.len() < 11 {
+            return Err(NmeaError::InvalidFormat(
+                "GPRMC message must have at least 11 fields".to_string()
+            ));
+        }
+        Ok(())
+    });
     
     // Field 0: Time (hhmmss.ss)
-    if let Some(time) = message.get_field(0) {
-        if !time.is_empty() {
-            validate_time(time)?;
+    checks.run(|| {
+        if let Some(time) = message.get_field(0) {
+            if !time.is_empty() {
+                validate_time(time)?;
+            }
         }
-    }
+        Ok(())
+    });
     
     // Field 1: Status (A=active, V=void)
-    if let Some(status) = message.get_field(1) {
-        if !status.is_empty() && status != "A" && status != "V" {
-            return Err(NmeaError::InvalidFieldValue {
-                field: "Status".to_string(),
-                value: status.clone(),
-                reason: "Must be A (active) or V (void)".to_string(),
-            });
+    checks.run(|| {
+        if let Some(status) = message.get_field(1) {
+            if !status.is_empty() && status != "A" && status != "V" {
+                return Err(NmeaError::InvalidFieldValue {
+                    field: "Status".to_string(),
+                    value: status.clone(),
+                    reason: "Must be A (active) or V (void)".to_string(),
+                });
+            }
         }
-    }
+        Ok(())
+    });
     
     // Field 2: Latitude
-    if let Some(lat) = message.get_field(2) {
-        if !lat.is_empty() {
-            validate_latitude(lat)?;
+    checks.run(|| {
+        if let Some(lat) = message.get_field(2) {
+            if !lat.is_empty() {
+                validate_latitude(lat)?;
+            }
         }
-    }
+        Ok(())
+    });
     
     // Field 3: Latitude direction
-    if let Some(lat_dir) = message.get_field(3) {
-        if !lat_dir.is_empty() && lat_dir != "N" && lat_dir != "S" {
-            return Err(NmeaError::InvalidFieldValue {
-                field: "Latitude direction".to_string(),
-                value: lat_dir.clone(),
-                reason: "Must be N or S".to_string(),
-            });
+    checks.run(|| {
+        if let Some(lat_dir) = message.get_field(3) {
+            if !lat_dir.is_empty() && lat_dir != "N" && lat_dir != "S" {
+                return Err(NmeaError::InvalidFieldValue {
+                    field: "Latitude direction".to_string(),
+                    value: lat_dir.clone(),
+                    reason: "Must be N or S".to_string(),
+                });
+            }
         }
-    }
+        Ok(())
+    });
     
     // Field 4: Longitude
-    if let Some(lon) = message.get_field(4) {
-        if !lon.is_empty() {
-            validate_longitude(lon)?;
+    checks.run(|| {
+        if let Some(lon) = message.get_field(4) {
+            if !lon.is_empty() {
+                validate_longitude(lon)?;
+            }
         }
-    }
+        Ok(())
+    });
     
     // Field 5: Longitude direction
-    if let Some(lon_dir) = message.get_field(5) {
-        if !lon_dir.is_empty() && lon_dir != "E" && lon_dir != "W" {
-            return Err(NmeaError::InvalidFieldValue {
-                field: "Longitude direction".to_string(),
-                value: lon_dir.clone(),
-                reason: "Must be E or W".to_string(),
-            });
+    checks.run(|| {
+        if let Some(lon_dir) = message.get_field(5) {
+            if !lon_dir.is_empty() && lon_dir != "E" && lon_dir != "W" {
+                return Err(NmeaError::InvalidFieldValue {
+                    field: "Longitude direction".to_string(),
+                    value: lon_dir.clone(),
+                    reason: "Must be E or W".to_string(),
+                });
+            }
         }
-    }
+        Ok(())
+    });
     
     // Field 6: Speed over ground (knots)
-    if let Some(speed) = message.get_field(6) {
-        if !speed.is_empty() {
-            let s = speed.parse::<f64>()
-                .map_err(|_| NmeaError::InvalidFieldValue {
-                    field: "Speed".to_string(),
-                    value: speed.clone(),
-                    reason: "Must be a number".to_string(),
-                })?;
-            if s < 0.0 || s > 1000.0 {
-                return Err(NmeaError::InvalidFieldValue {
-                    field: "Speed".to_string(),
-                    value: speed.clone(),
-                    reason: "Must be between 0 and 1000 knots".to_string(),
-                });
+    checks.run(|| {
+        if let Some(speed) = message.get_field(6) {
+            if !speed.is_empty() {
+                let s = speed.parse::<f64>()
+                    .map_err(|_| NmeaError::InvalidFieldValue {
+                        field: "Speed".to_string(),
+                        value: speed.clone(),
+                        reason: "Must be a number".to_string(),
+                    })?;
+                if !(0.0..=1000.0).contains(&s) {
+                    return Err(NmeaError::InvalidFieldValue {
+                        field: "Speed".to_string(),
+                        value: speed.clone(),
+                        reason: "Must be between 0 and 1000 knots".to_string(),
+                    });
+                }
             }
         }
-    }
+        Ok(())
+    });
     
     // Field 7: Course over ground (degrees)
-    if let Some(course) = message.get_field(7) {
-        if !course.is_empty() {
-            let c = course.parse::<f64>()
-                .map_err(|_| NmeaError::InvalidFieldValue {
-                    field: "Course".to_string(),
-                    value: course.clone(),
-                    reason: "Must be a number".to_string(),
-                })?;
-            if c < 0.0 || c >= 360.0 {
-                return Err(NmeaError::InvalidFieldValue {
-                    field: "Course".to_string(),
-                    value: course.clone(),
-                    reason: "Must be between 0 and 360 degrees".to_string(),
-                });
+    checks.run(|| {
+        if let Some(course) = message.get_field(7) {
+            if !course.is_empty() {
+                let c = course.parse::<f64>()
+                    .map_err(|_| NmeaError::InvalidFieldValue {
+                        field: "Course".to_string(),
+                        value: course.clone(),
+                        reason: "Must be a number".to_string(),
+                    })?;
+                if !(0.0..360.0).contains(&c) {
+                    return Err(NmeaError::InvalidFieldValue {
+                        field: "Course".to_string(),
+                        value: course.clone(),
+                        reason: "Must be between 0 and 360 degrees".to_string(),
+                    });
+                }
             }
         }
-    }
+        Ok(())
+    });
     
     // Field 8: Date (ddmmyy)
-    if let Some(date) = message.get_field(8) {
-        if !date.is_empty() {
-            validate_date_nmea(date)?;
+    checks.run(|| {
+        if let Some(date) = message.get_field(8) {
+            if !date.is_empty() {
+                validate_date_nmea(date)?;
+            }
         }
-    }
-    
-    Ok(())
+        Ok(())
+    });
 }
 
 /// Valide les autres types de messages (structure de base)
-fn validate_gsa(_message: &NmeaMessage) -> Result<(), NmeaError> {
+fn check_gsa(_message: &NmeaMessage, _checks: &mut Checks<NmeaError>) {
     // Validation de base pour GPGSA
-    Ok(())
 }
 
-fn validate_gsv(_message: &NmeaMessage) -> Result<(), NmeaError> {
-    // Validation de base pour GPGSV
-    Ok(())
+fn check_vtg(_message: &NmeaMessage, _checks: &mut Checks<NmeaError>) {
+    // Validation de base pour GPVTG
 }
 
-fn validate_vtg(_message: &NmeaMessage) -> Result<(), NmeaError> {
-    // Validation de base pour GPVTG
-    Ok(())
+/// Valide un message GSV (GNSS Satellites in View)
+/// Format: $GPGSV,t,n,ss,prn,el,az,snr,...[,signal]*hh
+fn check_gsv(message: &NmeaMessage, checks: &mut Checks<NmeaError>) {
+    checks.run(|| {
+        let blocks = message.fields.len().saturating_sub(3);
+        if message.fields.len() < 3 || blocks % 4 > 1 || blocks > 17 {
+            return Err(NmeaError::InvalidFormat(
+                "GSV message must have 3 header fields followed by up to 4 satellite blocks of 4 fields".to_string()
+            ));
+        }
+        Ok(())
+    });
+
+    // Fields 0-1: Total number of messages (1-9) and message number (1-total)
+    checks.run(|| {
+        let total = parse_number(message, 0, "GSV total messages")?;
+        let number = parse_number(message, 1, "GSV message number")?;
+        match (total, number) {
+            (Some(total), _) if !(1.0..=9.0).contains(&total) => Err(NmeaError::InvalidFieldValue {
+                field: "GSV total messages".to_string(),
+                value: total.to_string(),
+                reason: "Must be between 1 and 9".to_string(),
+            }),
+            (Some(total), Some(number)) if number < 1.0 || number > total => Err(NmeaError::InvalidFieldValue {
+                field: "GSV message number".to_string(),
+                value: number.to_string(),
+                reason: format!("Must be between 1 and {}", total),
+            }),
+            _ => Ok(()),
+        }
+    });
+
+    check_number(message, checks, 2, "Satellites in view", |v| v >= 0.0, "Must be a positive number");
+
+    // Blocs satellite : PRN, élévation (0-90), azimut (0-359), SNR (0-99)
+    let mut index = 3;
+    while index + 4 <= message.fields.len() {
+        check_number(message, checks, index, "Satellite PRN", |v| v >= 1.0, "Must be a positive number");
+        check_number(message, checks, index + 1, "Satellite elevation", |v| (0.0..=90.0).contains(&v), "Must be between 0 and 90 degrees");
+        check_number(message, checks, index + 2, "Satellite azimuth", |v| (0.0..360.0).contains(&v), "Must be between 0 and 359 degrees");
+        check_number(message, checks, index + 3, "Satellite SNR", |v| (0.0..=99.0).contains(&v), "Must be between 0 and 99 dB-Hz");
+        index += 4;
+    }
 }
 
-fn validate_wpl(_message: &NmeaMessage) -> Result<(), NmeaError> {
-    // Validation de base pour GPWPL
-    Ok(())
+/// Valide un message GLL (Geographic Position - Latitude/Longitude)
+/// Format: $GPGLL,llll.ll,a,yyyyy.yy,a,hhmmss.ss,A[,m]*hh
+fn check_gll(message: &NmeaMessage, checks: &mut Checks<NmeaError>) {
+    check_min_fields(message, checks, 6, "GLL");
+    check_position(message, checks, 0);
+    check_time_field(message, checks, 4);
+    check_choice(message, checks, 5, "Status", &["A", "V"]);
+    check_choice(message, checks, 6, "Mode indicator", &["A", "D", "E", "M", "S", "N"]);
 }
 
-fn validate_bod(_message: &NmeaMessage) -> Result<(), NmeaError> {
-    // Validation de base pour GPBOD
-    Ok(())
+/// Valide un message GST (GNSS Pseudorange Error Statistics)
+/// Format: $GPGST,hhmmss.ss,rms,smaj,smin,ori,lat,lon,alt*hh
+fn check_gst(message: &NmeaMessage, checks: &mut Checks<NmeaError>) {
+    check_min_fields(message, checks, 8, "GST");
+    check_time_field(message, checks, 0);
+    for (index, field) in [(1, "RMS"), (2, "Semi-major error"), (3, "Semi-minor error"), (5, "Latitude error"), (6, "Longitude error"), (7, "Altitude error")] {
+        check_number(message, checks, index, field, |v| v >= 0.0, "Must be a positive number");
+    }
+    check_number(message, checks, 4, "Error ellipse orientation", |v| (0.0..=360.0).contains(&v), "Must be between 0 and 360 degrees");
 }
 
-fn validate_xte(_message: &NmeaMessage) -> Result<(), NmeaError> {
-    // Validation de base pour GPXTE
-    Ok(())
+/// Valide un message HDT (Heading - True)
+/// Format: $HEHDT,x.x,T*hh
+fn check_hdt(message: &NmeaMessage, checks: &mut Checks<NmeaError>) {
+    check_min_fields(message, checks, 2, "HDT");
+    check_heading(message, checks, 0, "Heading");
+    check_choice(message, checks, 1, "Heading reference", &["T"]);
 }
 
-fn validate_zda(_message: &NmeaMessage) -> Result<(), NmeaError> {
-    // Validation de base pour GPZDA
-    Ok(())
+/// Valide un message HDG (Heading, Deviation and Variation)
+/// Format: $HCHDG,x.x,x.x,a,x.x,a*hh
+fn check_hdg(message: &NmeaMessage, checks: &mut Checks<NmeaError>) {
+    check_min_fields(message, checks, 5, "HDG");
+    check_heading(message, checks, 0, "Heading");
+    check_number(message, checks, 1, "Magnetic deviation", |v| (0.0..=180.0).contains(&v), "Must be between 0 and 180 degrees");
+    check_choice(message, checks, 2, "Deviation direction", &["E", "W"]);
+    check_number(message, checks, 3, "Magnetic variation", |v| (0.0..=180.0).contains(&v), "Must be between 0 and 180 degrees");
+    check_choice(message, checks, 4, "Variation direction", &["E", "W"]);
+}
+
+/// Valide un message WPL (Waypoint Location)
+/// Format: $GPWPL,llll.ll,a,yyyyy.yy,a,c--c*hh
+fn check_wpl(message: &NmeaMessage, checks: &mut Checks<NmeaError>) {
+    check_min_fields(message, checks, 5, "WPL");
+    check_position(message, checks, 0);
+    checks.run(|| {
+        match message.get_field(4) {
+            Some(id) if !id.is_empty() => Ok(()),
+            _ => Err(NmeaError::MissingField("Waypoint identifier".to_string())),
+        }
+    });
+}
+
+/// Valide un message BOD (Bearing - Waypoint to Waypoint)
+/// Format: $GPBOD,x.x,T,x.x,M,c--c,c--c*hh
+fn check_bod(message: &NmeaMessage, checks: &mut Checks<NmeaError>) {
+    check_min_fields(message, checks, 6, "BOD");
+    check_heading(message, checks, 0, "Bearing (True)");
+    check_choice(message, checks, 1, "Bearing reference", &["T"]);
+    check_heading(message, checks, 2, "Bearing (Magnetic)");
+    check_choice(message, checks, 3, "Bearing reference", &["M"]);
+}
+
+/// Valide un message XTE (Cross-Track Error, Measured)
+/// Format: $GPXTE,A,A,x.x,a,N[,m]*hh
+fn check_xte(message: &NmeaMessage, checks: &mut Checks<NmeaError>) {
+    check_min_fields(message, checks, 5, "XTE");
+    check_choice(message, checks, 0, "Status", &["A", "V"]);
+    check_choice(message, checks, 1, "Cycle lock status", &["A", "V"]);
+    check_number(message, checks, 2, "Cross track error", |v| v >= 0.0, "Must be a positive number");
+    check_choice(message, checks, 3, "Steer direction", &["L", "R"]);
+    check_choice(message, checks, 4, "Cross track units", &["N", "K"]);
+    check_choice(message, checks, 5, "Mode indicator", &["A", "D", "E", "M", "S", "N"]);
+}
+
+/// Valide un message RMB (Recommended Minimum Navigation Information)
+/// Format: $GPRMB,A,x.x,a,c--c,c--c,llll.ll,a,yyyyy.yy,a,x.x,x.x,x.x,A[,m]*hh
+fn check_rmb(message: &NmeaMessage, checks: &mut Checks<NmeaError>) {
+    check_min_fields(message, checks, 13, "RMB");
+    check_choice(message, checks, 0, "Status", &["A", "V"]);
+    check_number(message, checks, 1, "Cross track error", |v| v >= 0.0, "Must be a positive number");
+    check_choice(message, checks, 2, "Steer direction", &["L", "R"]);
+    check_position(message, checks, 5);
+    check_number(message, checks, 9, "Range to destination", |v| v >= 0.0, "Must be a positive number");
+    check_heading(message, checks, 10, "Bearing to destination");
+    check_number(message, checks, 11, "Closing velocity", |_| true, "Must be a number");
+    check_choice(message, checks, 12, "Arrival status", &["A", "V"]);
+    check_choice(message, checks, 13, "Mode indicator", &["A", "D", "E", "M", "S", "N"]);
+}
+
+/// Valide un message ZDA (Time and Date)
+/// Format: $GPZDA,hhmmss.ss,dd,mm,yyyy,zh,zm*hh
+fn check_zda(message: &NmeaMessage, checks: &mut Checks<NmeaError>) {
+    check_min_fields(message, checks, 6, "ZDA");
+    check_time_field(message, checks, 0);
+
+    // Fields 1-3: Day, month, year (date existante, années bissextiles comprises)
+    checks.run(|| {
+        let day = parse_number(message, 1, "Day")?;
+        let month = parse_number(message, 2, "Month")?;
+        let year = parse_number(message, 3, "Year")?;
+        if let Some(month) = month {
+            if !(1.0..=12.0).contains(&month) {
+                return Err(NmeaError::InvalidDate(format!("Month must be 1-12, got: {}", month)));
+            }
+        }
+        if let Some(year) = year {
+            if !(1980.0..=9999.0).contains(&year) {
+                return Err(NmeaError::InvalidDate(format!("Year must have 4 digits from 1980, got: {}", year)));
+            }
+        }
+        if let Some(day) = day {
+            let max = match (month, year) {
+                (Some(month), Some(year)) => days_in_month(year as u16, month as u8) as f64,
+                _ => 31.0,
+            };
+            if day < 1.0 || day > max {
+                return Err(NmeaError::InvalidDate(format!("Day must be 1-{}, got: {}", max, day)));
+            }
+        }
+        Ok(())
+    });
+
+    check_number(message, checks, 4, "Local zone hours", |v| (-13.0..=13.0).contains(&v), "Must be between -13 and 13");
+    check_number(message, checks, 5, "Local zone minutes", |v| (0.0..=59.0).contains(&v), "Must be between 0 and 59");
 }
 
 /// Valide un message AIS (AIVDM ou AIVDO)
-fn validate_ais(message: &NmeaMessage) -> Result<(), NmeaError> {
-    if message.fields.len() < 5 {
-        return Err(NmeaError::InvalidFormat(
-            "AIS message must have at least 5 fields".to_string()
-        ));
-    }
-    
-    // Field 0: Sequence number (1-9)
-    if let Some(seq) = message.get_field(0) {
-        if !seq.is_empty() {
-            let s = seq.parse::<u8>()
-                .map_err(|_| NmeaError::InvalidFieldValue {
-                    field: "Sequence number".to_string(),
-                    value: seq.clone(),
-                    reason: "Must be a number 1-9".to_string(),
-                })?;
-            if s < 1 || s > 9 {
-                return Err(NmeaError::InvalidFieldValue {
-                    field: "Sequence number".to_string(),
-                    value: seq.clone(),
-                    reason: "Must be between 1 and 9".to_string(),
-                });
+fn check_ais(message: &NmeaMessage, checks: &mut Checks<NmeaError>) {
+    checks.run(|| {
+        if message.fields.len() < 5 {
+            return Err(NmeaError::InvalidFormat(
+                "AIS message must have at least 5 fields".to_string()
+            ));
+        }
+        Ok(())
+    });
+    
+    // Field 0: Fragment count (1-9)
+    checks.run(|| {
+        if let Some(count) = message.get_field(0) {
+            if !count.is_empty() {
+                let c = count.parse::<u8>()
+                    .map_err(|_| NmeaError::InvalidFieldValue {
+                        field: "Fragment count".to_string(),
+                        value: count.clone(),
+                        reason: "Must be a number 1-9".to_string(),
+                    })?;
+                if !(1..=9).contains(&c) {
+                    return Err(NmeaError::InvalidFieldValue {
+                        field: "Fragment count".to_string(),
+                        value: count.clone(),
+                        reason: "Must be between 1 and 9".to_string(),
+                    });
+                }
             }
         }
-    }
+        Ok(())
+    });
+    
+    // Field 1: Fragment number (1-fragment count)
+    checks.run(|| {
+        if let Some(frag) = message.get_field(1) {
+            if !frag.is_empty() {
+                let f = frag.parse::<u8>()
+                    .map_err(|_| NmeaError::InvalidFieldValue {
+                        field: "Fragment number".to_string(),
+                        value: frag.clone(),
+                        reason: "Must be a number 1-9".to_string(),
+                    })?;
+                if !(1..=9).contains(&f) {
+                    return Err(NmeaError::InvalidFieldValue {
+                        field: "Fragment number".to_string(),
+                        value: frag.clone(),
+                        reason: "Must be between 1 and 9".to_string(),
+                    });
+                }
+                let count = message.get_field(0).and_then(|c| c.parse::<u8>().ok());
+                if count.is_some_and(|count| f > count) {
+                    return Err(NmeaError::InvalidFieldValue {
+                        field: "Fragment number".to_string(),
+                        value: frag.clone(),
+                        reason: "Must not exceed the fragment count".to_string(),
+                    });
+                }
+            }
+        }
+        Ok(())
+    });
     
-    // Field 1: Fragment number (1-9)
-    if let Some(frag) = message.get_field(1) {
-        if !frag.is_empty() {
-            let f = frag.parse::<u8>()
-                .map_err(|_| NmeaError::InvalidFieldValue {
-                    field: "Fragment number".to_string(),
-                    value: frag.clone(),
-                    reason: "Must be a number 1-9".to_string(),
-                })?;
-            if f < 1 || f > 9 {
+    // Field 2: Sequential message ID (0-9)
+    check_number(message, checks, 2, "Sequential message ID", |v| (0.0..=9.0).contains(&v), "Must be between 0 and 9");
+    
+    // Field 3: Radio channel (A or B)
+    checks.run(|| {
+        if let Some(channel) = message.get_field(3) {
+            if !channel.is_empty() && channel != "A" && channel != "B" {
                 return Err(NmeaError::InvalidFieldValue {
-                    field: "Fragment number".to_string(),
-                    value: frag.clone(),
-                    reason: "Must be between 1 and 9".to_string(),
+                    field: "Radio channel".to_string(),
+                    value: channel.clone(),
+                    reason: "Must be A or B".to_string(),
                 });
             }
         }
-    }
+        Ok(())
+    });
     
-    // Field 3: Radio channel (A or B)
-    if let Some(channel) = message.get_field(3) {
-        if !channel.is_empty() && channel != "A" && channel != "B" {
-            return Err(NmeaError::InvalidFieldValue {
-                field: "Radio channel".to_string(),
-                value: channel.clone(),
-                reason: "Must be A or B".to_string(),
-            });
+    // Field 4: Payload (6-bit ASCII encoded)
+    checks.run(|| {
+        if let Some(payload) = message.get_field(4) {
+            if payload.is_empty() {
+                return Err(NmeaError::InvalidFormat(
+                    "AIS payload cannot be empty".to_string()
+                ));
+            }
+            
+            // Valider que le payload contient uniquement des caractères 6-bit ASCII valides
+            for ch in payload.chars() {
+                let byte = ch as u8;
+                if !(0x30..=0x57).contains(&byte) && !(0x60..=0x77).contains(&byte) {
+                    return Err(NmeaError::InvalidFieldValue {
+                        field: "AIS payload".to_string(),
+                        value: ch.to_string(),
+                        reason: "Must be 6-bit ASCII (0x30-0x57 or 0x60-0x77)".to_string(),
+                    });
+                }
+            }
+        } else {
+            return Err(NmeaError::MissingField("AIS payload".to_string()));
         }
-    }
+        Ok(())
+    });
     
-    // Field 4: Payload (6-bit ASCII encoded)
-    if let Some(payload) = message.get_field(4) {
-        if payload.is_empty() {
+    // Field 5: Fill bits (0-5)
+    checks.run(|| {
+        if let Some(fill) = message.get_field(5) {
+            if !fill.is_empty() {
+                let f = fill.parse::<u8>()
+                    .map_err(|_| NmeaError::InvalidFieldValue {
+                        field: "Fill bits".to_string(),
+                        value: fill.clone(),
+                        reason: "Must be a number 0-5".to_string(),
+                    })?;
+                if f > 5 {
+                    return Err(NmeaError::InvalidFieldValue {
+                        field: "Fill bits".to_string(),
+                        value: fill.clone(),
+                        reason: "Must be between 0 and 5".to_string(),
+                    });
+                }
+            }
+        }
+        Ok(())
+    });
+}
+
+/// Vérifie le nombre minimal de champs d'une phrase
+fn check_min_fields(message: &NmeaMessage, checks: &mut Checks<NmeaError>, min: usize, sentence: &str) {
+    checks.run(|| {
+        if message.fields.len() < min {
             return Err(NmeaError::InvalidFormat(
-                "AIS payload cannot be empty".to_string()
+                format!("{} message must have at least {} fields", sentence, min)
             ));
         }
-        
-        // Valider que le payload contient uniquement des caractères 6-bit ASCII valides
-        for ch in payload.chars() {
-            let byte = ch as u8;
-            if !(byte >= 0x30 && byte <= 0x77) {
+        Ok(())
+    });
+}
+
+/// Lit un champ numérique optionnel (`None` si vide ou absent)
+fn parse_number(message: &NmeaMessage, index: usize, field: &str) -> Result<Option<f64>, NmeaError> {
+    match message.get_field(index) {
+        Some(value) if !value.is_empty() => value.parse::<f64>()
+            .map(Some)
+            .map_err(|_| NmeaError::InvalidFieldValue {
+                field: field.to_string(),
+                value: value.clone(),
+                reason: "Must be a number".to_string(),
+            }),
+        _ => Ok(None),
+    }
+}
+
+/// Vérifie un champ numérique optionnel et sa plage de valeurs
+fn check_number(
+    message: &NmeaMessage,
+    checks: &mut Checks<NmeaError>,
+    index: usize,
+    field: &str,
+    valid: impl Fn(f64) -> bool,
+    reason: &str,
+) {
+    checks.run(|| {
+        match parse_number(message, index, field)? {
+            Some(value) if !valid(value) => Err(NmeaError::InvalidFieldValue {
+                field: field.to_string(),
+                value: message.fields[index].clone(),
+                reason: reason.to_string(),
+            }),
+            _ => Ok(()),
+        }
+    });
+}
+
+/// Vérifie un cap ou un relèvement optionnel (0-360 degrés)
+fn check_heading(message: &NmeaMessage, checks: &mut Checks<NmeaError>, index: usize, field: &str) {
+    check_number(message, checks, index, field, |v| (0.0..360.0).contains(&v), "Must be between 0 and 360 degrees");
+}
+
+/// Vérifie qu'un champ optionnel prend l'une des valeurs autorisées
+fn check_choice(message: &NmeaMessage, checks: &mut Checks<NmeaError>, index: usize, field: &str, allowed: &[&str]) {
+    checks.run(|| {
+        if let Some(value) = message.get_field(index) {
+            if !value.is_empty() && !allowed.contains(&value.as_str()) {
                 return Err(NmeaError::InvalidFieldValue {
-                    field: "AIS payload".to_string(),
-                    value: ch.to_string(),
-                    reason: "Must be 6-bit ASCII (0x30-0x77)".to_string(),
+                    field: field.to_string(),
+                    value: value.clone(),
+                    reason: format!("Must be {}", allowed.join(" or ")),
                 });
             }
         }
-    } else {
-        return Err(NmeaError::MissingField("AIS payload".to_string()));
-    }
-    
-    // Field 5: Fill bits (0-5)
-    if let Some(fill) = message.get_field(5) {
-        if !fill.is_empty() {
-            let f = fill.parse::<u8>()
-                .map_err(|_| NmeaError::InvalidFieldValue {
-                    field: "Fill bits".to_string(),
-                    value: fill.clone(),
-                    reason: "Must be a number 0-5".to_string(),
-                })?;
-            if f > 5 {
-                return Err(NmeaError::InvalidFieldValue {
-                    field: "Fill bits".to_string(),
-                    value: fill.clone(),
-                    reason: "Must be between 0 and 5".to_string(),
-                });
+        Ok(())
+    });
+}
+
+/// Vérifie un champ heure optionnel (hhmmss.ss)
+fn check_time_field(message: &NmeaMessage, checks: &mut Checks<NmeaError>, index: usize) {
+    checks.run(|| {
+        if let Some(time) = message.get_field(index) {
+            if !time.is_empty() {
+                validate_time(time)?;
             }
         }
-    }
-    
-    Ok(())
+        Ok(())
+    });
+}
+
+/// Vérifie une position latitude, N/S, longitude, E/W à partir du champ `index`
+fn check_position(message: &NmeaMessage, checks: &mut Checks<NmeaError>, index: usize) {
+    checks.run(|| {
+        if let Some(lat) = message.get_field(index) {
+            if !lat.is_empty() {
+                validate_latitude(lat)?;
+            }
+        }
+        Ok(())
+    });
+    check_choice(message, checks, index + 1, "Latitude direction", &["N", "S"]);
+    checks.run(|| {
+        if let Some(lon) = message.get_field(index + 2) {
+            if !lon.is_empty() {
+                validate_longitude(lon)?;
+            }
+        }
+        Ok(())
+    });
+    check_choice(message, checks, index + 3, "Longitude direction", &["E", "W"]);
 }
 
 /// Valide un format de temps NMEA (hhmmss.ss)
@@ -1,4 +1,5 @@
 use thiserror::Error;
+use crate::diagnostic::{FieldRef, LocatedError, SyntaxError};
 
 #[derive(Error, Debug, PartialEq)]
 pub enum SbsError {
@@ -6,7 +7,7 @@ pub enum SbsError {
     ParseError(String),
     
     #[error("PEST parse error: {0}")]
-    PestParseError(String),
+    PestParseError(Box<SyntaxError>),
     
     #[error("Invalid message format: {0}")]
     InvalidFormat(String),
@@ -36,3 +37,40 @@ pub enum SbsError {
     InvalidHeading(String),
 }
 
+impl LocatedError for SbsError {
+    fn code(&self) -> &'static str {
+        match self {
+            SbsError::ParseError(_) => "SBS001",
+            SbsError::PestParseError(_) => "SBS002",
+            SbsError::InvalidFormat(_) => "SBS003",
+            SbsError::InvalidMessageType(_) => "SBS004",
+            SbsError::InvalidFieldValue { .. } => "SBS005",
+            SbsError::MissingField(_) => "SBS006",
+            SbsError::InvalidIcaoAddress(_) => "SBS007",
+            SbsError::InvalidTimestamp(_) => "SBS008",
+            SbsError::InvalidAltitude(_) => "SBS009",
+            SbsError::InvalidSpeed(_) => "SBS010",
+            SbsError::InvalidHeading(_) => "SBS011",
+        }
+    }
+
+    fn syntax(&self) -> Option<&SyntaxError> {
+        match self {
+            SbsError::PestParseError(error) => Some(error),
+            _ => None,
+        }
+    }
+
+    fn field(&self) -> Option<FieldRef> {
+        match self {
+            SbsError::InvalidFieldValue { field, .. } | SbsError::MissingField(field) => Some(FieldRef::Name(field.clone())),
+            _ => self.syntax().and_then(|error| error.field.clone()),
+        }
+    }
+}
+
+impl From<SyntaxError> for SbsError {
+    fn from(error: SyntaxError) -> Self {
+        SbsError::PestParseError(Box::new(error))
+    }
+}
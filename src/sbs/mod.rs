@@ -5,15 +5,21 @@
 //!
 //! Format typique:
 //! MSG,type,transmission_type,session_id,aircraft_id,hex_ident,flight_id,date_gen,time_gen,date_log,time_log,callsign,altitude,speed,track,lat,lon,vertical_rate,squawk,alert,emergency,spi,is_on_ground
+//!
+//! Le flux BaseStation contient aussi des enregistrements d'événements (SEL,
+//! ID, AIR, STA, CLK) : `SbsParser::parse_record` retourne un `SbsRecord`
+//! pour n'importe lequel des six types.
 
 pub mod error;
 pub mod message;
 pub mod parser;
+pub mod record;
 pub mod types;
 pub mod validation;
 
 pub use error::SbsError;
 pub use message::SbsMessage;
 pub use parser::SbsParser;
+pub use record::{SbsRecord, SbsRecordHeader, SbsStatus};
 pub use types::SbsMessageType;
 
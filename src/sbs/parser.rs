@@ -8,8 +8,11 @@
 use pest::Parser;
 use pest_derive::Parser;
 use crate::sbs::message::SbsMessage;
+use crate::sbs::record::{SbsRecord, SbsRecordHeader, SbsStatus};
 use crate::sbs::types::SbsMessageType;
 use crate::sbs::error::SbsError;
+use crate::sbs::validation::{check_message, check_record};
+use crate::diagnostic::{Lenient, LocatedError, SyntaxError};
 
 #[derive(Parser)]
 #[grammar = "sbs/sbs.pest"]
@@ -45,7 +48,12 @@ impl SbsParser {
         
         // Parser avec PEST
         let mut pairs = SbsParser::parse(Rule::sbs_message, trimmed)
-            .map_err(|e| SbsError::PestParseError(format!("{}", e)))?;
+            .map_err(|e| {
+                let leading = input.len() - input.trim_start().len();
+                SbsError::from(SyntaxError::from_pest(&e, trimmed, "")
+                    .offset_in(input, leading)
+                    .with_field_index(input, ','))
+            })?;
         
         let message_pair = pairs.next().ok_or_else(|| {
             SbsError::ParseError("Empty parse result".to_string())
@@ -54,6 +62,88 @@ impl SbsParser {
         Self::parse_sbs_pair(message_pair, trimmed)
     }
     
+    /// Parse un message SBS en mode tolérant
+    /// 
+    /// Une erreur de syntaxe empêche de reconstruire le message ; sinon le
+    /// message est retourné avec tous les problèmes de validation relevés.
+    pub fn parse_lenient(input: &str) -> Lenient<SbsMessage> {
+        match Self::parse_message(input) {
+            Ok(message) => {
+                let diagnostics = check_message(&message).iter()
+                    .map(SbsError::to_diagnostic)
+                    .collect();
+                Lenient { message: Some(message), diagnostics }
+            }
+            Err(error) => Lenient { message: None, diagnostics: vec![error.to_diagnostic()] },
+        }
+    }
+    
+    /// Parse un enregistrement SBS de n'importe quel type du flux BaseStation
+    /// (MSG, SEL, ID, AIR, STA, CLK).
+    /// 
+    /// # Exemples
+    /// ```
+    /// use aftn::sbs::{SbsParser, SbsRecord, SbsStatus};
+    /// let input = "STA,,5,179,400AE7,10103,2008/11/28,14:58:51.153,2008/11/28,14:58:51.153,RM";
+    /// let record = SbsParser::parse_record(input).unwrap();
+    /// assert!(matches!(record, SbsRecord::StatusChange { status: Some(SbsStatus::Remove), .. }));
+    /// ```
+    pub fn parse_record(input: &str) -> Result<SbsRecord, SbsError> {
+        let trimmed = input.trim();
+        
+        let mut pairs = SbsParser::parse(Rule::sbs_record, trimmed)
+            .map_err(|e| {
+                let leading = input.len() - input.trim_start().len();
+                SbsError::from(SyntaxError::from_pest(&e, trimmed, "")
+                    .offset_in(input, leading)
+                    .with_field_index(input, ','))
+            })?;
+        
+        let record_pair = pairs.next()
+            .and_then(|pair| pair.into_inner().next())
+            .ok_or_else(|| SbsError::ParseError("Empty parse result".to_string()))?;
+        
+        match record_pair.as_rule() {
+            Rule::sbs_message => Ok(SbsRecord::Message(Self::parse_sbs_pair(record_pair, trimmed)?)),
+            _ => Self::parse_event_record(trimmed),
+        }
+    }
+    
+    /// Parse un enregistrement SBS en mode tolérant, tous les problèmes de
+    /// validation relevés
+    pub fn parse_record_lenient(input: &str) -> Lenient<SbsRecord> {
+        match Self::parse_record(input) {
+            Ok(record) => {
+                let diagnostics = check_record(&record).iter()
+                    .map(SbsError::to_diagnostic)
+                    .collect();
+                Lenient { message: Some(record), diagnostics }
+            }
+            Err(error) => Lenient { message: None, diagnostics: vec![error.to_diagnostic()] },
+        }
+    }
+    
+    /// Extrait un enregistrement SEL, ID, AIR, STA ou CLK (structure validée
+    /// par PEST)
+    /// Format: TYPE,,session_id,aircraft_id,hex_ident,flight_id,date_gen,time_gen,date_log,time_log[,callsign|status]
+    fn parse_event_record(raw: &str) -> Result<SbsRecord, SbsError> {
+        let fields: Vec<&str> = raw.split(',').collect();
+        let header = SbsRecordHeader::from_fields(&fields);
+        let last = fields.get(10).filter(|value| !value.is_empty()).map(|value| value.to_string());
+        
+        // PANIC: fields[0] existe toujours, split retourne au moins un élément
+        Ok(match fields[0] {
+            "SEL" => SbsRecord::Selection { header, callsign: last },
+            "ID" => SbsRecord::NewId { header, callsign: last },
+            "AIR" => SbsRecord::NewAircraft { header },
+            "STA" => SbsRecord::StatusChange { header, status: last.as_deref().map(SbsStatus::from_code) },
+            "CLK" => SbsRecord::Clock { header },
+            other => {
+                return Err(SbsError::InvalidMessageType(format!("Unknown record type: {}", other)));
+            }
+        })
+    }
+    
     /// Parse une paire PEST en SbsMessage
     /// PEST valide la structure de base (MSG,type,...), puis on extrait les champs manuellement
     fn parse_sbs_pair(pair: pest::iterators::Pair<Rule>, raw: &str) -> Result<SbsMessage, SbsError> {
@@ -0,0 +1,213 @@
+//! Enregistrements SBS (BaseStation, port 30003)
+//!
+//! Outre les transmissions `MSG`, le flux BaseStation contient des
+//! enregistrements d'événements qui partagent l'en-tête des messages (session,
+//! aéronef, adresse ICAO, vol, dates et heures) :
+//!
+//! - `SEL` : sélection d'un aéronef, avec son indicatif
+//! - `ID` : nouvel indicatif pour un aéronef
+//! - `AIR` : nouvel aéronef détecté
+//! - `STA` : changement d'état (`PL`, `SL`, `RM`, `AD`, `OK`)
+//! - `CLK` : top d'horloge
+//!
+//! Format: TYPE,,session_id,aircraft_id,hex_ident,flight_id,date_gen,time_gen,date_log,time_log[,callsign|status]
+
+use serde::{Deserialize, Serialize};
+use crate::sbs::message::SbsMessage;
+
+/// En-tête commun des enregistrements SEL, ID, AIR, STA et CLK
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SbsRecordHeader {
+    /// Session ID
+    pub session_id: Option<String>,
+    /// Aircraft ID (numéro attribué par BaseStation)
+    pub aircraft_id: Option<String>,
+    /// Hex Ident (adresse ICAO 24-bit)
+    pub hex_ident: Option<String>,
+    /// Flight ID (identifiant de vol)
+    pub flight_id: Option<String>,
+    /// Date message généré
+    pub date_message_generated: Option<String>,
+    /// Time message généré
+    pub time_message_generated: Option<String>,
+    /// Date message logged
+    pub date_message_logged: Option<String>,
+    /// Time message logged
+    pub time_message_logged: Option<String>,
+}
+
+impl SbsRecordHeader {
+    /// Extrait l'en-tête des champs d'un enregistrement (index 2 à 9)
+    pub(crate) fn from_fields(fields: &[&str]) -> Self {
+        let field = |index: usize| fields.get(index).filter(|value| !value.is_empty()).map(|value| value.to_string());
+        SbsRecordHeader {
+            session_id: field(2),
+            aircraft_id: field(3),
+            hex_ident: field(4),
+            flight_id: field(5),
+            date_message_generated: field(6),
+            time_message_generated: field(7),
+            date_message_logged: field(8),
+            time_message_logged: field(9),
+        }
+    }
+
+    /// Champs de l'en-tête, dans l'ordre de la ligne
+    fn fields(&self) -> [&Option<String>; 8] {
+        [
+            &self.session_id,
+            &self.aircraft_id,
+            &self.hex_ident,
+            &self.flight_id,
+            &self.date_message_generated,
+            &self.time_message_generated,
+            &self.date_message_logged,
+            &self.time_message_logged,
+        ]
+    }
+}
+
+/// État signalé par un enregistrement STA
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SbsStatus {
+    /// PL : position perdue
+    PositionLost,
+    /// SL : signal perdu
+    SignalLost,
+    /// RM : aéronef retiré
+    Remove,
+    /// AD : aéronef supprimé
+    Delete,
+    /// OK : aéronef de nouveau couvert (réinitialise les délais)
+    Ok,
+    /// Code non reconnu
+    Other(String),
+}
+
+impl SbsStatus {
+    /// État correspondant à un code STA
+    pub fn from_code(code: &str) -> Self {
+        match code {
+            "PL" => SbsStatus::PositionLost,
+            "SL" => SbsStatus::SignalLost,
+            "RM" => SbsStatus::Remove,
+            "AD" => SbsStatus::Delete,
+            "OK" => SbsStatus::Ok,
+            _ => SbsStatus::Other(code.to_string()),
+        }
+    }
+
+    /// Code de l'état
+    pub fn code(&self) -> &str {
+        match self {
+            SbsStatus::PositionLost => "PL",
+            SbsStatus::SignalLost => "SL",
+            SbsStatus::Remove => "RM",
+            SbsStatus::Delete => "AD",
+            SbsStatus::Ok => "OK",
+            SbsStatus::Other(code) => code,
+        }
+    }
+}
+
+/// Enregistrement SBS de l'un des six types du flux BaseStation
+///
+/// En JSON, le type d'enregistrement est porté par `record_type` à côté des
+/// champs : un MSG garde la forme d'un `SbsMessage`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "record_type")]
+pub enum SbsRecord {
+    /// MSG : transmission Mode-S/ADS-B
+    #[serde(rename = "MSG")]
+    Message(SbsMessage),
+    /// SEL : sélection d'un aéronef
+    #[serde(rename = "SEL")]
+    Selection {
+        header: SbsRecordHeader,
+        callsign: Option<String>,
+    },
+    /// ID : nouvel indicatif
+    #[serde(rename = "ID")]
+    NewId {
+        header: SbsRecordHeader,
+        callsign: Option<String>,
+    },
+    /// AIR : nouvel aéronef
+    #[serde(rename = "AIR")]
+    NewAircraft {
+        header: SbsRecordHeader,
+    },
+    /// STA : changement d'état
+    #[serde(rename = "STA")]
+    StatusChange {
+        header: SbsRecordHeader,
+        status: Option<SbsStatus>,
+    },
+    /// CLK : top d'horloge
+    #[serde(rename = "CLK")]
+    Clock {
+        header: SbsRecordHeader,
+    },
+}
+
+impl SbsRecord {
+    /// Type de l'enregistrement (`MSG`, `SEL`, `ID`, `AIR`, `STA`, `CLK`)
+    pub fn record_type(&self) -> &'static str {
+        match self {
+            SbsRecord::Message(_) => "MSG",
+            SbsRecord::Selection { .. } => "SEL",
+            SbsRecord::NewId { .. } => "ID",
+            SbsRecord::NewAircraft { .. } => "AIR",
+            SbsRecord::StatusChange { .. } => "STA",
+            SbsRecord::Clock { .. } => "CLK",
+        }
+    }
+
+    /// En-tête d'un enregistrement autre que MSG
+    pub fn header(&self) -> Option<&SbsRecordHeader> {
+        match self {
+            SbsRecord::Message(_) => None,
+            SbsRecord::Selection { header, .. }
+            | SbsRecord::NewId { header, .. }
+            | SbsRecord::NewAircraft { header }
+            | SbsRecord::StatusChange { header, .. }
+            | SbsRecord::Clock { header } => Some(header),
+        }
+    }
+
+    /// Adresse ICAO de l'aéronef concerné
+    pub fn hex_ident(&self) -> Option<&str> {
+        match self {
+            SbsRecord::Message(message) => message.hex_ident.as_deref(),
+            _ => self.header().and_then(|header| header.hex_ident.as_deref()),
+        }
+    }
+
+    /// Valide l'enregistrement (premier problème relevé)
+    pub fn validate(&self) -> Result<(), crate::sbs::error::SbsError> {
+        crate::sbs::validation::validate_record(self)
+    }
+
+    /// Sérialise l'enregistrement au format BaseStation
+    ///
+    /// Les enregistrements MSG sont sérialisés par `SbsMessage::serialize` ;
+    /// les autres laissent vide le type de transmission.
+    pub fn serialize(&self) -> String {
+        let header = match self {
+            SbsRecord::Message(message) => return message.serialize(),
+            _ => self.header().expect("non-MSG records have a header"),
+        };
+        let mut fields = vec![self.record_type().to_string(), String::new()];
+        fields.extend(header.fields().into_iter().map(|field| field.clone().unwrap_or_default()));
+        match self {
+            SbsRecord::Selection { callsign, .. } | SbsRecord::NewId { callsign, .. } => {
+                fields.push(callsign.clone().unwrap_or_default());
+            }
+            SbsRecord::StatusChange { status, .. } => {
+                fields.push(status.as_ref().map(|status| status.code().to_string()).unwrap_or_default());
+            }
+            _ => {}
+        }
+        fields.join(",")
+    }
+}
@@ -1,228 +1,343 @@
 //! Validation sémantique des messages SBS (Mode-S/ADS-B)
 
 use crate::sbs::message::SbsMessage;
+use crate::sbs::record::{SbsRecord, SbsRecordHeader, SbsStatus};
 use crate::sbs::error::SbsError;
+use crate::diagnostic::Checks;
 
-/// Valide un message SBS selon son type
+/// Valide un message SBS selon son type (premier problème relevé)
 pub fn validate_message(message: &SbsMessage) -> Result<(), SbsError> {
+    let mut checks = Checks::new();
+    run_checks(message, &mut checks);
+    checks.first()
+}
+
+/// Contrôle un message SBS et retourne tous les problèmes relevés
+pub fn check_message(message: &SbsMessage) -> Vec<SbsError> {
+    let mut checks = Checks::new();
+    run_checks(message, &mut checks);
+    checks.0
+}
+
+/// Valide un enregistrement SBS de n'importe quel type (premier problème relevé)
+pub fn validate_record(record: &SbsRecord) -> Result<(), SbsError> {
+    let mut checks = Checks::new();
+    run_record_checks(record, &mut checks);
+    checks.first()
+}
+
+/// Contrôle un enregistrement SBS et retourne tous les problèmes relevés
+pub fn check_record(record: &SbsRecord) -> Vec<SbsError> {
+    let mut checks = Checks::new();
+    run_record_checks(record, &mut checks);
+    checks.0
+}
+
+fn run_record_checks(record: &SbsRecord, checks: &mut Checks<SbsError>) {
+    match record {
+        SbsRecord::Message(message) => run_checks(message, checks),
+        SbsRecord::Selection { header, .. } | SbsRecord::NewAircraft { header } => {
+            check_aircraft(record.record_type(), header, checks);
+        }
+        SbsRecord::NewId { header, callsign } => {
+            check_aircraft(record.record_type(), header, checks);
+            checks.run(|| {
+                if callsign.is_none() {
+                    return Err(SbsError::MissingField("ID must have callsign".to_string()));
+                }
+                Ok(())
+            });
+        }
+        SbsRecord::StatusChange { header, status } => {
+            check_aircraft(record.record_type(), header, checks);
+            checks.run(|| match status {
+                None => Err(SbsError::MissingField("STA must have status".to_string())),
+                Some(SbsStatus::Other(code)) => Err(SbsError::InvalidFieldValue {
+                    field: "Status".to_string(),
+                    value: code.clone(),
+                    reason: "Must be one of PL, SL, RM, AD, OK".to_string(),
+                }),
+                Some(_) => Ok(()),
+            });
+        }
+        SbsRecord::Clock { header } => {
+            // Le top d'horloge ne concerne aucun aéronef mais doit être daté
+            checks.run(|| {
+                if header.time_message_generated.is_none() && header.time_message_logged.is_none() {
+                    return Err(SbsError::MissingField("CLK must have a time".to_string()));
+                }
+                Ok(())
+            });
+        }
+    }
+}
+
+/// Valide l'aéronef d'un enregistrement SEL, ID, AIR ou STA
+fn check_aircraft(record_type: &str, header: &SbsRecordHeader, checks: &mut Checks<SbsError>) {
+    checks.run(|| match header.hex_ident {
+        Some(ref hex_ident) => validate_icao_address(hex_ident),
+        None => Err(SbsError::MissingField(format!("{} must have hex_ident", record_type))),
+    });
+}
+
+fn run_checks(message: &SbsMessage, checks: &mut Checks<SbsError>) {
     match message.message_type {
-        crate::sbs::types::SbsMessageType::Identification => validate_identification(message),
-        crate::sbs::types::SbsMessageType::SurfacePosition => validate_surface_position(message),
-        crate::sbs::types::SbsMessageType::AirbornePosition => validate_airborne_position(message),
-        crate::sbs::types::SbsMessageType::AirborneVelocity => validate_airborne_velocity(message),
-        crate::sbs::types::SbsMessageType::SurveillanceAltitude => validate_surveillance_altitude(message),
-        crate::sbs::types::SbsMessageType::SurveillanceId => validate_surveillance_id(message),
-        crate::sbs::types::SbsMessageType::AirToAir => validate_air_to_air(message),
-        crate::sbs::types::SbsMessageType::AllCallReply => validate_all_call_reply(message),
-        _ => Ok(()), // Pour les types génériques, validation de base
+        crate::sbs::types::SbsMessageType::Identification => check_identification(message, checks),
+        crate::sbs::types::SbsMessageType::SurfacePosition => check_surface_position(message, checks),
+        crate::sbs::types::SbsMessageType::AirbornePosition => check_airborne_position(message, checks),
+        crate::sbs::types::SbsMessageType::AirborneVelocity => check_airborne_velocity(message, checks),
+        crate::sbs::types::SbsMessageType::SurveillanceAltitude => check_surveillance_altitude(message, checks),
+        crate::sbs::types::SbsMessageType::SurveillanceId => check_surveillance_id(message, checks),
+        crate::sbs::types::SbsMessageType::AirToAir => check_air_to_air(message, checks),
+        crate::sbs::types::SbsMessageType::AllCallReply => check_all_call_reply(message, checks),
+        _ => {} // Pour les types génériques, validation de base
     }
 }
 
 /// Valide un message MSG,1 (Identification)
-fn validate_identification(message: &SbsMessage) -> Result<(), SbsError> {
+fn check_identification(message: &SbsMessage, checks: &mut Checks<SbsError>) {
     // MSG,1 devrait avoir un callsign ou un hex_ident, mais ce n'est pas strictement requis
     // Valider l'adresse ICAO si présente
-    if let Some(ref hex_ident) = message.hex_ident {
-        validate_icao_address(hex_ident)?;
-    }
-    
-    Ok(())
+    checks.run(|| {
+        if let Some(ref hex_ident) = message.hex_ident {
+            validate_icao_address(hex_ident)?;
+        }
+        Ok(())
+    });
 }
 
 /// Valide un message MSG,2 (Surface Position)
-fn validate_surface_position(message: &SbsMessage) -> Result<(), SbsError> {
+fn check_surface_position(message: &SbsMessage, checks: &mut Checks<SbsError>) {
     // MSG,2 doit avoir une position (latitude/longitude) ou altitude
-    if message.latitude.is_none() && message.longitude.is_none() && message.altitude.is_none() {
-        return Err(SbsError::MissingField(
-            "MSG,2 must have position or altitude".to_string()
-        ));
-    }
+    checks.run(|| {
+        if message.latitude.is_none() && message.longitude.is_none() && message.altitude.is_none() {
+            return Err(SbsError::MissingField(
+                "MSG,2 must have position or altitude".to_string()
+            ));
+        }
+        Ok(())
+    });
     
     // Valider les coordonnées si présentes
-    if let Some(lat) = message.latitude {
-        if lat < -90.0 || lat > 90.0 {
-            return Err(SbsError::InvalidFieldValue {
-                field: "Latitude".to_string(),
-                value: lat.to_string(),
-                reason: "Must be between -90 and 90 degrees".to_string(),
-            });
+    checks.run(|| {
+        if let Some(lat) = message.latitude {
+            if !(-90.0..=90.0).contains(&lat) {
+                return Err(SbsError::InvalidFieldValue {
+                    field: "Latitude".to_string(),
+                    value: lat.to_string(),
+                    reason: "Must be between -90 and 90 degrees".to_string(),
+                });
+            }
         }
-    }
+        Ok(())
+    });
     
-    if let Some(lon) = message.longitude {
-        if lon < -180.0 || lon > 180.0 {
-            return Err(SbsError::InvalidFieldValue {
-                field: "Longitude".to_string(),
-                value: lon.to_string(),
-                reason: "Must be between -180 and 180 degrees".to_string(),
-            });
+    checks.run(|| {
+        if let Some(lon) = message.longitude {
+            if !(-180.0..=180.0).contains(&lon) {
+                return Err(SbsError::InvalidFieldValue {
+                    field: "Longitude".to_string(),
+                    value: lon.to_string(),
+                    reason: "Must be between -180 and 180 degrees".to_string(),
+                });
+            }
         }
-    }
+        Ok(())
+    });
     
     // Valider l'altitude si présente
-    if let Some(alt) = message.altitude {
-        if alt < -1000 || alt > 100000 {
-            return Err(SbsError::InvalidAltitude(
-                format!("Altitude must be between -1000 and 100000 feet, got: {}", alt)
-            ));
+    checks.run(|| {
+        if let Some(alt) = message.altitude {
+            if !(-1000..=100000).contains(&alt) {
+                return Err(SbsError::InvalidAltitude(
+                    format!("Altitude must be between -1000 and 100000 feet, got: {}", alt)
+                ));
+            }
         }
-    }
-    
-    Ok(())
+        Ok(())
+    });
 }
 
 /// Valide un message MSG,3 (Airborne Position)
-fn validate_airborne_position(message: &SbsMessage) -> Result<(), SbsError> {
+fn check_airborne_position(message: &SbsMessage, checks: &mut Checks<SbsError>) {
     // MSG,3 devrait avoir une altitude, mais ce n'est pas toujours présent
     // Valider l'altitude si présente
-    if let Some(alt) = message.altitude {
-        if alt < -1000 || alt > 100000 {
-            return Err(SbsError::InvalidAltitude(
-                format!("Altitude must be between -1000 and 100000 feet, got: {}", alt)
-            ));
+    checks.run(|| {
+        if let Some(alt) = message.altitude {
+            if !(-1000..=100000).contains(&alt) {
+                return Err(SbsError::InvalidAltitude(
+                    format!("Altitude must be between -1000 and 100000 feet, got: {}", alt)
+                ));
+            }
         }
-    }
+        Ok(())
+    });
     
     // Valider les coordonnées si présentes
-    if let Some(lat) = message.latitude {
-        if lat < -90.0 || lat > 90.0 {
-            return Err(SbsError::InvalidFieldValue {
-                field: "Latitude".to_string(),
-                value: lat.to_string(),
-                reason: "Must be between -90 and 90 degrees".to_string(),
-            });
+    checks.run(|| {
+        if let Some(lat) = message.latitude {
+            if !(-90.0..=90.0).contains(&lat) {
+                return Err(SbsError::InvalidFieldValue {
+                    field: "Latitude".to_string(),
+                    value: lat.to_string(),
+                    reason: "Must be between -90 and 90 degrees".to_string(),
+                });
+            }
         }
-    }
+        Ok(())
+    });
     
-    if let Some(lon) = message.longitude {
-        if lon < -180.0 || lon > 180.0 {
-            return Err(SbsError::InvalidFieldValue {
-                field: "Longitude".to_string(),
-                value: lon.to_string(),
-                reason: "Must be between -180 and 180 degrees".to_string(),
-            });
+    checks.run(|| {
+        if let Some(lon) = message.longitude {
+            if !(-180.0..=180.0).contains(&lon) {
+                return Err(SbsError::InvalidFieldValue {
+                    field: "Longitude".to_string(),
+                    value: lon.to_string(),
+                    reason: "Must be between -180 and 180 degrees".to_string(),
+                });
+            }
         }
-    }
-    
-    Ok(())
+        Ok(())
+    });
 }
 
 /// Valide un message MSG,4 (Airborne Velocity)
-fn validate_airborne_velocity(message: &SbsMessage) -> Result<(), SbsError> {
+fn check_airborne_velocity(message: &SbsMessage, checks: &mut Checks<SbsError>) {
     // MSG,4 doit avoir une vitesse ou un cap
-    if message.ground_speed.is_none() && message.track.is_none() {
-        return Err(SbsError::MissingField(
-            "MSG,4 must have ground speed or track".to_string()
-        ));
-    }
+    checks.run(|| {
+        if message.ground_speed.is_none() && message.track.is_none() {
+            return Err(SbsError::MissingField(
+                "MSG,4 must have ground speed or track".to_string()
+            ));
+        }
+        Ok(())
+    });
     
     // Valider la vitesse si présente
-    if let Some(speed) = message.ground_speed {
-        if speed < 0.0 || speed > 2000.0 {
-            return Err(SbsError::InvalidSpeed(
-                format!("Ground speed must be between 0 and 2000 knots, got: {}", speed)
-            ));
+    checks.run(|| {
+        if let Some(speed) = message.ground_speed {
+            if !(0.0..=2000.0).contains(&speed) {
+                return Err(SbsError::InvalidSpeed(
+                    format!("Ground speed must be between 0 and 2000 knots, got: {}", speed)
+                ));
+            }
         }
-    }
+        Ok(())
+    });
     
     // Valider le cap si présent
-    if let Some(track) = message.track {
-        if track < 0.0 || track >= 360.0 {
-            return Err(SbsError::InvalidHeading(
-                format!("Track must be between 0 and 360 degrees, got: {}", track)
-            ));
+    checks.run(|| {
+        if let Some(track) = message.track {
+            if !(0.0..360.0).contains(&track) {
+                return Err(SbsError::InvalidHeading(
+                    format!("Track must be between 0 and 360 degrees, got: {}", track)
+                ));
+            }
         }
-    }
+        Ok(())
+    });
     
     // Valider le taux de montée/descente si présent
-    if let Some(vr) = message.vertical_rate {
-        if vr < -10000 || vr > 10000 {
-            return Err(SbsError::InvalidFieldValue {
-                field: "Vertical rate".to_string(),
-                value: vr.to_string(),
-                reason: "Must be between -10000 and 10000 ft/min".to_string(),
-            });
+    checks.run(|| {
+        if let Some(vr) = message.vertical_rate {
+            if !(-10000..=10000).contains(&vr) {
+                return Err(SbsError::InvalidFieldValue {
+                    field: "Vertical rate".to_string(),
+                    value: vr.to_string(),
+                    reason: "Must be between -10000 and 10000 ft/min".to_string(),
+                });
+            }
         }
-    }
-    
-    Ok(())
+        Ok(())
+    });
 }
 
 /// Valide un message MSG,5 (Surveillance Altitude)
-fn validate_surveillance_altitude(message: &SbsMessage) -> Result<(), SbsError> {
+fn check_surveillance_altitude(message: &SbsMessage, checks: &mut Checks<SbsError>) {
     // MSG,5 doit avoir une altitude
-    if message.altitude.is_none() {
-        return Err(SbsError::MissingField(
-            "MSG,5 must have altitude".to_string()
-        ));
-    }
-    
-    // Valider l'altitude
-    if let Some(alt) = message.altitude {
-        if alt < -1000 || alt > 100000 {
-            return Err(SbsError::InvalidAltitude(
-                format!("Altitude must be between -1000 and 100000 feet, got: {}", alt)
+    checks.run(|| {
+        if message.altitude.is_none() {
+            return Err(SbsError::MissingField(
+                "MSG,5 must have altitude".to_string()
             ));
         }
-    }
+        Ok(())
+    });
     
-    Ok(())
+    // Valider l'altitude
+    checks.run(|| {
+        if let Some(alt) = message.altitude {
+            if !(-1000..=100000).contains(&alt) {
+                return Err(SbsError::InvalidAltitude(
+                    format!("Altitude must be between -1000 and 100000 feet, got: {}", alt)
+                ));
+            }
+        }
+        Ok(())
+    });
 }
 
 /// Valide un message MSG,6 (Surveillance ID)
-fn validate_surveillance_id(message: &SbsMessage) -> Result<(), SbsError> {
+fn check_surveillance_id(message: &SbsMessage, checks: &mut Checks<SbsError>) {
     // MSG,6 doit avoir un squawk
-    if message.squawk.is_none() {
-        return Err(SbsError::MissingField(
-            "MSG,6 must have squawk".to_string()
-        ));
-    }
+    checks.run(|| {
+        if message.squawk.is_none() {
+            return Err(SbsError::MissingField(
+                "MSG,6 must have squawk".to_string()
+            ));
+        }
+        Ok(())
+    });
     
     // Valider le squawk (code transpondeur, 4 chiffres octaux)
-    if let Some(ref squawk) = message.squawk {
-        if squawk.len() != 4 {
-            return Err(SbsError::InvalidFieldValue {
-                field: "Squawk".to_string(),
-                value: squawk.clone(),
-                reason: "Must be 4 digits".to_string(),
-            });
-        }
-        
-        for ch in squawk.chars() {
-            if !ch.is_ascii_digit() || ch > '7' {
+    checks.run(|| {
+        if let Some(ref squawk) = message.squawk {
+            if squawk.len() != 4 {
                 return Err(SbsError::InvalidFieldValue {
                     field: "Squawk".to_string(),
                     value: squawk.clone(),
-                    reason: "Must contain only octal digits (0-7)".to_string(),
+                    reason: "Must be 4 digits".to_string(),
                 });
             }
+            
+            for ch in squawk.chars() {
+                if !ch.is_ascii_digit() || ch > '7' {
+                    return Err(SbsError::InvalidFieldValue {
+                        field: "Squawk".to_string(),
+                        value: squawk.clone(),
+                        reason: "Must contain only octal digits (0-7)".to_string(),
+                    });
+                }
+            }
         }
-    }
-    
-    Ok(())
+        Ok(())
+    });
 }
 
 /// Valide un message MSG,7 (Air to Air)
-fn validate_air_to_air(_message: &SbsMessage) -> Result<(), SbsError> {
+fn check_air_to_air(_message: &SbsMessage, _checks: &mut Checks<SbsError>) {
     // Validation de base pour MSG,7
-    Ok(())
 }
 
 /// Valide un message MSG,8 (All Call Reply)
-fn validate_all_call_reply(message: &SbsMessage) -> Result<(), SbsError> {
+fn check_all_call_reply(message: &SbsMessage, checks: &mut Checks<SbsError>) {
     // MSG,8 doit avoir un hex_ident
-    if message.hex_ident.is_none() {
-        return Err(SbsError::MissingField(
-            "MSG,8 must have hex_ident".to_string()
-        ));
-    }
+    checks.run(|| {
+        if message.hex_ident.is_none() {
+            return Err(SbsError::MissingField(
+                "MSG,8 must have hex_ident".to_string()
+            ));
+        }
+        Ok(())
+    });
     
     // Valider l'adresse ICAO
-    if let Some(ref hex_ident) = message.hex_ident {
-        validate_icao_address(hex_ident)?;
-    }
-    
-    Ok(())
+    checks.run(|| {
+        if let Some(ref hex_ident) = message.hex_ident {
+            validate_icao_address(hex_ident)?;
+        }
+        Ok(())
+    });
 }
 
 /// Valide une adresse ICAO 24-bit (format hexadécimal)
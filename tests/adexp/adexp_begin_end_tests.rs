@@ -128,3 +128,104 @@ fn test_parse_multiple_begin_end_blocks() {
     assert_eq!(pt_values.len(), 2);
 }
 
+
+#[test]
+fn test_parse_begin_end_negative_coordinates() {
+    let input = "-ADEXP
+-TITLE FPL
+-ARCID ABC123
+-BEGIN RTEPTS
+-PT -PTID WPT1 -LAT -12.5 -LON -45.25
+-END RTEPTS
+";
+    
+    let message = AdexpParser::parse_message(input).expect("Should parse successfully");
+    let rtepts = message.get_section("RTEPTS").expect("RTEPTS section should exist");
+    
+    assert_eq!(rtepts.fields.get("PT").unwrap()[0], "");
+    assert_eq!(rtepts.fields.get("PTID").unwrap()[0], "WPT1");
+    assert_eq!(rtepts.fields.get("LAT").unwrap()[0], "-12.5");
+    assert_eq!(rtepts.fields.get("LON").unwrap()[0], "-45.25");
+}
+
+#[test]
+fn test_begin_end_elements_keep_their_subfields() {
+    use aftn::adexp::AdexpItem;
+    
+    let input = "-ADEXP
+-TITLE FPL
+-ARCID ABC123
+-BEGIN RTEPTS
+-PT -PTID LFPG
+-PT -PTID PIROT -FL F350
+-PT -PTID LFPB
+-END RTEPTS
+";
+    
+    let message = AdexpParser::parse_message(input).expect("Should parse successfully");
+    let rtepts = message.find_list("RTEPTS").expect("RTEPTS list should exist");
+    let points: Vec<_> = rtepts.fields().collect();
+    
+    assert_eq!(points.len(), 3);
+    assert_eq!(points[1].subfield("PTID").unwrap().value, "PIROT");
+    assert_eq!(points[1].subfield("FL").unwrap().value, "F350");
+    assert!(points[0].subfield("FL").is_none());
+    assert!(matches!(message.items[2], AdexpItem::List(_)));
+    
+    // Le FL appartient au point qui le porte, pas au premier point
+    let route_points = message.route_points().expect("Route points should decode");
+    assert_eq!(route_points[0].fl, None);
+    assert_eq!(route_points[1].fl, Some(350));
+    assert_eq!(route_points[2].name(), Some("LFPB"));
+}
+
+#[test]
+fn test_parse_nested_begin_end_lists() {
+    let input = "-ADEXP
+-TITLE FPL
+-ARCID ABC123
+-BEGIN RTEPTS
+-PT -PTID LFPG
+-BEGIN ADDR
+-FAC LFPGZQZX
+-END ADDR
+-PT -PTID LFPB
+-END RTEPTS
+-ADEP LFPG
+";
+    
+    let message = AdexpParser::parse_message(input).expect("Should parse successfully");
+    let rtepts = message.find_list("RTEPTS").expect("RTEPTS list should exist");
+    
+    assert_eq!(rtepts.fields().count(), 2);
+    let addr = rtepts.find_list("ADDR").expect("Nested ADDR list should exist");
+    assert_eq!(addr.fields().next().unwrap().value, "LFPGZQZX");
+    
+    // La vue aplatie reste disponible
+    let section = message.get_section("RTEPTS").unwrap();
+    assert_eq!(section.fields.get("PTID").unwrap(), &vec!["LFPG".to_string(), "LFPB".to_string()]);
+    assert_eq!(section.fields.get("FAC").unwrap(), &vec!["LFPGZQZX".to_string()]);
+}
+
+#[test]
+fn test_serialize_preserves_original_order() {
+    let input = "-ADEXP
+-TITLE FPL
+-ARCID ABC123
+-REFDATA -IFPLID AA12345678 -ORIGIN LFPG
+-BEGIN RTEPTS
+-PT -PTID LFPG -FL F004
+-BEGIN ADDR
+-FAC LFPGZQZX
+-END ADDR
+-PT -PTID LFPB
+-END RTEPTS
+-ADES LFPB
+-ADEP LFPG";
+    
+    let message = AdexpParser::parse_message(input).expect("Should parse successfully");
+    assert_eq!(message.serialize(), input);
+    
+    let reparsed = AdexpParser::parse_message(&message.serialize()).expect("Should reparse");
+    assert_eq!(reparsed.items, message.items);
+}
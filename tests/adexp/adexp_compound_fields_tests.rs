@@ -211,3 +211,33 @@ fn test_validate_refdata_in_message() {
     assert!(result.is_ok(), "Validation should succeed for valid REFDATA in message");
 }
 
+
+#[test]
+fn test_typed_route_points_from_begin_block() {
+    use aftn::adexp::{AdexpTypedMessage, AdexpTime};
+    
+    let input = "-ADEXP
+-TITLE FPL
+-ARCID ABC123
+-ADEP LFPG
+-ADES LFPB
+-EOBD 010624
+-EOBT 0930
+-BEGIN RTEPTS
+-PT -PTID LFPG -LAT 49.0097 -LON 2.5479 -FL 000 -ETO 0940
+-PT -PTID PIROT -LAT 48.8566 -LON 2.3522 -FL 050 -ETO 0950
+-END RTEPTS
+";
+    let message = AdexpParser::parse_message(input).expect("Should parse successfully");
+    let fpl = match message.typed().expect("Typed conversion should succeed") {
+        Some(AdexpTypedMessage::Fpl(fpl)) => fpl,
+        other => panic!("Expected typed FPL, got {:?}", other),
+    };
+    
+    assert_eq!(fpl.route_points.len(), 2);
+    assert_eq!(fpl.route_points[0].name(), Some("LFPG"));
+    assert_eq!(fpl.route_points[0].eto, Some(AdexpTime { hour: 9, minute: 40, second: 0 }));
+    assert_eq!(fpl.route_points[1].fl, Some(50));
+    assert!((fpl.route_points[1].lat.unwrap() - 48.8566).abs() < 1e-9);
+    assert_eq!(fpl.off_block_time().map(|t| t.to_string()), Some("2024-06-01T09:30:00Z".to_string()));
+}
@@ -1,7 +1,7 @@
 //! Tests de dysfonctionnement pour ADEXP 3.4
 //! Vérifie que le parser rejette correctement les messages invalides
 
-use aftn::{AdexpParser, AdexpError};
+use aftn::{AdexpParser, AdexpError, LocatedError};
 
 #[test]
 fn test_missing_adexp_marker() {
@@ -290,3 +290,13 @@ fn test_message_with_nested_sections() {
     }
 }
 
+#[test]
+fn test_syntax_error_location() {
+    let input = "ADEXP\n-TITLE IFPL\n";
+    let error = AdexpParser::parse_message(input).unwrap_err();
+    assert!(matches!(error, AdexpError::Syntax(_)));
+    assert_eq!(error.code(), "ADEXP012");
+    let span = error.span().expect("Syntax errors carry a span");
+    assert_eq!((span.line, span.column), (1, 1));
+    assert!(error.render(input).contains("1 | ADEXP\n  | ^\n"));
+}
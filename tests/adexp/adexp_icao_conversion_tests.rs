@@ -0,0 +1,87 @@
+//! Tests de conversion entre ADEXP et les messages ATS OACI transportés par AFTN
+
+use aftn::{AdexpMessage, AdexpParser, AftnParser};
+use aftn::aftn::SubMessageData;
+
+#[test]
+fn test_convert_aftn_fpl_to_adexp_and_back() {
+    let input = "FF LFPGZPZX EGLLZPZX 170830 (FPL-AFR123-IS -A320/M-SDFGIRWY/S -LFPG0900 -N0450F350 UM184 RESMI UN860 -EGLL0105 EGKK -PBN/A1B1 DOF/261017)";
+    let message = AftnParser::parse_message(input).expect("Should parse successfully");
+    let ats = match message.submessage.parsed() {
+        Some(SubMessageData::FlightPlan(fpl)) => fpl.ats.clone().expect("ICAO flight plan expected"),
+        other => panic!("Expected FPL submessage, got {:?}", other),
+    };
+    
+    let adexp = AdexpMessage::from_ats(&ats).expect("Conversion to ADEXP should succeed");
+    assert!(adexp.is_lossless());
+    let fpl = adexp.message.typed().unwrap();
+    assert!(matches!(fpl, Some(aftn::adexp::AdexpTypedMessage::Fpl(_))));
+    
+    // Le message ADEXP produit se relit et se reconvertit à l'identique
+    let reparsed = AdexpParser::parse_message(&adexp.message.serialize()).unwrap();
+    assert_eq!(reparsed.to_ats().unwrap().message, ats);
+}
+
+#[test]
+fn test_adexp_without_icao_equivalent() {
+    let input = "-ADEXP
+-TITLE ACK
+-ARCID AFR123
+";
+    let message = AdexpParser::parse_message(input).unwrap();
+    assert!(message.to_ats().is_err());
+}
+
+#[test]
+fn test_ifps_titles_convert_to_icao_messages() {
+    // Les messages émis par l'IFPS (IFPL, ICHG...) portent les mêmes données
+    // que leurs équivalents OACI
+    let fpl = "-ARCID AFR123
+-FLTRUL I
+-FLTTYP S
+-ARCTYP A320
+-WKTRC M
+-CEQPT SDFGIRWY
+-SEQPT S
+-ADEP LFPG
+-EOBT 0900
+-SPEED N0450
+-RFL F350
+-ROUTE N0450F350 DCT
+-ADES EGLL
+-TTLEET 0105
+-EOBD 171026
+";
+    let flight = "-ARCID AFR123
+-ADEP LFPG
+-EOBT 0900
+-ADES EGLL
+-EOBD 171026
+";
+    let arrival = "-ARCID AFR123
+-ADEP LFPG
+-EOBT 0900
+-ADES EGLL
+-ATA 1005
+";
+    let departure = "-ARCID AFR123
+-ADEP LFPG
+-ATD 0912
+-ADES EGLL
+";
+    for (title, body, expected) in [
+        ("IFPL", fpl, "(FPL-AFR123-IS-A320/M-SDFGIRWY/S-LFPG0900-N0450F350 DCT-EGLL0105-DOF/261017)"),
+        ("ICHG", fpl, "(CHG-AFR123-LFPG0900-EGLL0105-DOF/261017-8/IS-9/A320/M-10/SDFGIRWY/S-15/N0450F350 DCT)"),
+        ("IDLA", flight, "(DLA-AFR123-LFPG0900-EGLL-DOF/261017)"),
+        ("ICNL", flight, "(CNL-AFR123-LFPG0900-EGLL-DOF/261017)"),
+        ("IDEP", departure, "(DEP-AFR123-LFPG0912-EGLL)"),
+        ("IARR", arrival, "(ARR-AFR123-LFPG0900-EGLL1005)"),
+    ] {
+        let input = format!("-ADEXP\n-TITLE {}\n{}", title, body);
+        let message = AdexpParser::parse_message(&input).unwrap();
+        let ats = message.to_ats().unwrap_or_else(|e| panic!("{}: {}", title, e));
+        assert!(ats.is_lossless(), "{}: {:?}", title, ats.losses);
+        assert_eq!(ats.message.message_type.designator, &title[1..]);
+        assert_eq!(ats.message.serialize(), expected, "{}", title);
+    }
+}
@@ -205,3 +205,25 @@ fn test_validate_invalid_ssr_code() {
     assert!(result.is_err(), "Validation should fail for invalid SSR code (8 > 7)");
 }
 
+
+#[test]
+fn test_check_collects_all_errors() {
+    // Code SSR invalide et ADES manquant
+    let input = "-ADEXP
+-TITLE FPL
+-ARCID ABC123
+-ADEP LFPG
+-COD 1238
+";
+    
+    let message = AdexpParser::parse_message(input).expect("Should parse");
+    let errors = message.check();
+    assert_eq!(errors.len(), 2);
+    assert_eq!(message.validate().unwrap_err(), errors[0]);
+    assert_eq!(errors[1], AdexpError::MissingField("ADES".to_string()));
+    
+    let result = AdexpParser::parse_lenient(input);
+    assert!(result.message.is_some());
+    assert_eq!(result.diagnostics.len(), 2);
+    assert_eq!(result.diagnostics[1].code, "ADEXP005");
+}
@@ -17,6 +17,9 @@ mod compound_fields_tests;
 #[path = "adexp/adexp_fields_tests.rs"]
 mod fields_tests;
 
+#[path = "adexp/adexp_icao_conversion_tests.rs"]
+mod icao_conversion_tests;
+
 #[path = "adexp/adexp_integration_tests.rs"]
 mod integration_tests;
 
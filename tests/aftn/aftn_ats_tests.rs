@@ -0,0 +1,57 @@
+//! Tests des messages ATS OACI en forme parenthésée (Doc 4444, Appendice 3)
+
+use aftn::{AftnParser, MessageCategory};
+use aftn::aftn::SubMessageData;
+use aftn::aftn::categories::flight_plan::ats_fields::{CruisingLevel, WakeTurbulenceCategory};
+
+#[test]
+fn test_aftn_fpl_icao_format() {
+    let input = "FF LFPGZPZX EGLLZPZX 170830 (FPL-AFR123-IS -A320/M-SDFGIRWY/S -LFPG0900 -N0450F350 UM184 RESMI UN860 -EGLL0105 EGKK -PBN/A1B1 DOF/261017)";
+    let message = AftnParser::parse_message(input).expect("Should parse successfully");
+    
+    assert_eq!(message.category, MessageCategory::FlightPlan);
+    match message.submessage.parsed() {
+        Some(SubMessageData::FlightPlan(fpl)) => {
+            let ats = fpl.ats.as_ref().expect("Le FPL devrait être décodé champ par champ");
+            assert_eq!(ats.aircraft_id(), "AFR123");
+            assert_eq!(ats.aircraft.as_ref().unwrap().wake_turbulence, WakeTurbulenceCategory::Medium);
+            assert_eq!(ats.route.as_ref().unwrap().initial.level, CruisingLevel::FlightLevel(350));
+            assert_eq!(ats.destination.as_ref().unwrap().alternates, vec!["EGKK"]);
+        }
+        other => panic!("Sous-message FPL attendu, obtenu {:?}", other),
+    }
+}
+
+#[test]
+fn test_aftn_ats_message_categories() {
+    let test_cases = vec![
+        ("(CHG-AFR123-LFPG0900-EGLL-8/IN)", MessageCategory::Change),
+        ("(CNL-AFR123-LFPG0900-EGLL-0)", MessageCategory::Cancel),
+        ("(DLA-AFR123-LFPG1030-EGLL)", MessageCategory::Delay),
+        ("(DEP-AFR123/A1234-LFPG0912-EGLL-DOF/261017)", MessageCategory::Departure),
+        ("(ARR-AFR123/A1234-LFPG0900-EGLL1005)", MessageCategory::Arrival),
+        ("(EST-AFR123/A1234-LFPG-LIMRI/1015F350-EGLL)", MessageCategory::Estimate),
+        ("(SPL-AFR123-LFPG0900-EGLL0105-0-E/0745 P/180)", MessageCategory::SupplementaryFlightPlan),
+        ("(ABI-AFR123/A1234-LFPG-LIMRI/1015F350-EGLL)", MessageCategory::AdvanceBoundaryInformation),
+        ("(CPL-AFR123/A1234-IS-A320/M-SDFGIRWY/S-LFPG-LIMRI/1015F350-N0450F350 UM184-EGLL)", MessageCategory::CurrentFlightPlan),
+    ];
+    
+    for (body, expected_category) in test_cases {
+        let input = format!("FF LFPGZPZX EGLLZPZX 170830 {}", body);
+        let message = AftnParser::parse_message(&input)
+            .unwrap_or_else(|e| panic!("Failed to parse {}: {:?}", body, e));
+        
+        assert_eq!(message.category, expected_category, "Catégorie incorrecte pour {}", body);
+        assert!(message.submessage.is_parsed(), "Sous-message non parsé pour {}: {:?}", body, message.submessage.error());
+    }
+}
+
+#[test]
+fn test_aftn_ats_invalid_field_reported() {
+    // Catégorie de turbulence de sillage invalide (X)
+    let input = "FF LFPGZPZX EGLLZPZX 170830 (FPL-AFR123-IS-A320/X-S/S-LFPG0900-N0450F350 DCT-EGLL0105-0)";
+    let message = AftnParser::parse_message(input).expect("L'enveloppe AFTN reste valide");
+    
+    let error = message.submessage.error().expect("Le champ 9 invalide devrait être signalé");
+    assert!(format!("{}", error).contains("X"));
+}
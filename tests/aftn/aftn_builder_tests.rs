@@ -0,0 +1,138 @@
+//! Tests de construction programmatique de messages AFTN
+
+use aftn::AftnParser;
+use aftn::aftn::AftnMessageBuilder;
+use aftn::aftn::builder::MAX_LINE_LENGTH;
+use aftn::aftn::categories::coordination::CofMessage;
+use aftn::aftn::categories::flight_plan::{AtsMessage, ChgMessage, FplMessage};
+use aftn::aftn::categories::flight_plan::ats_fields::{CruisingLevel, CruisingSpeed, Hhmm};
+use aftn::submessages::SubMessageData;
+
+fn sample_fpl() -> FplMessage {
+    FplMessage::builder()
+        .aircraft_id("AFR123")
+        .flight_rules('I', Some('S'))
+        .aircraft(None, "A320", 'M')
+        .equipment("SDFGIRWY", "S")
+        .departure("LFPG", Some(Hhmm { hour: 9, minute: 0 }))
+        .route(
+            CruisingSpeed::Knots(450),
+            CruisingLevel::FlightLevel(350),
+            "UM184 RESMI UN860 SOVAT UN859 KOTAP UL612 LAMSO L10 BIG DCT OCK",
+        )
+        .destination("EGLL", Some(Hhmm { hour: 1, minute: 5 }))
+        .alternate("EGKK")
+        .other_information("PBN", "A1B1")
+        .other_information("DOF", "261017")
+        .build()
+        .expect("Should build a valid FPL")
+}
+
+#[test]
+fn test_build_fpl_emits_fields_in_order() {
+    let fpl = sample_fpl();
+    assert_eq!(
+        fpl.raw,
+        "(FPL-AFR123-IS-A320/M-SDFGIRWY/S-LFPG0900-N0450F350 UM184 RESMI UN860 SOVAT UN859 KOTAP UL612 LAMSO L10 BIG DCT OCK-EGLL0105 EGKK-PBN/A1B1 DOF/261017)"
+    );
+    assert_eq!(fpl.callsign.as_deref(), Some("AFR123"));
+    assert_eq!(fpl.ats.as_ref().unwrap().date_of_flight(), Some("261017"));
+}
+
+#[test]
+fn test_build_fpl_rejects_invalid_values() {
+    let invalid_callsign = FplMessage::builder()
+        .aircraft_id("123AFR")
+        .flight_rules('I', Some('S'))
+        .build();
+    assert!(invalid_callsign.is_err());
+
+    let invalid_aerodrome = AtsMessage::builder("DLA")
+        .aircraft_id("AFR123")
+        .departure("LFP", Some(Hhmm { hour: 9, minute: 0 }))
+        .destination("EGLL", None)
+        .build();
+    assert!(invalid_aerodrome.is_err());
+
+    let invalid_time = AtsMessage::builder("DLA")
+        .aircraft_id("AFR123")
+        .departure("LFPG", Some(Hhmm { hour: 25, minute: 0 }))
+        .destination("EGLL", None)
+        .build();
+    assert!(invalid_time.is_err());
+}
+
+#[test]
+fn test_build_chg_with_amendments() {
+    let chg = ChgMessage::builder()
+        .aircraft_id("AFR123")
+        .departure("LFPG", Some(Hhmm { hour: 9, minute: 0 }))
+        .destination("EGLL", None)
+        .other_information("DOF", "261017")
+        .amendment(8, "IS")
+        .amendment(15, "N0460F370 UM184 RESMI")
+        .build()
+        .expect("Should build a valid CHG");
+    assert_eq!(chg.raw, "(CHG-AFR123-LFPG0900-EGLL-DOF/261017-8/IS-15/N0460F370 UM184 RESMI)");
+    assert_eq!(chg.ats.unwrap().amendments.len(), 2);
+}
+
+#[test]
+fn test_build_aftn_message_wraps_long_lines() {
+    let fpl = sample_fpl();
+    let message = AftnMessageBuilder::new()
+        .priority("FF")
+        .origin("LFPGZPZX")
+        .destination("EGLLZPZX")
+        .filing_time(17, 8, 30)
+        .heading("ABC", "123")
+        .text(&fpl.raw)
+        .build()
+        .expect("Should build a valid AFTN message");
+
+    assert!(message.body.lines().count() > 1);
+    let serialized = message.serialize();
+    assert!(serialized.lines().all(|line| line.len() <= MAX_LINE_LENGTH));
+    assert!(matches!(message.submessage.parsed(), Some(SubMessageData::FlightPlan(_))));
+
+    // Le texte émis est relu à l'identique
+    let reparsed = AftnParser::parse_message(&serialized).expect("Should parse successfully");
+    assert_eq!(reparsed.body, message.body);
+    assert_eq!(reparsed.addresses, message.addresses);
+}
+
+#[test]
+fn test_build_aftn_message_splits_address_lines() {
+    let destinations: Vec<String> = (0..9).map(|i| format!("LFPGZP{}X", (b'A' + i) as char)).collect();
+    let message = AftnMessageBuilder::new()
+        .priority("GG")
+        .origin("LFPGYYYX")
+        .destinations(destinations.iter().map(String::as_str))
+        .filing_time(15, 12, 30)
+        .heading("LFA", "001")
+        .text(&CofMessage::builder().callsign("AFR123").coordination_data("FL350 RESMI 1230").build().unwrap().raw)
+        .build()
+        .expect("Should build a valid AFTN message");
+
+    let serialized = message.serialize();
+    assert_eq!(serialized.lines().nth(1).unwrap().split_whitespace().count(), 8);
+    assert_eq!(serialized.lines().nth(2).unwrap(), "LFPGZPHX LFPGZPIX");
+}
+
+#[test]
+fn test_build_aftn_message_rejects_invalid_envelope() {
+    let builder = AftnMessageBuilder::new()
+        .priority("FF")
+        .origin("LFPGZPZX")
+        .destination("EGLLZPZX")
+        .filing_time(17, 8, 30)
+        .text("COF AFR123 FL350");
+
+    assert!(builder.clone().priority("XX").build().is_err());
+    assert!(builder.clone().origin("LFPG").build().is_err());
+    assert!(builder.clone().filing_time(32, 8, 30).build().is_err());
+    assert!(builder.clone().heading("AB1", "123").build().is_err());
+    assert!(builder.clone().origin_info("URGENT").build().is_err());
+    assert!(builder.clone().destinations(std::iter::repeat_n("EGLLZPZX", 21)).build().is_err());
+    assert!(builder.build().is_ok());
+}
@@ -0,0 +1,60 @@
+//! Tests de localisation des erreurs de syntaxe AFTN
+
+use aftn::{AftnParser, FieldRef, LocatedError};
+
+#[test]
+fn test_header_error_has_location_and_code() {
+    let input = "GG LFPGYYYX 12 151230 NOTAM A1234/24";
+    let error = AftnParser::parse_message(input).unwrap_err();
+
+    assert_eq!(error.code(), "AFTN010");
+    let span = error.span().expect("Syntax errors carry a span");
+    assert_eq!(span.line, 1);
+    assert_eq!(span.column, 15);
+    assert_eq!(error.field(), Some(FieldRef::Index(2)));
+    assert!(!error.expected().is_empty());
+
+    let rendered = error.render(input);
+    assert!(rendered.starts_with("error[AFTN010]: "));
+    assert!(rendered.contains("1 | GG LFPGYYYX 12 151230 NOTAM A1234/24\n"));
+    assert!(rendered.contains("  |               ^\n"));
+}
+
+#[test]
+fn test_envelope_error_reports_line() {
+    let input = "ZCZC ABC123\r\nFF EGLLZPZX\r\n17083 LFPGZPZX\r\nTEXT\r\nNNNN";
+    let error = AftnParser::parse_message(input).unwrap_err();
+
+    let span = error.span().expect("Syntax errors carry a span");
+    assert_eq!(span.line, 3);
+    assert!(error.render(input).contains("3 | 17083 LFPGZPZX\n"));
+}
+
+#[test]
+fn test_ats_field_error_names_the_field() {
+    let input = "FF LFPGZPZX EGLLZPZX 170830 (FPL-AFR123-IS-A320/X-SDFGIRWY/S-LFPG0900-N0450F350 UM184-EGLL0105-0)";
+    let message = AftnParser::parse_message(input).expect("Envelope should parse");
+    let error = message.submessage.error().expect("Field 9 is invalid");
+
+    assert_eq!(error.field(), Some(FieldRef::Name("Field 9".to_string())));
+    let span = error.span().unwrap();
+    assert_eq!(&message.body[span.start..span.end], "A320/X");
+    assert!(error.render(&message.body).contains("^^^^^^"));
+    // Le code reste celui de l'erreur de décodage du champ
+    assert_eq!(error.code(), "AFTN002");
+    assert!(error.to_string().starts_with("Invalid message format: Wake turbulence"));
+}
+
+#[test]
+fn test_error_codes_are_stable() {
+    use aftn::{AdexpError, AftnError, NmeaError, SbsError};
+
+    assert_eq!(AftnError::InvalidPriority("XX".to_string()).code(), "AFTN003");
+    assert_eq!(AdexpError::MissingField("ARCID".to_string()).code(), "ADEXP005");
+    assert_eq!(NmeaError::MissingChecksum.code(), "NMEA004");
+    assert_eq!(SbsError::InvalidHeading("400".to_string()).code(), "SBS011");
+    assert_eq!(
+        AdexpError::MissingField("ARCID".to_string()).field(),
+        Some(FieldRef::Name("ARCID".to_string()))
+    );
+}
@@ -0,0 +1,37 @@
+//! Tests du suivi des plans de vol à partir de messages AFTN et ADEXP
+
+use aftn::{AdexpParser, AftnParser};
+use aftn::aftn::categories::flight_plan::{FlightPlanStore, FlightStatus, IngestOutcome};
+
+#[test]
+fn test_store_mixes_aftn_and_adexp_messages() {
+    let mut store = FlightPlanStore::new();
+    
+    let fpl = AftnParser::parse_message(
+        "FF LFPGZPZX EGLLZPZX 170830 (FPL-AFR123-IS -A320/M-SDFGIRWY/S -LFPG0900 -N0450F350 UM184 RESMI UN860 -EGLL0105 EGKK -PBN/A1B1 DOF/261017)"
+    ).expect("Should parse successfully");
+    assert!(matches!(store.ingest_aftn(&fpl).unwrap(), IngestOutcome::Created(_)));
+    
+    // Retard reçu en ADEXP pour le même vol
+    let dla = AdexpParser::parse_message("-ADEXP
+-TITLE IDLA
+-ARCID AFR123
+-ADEP LFPG
+-EOBT 0940
+-ADES EGLL
+-EOBD 171026
+").unwrap();
+    let key = match store.ingest_adexp(&dla).unwrap() {
+        IngestOutcome::Updated(key) => key,
+        other => panic!("Expected update, got {:?}", other),
+    };
+    
+    let record = store.get(&key).unwrap();
+    assert_eq!(record.status, FlightStatus::Delayed);
+    assert_eq!(record.plan.departure.as_ref().unwrap().serialize(), "LFPG0940");
+    assert_eq!(record.history.len(), 2);
+    
+    // Un METAR n'a pas d'effet sur les plans de vol
+    let metar = AftnParser::parse_message("GG LFPGYYYX LFPOYYYX 151230 METAR LFPG 151230Z 28015KT 9999 FEW030 12/08 Q1013").unwrap();
+    assert_eq!(store.ingest_aftn(&metar).unwrap(), IngestOutcome::Ignored);
+}
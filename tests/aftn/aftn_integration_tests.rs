@@ -50,3 +50,64 @@ fn test_integration_with_sequence() {
     assert!(message.body.contains("TEST MESSAGE CONTENT"));
 }
 
+
+#[test]
+fn test_integration_telegraph_envelope() {
+    let input = "ZCZC LFA0153\r\nFF EGLLZPZX LFPOZPZX\r\n170830 LFPGZPZX\r\n(FPL-AFR123-IS -A320/M-SDFGIRWY/S -LFPG0900 -N0450F350 UM184 RESMI UN860 -EGLL0105 EGKK -0)\r\n\r\n\r\nNNNN\r\n";
+    let message = AftnParser::parse_message(input).expect("Should parse successfully");
+    
+    let envelope = message.envelope.as_ref().expect("L'enveloppe devrait être conservée");
+    assert_eq!(envelope.channel_id, "LFA");
+    assert_eq!(envelope.channel_sequence, "0153");
+    assert!(envelope.end_of_message);
+    assert_eq!(message.priority, "FF");
+    assert_eq!(message.addresses.origin, "LFPGZPZX");
+    assert_eq!(message.addresses.destinations, vec!["EGLLZPZX", "LFPOZPZX"]);
+    assert_eq!(message.category, MessageCategory::FlightPlan);
+    assert!(message.submessage.is_parsed());
+    
+    let reparsed = AftnParser::parse_message(&message.serialize()).expect("Should parse serialized envelope");
+    assert_eq!(reparsed, message);
+}
+
+#[test]
+fn test_integration_stream_reader() {
+    use aftn::aftn::AftnStreamReader;
+    
+    let log = "ZCZC LFA0001\r\nGG LFPOYYYX\r\n151230 LFPGYYYX\r\nNOTAM A1234/24 LFPG RWY 09/27 CLOSED\r\nNNNN\r\n\
+               \x0c\r\n\r\nZCZC LFA0002\r\nGG LFPOYYYX\r\n1512\r\n\
+               ZCZC LFA0003\r\nDD LFPBYYYX\r\n151231 LFPGYYYX\r\nMETAR LFPG 151230Z 28015KT 9999 FEW030 12/08 Q1013\r\nNNNN\r\n";
+    let mut reader = AftnStreamReader::new(log.as_bytes());
+    
+    let mut results = Vec::new();
+    while let Some((offset, result)) = reader.next_with_offset() {
+        results.push((offset, result));
+    }
+    
+    assert_eq!(results.len(), 3);
+    assert_eq!(results[0].0, 0);
+    assert_eq!(results[0].1.as_ref().unwrap().category, MessageCategory::Notam);
+    assert_eq!(results[1].0, log.find("ZCZC LFA0002").unwrap() as u64);
+    assert!(results[1].1.is_err());
+    assert_eq!(results[2].0, log.find("ZCZC LFA0003").unwrap() as u64);
+    assert_eq!(results[2].1.as_ref().unwrap().category, MessageCategory::Metar);
+}
+
+#[test]
+fn test_integration_resolve_transmission_time() {
+    use aftn::aftn::UtcDateTime;
+    
+    let message = AftnParser::parse_message("GG LFPGYYYX LFPOYYYX 010005 NOTAM A1234/24 LFPG RWY 09/27 CLOSED")
+        .expect("Should parse successfully");
+    
+    // Reçu juste avant minuit le 31 décembre: le message date du 1er janvier suivant
+    let received = UtcDateTime::new(2024, 12, 31, 23, 59).unwrap();
+    let filed = message.transmission_time.resolve(&received).expect("Should resolve");
+    assert_eq!(filed, UtcDateTime::new(2025, 1, 1, 0, 5).unwrap());
+    assert_eq!(filed.to_string(), "2025-01-01T00:05:00Z");
+    
+    let message = AftnParser::parse_message("GG LFPGYYYX LFPOYYYX 311200 NOTAM A1234/24 LFPG RWY 09/27 CLOSED")
+        .expect("Should parse successfully");
+    let received = UtcDateTime::new(2024, 4, 20, 12, 0).unwrap();
+    assert!(message.transmission_time.resolve(&received).is_err());
+}
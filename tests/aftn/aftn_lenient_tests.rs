@@ -0,0 +1,84 @@
+//! Tests du mode tolérant AFTN (tous les problèmes relevés)
+
+use aftn::{AftnParser, LocatedError, Severity};
+
+#[test]
+fn test_lenient_collects_every_validation_error() {
+    let input = "XX LFPGYYYX LFPOYYYX 322599 NOTAM A1234/24 LFPG RWY 09/27 CLOSED";
+    assert_eq!(AftnParser::parse_message(input).unwrap_err().code(), "AFTN003");
+
+    let result = AftnParser::parse_lenient(input);
+    let message = result.message.as_ref().expect("Header is readable");
+    assert_eq!(message.priority, "XX");
+
+    let codes: Vec<&str> = result.with_severity(Severity::Error).map(|d| d.code.as_str()).collect();
+    assert_eq!(codes, vec!["AFTN003", "AFTN006", "AFTN006", "AFTN006"]);
+    assert!(result.has_errors());
+}
+
+#[test]
+fn test_lenient_valid_message_has_no_diagnostics() {
+    let input = "GG LFPGYYYX LFPOYYYX 151230 NOTAM A1234/24 LFPG RWY 09/27 CLOSED";
+    let result = AftnParser::parse_lenient(input);
+
+    assert_eq!(result.message, Some(AftnParser::parse_message(input).unwrap()));
+    assert!(result.diagnostics.is_empty());
+}
+
+#[test]
+fn test_lenient_syntax_error_has_no_message() {
+    let result = AftnParser::parse_lenient("GG LFPGYYYX 12 151230 NOTAM A1234/24");
+
+    assert!(result.message.is_none());
+    assert_eq!(result.diagnostics.len(), 1);
+    assert_eq!(result.diagnostics[0].code, "AFTN010");
+    assert!(result.diagnostics[0].span.is_some());
+}
+
+#[test]
+fn test_lenient_submessage_error_is_located_in_input() {
+    let input = "FF LFPGZPZX EGLLZPZX 170830 (FPL-AFR123-IS-A320/X-SDFGIRWY/S-LFPG0900-N0450F350 UM184-EGLL0105-0)";
+    let result = AftnParser::parse_lenient(input);
+
+    assert!(result.message.is_some());
+    let diagnostic = result.with_severity(Severity::Error).next().expect("Field 9 is invalid");
+    let span = diagnostic.span.unwrap();
+    assert_eq!(&input[span.start..span.end], "A320/X");
+}
+
+#[test]
+fn test_lenient_envelope_warnings() {
+    let long_line = "NOTAM A1234/24 LFPG RWY 09/27 CLOSED DUE TO WORKS IN PROGRESS UNTIL FURTHER NOTICE";
+    let input = format!("ZCZC ABC123 151230\r\nGG LFPOYYYX\r\n151229 LFPGYYYX\r\n{}\r\n", long_line);
+    let result = AftnParser::parse_lenient(&input);
+
+    assert!(result.message.is_some());
+    assert!(!result.has_errors());
+    let warnings: Vec<_> = result.with_severity(Severity::Warning).collect();
+    assert_eq!(warnings.len(), 2);
+    assert_eq!(warnings[0].code, "AFTN101");
+    let span = warnings[0].span.unwrap();
+    assert_eq!(&input[span.start..span.end], long_line);
+    assert_eq!(warnings[1].code, "AFTN103");
+    assert!(warnings[0].render(&input).starts_with("warning[AFTN101]"));
+}
+
+#[test]
+fn test_lenient_reports_unknown_category_as_info() {
+    let result = AftnParser::parse_lenient("GG LFPGYYYX LFPOYYYX 151230 TEST MESSAGE");
+
+    assert!(result.diagnostics.iter().all(|d| d.severity == Severity::Info));
+    assert_eq!(result.diagnostics[0].code, "AFTN201");
+}
+
+#[test]
+fn test_lenient_reports_malformed_taf_change_group() {
+    let input = "FF LFPGYYYX LFPOYYYX 151100 TAF EGLL 151100Z 1512/1618 22012KT 9999 BKN025 BECMG 22015KT TEMPO 1514/1518 3000 RA";
+    let result = AftnParser::parse_lenient(input);
+    assert!(result.message.as_ref().unwrap().submessage.is_parsed());
+    assert!(!result.has_errors());
+
+    let warning = result.diagnostics.iter().find(|d| d.code == "AFTN104").expect("Change group is reported");
+    let span = warning.span.unwrap();
+    assert_eq!(&input[span.start..span.end], "BECMG 22015KT");
+}
@@ -15,6 +15,47 @@ fn test_notam_submessage() {
     assert!(submessage.is_ok());
 }
 
+#[test]
+fn test_icao_notam_submessage() {
+    use aftn::aftn::{SubMessageData, UtcDateTime};
+    use aftn::aftn::categories::meteorological::icao_notam::Coordinate;
+    
+    let input = "GG LFPGYYYX LFPOYYYX 151230 (A1234/24 NOTAMN Q) LFFF/QMRLC/IV/NBO/A/000/999/4900N00233E005 A) LFPG B) 2404150600 C) PERM E) RWY 09L/27R CLSD)";
+    let message = AftnParser::parse_message(input).expect("Should parse successfully");
+    
+    assert_eq!(message.category, MessageCategory::Notam);
+    let notam = match message.submessage.parsed() {
+        Some(SubMessageData::Notam(notam)) => notam.icao.as_ref().expect("NOTAM OACI attendu"),
+        other => panic!("Sous-message NOTAM attendu, obtenu {:?}", other),
+    };
+    
+    let briefing_start = UtcDateTime::new(2024, 6, 1, 0, 0).unwrap();
+    let briefing_end = UtcDateTime::new(2024, 6, 2, 0, 0).unwrap();
+    assert!(notam.is_active_between(&briefing_start, &briefing_end));
+    assert!(notam.matches_code("QMR"));
+    assert!(notam.qualifier.as_ref().unwrap().intersects(&Coordinate::parse("4901N00232E").unwrap(), 0.0));
+}
+
+#[test]
+fn test_icao_sigmet_submessage() {
+    use aftn::aftn::SubMessageData;
+    use aftn::aftn::categories::meteorological::icao_notam::Coordinate;
+    use aftn::aftn::categories::meteorological::weather_warning::PhenomenonKind;
+    
+    let input = "GG LFPGYYYX LFPOYYYX 151230 LFFF SIGMET 2 VALID 151200/151600 LFPW- LFFF PARIS FIR EMBD TS FCST WI N4900 E00100 - N4900 E00400 - N4700 E00400 - N4700 E00100 - N4900 E00100 TOP FL380 MOV E 15KT NC=";
+    let message = AftnParser::parse_message(input).expect("Should parse successfully");
+    
+    assert_eq!(message.category, MessageCategory::Sigmet);
+    let sigmet = match message.submessage.parsed() {
+        Some(SubMessageData::Sigmet(sigmet)) => &sigmet.warning,
+        other => panic!("Sous-message SIGMET attendu, obtenu {:?}", other),
+    };
+    assert_eq!(sigmet.fir.as_deref(), Some("LFFF"));
+    assert_eq!(sigmet.phenomenon.as_ref().unwrap().kind, PhenomenonKind::Thunderstorm);
+    let paris = Coordinate { latitude: 48.85, longitude: 2.35 };
+    assert_eq!(sigmet.area.as_ref().unwrap().contains(&paris), Some(true));
+}
+
 #[test]
 fn test_metar_submessage() {
     let input = "GG LFPGYYYX LFPOYYYX 151230 METAR LFPG 151230Z 28015KT 9999 FEW030 12/08 Q1013";
@@ -26,6 +67,48 @@ fn test_metar_submessage() {
     assert!(submessage.is_ok());
 }
 
+#[test]
+fn test_speci_submessage() {
+    use aftn::aftn::SubMessageData;
+    use aftn::aftn::categories::meteorological::weather_groups::CloudGroup;
+    
+    let input = "GG LFPGYYYX LFPOYYYX 151245 SPECI LFPG 151245Z 27020G35KT 0800 R27L/1200D +TSRA BKN008CB 14/13 Q1008";
+    let message = AftnParser::parse_message(input).expect("Should parse successfully");
+    
+    assert_eq!(message.category, MessageCategory::Speci);
+    match message.submessage.parsed() {
+        Some(SubMessageData::Metar(speci)) => {
+            assert_eq!(speci.conditions.wind.as_ref().unwrap().gust, Some(35));
+            assert_eq!(speci.runway_visual_ranges[0].value, 1200);
+            assert_eq!(CloudGroup::ceiling_ft(&speci.conditions.clouds), Some(800));
+        }
+        other => panic!("Sous-message SPECI attendu, obtenu {:?}", other),
+    }
+}
+
+#[test]
+fn test_taf_conditions_at_destination() {
+    use aftn::aftn::{SubMessageData, UtcDateTime};
+    use aftn::aftn::categories::meteorological::weather_groups::CloudGroup;
+    
+    let input = "FF LFPGYYYX LFPOYYYX 151100 TAF EGLL 151100Z 1512/1618 22012KT 9999 BKN025 TEMPO 1514/1518 3000 RA BKN008 FM152000 25008KT 6000 OVC004";
+    let message = AftnParser::parse_message(input).expect("Should parse successfully");
+    
+    let taf = match message.submessage.parsed() {
+        Some(SubMessageData::Taf(taf)) => taf,
+        other => panic!("Sous-message TAF attendu, obtenu {:?}", other),
+    };
+    
+    let arrival = UtcDateTime::new(2024, 6, 15, 16, 30).unwrap();
+    let forecast = taf.conditions_at(&arrival).unwrap().expect("Arrivée dans la période de validité");
+    assert_eq!(CloudGroup::ceiling_ft(&forecast.prevailing.clouds), Some(2500));
+    assert_eq!(CloudGroup::ceiling_ft(&forecast.variations[0].conditions.clouds), Some(800));
+    
+    let late_arrival = UtcDateTime::new(2024, 6, 15, 22, 0).unwrap();
+    let forecast = taf.conditions_at(&late_arrival).unwrap().unwrap();
+    assert_eq!(CloudGroup::ceiling_ft(&forecast.prevailing.clouds), Some(400));
+}
+
 #[test]
 fn test_taf_submessage() {
     let input = "DD LFPGYYYX LFPOYYYX 151200 TAF LFPG 151200Z 1512/1612 28015KT 9999 FEW030";
@@ -8,6 +8,12 @@ mod address_length_tests;
 #[path = "aftn/aftn_fpl_database_tests.rs"]
 mod aftn_fpl_database_tests;
 
+#[path = "aftn/aftn_flight_plan_store_tests.rs"]
+mod flight_plan_store_tests;
+
+#[path = "aftn/aftn_ats_tests.rs"]
+mod ats_tests;
+
 #[path = "aftn/aftn_all_categories_tests.rs"]
 mod all_categories_tests;
 
@@ -44,3 +50,11 @@ mod submessage_tests;
 #[path = "aftn/aftn_dysfunctional_tests.rs"]
 mod dysfunctional_tests;
 
+#[path = "aftn/aftn_builder_tests.rs"]
+mod builder_tests;
+
+#[path = "aftn/aftn_error_location_tests.rs"]
+mod error_location_tests;
+
+#[path = "aftn/aftn_lenient_tests.rs"]
+mod lenient_tests;
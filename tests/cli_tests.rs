@@ -0,0 +1,128 @@
+//! Tests d'intégration de l'outil en ligne de commande `aftn`
+
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+const AFTN_LOG: &str = "\
+GG LFPGYYYX LFPOYYYX 151230 NOTAM A1234/24 LFPG RWY 09/27 CLOSED
+
+XX LFPGYYYX LFPOYYYX 322599 NOTAM A1234/24
+";
+
+const ADEXP_LOG: &str = "\
+-ADEXP
+-TITLE FPL
+-ARCID ABC123
+-ADEP LFPG
+-ADES EGLL
+";
+
+const NMEA_LOG: &str = "\
+$GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*47
+$GPRMC,123519,A,4807.038,N,01131.000,E,022.4,084.4,230394,003.1,W*6A
+";
+
+fn run(args: &[&str], stdin: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_aftn"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("Binary should start");
+    child.stdin.take().unwrap().write_all(stdin.as_bytes()).unwrap();
+    child.wait_with_output().unwrap()
+}
+
+fn stdout(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stdout).into_owned()
+}
+
+#[test]
+fn test_parse_ndjson_one_line_per_message() {
+    let output = run(&["parse", "--format", "nmea", "--output", "ndjson"], NMEA_LOG);
+    let text = stdout(&output);
+    let lines: Vec<&str> = text.lines().collect();
+
+    assert_eq!(lines.len(), 2);
+    assert!(lines[0].starts_with("{\"source\":\"<stdin>:1\",\"format\":\"nmea\""));
+    assert!(lines[1].starts_with("{\"source\":\"<stdin>:2\""));
+    assert_eq!(output.status.code(), Some(0));
+}
+
+#[test]
+fn test_parse_json_groups_multiline_messages() {
+    let output = run(&["parse", "--format", "adexp"], ADEXP_LOG);
+    let text = stdout(&output);
+
+    assert!(text.starts_with("[\n{"));
+    assert!(text.ends_with("}\n]\n"));
+    assert_eq!(text.matches("\"source\"").count(), 1);
+    assert!(text.contains("\"format\": \"adexp\""));
+    assert_eq!(output.status.code(), Some(0));
+}
+
+#[test]
+fn test_validate_reports_and_sets_exit_code() {
+    let output = run(&["validate", "--format", "aftn"], AFTN_LOG);
+    let text = stdout(&output);
+
+    assert_eq!(output.status.code(), Some(1));
+    assert!(text.contains("<stdin>:3: error[AFTN003]: Invalid priority: XX"));
+    assert!(text.ends_with("2 message(s), 1 invalide(s) : 4 erreur(s), 0 avertissement(s), 0 information(s)\n"));
+
+    let valid = run(&["validate", "--quiet", "--format", "nmea"], NMEA_LOG);
+    assert_eq!(valid.status.code(), Some(0));
+}
+
+#[test]
+fn test_convert_aftn_to_adexp() {
+    let input = "FF LFPGZPZX EGLLZPZX 170830 (FPL-AFR123-IS-A320/M-SDFGIRWY/S-LFPG0900-N0450F350 UM184-EGLL0105-0)";
+    let output = run(&["convert", "--to", "adexp"], input);
+
+    assert_eq!(output.status.code(), Some(0));
+    let text = stdout(&output);
+    assert!(text.starts_with("-ADEXP\n-TITLE FPL\n-ARCID AFR123\n"));
+
+    let back = run(&["convert", "--to", "icao"], &text);
+    assert_eq!(back.status.code(), Some(0));
+    assert!(stdout(&back).starts_with("(FPL-AFR123-IS"));
+}
+
+#[test]
+fn test_stats_json_counts_per_type() {
+    let output = run(&["stats", "--format", "aftn", "--output", "json"], AFTN_LOG);
+    let text = stdout(&output);
+
+    assert!(text.contains("\"Notam\": 2"));
+    assert!(text.contains("\"failure_rate\": 0.5"));
+
+    let output = run(&["stats", "--format", "nmea", "--output", "json"], NMEA_LOG);
+    assert!(stdout(&output).contains("\"GPGGA\": 1"));
+}
+
+#[test]
+fn test_usage_errors_exit_with_2() {
+    assert_eq!(run(&["frobnicate"], "").status.code(), Some(2));
+    assert_eq!(run(&["parse", "--format", "xml"], "").status.code(), Some(2));
+    assert_eq!(run(&["parse", "--format", "aftn", "/nonexistent/file.log"], "").status.code(), Some(2));
+    assert_eq!(run(&["convert"], "").status.code(), Some(2));
+}
+
+#[test]
+fn test_auto_detects_formats_in_mixed_log() {
+    let log = [AFTN_LOG, ADEXP_LOG, NMEA_LOG].concat();
+    let output = run(&["parse", "--output", "ndjson"], &log);
+    let text = stdout(&output);
+    let formats: Vec<&str> = text.lines()
+        .map(|line| line.split("\"format\":\"").nth(1).and_then(|rest| rest.split('"').next()).unwrap_or(""))
+        .collect();
+
+    assert_eq!(formats, vec!["aftn", "aftn", "adexp", "nmea", "nmea"]);
+    assert!(text.lines().nth(2).unwrap().starts_with("{\"source\":\"<stdin>:4\""));
+    assert_eq!(output.status.code(), Some(0));
+
+    let output = run(&["validate"], "line noise\n");
+    assert_eq!(output.status.code(), Some(1));
+    assert!(stdout(&output).starts_with("<stdin>:1: error[FORMAT001]"));
+}
@@ -0,0 +1,53 @@
+//! Tests d'intégration de la reconnaissance automatique du format
+
+use std::io::Cursor;
+use aftn::{detect_format, parse_any, AnyMessage, Format, MixedStreamReader};
+
+#[test]
+fn test_parse_any_each_format() {
+    let inputs = [
+        "GG LFPGYYYX LFPOYYYX 151230 NOTAM A1234/24 LFPG RWY 09/27 CLOSED",
+        "-ADEXP\n-TITLE FPL\n-ARCID ABC123\n-ADEP LFPG\n-ADES EGLL",
+        "$GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*47",
+        "MSG,3,145,29315,4CA2E6,27215,2015/02/05,14:53:22.734,2015/02/05,14:53:22.734,,37025,,,51.4703,-0.4543,,,,,,0",
+    ];
+    let formats: Vec<Format> = inputs.iter()
+        .map(|input| parse_any(input).expect("Should parse").message.format())
+        .collect();
+    assert_eq!(formats, vec![Format::Aftn, Format::Adexp, Format::Nmea, Format::Sbs]);
+}
+
+#[test]
+fn test_recorder_stream_line_by_line() {
+    // Enregistrement mêlant NMEA, SBS et AFTN
+    let log = "\
+$GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*47
+MSG,3,145,29315,4CA2E6,27215,2015/02/05,14:53:22.734,2015/02/05,14:53:22.734,,37025,,,51.4703,-0.4543,,,,,,0
+ZCZC ABC123 151230
+GG LFPOYYYX
+151229 LFPGYYYX
+NOTAM A1234/24 LFPG RWY 09/27 CLOSED
+NNNN
+$GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*47
+";
+    let messages: Vec<_> = MixedStreamReader::new(Cursor::new(log))
+        .map(|result| result.expect("Every message should parse"))
+        .collect();
+
+    assert_eq!(messages.len(), 4);
+    assert!(matches!(messages[0].message, AnyMessage::Nmea(_)));
+    assert!(matches!(messages[1].message, AnyMessage::Sbs(_)));
+    match &messages[2].message {
+        AnyMessage::Aftn(message) => assert!(message.envelope.is_some()),
+        other => panic!("AFTN envelope expected, got {:?}", other),
+    }
+    assert!(messages.iter().all(|m| m.confidence == 1.0));
+}
+
+#[test]
+fn test_partial_match_lowers_confidence() {
+    let full = detect_format("GG LFPGYYYX LFPOYYYX 151230 NOTAM A1234/24").unwrap();
+    let partial = detect_format("QQ LFPGYYYX LFPOYYYX 151230 NOTAM A1234/24").unwrap();
+    assert_eq!(partial.format, Format::Aftn);
+    assert!(partial.confidence < full.confidence);
+}
@@ -0,0 +1,82 @@
+//! Tests du réassemblage des messages AIS multi-fragments
+
+use std::time::Duration;
+use aftn::NmeaParser;
+use aftn::nmea::{AisAssembler, AisMessageData, DropReason, NmeaMessage};
+
+const FIRST: &str = "!AIVDM,2,1,1,A,55?MbV02;H;s<HtKR20EHE:0@T4@Dn2222222216L961O5Gf0NSQEp6ClRp8,0*1C";
+const SECOND: &str = "!AIVDM,2,2,1,A,88888888880,2*25";
+
+fn parse(input: &str) -> NmeaMessage {
+    NmeaParser::parse_message(input).expect("Should parse")
+}
+
+#[test]
+fn test_reassemble_static_data() {
+    let mut assembler = AisAssembler::new();
+    assert!(assembler.push(&parse(FIRST)).unwrap().is_none());
+    assert_eq!(assembler.pending(), 1);
+
+    let message = assembler.push(&parse(SECOND)).unwrap().expect("Group is complete");
+    assert_eq!(assembler.pending(), 0);
+    assert_eq!(message.total_fragments, Some(2));
+    assert_eq!(message.sequence_number, Some(1));
+
+    let decoded = message.decoded_data.expect("Decoded");
+    assert_eq!(decoded.message_id, 5);
+    assert_eq!(decoded.mmsi, Some(351759000));
+    match decoded.data {
+        AisMessageData::StaticData { call_sign, vessel_name, destination, ship_type, .. } => {
+            assert_eq!(call_sign.as_deref(), Some("3FOF8"));
+            assert_eq!(vessel_name.as_deref(), Some("EVER DIADEM"));
+            assert_eq!(destination.as_deref(), Some("NEW YORK"));
+            assert_eq!(ship_type, Some(70));
+        }
+        other => panic!("Expected static data, got {:?}", other),
+    }
+    assert!(assembler.take_dropped().is_empty());
+}
+
+#[test]
+fn test_out_of_order_and_duplicate_fragments() {
+    let mut assembler = AisAssembler::new();
+    assert!(assembler.push(&parse(SECOND)).unwrap().is_none());
+    assert!(assembler.push(&parse(SECOND)).unwrap().is_none());
+    let message = assembler.push(&parse(FIRST)).unwrap().expect("Reordered group is complete");
+    assert_eq!(message.decoded_data.unwrap().message_id, 5);
+
+    let dropped = assembler.take_dropped();
+    assert_eq!(dropped.len(), 1);
+    assert_eq!(dropped[0].reason, DropReason::Duplicate);
+    assert_eq!(dropped[0].fragment.fragment_number, Some(2));
+}
+
+#[test]
+fn test_incomplete_groups_are_dropped() {
+    let mut assembler = AisAssembler::with_timeout(Duration::from_secs(30));
+
+    // Premier fragment orphelin remplacé par un nouveau message de même identifiant
+    let other_first = "!AIVDM,2,1,1,A,55?MbV02;H;s<HtKR20EHE:0@T4@Dn2222222216L961O5Gf0NSQEp6ClRp0,0*14";
+    assert!(assembler.push_at(&parse(other_first), Duration::from_secs(0)).unwrap().is_none());
+    assert!(assembler.push_at(&parse(FIRST), Duration::from_secs(1)).unwrap().is_none());
+    let dropped = assembler.take_dropped();
+    assert_eq!(dropped.len(), 1);
+    assert_eq!(dropped[0].reason, DropReason::Superseded);
+
+    // Le second fragment arrive trop tard
+    assert!(assembler.push_at(&parse(SECOND), Duration::from_secs(40)).unwrap().is_none());
+    let reasons: Vec<DropReason> = assembler.take_dropped().iter().map(|d| d.reason).collect();
+    assert_eq!(reasons, vec![DropReason::Timeout]);
+    assert_eq!(assembler.pending(), 1);
+}
+
+#[test]
+fn test_single_fragment_is_decoded_immediately() {
+    let mut assembler = AisAssembler::new();
+    let message = assembler
+        .push(&parse("!AIVDM,1,1,,B,177KQJ5000G?tO`K>RA1wUbN0TKH,0*5C"))
+        .unwrap()
+        .expect("Single fragment message");
+    assert_eq!(message.decoded_data.unwrap().message_id, 1);
+    assert_eq!(assembler.pending(), 0);
+}
@@ -1,6 +1,7 @@
 //! Tests pour les messages AIS NMEA
 
 use aftn::{NmeaParser, AisMessage};
+use aftn::nmea::AisMessageData;
 
 #[test]
 fn test_parse_ais_message() {
@@ -15,7 +16,7 @@ fn test_parse_ais_message() {
         
         let ais = AisMessage::from_nmea(&msg).expect("Should parse AIS");
         assert_eq!(ais.message_type, "AIVDM");
-        assert_eq!(ais.sequence_number, Some(1));
+        assert_eq!(ais.total_fragments, Some(1));
         assert_eq!(ais.fragment_number, Some(1));
         assert_eq!(ais.radio_channel, Some('A'));
         assert!(!ais.payload.is_empty());
@@ -35,6 +36,7 @@ fn test_ais_decode_6bit() {
         payload: payload.to_string(),
         fill_bits: Some(0),
         decoded_data: None,
+        tag_block: None,
     };
     
     let result = ais_msg.decode_payload();
@@ -60,3 +62,65 @@ fn test_ais_validation() {
     }
 }
 
+
+#[test]
+fn test_ais_fragment_fields_order() {
+    // !AIVDM,nombre de fragments,numéro du fragment,identifiant séquentiel,canal,...
+    let input = "!AIVDM,2,1,1,A,55?MbV02;H;s<HtKR20EHE:0@T4@Dn2222222216L961O5Gf0NSQEp6ClRp8,0*1C";
+    let message = NmeaParser::parse_message(input).expect("Should parse");
+    let ais = AisMessage::from_nmea(&message).expect("Should parse AIS");
+    assert_eq!(ais.total_fragments, Some(2));
+    assert_eq!(ais.fragment_number, Some(1));
+    assert_eq!(ais.sequence_number, Some(1));
+    assert_eq!(ais.radio_channel, Some('A'));
+}
+
+fn ais_with_payload(payload: &str, fill_bits: u8) -> AisMessage {
+    AisMessage {
+        message_type: "AIVDM".to_string(),
+        sequence_number: None,
+        fragment_number: Some(1),
+        total_fragments: Some(1),
+        radio_channel: Some('A'),
+        payload: payload.to_string(),
+        fill_bits: Some(fill_bits),
+        decoded_data: None,
+        tag_block: None,
+    }
+}
+
+#[test]
+fn test_ais_6bit_table() {
+    // 'W' (0x57) vaut 39, '`' (0x60) vaut 40, 'P' (0x50) vaut 32 ;
+    // 0x58-0x5F ne sont pas utilisés
+    assert_eq!(
+        ais_with_payload("W`P0", 0).decode_payload().unwrap(),
+        vec![0b1001_1110, 0b1000_1000, 0b0000_0000]
+    );
+    assert!(ais_with_payload("X", 0).decode_payload().is_err());
+}
+
+#[test]
+fn test_ais_decode_static_data() {
+    let payload = "55?MbV02;H;s<HtKR20EHE:0@T4@Dn2222222216L961O5Gf0NSQEp6ClRp888888888880";
+    let mut ais = ais_with_payload(payload, 2);
+    ais.decode().expect("Should decode");
+
+    let decoded = ais.decoded_data.expect("Decoded");
+    assert_eq!(decoded.message_id, 5);
+    match decoded.data {
+        AisMessageData::StaticData { mmsi, call_sign, vessel_name, ship_type, dimensions, eta, draught, destination, .. } => {
+            assert_eq!(mmsi, 351759000);
+            assert_eq!(call_sign.as_deref(), Some("3FOF8"));
+            assert_eq!(vessel_name.as_deref(), Some("EVER DIADEM"));
+            assert_eq!(ship_type, Some(70));
+            let dimensions = dimensions.expect("Dimensions");
+            assert_eq!((dimensions.length, dimensions.width), (295, 32));
+            let eta = eta.expect("ETA");
+            assert_eq!((eta.month, eta.day, eta.hour, eta.minute), (5, 15, 14, 0));
+            assert_eq!(draught, Some(122));
+            assert_eq!(destination.as_deref(), Some("NEW YORK"));
+        }
+        other => panic!("Expected static data, got {:?}", other),
+    }
+}
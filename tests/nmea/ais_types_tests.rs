@@ -0,0 +1,174 @@
+//! Tests du décodage des types AIS 4, 6, 8, 9, 18, 19, 21, 24 et 27
+
+use aftn::NmeaParser;
+use aftn::nmea::{AisDecodedData, AisDimensions, AisMessage, AisMessageData, AisUtcTime};
+
+fn decode(input: &str) -> AisDecodedData {
+    let message = NmeaParser::parse_message(input).expect("Should parse");
+    assert!(message.validate().is_ok(), "{} should be valid", input);
+    let mut ais = AisMessage::from_nmea(&message).expect("Should parse AIS");
+    ais.decode().expect("Should decode");
+    ais.decoded_data.expect("Decoded data")
+}
+
+fn assert_position(data: &AisMessageData, latitude: f64, longitude: f64) {
+    let (lat, lon) = data.position().expect("Position available");
+    assert!((lat - latitude).abs() < 1e-4, "latitude {} != {}", lat, latitude);
+    assert!((lon - longitude).abs() < 1e-4, "longitude {} != {}", lon, longitude);
+}
+
+#[test]
+fn test_base_station_report() {
+    let decoded = decode("!AIVDM,1,1,,A,402MN7ivPodNewq8S0K2F<700000,0*12");
+    assert_eq!(decoded.message_id, 4);
+    assert_eq!(decoded.mmsi, Some(2579999));
+    assert_position(&decoded.data, 47.25, -1.5);
+    match decoded.data {
+        AisMessageData::BaseStationReport { utc, position_accuracy, position_fix_type, .. } => {
+            let expected = AisUtcTime { year: 2024, month: 3, day: 15, hour: 12, minute: 30, second: 45 };
+            assert_eq!(utc, Some(expected));
+            assert!(position_accuracy);
+            assert_eq!(position_fix_type, 7);
+        }
+        other => panic!("Expected base station report, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_sar_aircraft_report() {
+    let decoded = decode("!AIVDM,1,1,,A,91b55wi;j>OS@OhQAC062Ch00000,0*3A");
+    assert_eq!(decoded.mmsi, Some(111232511));
+    assert_position(&decoded.data, 58.144, -6.27884);
+    match decoded.data {
+        AisMessageData::SarAircraftReport { altitude, speed_over_ground, course_over_ground, timestamp, .. } => {
+            assert_eq!(altitude, Some(303));
+            assert_eq!(speed_over_ground, Some(142.0));
+            assert_eq!(course_over_ground, Some(154.5));
+            assert_eq!(timestamp, Some(15));
+        }
+        other => panic!("Expected SAR aircraft report, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_class_b_position_not_available() {
+    let decoded = decode("!AIVDM,1,1,,A,B3HOI:00Nk?8mP=18D3Q3wv7h000,0*73");
+    assert_eq!(decoded.message_id, 18);
+    assert_eq!(decoded.data.position(), None);
+    match decoded.data {
+        AisMessageData::ClassBPositionReport { speed_over_ground, latitude, longitude, course_over_ground, true_heading, timestamp, .. } => {
+            assert_eq!(speed_over_ground, Some(12.3));
+            assert_eq!(latitude, None);
+            assert_eq!(longitude, None);
+            assert_eq!(course_over_ground, None);
+            assert_eq!(true_heading, None);
+            assert_eq!(timestamp, None);
+        }
+        other => panic!("Expected class B position report, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_extended_class_b_position_report() {
+    let decoded = decode("!AIVDM,1,1,,A,C3HOI:00=p59Uh7K::2a6760V:304T::l:0000000000BPP210R0,0*3C");
+    assert_eq!(decoded.mmsi, Some(227006760));
+    assert_position(&decoded.data, 51.9, 4.5);
+    match decoded.data {
+        AisMessageData::ExtendedClassBPositionReport { speed_over_ground, course_over_ground, true_heading, vessel_name, ship_type, dimensions, .. } => {
+            assert_eq!(speed_over_ground, Some(5.5));
+            assert_eq!(course_over_ground, Some(270.5));
+            assert_eq!(true_heading, Some(270));
+            assert_eq!(vessel_name.as_deref(), Some("SEA BREEZE"));
+            assert_eq!(ship_type, Some(37));
+            assert_eq!(dimensions, Some(AisDimensions { length: 12, width: 3 }));
+        }
+        other => panic!("Expected extended class B report, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_aid_to_navigation() {
+    let decoded = decode("!AIVDM,1,1,,A,E>jCKPW7;h40a17ba@1:WdP0000Omfad=moD000003i010,4*71");
+    assert_eq!(decoded.mmsi, Some(992271234));
+    assert_position(&decoded.data, 48.38, -4.4875);
+    match decoded.data {
+        AisMessageData::AidToNavigation { aid_type, name, timestamp, off_position, virtual_aid, dimensions, .. } => {
+            assert_eq!(aid_type, 14);
+            assert_eq!(name.as_deref(), Some("NW HARBOUR BUOY"));
+            assert_eq!(timestamp, Some(34));
+            assert!(!off_position);
+            assert!(virtual_aid);
+            assert_eq!(dimensions, None);
+        }
+        other => panic!("Expected aid to navigation, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_static_data_report_parts() {
+    let part_a = decode("!AIVDM,1,1,,A,H3HOI:1<D6098DE`D0000000000,2*40");
+    assert_eq!(part_a.data, AisMessageData::StaticDataReportA { vessel_name: Some("SEA BREEZE".to_string()) });
+
+    let part_b = decode("!AIVDM,1,1,,A,H3HOI:4U13=80CB678ijk0104210,0*19");
+    match part_b.data {
+        AisMessageData::StaticDataReportB { ship_type, vendor_id, call_sign, dimensions, mothership_mmsi } => {
+            assert_eq!(ship_type, Some(37));
+            assert_eq!(vendor_id.as_deref(), Some("ACM"));
+            assert_eq!(call_sign.as_deref(), Some("FGH123"));
+            assert_eq!(dimensions, Some(AisDimensions { length: 12, width: 3 }));
+            assert_eq!(mothership_mmsi, None);
+        }
+        other => panic!("Expected static data report part B, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_long_range_position() {
+    let decoded = decode("!AIVDM,1,1,,A,KkHOI:8?pHSNb65t,0*45");
+    assert_eq!(decoded.message_id, 27);
+    assert_position(&decoded.data, 47.5, -3.25);
+    match decoded.data {
+        AisMessageData::LongRangePosition { position_accuracy, speed_over_ground, course_over_ground, gnss_position_current, .. } => {
+            assert!(position_accuracy);
+            assert_eq!(speed_over_ground, Some(12.0));
+            assert_eq!(course_over_ground, Some(95.0));
+            assert!(gnss_position_current);
+        }
+        other => panic!("Expected long range position, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_binary_messages() {
+    let broadcast = decode("!AIVDM,1,1,,A,83HOI:00Grg=,0*36");
+    assert_eq!(
+        broadcast.data,
+        AisMessageData::BinaryMessage {
+            destination_mmsi: None,
+            retransmitted: false,
+            dac: 1,
+            fi: 31,
+            data: vec![0xAB, 0xCD],
+            data_bits: 16,
+        }
+    );
+
+    let addressed = decode("!AIVDM,1,1,,A,63HOI:40WGQt>dch,0*37");
+    match addressed.data {
+        AisMessageData::BinaryMessage { destination_mmsi, dac, fi, data, data_bits, .. } => {
+            assert_eq!(destination_mmsi, Some(2579999));
+            assert_eq!(dac, 235);
+            assert_eq!(fi, 10);
+            assert_eq!(data, vec![0xF0]);
+            assert_eq!(data_bits, 8);
+        }
+        other => panic!("Expected binary message, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_truncated_message_is_rejected() {
+    let message = NmeaParser::parse_message("!AIVDM,1,1,,A,B3HOI:00Nk?8,0*01").expect("Should parse");
+    let mut ais = AisMessage::from_nmea(&message).expect("Should parse AIS");
+    assert!(ais.decode().is_err());
+}
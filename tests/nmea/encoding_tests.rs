@@ -0,0 +1,144 @@
+//! Tests de l'encodage des phrases NMEA et AIS
+
+use aftn::NmeaParser;
+use aftn::nmea::{
+    AisAssembler, AisDecodedData, AisDimensions, AisEta, AisMessage, AisMessageData, GgaMessage,
+    RmcMessage, SentenceFormatter, TalkerId,
+};
+
+fn decode_single(sentence: &str) -> AisDecodedData {
+    let message = NmeaParser::parse_message(sentence).expect("Encoded sentence should parse");
+    assert!(message.validate().is_ok());
+    let mut ais = AisMessage::from_nmea(&message).expect("Should parse AIS");
+    ais.decode().expect("Should decode");
+    ais.decoded_data.expect("Decoded data")
+}
+
+#[test]
+fn test_encode_position_report_round_trip() {
+    let data = AisDecodedData {
+        message_id: 1,
+        mmsi: Some(227006760),
+        data: AisMessageData::PositionReport {
+            navigation_status: Some(0),
+            rate_of_turn: Some(-12),
+            speed_over_ground: Some(123),
+            position_accuracy: Some(true),
+            longitude: Some(-900_000),
+            latitude: Some(28_500_000),
+            course_over_ground: Some(2705),
+            true_heading: Some(271),
+            timestamp: Some(42),
+        },
+    };
+
+    let sentences = data.to_sentences(SentenceFormatter::VDM, 'A', 0).expect("Should encode");
+    assert_eq!(sentences.len(), 1);
+    let text = sentences[0].serialize();
+    assert!(text.starts_with("!AIVDM,1,1,,A,"));
+    assert_eq!(text, sentences[0].raw);
+
+    assert_eq!(decode_single(&text), data);
+}
+
+#[test]
+fn test_encode_static_data_in_fragments() {
+    let data = AisDecodedData {
+        message_id: 5,
+        mmsi: Some(351759000),
+        data: AisMessageData::StaticData {
+            mmsi: 351759000,
+            call_sign: Some("3FOF8".to_string()),
+            vessel_name: Some("EVER DIADEM".to_string()),
+            ship_type: Some(70),
+            dimensions: Some(AisDimensions { length: 294, width: 32 }),
+            position_fix_type: Some(1),
+            eta: Some(AisEta { month: 5, day: 15, hour: 14, minute: 0 }),
+            draught: Some(122),
+            destination: Some("NEW YORK".to_string()),
+        },
+    };
+
+    let sentences = data.to_sentences(SentenceFormatter::VDM, 'B', 3).expect("Should encode");
+    assert_eq!(sentences.len(), 2);
+    let texts: Vec<String> = sentences.iter().map(|s| s.serialize()).collect();
+    assert!(texts[0].starts_with("!AIVDM,2,1,3,B,"));
+    assert!(texts[0].ends_with(&format!(",0*{}", sentences[0].checksum)));
+    assert!(texts[1].starts_with("!AIVDM,2,2,3,B,"));
+    assert!(texts[1].ends_with(&format!(",2*{}", sentences[1].checksum)), "424 bits need 2 fill bits");
+
+    let mut assembler = AisAssembler::new();
+    assert!(assembler.push(&NmeaParser::parse_message(&texts[0]).unwrap()).unwrap().is_none());
+    let message = assembler
+        .push(&NmeaParser::parse_message(&texts[1]).unwrap())
+        .unwrap()
+        .expect("Complete message");
+    assert_eq!(message.decoded_data, Some(data));
+}
+
+#[test]
+fn test_encode_class_b_round_trip() {
+    let position = AisDecodedData {
+        message_id: 18,
+        mmsi: Some(227006760),
+        data: AisMessageData::ClassBPositionReport {
+            speed_over_ground: Some(5.5),
+            position_accuracy: false,
+            longitude: Some(4.5),
+            latitude: Some(51.9),
+            course_over_ground: None,
+            true_heading: None,
+            timestamp: Some(12),
+        },
+    };
+    let sentence = position.to_sentences(SentenceFormatter::VDM, 'A', 0).unwrap()[0].serialize();
+    assert_eq!(decode_single(&sentence), position);
+
+    // Position du navire propre : phrase AIVDO
+    let sentence = position.to_sentences(SentenceFormatter::VDO, 'A', 0).unwrap()[0].serialize();
+    assert!(sentence.starts_with("!AIVDO,1,1,,A,"));
+    assert_eq!(decode_single(&sentence), position);
+    assert!(position.to_sentences(SentenceFormatter::GGA, 'A', 0).is_err());
+
+    let part_b = AisDecodedData {
+        message_id: 24,
+        mmsi: Some(227006760),
+        data: AisMessageData::StaticDataReportB {
+            ship_type: Some(37),
+            vendor_id: Some("ACM".to_string()),
+            call_sign: Some("FGH123".to_string()),
+            dimensions: Some(AisDimensions { length: 12, width: 3 }),
+            mothership_mmsi: None,
+        },
+    };
+    let sentence = part_b.to_sentences(SentenceFormatter::VDM, 'A', 0).unwrap()[0].serialize();
+    assert_eq!(decode_single(&sentence), part_b);
+}
+
+#[test]
+fn test_encode_unsupported_type() {
+    let data = AisDecodedData {
+        message_id: 8,
+        mmsi: Some(227006760),
+        data: AisMessageData::Generic(vec![]),
+    };
+    assert!(data.encode_payload().is_err());
+}
+
+#[test]
+fn test_encode_gga_and_rmc() {
+    let input = "$GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*47";
+    let gga = GgaMessage::from_nmea(&NmeaParser::parse_message(input).unwrap()).unwrap();
+    let encoded = gga.to_nmea(TalkerId::GN).serialize();
+    assert!(encoded.starts_with("$GNGGA,123519,4807.0380,N,01131.0000,E,1,08,0.9,545.4,M,46.9,M,,"));
+    let reparsed = NmeaParser::parse_message(&encoded).expect("Encoded GGA should parse");
+    assert!(reparsed.validate().is_ok());
+    assert_eq!(GgaMessage::from_nmea(&reparsed).unwrap(), gga);
+
+    let input = "$GPRMC,123519,A,4807.038,N,01131.000,E,022.4,084.4,230394,003.1,W*6A";
+    let rmc = RmcMessage::from_nmea(&NmeaParser::parse_message(input).unwrap()).unwrap();
+    let encoded = rmc.to_nmea(TalkerId::GP).serialize();
+    let reparsed = NmeaParser::parse_message(&encoded).expect("Encoded RMC should parse");
+    assert!(reparsed.validate().is_ok());
+    assert_eq!(RmcMessage::from_nmea(&reparsed).unwrap(), rmc);
+}
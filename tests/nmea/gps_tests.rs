@@ -1,6 +1,8 @@
 //! Tests pour les messages GPS NMEA
 
 use aftn::{NmeaParser, GgaMessage, RmcMessage, GsaMessage, VtgMessage};
+use aftn::nmea::{GsvMessage, ZdaMessage, GllMessage, GstMessage};
+use aftn::nmea::{NmeaSentence, SentenceFormatter, TalkerId};
 
 #[test]
 fn test_parse_gga_message() {
@@ -87,3 +89,140 @@ fn test_rmc_validation() {
     assert!(result.is_ok(), "Valid RMC message should pass validation");
 }
 
+
+#[test]
+fn test_multi_constellation_talkers() {
+    let input = "$GNGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*59";
+    let message = NmeaParser::parse_message(input).expect("Should parse");
+    assert_eq!(message.message_type.talker(), Some(&TalkerId::GN));
+    assert_eq!(message.message_type.formatter(), Some(&SentenceFormatter::GGA));
+    assert!(message.validate().is_ok());
+    match message.decode().expect("Should decode") {
+        NmeaSentence::Gga(gga) => assert_eq!(gga.satellites, Some(8)),
+        other => panic!("Expected GGA, got {:?}", other),
+    }
+
+    // Récepteur multi-constellation en RTK fixe : plus de 12 satellites suivis
+    let input = "$GNGGA,123519,4807.038,N,01131.000,E,4,18,0.7,545.4,M,46.9,M,1.0,0000*7C";
+    let message = NmeaParser::parse_message(input).expect("Should parse");
+    assert!(message.validate().is_ok());
+    match message.decode().expect("Should decode") {
+        NmeaSentence::Gga(gga) => {
+            assert_eq!(gga.quality, Some(4));
+            assert_eq!(gga.satellites, Some(18));
+        }
+        other => panic!("Expected GGA, got {:?}", other),
+    }
+
+    // La validation s'applique quel que soit l'émetteur
+    let input = "$GNGGA,123519,9907.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*55";
+    let message = NmeaParser::parse_message(input).expect("Should parse");
+    assert!(message.validate().is_err());
+
+    let input = "$BDGSA,A,3,04,05,,09,12,,,24,,,,,2.5,1.3,2.1*28";
+    let message = NmeaParser::parse_message(input).expect("Should parse");
+    assert_eq!(message.message_type.talker().and_then(|t| t.constellation()), Some("BeiDou"));
+    assert!(matches!(message.decode(), Ok(NmeaSentence::Gsa(_))));
+}
+
+#[test]
+fn test_proprietary_sentence() {
+    let input = "$PGRME,15.0,M,45.0,M,25.0,M*1C";
+    let message = NmeaParser::parse_message(input).expect("Should parse");
+    assert!(message.message_type.is_proprietary());
+    assert_eq!(message.message_type.manufacturer(), Some("GRM"));
+    assert!(message.validate().is_ok());
+    match message.decode().expect("Should decode") {
+        NmeaSentence::Proprietary { manufacturer, sentence, fields } => {
+            assert_eq!(manufacturer, "GRM");
+            assert_eq!(sentence, "E");
+            assert_eq!(fields[0], "15.0");
+        }
+        other => panic!("Expected proprietary sentence, got {:?}", other),
+    }
+    assert_eq!(message.serialize(), input);
+}
+
+#[test]
+fn test_gsv_cycle() {
+    let inputs = [
+        "$GPGSV,3,1,11,03,03,111,00,04,15,270,00,06,01,010,00,13,06,292,00*74",
+        "$GPGSV,3,2,11,14,25,170,00,16,57,208,39,18,67,296,40,19,40,246,00*74",
+        "$GPGSV,3,3,11,22,42,067,42,24,14,311,43,27,05,244,00*4D",
+    ];
+    let parts: Vec<GsvMessage> = inputs
+        .iter()
+        .map(|input| {
+            let message = NmeaParser::parse_message(input).expect("Should parse");
+            assert!(message.validate().is_ok(), "{} should be valid", input);
+            GsvMessage::from_nmea(&message).expect("Should parse GSV")
+        })
+        .collect();
+    assert_eq!(parts[0].satellites.len(), 4);
+    assert_eq!(parts[2].satellites.len(), 3);
+    assert_eq!(parts[1].satellites[1].prn, 16);
+    assert_eq!(parts[1].satellites[1].elevation, Some(57));
+    assert_eq!(parts[1].satellites[1].azimuth, Some(208));
+    assert_eq!(parts[1].satellites[1].snr, Some(39));
+
+    let view = GsvMessage::combine(&parts).expect("Complete cycle");
+    assert_eq!(view.satellites_in_view, Some(11));
+    assert_eq!(view.satellites.len(), 11);
+
+    // Cycle incomplet ou désordonné
+    assert!(GsvMessage::combine(&parts[..2]).is_err());
+    assert!(GsvMessage::combine(&[parts[1].clone(), parts[0].clone(), parts[2].clone()]).is_err());
+}
+
+#[test]
+fn test_gsv_validation() {
+    let input = "$GLGSV,1,1,02,65,45,120,30,66,95,200,31*69";
+    let message = NmeaParser::parse_message(input).expect("Should parse");
+    assert!(message.validate().is_err(), "Elevation above 90 degrees should be rejected");
+}
+
+#[test]
+fn test_parse_zda_message() {
+    let input = "$GPZDA,201530.00,04,07,2002,-05,30*4B";
+    let message = NmeaParser::parse_message(input).expect("Should parse");
+    assert!(message.validate().is_ok());
+
+    let zda = ZdaMessage::from_nmea(&message).expect("Should parse ZDA");
+    assert_eq!(zda.year, Some(2002));
+    assert_eq!(zda.utc_datetime().unwrap().to_string(), "2002-07-04T20:15:00Z");
+    assert_eq!(zda.unix_timestamp(), Some(1025813730.0));
+    assert_eq!(zda.utc_offset_minutes(), Some(-330));
+    // Zone -05:30 : l'heure locale est en avance de 5h30 sur UTC (Inde)
+    assert_eq!(zda.local_datetime().unwrap().to_string(), "2002-07-05T01:45:00Z");
+
+    // Zone +02 : l'heure locale est en retard de 2 heures sur UTC
+    let input = "$GPZDA,201530.00,04,07,2002,02,00*62";
+    let message = NmeaParser::parse_message(input).expect("Should parse");
+    let zda = ZdaMessage::from_nmea(&message).expect("Should parse ZDA");
+    assert_eq!(zda.utc_offset_minutes(), Some(120));
+    assert_eq!(zda.local_datetime().unwrap().to_string(), "2002-07-04T18:15:00Z");
+
+    // 29 février d'une année non bissextile
+    let input = "$GPZDA,201530.00,29,02,2023,00,00*69";
+    let message = NmeaParser::parse_message(input).expect("Should parse");
+    assert!(message.validate().is_err());
+}
+
+#[test]
+fn test_parse_gll_and_gst_messages() {
+    let input = "$GPGLL,4916.45,N,12311.12,W,225444,A,A*5C";
+    let message = NmeaParser::parse_message(input).expect("Should parse");
+    assert!(message.validate().is_ok());
+    let gll = GllMessage::from_nmea(&message).expect("Should parse GLL");
+    assert_eq!(gll.status, Some('A'));
+    assert!((gll.latitude_decimal().unwrap() - 49.2742).abs() < 0.001);
+    assert!((gll.longitude_decimal().unwrap() + 123.1853).abs() < 0.001);
+
+    let input = "$GPGST,172814.0,0.006,0.023,0.020,273.6,0.023,0.020,0.031*6A";
+    let message = NmeaParser::parse_message(input).expect("Should parse");
+    assert!(message.validate().is_ok());
+    let gst = GstMessage::from_nmea(&message).expect("Should parse GST");
+    assert_eq!(gst.rms, Some(0.006));
+    assert_eq!(gst.orientation, Some(273.6));
+    assert_eq!(gst.altitude_error, Some(0.031));
+}
@@ -0,0 +1,62 @@
+//! Tests pour les phrases de navigation NMEA (WPL, BOD, XTE, RMB, HDT, HDG)
+
+use aftn::NmeaParser;
+use aftn::nmea::{NmeaSentence, WplMessage, BodMessage, XteMessage, RmbMessage, HdtMessage, HdgMessage};
+
+#[test]
+fn test_parse_wpl_and_bod_messages() {
+    let message = NmeaParser::parse_message("$GPWPL,4917.16,N,12310.64,W,003*65").expect("Should parse");
+    assert!(message.validate().is_ok());
+    let wpl = WplMessage::from_nmea(&message).expect("Should parse WPL");
+    assert_eq!(wpl.waypoint_id.as_deref(), Some("003"));
+    assert!((wpl.latitude_decimal().unwrap() - 49.286).abs() < 0.001);
+
+    let message = NmeaParser::parse_message("$GPBOD,099.3,T,105.6,M,POINTB,POINTA*45").expect("Should parse");
+    assert!(message.validate().is_ok());
+    let bod = BodMessage::from_nmea(&message).expect("Should parse BOD");
+    assert_eq!(bod.bearing_true, Some(99.3));
+    assert_eq!(bod.bearing_magnetic, Some(105.6));
+    assert_eq!(bod.destination.as_deref(), Some("POINTB"));
+    assert_eq!(bod.origin.as_deref(), Some("POINTA"));
+}
+
+#[test]
+fn test_parse_xte_and_rmb_messages() {
+    let message = NmeaParser::parse_message("$GPXTE,A,A,0.67,L,N*6F").expect("Should parse");
+    assert!(message.validate().is_ok());
+    let xte = XteMessage::from_nmea(&message).expect("Should parse XTE");
+    assert_eq!(xte.magnitude, Some(0.67));
+    assert_eq!(xte.steer_direction, Some('L'));
+
+    let input = "$GPRMB,A,0.66,L,003,004,4917.24,N,12309.57,W,001.3,052.5,000.5,V*20";
+    let message = NmeaParser::parse_message(input).expect("Should parse");
+    assert!(message.validate().is_ok());
+    let rmb = RmbMessage::from_nmea(&message).expect("Should parse RMB");
+    assert_eq!(rmb.origin.as_deref(), Some("003"));
+    assert_eq!(rmb.destination.as_deref(), Some("004"));
+    assert_eq!(rmb.range, Some(1.3));
+    assert_eq!(rmb.bearing, Some(52.5));
+    assert_eq!(rmb.arrival_status, Some('V'));
+    assert!(rmb.destination_longitude_decimal().unwrap() < 0.0);
+
+    let input = "$GPRMB,A,0.66,X,003,004,4917.24,N,12309.57,W,001.3,052.5,000.5,V*34";
+    let message = NmeaParser::parse_message(input).expect("Should parse");
+    assert!(message.validate().is_err(), "Steer direction must be L or R");
+}
+
+#[test]
+fn test_parse_heading_messages() {
+    let message = NmeaParser::parse_message("$HEHDT,274.07,T*19").expect("Should parse");
+    assert!(message.validate().is_ok());
+    assert_eq!(HdtMessage::from_nmea(&message).unwrap().heading, Some(274.07));
+    assert!(matches!(message.decode(), Ok(NmeaSentence::Hdt(_))));
+
+    let message = NmeaParser::parse_message("$HEHDT,374.07,T*18").expect("Should parse");
+    assert!(message.validate().is_err());
+
+    let message = NmeaParser::parse_message("$HCHDG,101.1,,,7.1,W*3C").expect("Should parse");
+    assert!(message.validate().is_ok());
+    let hdg = HdgMessage::from_nmea(&message).expect("Should parse HDG");
+    assert_eq!(hdg.variation, Some(7.1));
+    assert!((hdg.true_heading().unwrap() - 94.0).abs() < 1e-9);
+}
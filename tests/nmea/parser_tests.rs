@@ -1,6 +1,6 @@
 //! Tests pour le parser NMEA
 
-use aftn::{NmeaParser, NmeaError};
+use aftn::{LocatedError, NmeaParser, NmeaError};
 
 #[test]
 fn test_parse_valid_checksum() {
@@ -50,3 +50,30 @@ fn test_parse_with_exclamation() {
     }
 }
 
+#[test]
+fn test_syntax_error_location() {
+    // Checksum valide mais identifiant de phrase invalide
+    let input = "$GPGGA,1\u{7}2*7E";
+    let error = NmeaParser::parse_message(input).unwrap_err();
+    assert!(matches!(error, NmeaError::Syntax(_)));
+    assert_eq!(error.code(), "NMEA011");
+    assert_eq!(error.span().unwrap().column, 9);
+    assert_eq!(error.field(), Some(aftn::FieldRef::Index(1)));
+}
+
+#[test]
+fn test_lenient_repairs_checksum_and_spacing() {
+    // Checksum faux : erreur relevée mais message décodé
+    let result = NmeaParser::parse_lenient("$GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*00");
+    assert!(result.message.is_some());
+    assert_eq!(result.diagnostics.len(), 1);
+    assert_eq!(result.diagnostics[0].code, "NMEA003");
+
+    // Checksum absent et espaces autour des virgules : avertissements seulement
+    let result = NmeaParser::parse_lenient("$GPGGA,123519, 4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,");
+    let message = result.message.as_ref().expect("Sentence is repaired");
+    assert_eq!(message.checksum, "47");
+    assert!(!result.has_errors());
+    let codes: Vec<&str> = result.diagnostics.iter().map(|d| d.code.as_str()).collect();
+    assert_eq!(codes, vec!["NMEA101", "NMEA102"]);
+}
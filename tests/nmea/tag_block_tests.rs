@@ -0,0 +1,97 @@
+//! Tests des blocs TAG NMEA 4.x (IEC 61162-450)
+
+use aftn::NmeaParser;
+use aftn::nmea::{AisAssembler, DropReason, NmeaError, TagGroup};
+
+const FIRST: &str = "!AIVDM,2,1,1,A,55?MbV02;H;s<HtKR20EHE:0@T4@Dn2222222216L961O5Gf0NSQEp6ClRp8,0*1C";
+const SECOND: &str = "!AIVDM,2,2,1,A,88888888880,2*25";
+
+#[test]
+fn test_parse_tag_block_fields() {
+    let input = "\\s:station1,c:1697000000*7E\\!AIVDM,1,1,,A,13HOI:0P0000VOHLCnHQKwvL05Ip,0*23";
+    let message = NmeaParser::parse_message(input).expect("Tagged sentence should parse");
+    assert_eq!(message.message_type.identifier(), "AIVDM");
+    assert_eq!(message.fields[4], "13HOI:0P0000VOHLCnHQKwvL05Ip");
+    assert_eq!(message.raw, input);
+
+    let tag = message.tag_block.as_ref().expect("TAG block");
+    assert_eq!(tag.source.as_deref(), Some("station1"));
+    assert_eq!(tag.unix_time, Some(1697000000));
+    assert_eq!(tag.group, None);
+
+    let tag = NmeaParser::parse_message(&format!("\\s:station1,d:shore,r:1500,t:test*07\\{}", SECOND))
+        .unwrap()
+        .tag_block
+        .unwrap();
+    assert_eq!(tag.destination.as_deref(), Some("shore"));
+    assert_eq!(tag.relative_time, Some(1500));
+    assert_eq!(tag.text.as_deref(), Some("test"));
+
+    let tag = NmeaParser::parse_message(&format!("\\g:1-2-73874,n:157036,s:r003669945,c:1241544035*4A\\{}", FIRST))
+        .unwrap()
+        .tag_block
+        .unwrap();
+    assert_eq!(tag.line_count, Some(157036));
+    assert_eq!(tag.group, Some(TagGroup { sentence: 1, total: 2, id: 73874 }));
+}
+
+#[test]
+fn test_tag_block_checksum_and_format() {
+    let bad_checksum = format!("\\s:station1,c:1697000000*00\\{}", SECOND);
+    assert!(matches!(
+        NmeaParser::parse_message(&bad_checksum),
+        Err(NmeaError::InvalidChecksum { expected, .. }) if expected == "7E"
+    ));
+    assert!(NmeaParser::parse_message(&format!("\\s:station1{}", SECOND)).is_err(), "Unterminated TAG block");
+    assert!(NmeaParser::parse_message(&format!("\\s:station1\\{}", SECOND)).is_err(), "Missing TAG checksum");
+
+    let lenient = NmeaParser::parse_lenient(&bad_checksum);
+    assert!(lenient.has_errors());
+    assert_eq!(lenient.message.unwrap().tag_block.unwrap().source.as_deref(), Some("station1"));
+}
+
+#[test]
+fn test_serialize_keeps_tag_block() {
+    let input = format!("\\s:station1,c:1697000000*7E\\{}", SECOND);
+    let message = NmeaParser::parse_message(&input).unwrap();
+    assert_eq!(message.serialize(), input);
+}
+
+#[test]
+fn test_assemble_with_tag_group() {
+    // Les deux phrases ne partagent que le groupe TAG : l'identifiant
+    // séquentiel du second fragment est différent
+    let first = NmeaParser::parse_message(&format!("\\g:1-2-73874,n:157036,s:r003669945,c:1241544035*4A\\{}", FIRST)).unwrap();
+    let second_text = "!AIVDM,2,2,7,A,88888888880,2*23";
+    let second = NmeaParser::parse_message(&format!("\\g:2-2-73874,n:157037*1D\\{}", second_text)).unwrap();
+
+    let mut assembler = AisAssembler::new();
+    assert!(assembler.push(&first).unwrap().is_none());
+    let message = assembler.push(&second).unwrap().expect("Complete message");
+    assert_eq!(message.decoded_data.unwrap().message_id, 5);
+    assert_eq!(message.tag_block.unwrap().source.as_deref(), Some("r003669945"));
+}
+
+#[test]
+fn test_assemble_separates_sources() {
+    let mut assembler = AisAssembler::new();
+    for station in ["\\s:station1,c:1697000000*7E\\", "\\s:station2,c:1697000001*7C\\"] {
+        let first = NmeaParser::parse_message(&format!("{}{}", station, FIRST)).unwrap();
+        assert!(assembler.push(&first).unwrap().is_none());
+    }
+    assert_eq!(assembler.pending(), 2);
+
+    for station in ["\\s:station1,c:1697000000*7E\\", "\\s:station2,c:1697000001*7C\\"] {
+        let second = NmeaParser::parse_message(&format!("{}{}", station, SECOND)).unwrap();
+        assert!(assembler.push(&second).unwrap().is_some());
+    }
+    assert!(assembler.take_dropped().is_empty());
+}
+
+#[test]
+fn test_assemble_rejects_inconsistent_tag_group() {
+    let mut assembler = AisAssembler::new();
+    let first = NmeaParser::parse_message(&format!("\\g:1-3-73874*60\\{}", FIRST)).unwrap();
+    assert!(assembler.push(&first).unwrap().is_none());
+    assert_eq!(assembler.take_dropped()[0].reason, DropReason::Inconsistent);
+}
@@ -2,7 +2,12 @@
 
 mod nmea {
     mod gps_tests;
+    mod navigation_tests;
     mod ais_tests;
+    mod ais_assembler_tests;
+    mod ais_types_tests;
+    mod encoding_tests;
+    mod tag_block_tests;
     mod parser_tests;
     mod validation_tests;
     mod dysfunctional_tests;
@@ -1,6 +1,6 @@
 //! Tests pour le parser SBS
 
-use aftn::{SbsParser, SbsMessageType, SbsError};
+use aftn::{LocatedError, SbsParser, SbsMessageType, SbsError};
 
 #[test]
 fn test_parse_identification_message() {
@@ -103,3 +103,12 @@ fn test_parse_invalid_message_type() {
     assert!(result.is_ok(), "Should accept generic message types");
 }
 
+#[test]
+fn test_syntax_error_location() {
+    let input = "  INVALID,1,2,3";
+    let error = SbsParser::parse_message(input).unwrap_err();
+    assert_eq!(error.code(), "SBS002");
+    // Position rapportée dans le texte d'origine, espaces de tête compris
+    assert_eq!(error.span().unwrap().start, 2);
+    assert!(error.render(input).contains("  |   ^"));
+}
@@ -0,0 +1,122 @@
+//! Tests des enregistrements BaseStation autres que MSG (SEL, ID, AIR, STA, CLK)
+
+use aftn::sbs::{SbsParser, SbsRecord, SbsStatus};
+use aftn::{SbsError, SbsMessageType};
+
+const SEL: &str = "SEL,,496,2286,4CA4E5,27215,2010/02/19,18:06:07.710,2010/02/19,18:06:07.710,RYR1427";
+const ID: &str = "ID,,496,7162,405637,27928,2010/02/19,18:06:07.115,2010/02/19,18:06:07.115,EZY691A";
+const AIR: &str = "AIR,,496,906,400705,27262,2010/02/19,17:58:12.644,2010/02/19,17:58:12.644";
+const STA: &str = "STA,,5,179,400AE7,10103,2008/11/28,14:58:51.153,2008/11/28,14:58:51.153,RM";
+const CLK: &str = "CLK,,496,-1,,-1,2010/02/19,18:18:19.036,2010/02/19,18:18:19.036";
+
+#[test]
+fn test_parse_each_record_type() {
+    match SbsParser::parse_record(SEL).unwrap() {
+        SbsRecord::Selection { header, callsign } => {
+            assert_eq!(header.session_id.as_deref(), Some("496"));
+            assert_eq!(header.aircraft_id.as_deref(), Some("2286"));
+            assert_eq!(header.hex_ident.as_deref(), Some("4CA4E5"));
+            assert_eq!(header.flight_id.as_deref(), Some("27215"));
+            assert_eq!(header.time_message_logged.as_deref(), Some("18:06:07.710"));
+            assert_eq!(callsign.as_deref(), Some("RYR1427"));
+        }
+        other => panic!("SEL expected, got {:?}", other),
+    }
+
+    let id = SbsParser::parse_record(ID).unwrap();
+    assert!(matches!(&id, SbsRecord::NewId { callsign: Some(callsign), .. } if callsign == "EZY691A"));
+    assert_eq!(id.hex_ident(), Some("405637"));
+
+    let air = SbsParser::parse_record(AIR).unwrap();
+    assert_eq!(air.record_type(), "AIR");
+    assert_eq!(air.hex_ident(), Some("400705"));
+
+    let sta = SbsParser::parse_record(STA).unwrap();
+    assert!(matches!(sta, SbsRecord::StatusChange { status: Some(SbsStatus::Remove), .. }));
+
+    let clk = SbsParser::parse_record(CLK).unwrap();
+    assert_eq!(clk.record_type(), "CLK");
+    assert_eq!(clk.hex_ident(), None);
+    assert_eq!(clk.header().unwrap().date_message_generated.as_deref(), Some("2010/02/19"));
+}
+
+#[test]
+fn test_status_codes() {
+    for (code, status) in [
+        ("PL", SbsStatus::PositionLost),
+        ("SL", SbsStatus::SignalLost),
+        ("RM", SbsStatus::Remove),
+        ("AD", SbsStatus::Delete),
+        ("OK", SbsStatus::Ok),
+    ] {
+        assert_eq!(SbsStatus::from_code(code), status);
+        assert_eq!(status.code(), code);
+    }
+    assert_eq!(SbsStatus::from_code("XX"), SbsStatus::Other("XX".to_string()));
+}
+
+#[test]
+fn test_parse_record_msg() {
+    let input = "MSG,3,145,29315,4CA2E6,27215,2015/02/05,14:53:22.734,2015/02/05,14:53:22.734,,37025,1035.0,295.6,51.4703,-0.4543,,,,,0";
+    match SbsParser::parse_record(input).unwrap() {
+        SbsRecord::Message(message) => assert_eq!(message.message_type, SbsMessageType::AirbornePosition),
+        other => panic!("MSG expected, got {:?}", other),
+    }
+    assert!(SbsParser::parse_record("FOO,,1,2,3").is_err());
+}
+
+#[test]
+fn test_validate_records() {
+    for input in [SEL, ID, AIR, STA, CLK] {
+        let record = SbsParser::parse_record(input).unwrap();
+        assert!(record.validate().is_ok(), "{} should be valid", input);
+    }
+
+    let unknown_status = SbsParser::parse_record(&STA.replace(",RM", ",XX")).unwrap();
+    assert!(matches!(unknown_status.validate(), Err(SbsError::InvalidFieldValue { .. })));
+
+    let missing_status = SbsParser::parse_record(&STA.replace(",RM", "")).unwrap();
+    assert!(matches!(missing_status.validate(), Err(SbsError::MissingField(_))));
+
+    let bad_address = SbsParser::parse_record(&AIR.replace("400705", "40070Z")).unwrap();
+    assert!(matches!(bad_address.validate(), Err(SbsError::InvalidIcaoAddress(_))));
+
+    let lenient = SbsParser::parse_record_lenient(&ID.replace(",EZY691A", ""));
+    assert!(lenient.message.is_some());
+    assert!(lenient.has_errors());
+}
+
+#[test]
+fn test_lenient_reports_every_record_defect() {
+    // ID avec une adresse OACI invalide et sans indicatif : deux problèmes
+    let input = ID.replace("405637", "40563Z").replace(",EZY691A", "");
+    let record = SbsParser::parse_record(&input).unwrap();
+    assert!(matches!(record.validate(), Err(SbsError::InvalidIcaoAddress(_))));
+
+    let lenient = SbsParser::parse_record_lenient(&input);
+    let codes: Vec<&str> = lenient.diagnostics.iter().map(|d| d.code.as_str()).collect();
+    assert_eq!(codes, vec!["SBS007", "SBS006"]);
+}
+
+#[test]
+fn test_serialize_round_trip() {
+    for input in [SEL, ID, AIR, STA, CLK] {
+        let record = SbsParser::parse_record(input).unwrap();
+        assert_eq!(record.serialize(), input);
+        assert_eq!(SbsParser::parse_record(&record.serialize()).unwrap(), record);
+    }
+}
+
+#[test]
+fn test_record_json_carries_type() {
+    let record = SbsParser::parse_record(STA).unwrap();
+    let json = serde_json::to_value(&record).unwrap();
+    assert_eq!(json["record_type"], "STA");
+    assert_eq!(json["status"], "Remove");
+    assert_eq!(serde_json::from_value::<SbsRecord>(json).unwrap(), record);
+
+    let message = SbsParser::parse_record("MSG,3,145,29315,4CA2E6,27215,2015/02/05,14:53:22.734,2015/02/05,14:53:22.734,,37025,1035.0,295.6,51.4703,-0.4543,,,,,0").unwrap();
+    let json = serde_json::to_value(&message).unwrap();
+    assert_eq!(json["record_type"], "MSG");
+    assert!(json.get("message_type").is_some(), "MSG fields stay at the top level");
+}
@@ -70,3 +70,15 @@ fn test_validate_missing_altitude() {
     }
 }
 
+
+#[test]
+fn test_lenient_collects_all_validation_errors() {
+    let input = "MSG,3,145,29315,4CA2E6,27215,2015/02/05,14:53:22.734,2015/02/05,14:53:22.734,BAW1425,200000,1035.0,295.6,95.0,-0.4543,,,,,0";
+    let result = SbsParser::parse_lenient(input);
+
+    assert!(result.message.is_some());
+    let codes: Vec<&str> = result.diagnostics.iter().map(|d| d.code.as_str()).collect();
+    assert_eq!(codes.len(), 2);
+    assert_eq!(codes[0], "SBS009");
+    assert!(SbsParser::parse_lenient("INVALID,3,145").message.is_none());
+}
@@ -7,5 +7,6 @@ mod sbs {
     mod dysfunctional_tests;
     mod large_dataset_tests;
     mod robustness_tests;
+    mod record_tests;
 }
 